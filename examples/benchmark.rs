@@ -2,27 +2,52 @@ use chrono::Utc;
 use clockwords::default_scanner;
 use std::time::Instant;
 
+/// Prose that passes the keyword prefilter in every language ("and then",
+/// "nach", "depuis", "hasta") but holds only a few time expressions.
+const PROSE_EN: &str = "We met on Monday and then talked about the plan for the next release. After the review, the team agreed that the work should be finished before the end of the sprint, and then the documentation would follow. I may go to the office tomorrow at 9am, but it depends on the train. Since the last meeting, nothing has changed, and until somebody decides, we keep waiting. The first draft was sent around in the morning and then the second one in the afternoon. ";
+const PROSE_DE: &str = "Wir haben uns am Montag getroffen und dann über den Plan für die nächste Version gesprochen. Nach der Besprechung war das Team einig, dass die Arbeit vor dem Ende des Sprints fertig sein soll, und ab dann folgt die Dokumentation. Ich gehe vielleicht morgen um 9 Uhr ins Büro, aber das hängt vom Zug ab. Seit dem letzten Treffen hat sich nichts geändert, und bis jemand entscheidet, warten wir. Der erste Entwurf wurde am Vormittag verschickt und dann der zweite am Nachmittag. ";
+const PROSE_FR: &str = "Nous nous sommes vus lundi et puis nous avons parlé du plan pour la prochaine version. Après la revue, l'équipe a convenu que le travail devait être fini avant la fin du sprint, et depuis la documentation suit. Je vais peut-être au bureau demain à 9h, mais cela dépend du train. Depuis la dernière réunion, rien n'a changé, et jusqu'à ce que quelqu'un décide, nous attendons. Le premier brouillon a été envoyé le matin et le second l'après-midi. ";
+const PROSE_ES: &str = "Nos vimos el lunes y luego hablamos del plan para la próxima versión. Después de la revisión, el equipo acordó que el trabajo debía terminarse antes del final del sprint, y desde entonces sigue la documentación. Quizás vaya a la oficina mañana a las 9, pero depende del tren. Desde la última reunión no ha cambiado nada, y hasta que alguien decida, esperamos. El primer borrador se envió por la mañana y el segundo por la tarde. ";
+
 fn main() {
-    let scanner = default_scanner();
     let now = Utc::now();
 
+    // Building a scanner compiles the rule sets of all four languages
+    let iterations = 10;
+    let start = Instant::now();
+    for _ in 0..iterations {
+        default_scanner();
+    }
+    let construction = start.elapsed() / iterations;
+
+    let scanner = default_scanner();
+
     // Test cases matching the README scenarios
     let scenarios = vec![
         (
             "No keywords (fast rejection)",
-            "This text has absolutely no time related words in it. It should be rejected very quickly.",
+            "This text has absolutely no time related words in it. It should be rejected very quickly.".to_string(),
             1_000_000, // higher iterations for very fast operations
         ),
+        (
+            "Numbers only (fast rejection)",
+            "Buy 12 eggs, 3 buns, 40 cups, 2 kettles, 7 forks, 25 pots, 9 jugs, 16 bowls, 4 pots of glue.".to_string(),
+            1_000_000,
+        ),
         (
             "Short sentence (1 match)",
-            "I will see you tomorrow at 5pm.",
+            "I will see you tomorrow at 5pm.".to_string(),
             100_000,
         ),
         (
             "Paragraph (multiple matches)",
-            "I saw him yesterday. He said he would come back in 2 days. Maybe last week was better.",
+            "I saw him yesterday. He said he would come back in 2 days. Maybe last week was better.".to_string(),
             100_000,
         ),
+        ("Long prose, English (~1.8 KB)", PROSE_EN.repeat(4), 1_000),
+        ("Long prose, German (~1.9 KB)", PROSE_DE.repeat(4), 1_000),
+        ("Long prose, French (~1.8 KB)", PROSE_FR.repeat(4), 1_000),
+        ("Long prose, Spanish (~1.8 KB)", PROSE_ES.repeat(4), 1_000),
     ];
 
     println!(
@@ -30,16 +55,22 @@ fn main() {
         "Scenario", "Avg Time (ns)", "Avg Time (µs)"
     );
     println!("{:-<35}-|-{:-<15}-|-{:-<15}", "", "", "");
+    println!(
+        "{:<35} | {:<15.2} | {:<15.4}",
+        "Building the default scanner",
+        construction.as_nanos() as f64,
+        construction.as_nanos() as f64 / 1000.0
+    );
 
     for (name, text, iterations) in scenarios {
         // Warmup
-        for _ in 0..1000 {
-            scanner.scan(text, now);
+        for _ in 0..iterations.min(1000) {
+            scanner.scan(&text, now);
         }
 
         let start = Instant::now();
         for _ in 0..iterations {
-            scanner.scan(text, now);
+            scanner.scan(&text, now);
         }
         let duration = start.elapsed();
         let avg_ns = duration.as_nanos() as f64 / iterations as f64;
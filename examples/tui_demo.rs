@@ -69,6 +69,7 @@ fn run_app(terminal: &mut Terminal<ratatui::backend::CrosstermBackend<Stdout>>)
     loop {
         terminal.draw(|f| ui(f, &input, &scanner, local_tz))?;
 
+        #[allow(clippy::collapsible_if)]
         if event::poll(Duration::from_millis(100))? {
             if let Event::Key(key) = event::read()? {
                 if key.kind == KeyEventKind::Press {
@@ -138,7 +139,7 @@ fn ui(f: &mut Frame, input: &str, scanner: &TimeExpressionScanner, tz: Tz) {
                 ),
             ]));
 
-            let resolved_str = match m.resolved {
+            let resolved_str = match &m.resolved {
                 ResolvedTime::Point(dt) => {
                     let local = dt.with_timezone(&tz);
                     format!("{}", local)
@@ -148,6 +149,24 @@ fn ui(f: &mut Frame, input: &str, scanner: &TimeExpressionScanner, tz: Tz) {
                     let local_end = end.with_timezone(&tz);
                     format!("{} — {}", local_start, local_end)
                 }
+                ResolvedTime::Duration(d) => {
+                    format!("{}h {:02}min", d.num_hours(), d.num_minutes() % 60)
+                }
+                ResolvedTime::Recurrence(r) => {
+                    let next: Vec<String> = r
+                        .occurrences(now, tz, chrono::Weekday::Mon)
+                        .take(3)
+                        .map(|dt| dt.with_timezone(&tz).to_string())
+                        .collect();
+                    format!("next: {}", next.join(", "))
+                }
+                ResolvedTime::Since(start) => {
+                    format!("{} —", start.with_timezone(&tz))
+                }
+                ResolvedTime::Until(end) => {
+                    format!("— {}", end.with_timezone(&tz))
+                }
+                other => format!("{:?}", other),
             };
             result_lines.push(Line::from(vec![
                 Span::raw("  Resolved: "),
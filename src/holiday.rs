@@ -0,0 +1,609 @@
+//! Public holidays, movable feasts and regional holiday calendars.
+//!
+//! The language parsers recognize holidays by name (`"Christmas"`,
+//! `"Ostermontag"`, `"la Toussaint"`, `"el Día de Reyes"`) and look up their
+//! dates through the [`HolidayCalendar`] set in
+//! [`ParserConfig::holidays`](crate::ParserConfig::holidays). Without a
+//! calendar every [`Holiday`] is recognized on the date given by its
+//! [`HolidayRule`].
+//!
+//! A calendar decides which holidays are recognized and can add locally
+//! defined ones, such as company holidays, that are matched by name in any
+//! language:
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use clockwords::holiday::{CustomCalendar, Germany, GermanState, HolidayRule};
+//! # use clockwords::ParserConfig;
+//! let calendar = CustomCalendar::extending(Germany::state(GermanState::Bayern))
+//!     .with_holiday("Betriebsausflug", HolidayRule::Fixed { month: 6, day: 12 });
+//! let config = ParserConfig {
+//!     holidays: Some(Arc::new(calendar)),
+//!     ..ParserConfig::default()
+//! };
+//! ```
+
+use std::fmt;
+use std::sync::Arc;
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc, Weekday};
+use chrono_tz::Tz;
+
+use crate::resolve::{self, nth_weekday_of_month};
+use crate::types::ParserConfig;
+
+/// A holiday or feast that the language parsers recognize by name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Holiday {
+    /// January 1.
+    NewYearsDay,
+    /// January 6: Epiphany, Heilige Drei Könige, Día de Reyes.
+    Epiphany,
+    /// Third Monday in January (US).
+    MartinLutherKingDay,
+    /// Third Monday in February (US).
+    PresidentsDay,
+    /// March 8 (Berlin, Mecklenburg-Vorpommern).
+    WomensDay,
+    /// Thursday before Easter: Maundy Thursday, Gründonnerstag, Jueves Santo.
+    MaundyThursday,
+    /// Friday before Easter.
+    GoodFriday,
+    /// Easter Sunday, computed by the Gregorian computus.
+    EasterSunday,
+    /// The day after Easter Sunday.
+    EasterMonday,
+    /// May 1: Labour Day, Tag der Arbeit, fête du Travail.
+    LabourDay,
+    /// May 8 (France).
+    VictoryInEuropeDay,
+    /// Last Monday in May (US).
+    MemorialDay,
+    /// 39 days after Easter.
+    Ascension,
+    /// 49 days after Easter: Pentecost, Pfingsten, Pentecôte.
+    Pentecost,
+    /// 50 days after Easter.
+    WhitMonday,
+    /// 60 days after Easter: Corpus Christi, Fronleichnam, Fête-Dieu.
+    CorpusChristi,
+    /// June 19 (US).
+    Juneteenth,
+    /// July 4 (US).
+    IndependenceDay,
+    /// July 14 (France).
+    BastilleDay,
+    /// August 15: Assumption, Mariä Himmelfahrt, Asunción.
+    Assumption,
+    /// First Monday in September (US).
+    LaborDay,
+    /// September 20 (Thuringia).
+    ChildrensDay,
+    /// October 3 (Germany).
+    GermanUnityDay,
+    /// Second Monday in October (US).
+    ColumbusDay,
+    /// October 12 (Spain).
+    HispanicDay,
+    /// October 31 (several German states).
+    ReformationDay,
+    /// November 1: All Saints, Allerheiligen, Toussaint, Todos los Santos.
+    AllSaints,
+    /// November 11: Veterans Day (US), Armistice (France).
+    ArmisticeDay,
+    /// Wednesday before November 23 (Saxony).
+    RepentanceDay,
+    /// Fourth Thursday in November (US).
+    Thanksgiving,
+    /// December 6 (Spain).
+    ConstitutionDay,
+    /// December 8 (Spain).
+    ImmaculateConception,
+    /// December 24.
+    ChristmasEve,
+    /// December 25.
+    ChristmasDay,
+    /// December 26: Boxing Day, zweiter Weihnachtstag, Saint-Étienne.
+    BoxingDay,
+    /// December 31.
+    NewYearsEve,
+}
+
+/// How the date of a holiday is found in a given year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HolidayRule {
+    /// The same month and day every year.
+    Fixed { month: u32, day: u32 },
+
+    /// A number of days after Easter Sunday (negative for days before).
+    Easter(i64),
+
+    /// The `n`th weekday of a month: 1 = first, ..., -1 = last.
+    NthWeekday {
+        month: u32,
+        weekday: Weekday,
+        n: i32,
+    },
+
+    /// The last `weekday` strictly before a month and day.
+    WeekdayBefore {
+        month: u32,
+        day: u32,
+        weekday: Weekday,
+    },
+}
+
+impl HolidayRule {
+    /// The date this rule gives in `year`, or `None` if it does not exist.
+    pub fn date(self, year: i32) -> Option<NaiveDate> {
+        match self {
+            HolidayRule::Fixed { month, day } => NaiveDate::from_ymd_opt(year, month, day),
+            HolidayRule::Easter(days) => {
+                easter_sunday(year)?.checked_add_signed(Duration::try_days(days)?)
+            }
+            HolidayRule::NthWeekday { month, weekday, n } => {
+                nth_weekday_of_month(year, month, weekday, n)
+            }
+            HolidayRule::WeekdayBefore {
+                month,
+                day,
+                weekday,
+            } => {
+                let limit = NaiveDate::from_ymd_opt(year, month, day)?;
+                let back = (limit.weekday().num_days_from_monday() + 6
+                    - weekday.num_days_from_monday())
+                    % 7
+                    + 1;
+                limit.checked_sub_signed(Duration::days(i64::from(back)))
+            }
+        }
+    }
+}
+
+impl Holiday {
+    /// The rule giving this holiday's date.
+    pub fn rule(self) -> HolidayRule {
+        use HolidayRule::{Easter, Fixed, NthWeekday, WeekdayBefore};
+        match self {
+            Holiday::NewYearsDay => Fixed { month: 1, day: 1 },
+            Holiday::Epiphany => Fixed { month: 1, day: 6 },
+            Holiday::MartinLutherKingDay => NthWeekday {
+                month: 1,
+                weekday: Weekday::Mon,
+                n: 3,
+            },
+            Holiday::PresidentsDay => NthWeekday {
+                month: 2,
+                weekday: Weekday::Mon,
+                n: 3,
+            },
+            Holiday::WomensDay => Fixed { month: 3, day: 8 },
+            Holiday::MaundyThursday => Easter(-3),
+            Holiday::GoodFriday => Easter(-2),
+            Holiday::EasterSunday => Easter(0),
+            Holiday::EasterMonday => Easter(1),
+            Holiday::LabourDay => Fixed { month: 5, day: 1 },
+            Holiday::VictoryInEuropeDay => Fixed { month: 5, day: 8 },
+            Holiday::MemorialDay => NthWeekday {
+                month: 5,
+                weekday: Weekday::Mon,
+                n: -1,
+            },
+            Holiday::Ascension => Easter(39),
+            Holiday::Pentecost => Easter(49),
+            Holiday::WhitMonday => Easter(50),
+            Holiday::CorpusChristi => Easter(60),
+            Holiday::Juneteenth => Fixed { month: 6, day: 19 },
+            Holiday::IndependenceDay => Fixed { month: 7, day: 4 },
+            Holiday::BastilleDay => Fixed { month: 7, day: 14 },
+            Holiday::Assumption => Fixed { month: 8, day: 15 },
+            Holiday::LaborDay => NthWeekday {
+                month: 9,
+                weekday: Weekday::Mon,
+                n: 1,
+            },
+            Holiday::ChildrensDay => Fixed { month: 9, day: 20 },
+            Holiday::GermanUnityDay => Fixed { month: 10, day: 3 },
+            Holiday::ColumbusDay => NthWeekday {
+                month: 10,
+                weekday: Weekday::Mon,
+                n: 2,
+            },
+            Holiday::HispanicDay => Fixed { month: 10, day: 12 },
+            Holiday::ReformationDay => Fixed { month: 10, day: 31 },
+            Holiday::AllSaints => Fixed { month: 11, day: 1 },
+            Holiday::ArmisticeDay => Fixed { month: 11, day: 11 },
+            Holiday::RepentanceDay => WeekdayBefore {
+                month: 11,
+                day: 23,
+                weekday: Weekday::Wed,
+            },
+            Holiday::Thanksgiving => NthWeekday {
+                month: 11,
+                weekday: Weekday::Thu,
+                n: 4,
+            },
+            Holiday::ConstitutionDay => Fixed { month: 12, day: 6 },
+            Holiday::ImmaculateConception => Fixed { month: 12, day: 8 },
+            Holiday::ChristmasEve => Fixed { month: 12, day: 24 },
+            Holiday::ChristmasDay => Fixed { month: 12, day: 25 },
+            Holiday::BoxingDay => Fixed { month: 12, day: 26 },
+            Holiday::NewYearsEve => Fixed { month: 12, day: 31 },
+        }
+    }
+}
+
+/// Easter Sunday in the Gregorian calendar (anonymous Gregorian computus).
+///
+/// Returns `None` for years before 1583 or outside chrono's range.
+pub fn easter_sunday(year: i32) -> Option<NaiveDate> {
+    if year < 1583 {
+        return None;
+    }
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, u32::try_from(month).ok()?, u32::try_from(day).ok()?)
+}
+
+/// A holiday defined outside the built-in list, such as a company holiday.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomHoliday {
+    /// The name the holiday is written as. Matched as a whole word,
+    /// ignoring case, in any language.
+    pub name: String,
+
+    /// How the date is found in a given year.
+    pub rule: HolidayRule,
+}
+
+/// The holidays of a region, used to resolve holiday names.
+pub trait HolidayCalendar: fmt::Debug + Send + Sync {
+    /// Whether the calendar observes `holiday`.
+    ///
+    /// Names of holidays it does not observe are not recognized.
+    fn observes(&self, holiday: Holiday) -> bool;
+
+    /// Date of `holiday` in `year`, or `None` if the calendar does not observe it.
+    ///
+    /// Defaults to the date given by [`Holiday::rule`].
+    fn date(&self, holiday: Holiday, year: i32) -> Option<NaiveDate> {
+        if self.observes(holiday) {
+            holiday.rule().date(year)
+        } else {
+            None
+        }
+    }
+
+    /// Locally defined holidays, recognized by name in any language.
+    fn custom_holidays(&self) -> &[CustomHoliday] {
+        &[]
+    }
+}
+
+/// Date of `holiday` in `year` under `calendar`, or under its own rule
+/// when no calendar is configured.
+pub fn holiday_date(
+    holiday: Holiday,
+    year: i32,
+    calendar: Option<&dyn HolidayCalendar>,
+) -> Option<NaiveDate> {
+    match calendar {
+        Some(calendar) => calendar.date(holiday, year),
+        None => holiday.rule().date(year),
+    }
+}
+
+/// Resolve a holiday to midnight of its day under the configured calendar.
+///
+/// When `year` is `None`, the occurrence is picked by
+/// [`ParserConfig::year_bias`]. Returns `None` if the calendar does not
+/// observe the holiday.
+pub fn resolve_holiday(
+    holiday: Holiday,
+    year: Option<i32>,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<DateTime<Utc>> {
+    let calendar = config.holidays.as_deref();
+    resolve::resolve_yearly(
+        |y| holiday_date(holiday, y, calendar),
+        year,
+        now,
+        tz,
+        config.year_bias,
+    )
+}
+
+/// Pattern alternative for the names of custom holidays (group `custom`),
+/// for a language to add to its own holiday names, or `None` if there are
+/// none.
+///
+/// Longer names come first, so that "Company Day Off" is not cut short by
+/// "Company Day".
+pub fn custom_name_pattern(names: &[&str]) -> Option<String> {
+    if names.is_empty() {
+        return None;
+    }
+    let mut names = names.to_vec();
+    names.sort_by_key(|name| std::cmp::Reverse(name.len()));
+    let names: Vec<String> = names
+        .iter()
+        .map(|name| regex::escape(name).replace(' ', r"\s+"))
+        .collect();
+    Some(format!("(?P<custom>{})", names.join("|")))
+}
+
+/// Resolve a custom holiday of the configured calendar, found by its name
+/// as written, to midnight of its day.
+///
+/// When `year` is `None`, the occurrence is picked by
+/// [`ParserConfig::year_bias`].
+pub fn resolve_custom_holiday(
+    name: &str,
+    year: Option<i32>,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<DateTime<Utc>> {
+    let words = |s: &str| {
+        s.split_whitespace()
+            .map(str::to_lowercase)
+            .collect::<Vec<_>>()
+    };
+    let holiday = config
+        .holidays
+        .as_deref()?
+        .custom_holidays()
+        .iter()
+        .find(|h| words(&h.name) == words(name))?;
+    resolve::resolve_yearly(|y| holiday.rule.date(y), year, now, tz, config.year_bias)
+}
+
+/// Normalize a holiday name for lookup: lowercase ASCII letters only, with
+/// accents removed (`"Mariä Himmelfahrt"` becomes `"mariahimmelfahrt"`).
+pub fn name_key(s: &str) -> String {
+    s.to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            'à' | 'á' | 'â' | 'ä' => Some('a'),
+            'ç' => Some('c'),
+            'è' | 'é' | 'ê' | 'ë' => Some('e'),
+            'í' | 'î' | 'ï' => Some('i'),
+            'ñ' => Some('n'),
+            'ó' | 'ô' | 'ö' => Some('o'),
+            'ú' | 'û' | 'ü' => Some('u'),
+            'ß' => Some('s'),
+            c if c.is_ascii_alphabetic() => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Feasts recognized in every regional calendar, public holiday or not.
+fn is_common_feast(holiday: Holiday) -> bool {
+    matches!(
+        holiday,
+        Holiday::NewYearsDay
+            | Holiday::GoodFriday
+            | Holiday::EasterSunday
+            | Holiday::EasterMonday
+            | Holiday::Pentecost
+            | Holiday::ChristmasEve
+            | Holiday::ChristmasDay
+            | Holiday::NewYearsEve
+    )
+}
+
+/// Federal holidays of the United States, plus the common feasts.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UnitedStates;
+
+impl HolidayCalendar for UnitedStates {
+    fn observes(&self, holiday: Holiday) -> bool {
+        is_common_feast(holiday)
+            || matches!(
+                holiday,
+                Holiday::MartinLutherKingDay
+                    | Holiday::PresidentsDay
+                    | Holiday::MemorialDay
+                    | Holiday::Juneteenth
+                    | Holiday::IndependenceDay
+                    | Holiday::LaborDay
+                    | Holiday::ColumbusDay
+                    | Holiday::ArmisticeDay
+                    | Holiday::Thanksgiving
+            )
+    }
+}
+
+/// A German state (Land), for holidays observed only in some of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GermanState {
+    BadenWuerttemberg,
+    Bayern,
+    Berlin,
+    Brandenburg,
+    Bremen,
+    Hamburg,
+    Hessen,
+    MecklenburgVorpommern,
+    Niedersachsen,
+    NordrheinWestfalen,
+    RheinlandPfalz,
+    Saarland,
+    Sachsen,
+    SachsenAnhalt,
+    SchleswigHolstein,
+    Thueringen,
+}
+
+/// Public holidays of Germany.
+///
+/// With a `state`, holidays observed only in some states are recognized if
+/// that state observes them; without one, every state's holidays are.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Germany {
+    /// The state whose holidays are observed, or `None` for all of them.
+    pub state: Option<GermanState>,
+}
+
+impl Germany {
+    /// The holidays of one German state.
+    pub fn state(state: GermanState) -> Self {
+        Self { state: Some(state) }
+    }
+}
+
+impl HolidayCalendar for Germany {
+    fn observes(&self, holiday: Holiday) -> bool {
+        use GermanState::*;
+        let states: &[GermanState] = match holiday {
+            Holiday::Epiphany => &[BadenWuerttemberg, Bayern, SachsenAnhalt],
+            Holiday::WomensDay => &[Berlin, MecklenburgVorpommern],
+            Holiday::CorpusChristi => &[
+                BadenWuerttemberg,
+                Bayern,
+                Hessen,
+                NordrheinWestfalen,
+                RheinlandPfalz,
+                Saarland,
+            ],
+            Holiday::Assumption => &[Bayern, Saarland],
+            Holiday::ChildrensDay => &[Thueringen],
+            Holiday::ReformationDay => &[
+                Brandenburg,
+                Bremen,
+                Hamburg,
+                MecklenburgVorpommern,
+                Niedersachsen,
+                Sachsen,
+                SachsenAnhalt,
+                SchleswigHolstein,
+                Thueringen,
+            ],
+            Holiday::AllSaints => &[
+                BadenWuerttemberg,
+                Bayern,
+                NordrheinWestfalen,
+                RheinlandPfalz,
+                Saarland,
+            ],
+            Holiday::RepentanceDay => &[Sachsen],
+            _ => {
+                return is_common_feast(holiday)
+                    || matches!(
+                        holiday,
+                        Holiday::LabourDay
+                            | Holiday::Ascension
+                            | Holiday::WhitMonday
+                            | Holiday::GermanUnityDay
+                            | Holiday::BoxingDay
+                    );
+            }
+        };
+        self.state.is_none_or(|s| states.contains(&s))
+    }
+}
+
+/// Public holidays of metropolitan France, plus the common feasts.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct France;
+
+impl HolidayCalendar for France {
+    fn observes(&self, holiday: Holiday) -> bool {
+        is_common_feast(holiday)
+            || matches!(
+                holiday,
+                Holiday::LabourDay
+                    | Holiday::VictoryInEuropeDay
+                    | Holiday::Ascension
+                    | Holiday::WhitMonday
+                    | Holiday::BastilleDay
+                    | Holiday::Assumption
+                    | Holiday::AllSaints
+                    | Holiday::ArmisticeDay
+                    | Holiday::BoxingDay
+            )
+    }
+}
+
+/// National public holidays of Spain, plus the common feasts.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Spain;
+
+impl HolidayCalendar for Spain {
+    fn observes(&self, holiday: Holiday) -> bool {
+        is_common_feast(holiday)
+            || matches!(
+                holiday,
+                Holiday::Epiphany
+                    | Holiday::MaundyThursday
+                    | Holiday::LabourDay
+                    | Holiday::Assumption
+                    | Holiday::HispanicDay
+                    | Holiday::AllSaints
+                    | Holiday::ConstitutionDay
+                    | Holiday::ImmaculateConception
+            )
+    }
+}
+
+/// A calendar with locally defined holidays, optionally on top of another calendar.
+#[derive(Debug, Clone, Default)]
+pub struct CustomCalendar {
+    base: Option<Arc<dyn HolidayCalendar>>,
+    holidays: Vec<CustomHoliday>,
+}
+
+impl CustomCalendar {
+    /// A calendar containing only the custom holidays added to it.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A calendar observing everything `base` does, plus the custom holidays.
+    pub fn extending(base: impl HolidayCalendar + 'static) -> Self {
+        Self {
+            base: Some(Arc::new(base)),
+            holidays: Vec::new(),
+        }
+    }
+
+    /// Add a holiday recognized by `name`.
+    pub fn with_holiday(mut self, name: impl Into<String>, rule: HolidayRule) -> Self {
+        self.holidays.push(CustomHoliday {
+            name: name.into(),
+            rule,
+        });
+        self
+    }
+}
+
+impl HolidayCalendar for CustomCalendar {
+    fn observes(&self, holiday: Holiday) -> bool {
+        self.base.as_ref().is_some_and(|b| b.observes(holiday))
+    }
+
+    fn date(&self, holiday: Holiday, year: i32) -> Option<NaiveDate> {
+        self.base.as_ref()?.date(holiday, year)
+    }
+
+    fn custom_holidays(&self) -> &[CustomHoliday] {
+        &self.holidays
+    }
+}
@@ -0,0 +1,92 @@
+//! Language-independent handling of offsets counted from another expression.
+//!
+//! `"two days before Friday"`, `"a week from Monday"`, `"eine Woche nach dem
+//! 5. März"` or `"dos días después del lunes"` put a length of time in front
+//! of a day or time that is recognized on its own. Each language describes
+//! that offset in an [`OffsetRules`], and [`apply`] extends the matches of
+//! its other rules backwards over it.
+
+use chrono_tz::Tz;
+use regex::Regex;
+
+use crate::lang::words_before;
+use crate::resolve;
+use crate::types::{ExpressionKind, MatchConfidence, Span, TimeMatch, TimeUnit};
+
+/// The most words an offset takes ("dos días después de la").
+const OFFSET_WORDS: usize = 6;
+
+/// The offsets of a language.
+pub struct OffsetRules {
+    /// An offset, including its trailing whitespace, at the end of the text
+    /// before an anchor.
+    before_anchor: Regex,
+    /// Maps the captures of an offset to its unit and signed amount.
+    parse: fn(&regex::Captures) -> Option<(TimeUnit, i64)>,
+}
+
+impl OffsetRules {
+    /// Build the rules from a pattern of an amount, a unit and a preposition,
+    /// including the whitespace that follows it.
+    pub fn new(offset: &str, parse: fn(&regex::Captures) -> Option<(TimeUnit, i64)>) -> Self {
+        Self {
+            before_anchor: Regex::new(&format!(r"(?i)\b(?:{offset})$")).unwrap(),
+            parse,
+        }
+    }
+}
+
+/// Turn matches preceded by an offset into the day or time the offset lands on.
+///
+/// Matches inside an offset (`"two days"` read as a duration) are dropped.
+/// Anchors the offset cannot be counted from are left alone.
+pub fn apply(
+    text: &str,
+    mut matches: Vec<TimeMatch>,
+    rules: &OffsetRules,
+    tz: Tz,
+) -> Vec<TimeMatch> {
+    let mut offsets = Vec::new();
+    for i in 0..matches.len() {
+        let anchor = &matches[i];
+        if anchor.confidence != MatchConfidence::Complete {
+            continue;
+        }
+        let window = words_before(text, anchor.span.start, OFFSET_WORDS);
+        let Some(caps) = rules
+            .before_anchor
+            .captures(&text[window..anchor.span.start])
+        else {
+            continue;
+        };
+        let offset = Span::new(window + caps.get(0).unwrap().start(), anchor.span.start);
+        if matches
+            .iter()
+            .any(|m| m.span.overlaps(&offset) && !contains(&offset, &m.span))
+        {
+            continue;
+        }
+        let Some((unit, amount)) = (rules.parse)(&caps) else {
+            continue;
+        };
+        let Some(resolved) = resolve::resolve_anchored_offset(&anchor.resolved, unit, amount, tz)
+        else {
+            continue;
+        };
+        matches[i] = TimeMatch {
+            span: Span::new(offset.start, anchor.span.end),
+            resolved,
+            kind: ExpressionKind::AnchoredOffset,
+            spans_midnight: false,
+            ..anchor.clone()
+        };
+        offsets.push(offset);
+    }
+    matches.retain(|m| !offsets.iter().any(|o| contains(o, &m.span)));
+    matches
+}
+
+/// Whether `inner` lies within `outer`.
+fn contains(outer: &Span, inner: &Span) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
@@ -0,0 +1,126 @@
+//! Language-independent arithmetic for spoken clock readings.
+//!
+//! Spoken forms name an hour and an offset in minutes from it: `"quarter to
+//! five"` is five minus 15, `"halb vier"` is four minus 30 and `"trois heures
+//! et quart"` is three plus 15. Each language parses its own wording and
+//! passes the result to [`reading`].
+//!
+//! Seconds are written the same way in every language (`"15:30:45"`), so
+//! the pattern and parser for them live here as well, together with the
+//! reading of hours written without am/pm (`"at 3"`, `"9-5"`).
+
+use chrono::NaiveTime;
+
+use crate::resolve;
+use crate::types::ParserConfig;
+
+/// Resolve an hour and a signed minute offset to `(hour, minute)`.
+///
+/// Counting back from one o'clock lands on twelve rather than midnight
+/// (`"quarter to one"` is 12:45, `"halb eins"` is 12:30), matching how such
+/// readings are used on a 12-hour dial.
+/// Returns `None` if `hour` > 24, the offset is a full hour or more, or the
+/// result falls outside the day.
+pub fn reading(hour: u32, offset: i32) -> Option<(u32, u32)> {
+    if hour > 24 || offset.abs() >= 60 {
+        return None;
+    }
+    let mut total = hour as i32 * 60 + offset;
+    if offset < 0 && hour == 1 {
+        total += 12 * 60;
+    }
+    if total < 0 {
+        total += 24 * 60;
+    }
+    if total >= 24 * 60 {
+        return None;
+    }
+    Some((total as u32 / 60, total as u32 % 60))
+}
+
+/// The two ends of a range given as `(hour, minute)`, as times of day.
+///
+/// Returns `None` if an hour >= 24 or a minute >= 60.
+pub fn range_ends(from: (u32, u32), to: (u32, u32)) -> Option<(NaiveTime, NaiveTime)> {
+    Some((
+        NaiveTime::from_hms_opt(from.0, from.1, 0)?,
+        NaiveTime::from_hms_opt(to.0, to.1, 0)?,
+    ))
+}
+
+/// Read an hour written without am/pm ("at 3", "three o'clock") according to
+/// [`ParserConfig::hour_inference`]. A leading zero ("03:00") marks the
+/// 24-hour clock.
+pub fn bare_hour(written: &str, hour: u32, config: &ParserConfig) -> Option<u32> {
+    if hour > 23 {
+        return None;
+    }
+    if written.starts_with('0') {
+        return Some(hour);
+    }
+    Some(resolve::infer_hour(
+        hour,
+        config.hour_inference,
+        config.business_hours,
+    ))
+}
+
+/// Read a 12-hour `hour` on the same half of the day as the `other` end of
+/// its range, or on the other half if it would then not lie `before` (or
+/// after) `other`. Hours outside 1–12 are read as written.
+pub fn carry_meridiem(hour: u32, min: u32, other: (u32, u32), before: bool) -> Option<u32> {
+    if hour > 23 {
+        return None;
+    }
+    if !(1..=12).contains(&hour) {
+        return Some(hour);
+    }
+    let same = hour % 12 + if other.0 >= 12 { 12 } else { 0 };
+    let in_order = if before {
+        (same, min) <= other
+    } else {
+        (same, min) >= other
+    };
+    Some(if in_order { same } else { (same + 12) % 24 })
+}
+
+/// Optional `:SS` seconds after `H:MM`, with an optional fraction after `.`
+/// or `,` (`"15:30:45"`, `"09:00:30.250"`), for embedding in language rules.
+///
+/// Capture groups: `sec` and `subsec`. [`FROM_SECONDS_PAT`] and
+/// [`TO_SECONDS_PAT`] are the same pattern for the two ends of a range.
+pub const SECONDS_PAT: &str = r"(?::(?P<sec>\d{2})(?:[.,](?P<subsec>\d{1,9}))?)?";
+
+/// [`SECONDS_PAT`] for the start of a range (groups `fsec` and `fsubsec`).
+pub const FROM_SECONDS_PAT: &str = r"(?::(?P<fsec>\d{2})(?:[.,](?P<fsubsec>\d{1,9}))?)?";
+
+/// [`SECONDS_PAT`] for the end of a range (groups `tsec` and `tsubsec`).
+pub const TO_SECONDS_PAT: &str = r"(?::(?P<tsec>\d{2})(?:[.,](?P<tsubsec>\d{1,9}))?)?";
+
+/// Combine an hour and minute with the seconds captured in the `sec` and
+/// `subsec` groups, or their counterparts for the ends of a range, into a
+/// time of day.
+///
+/// A missing group counts as zero. Returns `None` if `hour` >= 24,
+/// `minute` >= 60 or the seconds are 60 or more.
+pub fn time_of_day(
+    caps: &regex::Captures,
+    hour: u32,
+    minute: u32,
+    sec: &str,
+    subsec: &str,
+) -> Option<NaiveTime> {
+    let second = match caps.name(sec) {
+        Some(s) => s.as_str().parse::<u32>().ok()?,
+        None => 0,
+    };
+    // "45.25" is 250 ms: pad the fraction to nanoseconds
+    let nano = match caps.name(subsec) {
+        Some(f) => format!("{:0<9}", f.as_str()).parse::<u32>().ok()?,
+        None => 0,
+    };
+    if second > 59 {
+        return None;
+    }
+    NaiveTime::from_hms_nano_opt(hour, minute, second, nano)
+}
@@ -0,0 +1,70 @@
+//! Language-independent compact time ranges, as written in timesheets.
+//!
+//! Recognizes two times joined by a hyphen, an en dash or an em dash, with or
+//! without spaces: `"9-12"`, `"9:15–11:45"`, `"14h-16h30"`, `"0930-1200"` or
+//! `"9.30 - 12"`. Each end is an hour, an hour with minutes after `:`, `.` or
+//! `h`, or four-digit military time. Such ranges also occur in prose (`"pages
+//! 9-12"`), so languages only accept them when
+//! [`ParserConfig::compact_ranges`](crate::types::ParserConfig::compact_ranges)
+//! is set. Two four-digit ends starting with `19` or `20` are taken for years
+//! (`"2025-2026"`), not military times.
+//!
+//! Hours are read like those of a written-out range such as `"from 9 to 5"`:
+//! the start by [`ParserConfig::hour_inference`], and a 12-hour end on the
+//! same day after it, so `"9-5"` is 09:00 to 17:00. Military times and hours
+//! with a leading zero (`"0900-1700"`, `"09-17"`) are on the 24-hour clock.
+
+use crate::lang::clock;
+use crate::types::ParserConfig;
+
+/// Compact range pattern for embedding in language rules.
+///
+/// Capture groups: `cf_mil`, or `cf_h` with `cf_m`, for the start, `ct_mil`,
+/// or `ct_h` with `ct_m`, for the end, and `ctail` for a trailing letter or
+/// digit group that shows the range is part of something longer, such as an
+/// ISO date (`"2026-03-05"`).
+pub const COMPACT_RANGE_PAT: &str = r"(?:(?P<cf_mil>\d{4})|(?P<cf_h>\d{1,2})(?:[:.h](?P<cf_m>\d{2})|h)?)\s*[-–—]\s*(?:(?P<ct_mil>\d{4})|(?P<ct_h>\d{1,2})(?:[:.h](?P<ct_m>\d{2})|h)?)(?P<ctail>\w|[-–—./:]\d)?";
+
+/// Parse the `(from_hour, from_min, to_hour, to_min)` of a compact range from
+/// captures produced by [`COMPACT_RANGE_PAT`].
+///
+/// Returns `None` if the range runs into a longer token, reads as a range of
+/// years (`"2025-2026"`, `"1998-2004"`), or any hour >= 24 or minute >= 60.
+pub fn parse(caps: &regex::Captures, config: &ParserConfig) -> Option<(u32, u32, u32, u32)> {
+    if caps.name("ctail").is_some() {
+        return None;
+    }
+    let year = |name: &str| {
+        caps.name(name)
+            .is_some_and(|m| m.as_str().starts_with("19") || m.as_str().starts_with("20"))
+    };
+    if year("cf_mil") && year("ct_mil") {
+        return None;
+    }
+    let (fh, fm) = end(caps, "cf_mil", "cf_h", "cf_m")?;
+    let (th, tm) = end(caps, "ct_mil", "ct_h", "ct_m")?;
+    // Military times are read as written
+    let written = |mil: &str, hour: &str| match caps.name(mil) {
+        Some(_) => "0",
+        None => caps.name(hour).map_or("0", |h| h.as_str()),
+    };
+    let (from, to) = (written("cf_mil", "cf_h"), written("ct_mil", "ct_h"));
+    let start = clock::bare_hour(from, fh, config)?;
+    let twelve_hour = |written: &str, h: u32| (1..=12).contains(&h) && !written.starts_with('0');
+    let end = if twelve_hour(from, fh) && twelve_hour(to, th) {
+        clock::carry_meridiem(th, tm, (start, fm), false)?
+    } else {
+        clock::bare_hour(to, th, config)?
+    };
+    Some((start, fm, end, tm))
+}
+
+/// One end of the range as `(hour, minute)`.
+fn end(caps: &regex::Captures, mil: &str, hour: &str, minute: &str) -> Option<(u32, u32)> {
+    let num = |name: &str| caps.name(name).and_then(|m| m.as_str().parse::<u32>().ok());
+    let (h, m) = match num(mil) {
+        Some(t) => (t / 100, t % 100),
+        None => (num(hour)?, num(minute).unwrap_or(0)),
+    };
+    (h < 24 && m < 60).then_some((h, m))
+}
@@ -1,9 +1,19 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveTime, Utc};
 use chrono_tz::Tz;
-use regex::Regex;
 
-use crate::lang::numbers::parse_number_de;
-use crate::lang::{GrammarRule, LanguageParser, apply_rules};
+use crate::holiday::{self, Holiday};
+use crate::lang::anchored_offset::{self, OffsetRules};
+use crate::lang::clock::{self, FROM_SECONDS_PAT, SECONDS_PAT, TO_SECONDS_PAT};
+use crate::lang::compact_range::{self, COMPACT_RANGE_PAT};
+use crate::lang::duration::{self, COMPACT_DURATION_PAT};
+use crate::lang::numbers::{parse_number_de, parse_ordinal_de};
+use crate::lang::numeric_date::{self, NUMERIC_DATE_PAT};
+use crate::lang::open_range::{self, Bound, OpenRangeRules};
+use crate::lang::zone::{self, CITY_PAT, ZoneRules};
+use crate::lang::{
+    GrammarRule, LanguageParser, RulePattern, apply_rules, apply_rules_with_rejections,
+    build_regex_set, without_rejected,
+};
 use crate::resolve;
 use crate::types::*;
 
@@ -11,6 +21,9 @@ const KEYWORDS: &[&str] = &[
     "heute",
     "morgen",
     "gestern",
+    "übermorgen",
+    "uebermorgen",
+    "vorgestern",
     "vor",
     "tagen",
     "tag",
@@ -39,6 +52,122 @@ const KEYWORDS: &[&str] = &[
     "samstag",
     "sonntag",
     "sonnabend",
+    "januar",
+    "jänner",
+    "februar",
+    "märz",
+    "maerz",
+    "april",
+    "mai",
+    "juni",
+    "juli",
+    "august",
+    "september",
+    "oktober",
+    "november",
+    "dezember",
+    "jan",
+    "feb",
+    "mär",
+    "mrz",
+    "apr",
+    "jun",
+    "jul",
+    "aug",
+    "sep",
+    "okt",
+    "nov",
+    "dez",
+    "woche",
+    "monat",
+    "quartal",
+    "jahr",
+    "vorige",
+    "vergangene",
+    "kommende",
+    "nächste",
+    "naechste",
+    "diese",
+    "sekunde",
+    "früh",
+    "frueh",
+    "vormittag",
+    "nachmittag",
+    "abend",
+    "nacht",
+    "mittag",
+    "mitternacht",
+    "jede",
+    "alle",
+    "täglich",
+    "taeglich",
+    "wöchentlich",
+    "woechentlich",
+    "jährlich",
+    "jaehrlich",
+    "stündlich",
+    "stuendlich",
+    "ersten",
+    "zweiten",
+    "dritten",
+    "vierten",
+    "fünften",
+    "fuenften",
+    "sechsten",
+    "siebten",
+    "siebenten",
+    "achten",
+    "neunten",
+    "zehnten",
+    "elften",
+    "zwölften",
+    "zwoelften",
+    "igsten",
+    "halb",
+    "viertel",
+    "nach",
+    "neujahr",
+    "drei k",
+    "dreik",
+    "epiphanias",
+    "frauentag",
+    "gründonnerstag",
+    "gruendonnerstag",
+    "karfreitag",
+    "ostern",
+    "ostersonntag",
+    "ostermontag",
+    "maifeiertag",
+    "himmelfahrt",
+    "pfingst",
+    "fronleichnam",
+    "weltkindertag",
+    "einheit",
+    "reformationstag",
+    "allerheiligen",
+    "bettag",
+    "heiligabend",
+    "weihnacht",
+    "stephanstag",
+    "silvester",
+    "anfang",
+    "beginn",
+    "mitte",
+    "ende",
+    "feierabend",
+    "dienstschluss",
+    "schluss",
+    "rest",
+    "ganzt",
+    "ganzen tag",
+    "gegen",
+    "etwa",
+    "ungefähr",
+    "ungefaehr",
+    "circa",
+    "ca.",
+    "seit",
+    "ab",
 ];
 
 const PREFIXES: &[&str] = &[
@@ -51,6 +180,22 @@ const PREFIXES: &[&str] = &[
     "gest",
     "geste",
     "gester",
+    "übe",
+    "über",
+    "überm",
+    "übermo",
+    "übermor",
+    "übermorg",
+    "übermorge",
+    "ueb",
+    "uebe",
+    "ueber",
+    "vorg",
+    "vorge",
+    "vorges",
+    "vorgest",
+    "vorgeste",
+    "vorgester",
     "zwi",
     "zwis",
     "zwisc",
@@ -91,13 +236,15 @@ const PREFIXES: &[&str] = &[
     "sonnta",
 ];
 
-const NUM_WORD_PATTERN: &str = r"(?:\d+|ein|eins|eine|einem|einen|zwei|drei|vier|f[uü]n[f]?|sechs|sieben|acht|neun|zehn|elf|zw[oö]lf)";
+const NUM_WORD_PATTERN: &str = r"(?:\d+|ein|eins|eine|einem|einen|einer|zwei|drei|vier|f[uü]n[f]?|sechs|sieben|acht|neun|zehn|elf|zw[oö]lf)";
 
 fn day_keyword_offset(s: &str) -> Option<i64> {
     match s.to_lowercase().as_str() {
         "heute" => Some(0),
         "morgen" => Some(1),
         "gestern" => Some(-1),
+        "übermorgen" | "uebermorgen" => Some(2),
+        "vorgestern" => Some(-2),
         _ => None,
     }
 }
@@ -115,6 +262,24 @@ fn parse_weekday(s: &str) -> Option<chrono::Weekday> {
     }
 }
 
+fn parse_month(s: &str) -> Option<u32> {
+    match s.to_lowercase().trim_end_matches('.') {
+        "januar" | "jänner" | "jaenner" | "jan" => Some(1),
+        "februar" | "feber" | "feb" => Some(2),
+        "märz" | "maerz" | "marz" | "mär" | "mrz" => Some(3),
+        "april" | "apr" => Some(4),
+        "mai" => Some(5),
+        "juni" | "jun" => Some(6),
+        "juli" | "jul" => Some(7),
+        "august" | "aug" => Some(8),
+        "september" | "sept" | "sep" => Some(9),
+        "oktober" | "okt" => Some(10),
+        "november" | "nov" => Some(11),
+        "dezember" | "dez" => Some(12),
+        _ => None,
+    }
+}
+
 fn parse_num(s: &str) -> Option<u32> {
     s.parse::<u32>()
         .ok()
@@ -124,6 +289,9 @@ fn parse_num(s: &str) -> Option<u32> {
 pub struct German {
     rules: Vec<GrammarRule>,
     regex_set: regex::RegexSet,
+    open_range: OpenRangeRules,
+    offsets: OffsetRules,
+    zones: ZoneRules,
 }
 
 impl Default for German {
@@ -134,15 +302,375 @@ impl Default for German {
 
 impl German {
     pub fn new() -> Self {
-        let rules = build_rules();
-        let regex_set = regex::RegexSet::new(rules.iter().map(|r| r.pattern.as_str())).unwrap();
-        Self { rules, regex_set }
+        let rules = build_rules(&[]);
+        let regex_set = build_regex_set(&rules);
+        let open_range = OpenRangeRules::new(OPEN_RANGE_PAT, open_bound, "um");
+        let offsets = OffsetRules::new(&offset_pattern(), anchor_offset);
+        let zones = ZoneRules::new(&format!(r"{CITY_PAT}\s+Zeit"));
+        Self {
+            rules,
+            regex_set,
+            open_range,
+            offsets,
+            zones,
+        }
     }
 }
 
 /// Shared weekday pattern
 const WEEKDAY_PAT: &str = r"montag|dienstag|mittwoch|donnerstag|freitag|samstag|sonnabend|sonntag";
 
+/// Relative day keywords ("übermorgen", "heute", "vorgestern")
+const RELATIVE_DAY_PAT: &str = r"(?:ü|ue)bermorgen|vorgestern|heute|morgen|gestern";
+
+/// Shared month pattern (full names and common abbreviations)
+const MONTH_PAT: &str = r"januar|j[äa]e?nner|februar|feber|m[äa]e?rz|april|mai|juni|juli|august|september|oktober|november|dezember|jan|feb|m[äa]r|mrz|apr|jun|jul|aug|sept|sep|okt|nov|dez";
+
+/// Clock hour as digits or a written-out number ("15", "drei", "fünfzehn")
+const HOUR_PAT: &str = r"\d{1,2}|eins?|zwei|drei|vier|f(?:ü|ue)nf|sechs|sieben|acht|neun|zehn|elf|zw(?:ö|oe)lf|dreizehn|vierzehn|f(?:ü|ue)nfzehn|sechzehn|siebzehn|achtzehn|neunzehn|zwanzig";
+
+/// Approximation markers: "gegen", "etwa um", "um ungefähr", "ca."
+const APPROX_PAT: &str = r"(?:so\s+)?gegen|(?:etwa|ungef(?:ä|ae)hr|circa|ca\.)(?:\s+um)?|um\s+(?:etwa|ungef(?:ä|ae)hr|circa|ca\.)";
+
+/// Approximation markers that make a bare hour a time: "gegen 9", "etwa um 9".
+/// A plain "etwa 9" is more often a quantity.
+const APPROX_HOUR_PAT: &str = r"(?:so\s+)?gegen|(?:etwa|ungef(?:ä|ae)hr|circa|ca\.)\s+um|um\s+(?:etwa|ungef(?:ä|ae)hr|circa|ca\.)";
+
+/// Shared "um H[:MM[:SS]] [Uhr]" pattern (groups `hour`, `min`, `sec`,
+/// `subsec`, and `approx` for "gegen 15 Uhr")
+fn at_time_pattern() -> String {
+    format!(
+        r"(?:um|(?P<approx>{APPROX_PAT}))\s+(?P<hour>{HOUR_PAT})(?::(?P<min>\d{{2}}){SECONDS_PAT}(?:\s+Uhr)?|\s+Uhr)\b"
+    )
+}
+
+/// Spoken clock reading: "halb vier", "viertel nach drei", "dreiviertel vier",
+/// "fünf vor halb vier", "15 Uhr 30" (groups `sp_min`, `sp_rel`, `sp_half`,
+/// `sp_frac`, `sp_hour`, and `sp_uh`/`sp_um` for the "Uhr" form)
+fn spoken_time_pattern() -> String {
+    format!(
+        r"(?:(?:(?P<sp_min>viertel|{NUM_WORD_PATTERN})\s+(?P<sp_rel>nach|vor)\s+(?P<sp_half>halb\s+)?|(?P<sp_frac>halb|drei\s*viertel|viertel)\s+)(?P<sp_hour>{HOUR_PAT})(?:\s+Uhr)?|(?P<sp_uh>\d{{1,2}})\s+Uhr\s+(?P<sp_um>\d{{1,2}}))\b"
+    )
+}
+
+/// Shared "[von] HH:MM[:SS] bis/- HH:MM[:SS] [Uhr]" pattern (groups `fh`, `fm`,
+/// `fsec`, `fsubsec`, `th`, `tm`, `tsec`, `tsubsec`)
+fn hm_range_pattern() -> String {
+    format!(
+        r"(?:von\s+)?(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}}){FROM_SECONDS_PAT}\s*(?:bis\b|-)\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}}){TO_SECONDS_PAT}(?:\s*Uhr)?\b"
+    )
+}
+
+/// Shared start-time pattern for duration-anchored ranges (groups `anchor`, `hour`, `min`)
+fn start_time_pattern() -> String {
+    format!(
+        r"(?P<anchor>ab|von|um)\s+(?P<hour>{HOUR_PAT})(?::(?P<min>\d{{2}}){SECONDS_PAT})?(?:\s*Uhr)?"
+    )
+}
+
+/// Calendar date pattern: a month-name date (`dom`, `month`, optional `year`)
+/// or a numeric date (see [`NUMERIC_DATE_PAT`]), optionally preceded by a
+/// weekday (`wd`) and/or "am"/"den" (`prep`).
+fn date_pattern() -> String {
+    format!(
+        r"(?:(?:am\s+)?(?P<wd>{WEEKDAY_PAT}),?\s+(?:de[nm]\s+)?|(?P<prep>am|de[nm])\s+)?(?:(?P<dom>\d{{1,2}})(?:\.\s*|\s+)(?P<month>{MONTH_PAT})\b\.?(?:\s+(?P<year>\d{{4}})\b)?|{NUMERIC_DATE_PAT})"
+    )
+}
+
+/// Parse a calendar date from captures produced by [`date_pattern`] into
+/// `(year, month, day, weekday)`.
+fn parse_date(
+    caps: &regex::Captures,
+    config: &ParserConfig,
+) -> Option<(Option<i32>, u32, u32, Option<chrono::Weekday>)> {
+    let weekday = match caps.name("wd") {
+        Some(w) => Some(parse_weekday(w.as_str())?),
+        None => None,
+    };
+    let Some(month) = caps.name("month") else {
+        let order = config.date_order.unwrap_or(DateOrder::DayMonthYear);
+        let date = numeric_date::parse(caps, order)?;
+        return Some((date.year, date.month, date.day, weekday));
+    };
+    let month = parse_month(month.as_str())?;
+    let day = caps.name("dom")?.as_str().parse::<u32>().ok()?;
+    let year = match caps.name("year") {
+        Some(y) => Some(y.as_str().parse::<i32>().ok()?),
+        None => None,
+    };
+    Some((year, month, day, weekday))
+}
+
+/// Resolve a calendar date from captures to midnight of that day.
+fn resolve_date_caps(
+    caps: &regex::Captures,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<DateTime<Utc>> {
+    let (year, month, day, weekday) = parse_date(caps, config)?;
+    resolve::resolve_date(year, month, day, weekday, now, tz, config.year_bias)
+}
+
+/// Ordinal words for the days of a month without their ending: "erst" ... "einunddreißigst"
+const ORDINAL_STEM_PAT: &str = r"(?:(?:ein|zwei|drei|vier|f(?:ü|ue)nf|sechs|sieben|acht|neun)und)?zwanzigst|(?:einund)?drei(?:ß|ss)igst|(?:drei|vier|f(?:ü|ue)nf|sech|sieb|acht|neun)zehnt|erst|zweit|dritt|viert|f(?:ü|ue)nft|sechst|sieb(?:en)?t|acht|neunt|zehnt|elft|zw(?:ö|oe)lft";
+
+/// Day of the month without a month: "am 15.", "zum 3.", "am dritten"
+/// (group `mday`). A digit must not follow the dot, so "am 15.3." stays a date.
+fn day_of_month_pattern() -> String {
+    format!(r"(?:am|de[nm]|zum)\s+(?P<mday>\d{{1,2}}\.\B|(?:{ORDINAL_STEM_PAT})en\b)")
+}
+
+/// Resolve a day of the month from captures to midnight of that day.
+fn resolve_day_of_month_caps(
+    caps: &regex::Captures,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<DateTime<Utc>> {
+    let day = parse_ordinal_de(&caps.name("mday")?.as_str().to_lowercase())?;
+    resolve::resolve_day_of_month(day, now, tz, config.day_of_month_bias)
+}
+
+/// Holiday names ("Ostermontag", "Heiligabend", "Tag der Deutschen Einheit")
+const HOLIDAY_PAT: &str = r"neujahr(?:stag)?|heiligen?\s+drei\s+k(?:ö|oe)nige|dreik(?:ö|oe)nigs(?:tag|fest)|epiphanias|(?:internationaler\s+)?frauentag|gr(?:ü|ue)ndonnerstag|karfreitag|ostersonntag|ostermontag|ostern|tag\s+der\s+arbeit|maifeiertag|mari(?:ä|ae|a)\s+himmelfahrt|(?:christi\s+)?himmelfahrt|pfingstsonntag|pfingstmontag|pfingsten|fronleichnam|weltkindertag|tag\s+der\s+deutschen\s+einheit|reformationstag|allerheiligen|bu(?:ß|ss)-?\s+und\s+bettag|heilig(?:e[rn]?\s*)?abend|(?:erste[rn]?\s+)?weihnachts(?:feier)?tag|zweite[rn]?\s+weihnachts(?:feier)?tag|weihnachten|stephanstag|silvester";
+
+/// Holiday name with an optional year: "Easter 2027" (groups `hol`, `hyear`,
+/// and `custom` for the calendar's custom holidays).
+fn holiday_pattern(custom_holidays: &[&str]) -> String {
+    let custom = holiday::custom_name_pattern(custom_holidays)
+        .map(|p| format!("|{p}"))
+        .unwrap_or_default();
+    format!(r"(?P<hol>{HOLIDAY_PAT}{custom})\b(?:,?\s+(?P<hyear>\d{{4}})\b)?")
+}
+
+fn parse_holiday(s: &str) -> Option<Holiday> {
+    match holiday::name_key(s).as_str() {
+        "neujahr" | "neujahrstag" => Some(Holiday::NewYearsDay),
+        "heiligedreikonige"
+        | "heiligendreikonige"
+        | "heiligedreikoenige"
+        | "heiligendreikoenige"
+        | "dreikonigstag"
+        | "dreikoenigstag"
+        | "dreikonigsfest"
+        | "dreikoenigsfest"
+        | "epiphanias" => Some(Holiday::Epiphany),
+        "frauentag" | "internationalerfrauentag" => Some(Holiday::WomensDay),
+        "grundonnerstag" | "gruendonnerstag" => Some(Holiday::MaundyThursday),
+        "karfreitag" => Some(Holiday::GoodFriday),
+        "ostern" | "ostersonntag" => Some(Holiday::EasterSunday),
+        "ostermontag" => Some(Holiday::EasterMonday),
+        "tagderarbeit" | "maifeiertag" => Some(Holiday::LabourDay),
+        "mariahimmelfahrt" | "mariaehimmelfahrt" => Some(Holiday::Assumption),
+        "himmelfahrt" | "christihimmelfahrt" => Some(Holiday::Ascension),
+        "pfingsten" | "pfingstsonntag" => Some(Holiday::Pentecost),
+        "pfingstmontag" => Some(Holiday::WhitMonday),
+        "fronleichnam" => Some(Holiday::CorpusChristi),
+        "weltkindertag" => Some(Holiday::ChildrensDay),
+        "tagderdeutscheneinheit" => Some(Holiday::GermanUnityDay),
+        "reformationstag" => Some(Holiday::ReformationDay),
+        "allerheiligen" => Some(Holiday::AllSaints),
+        "busundbettag" | "bussundbettag" => Some(Holiday::RepentanceDay),
+        "heiligabend" | "heiligeabend" | "heiligerabend" | "heiligenabend" => {
+            Some(Holiday::ChristmasEve)
+        }
+        "weihnachten"
+        | "weihnachtstag"
+        | "weihnachtsfeiertag"
+        | "erstenweihnachtstag"
+        | "ersterweihnachtstag"
+        | "ersteweihnachtstag"
+        | "erstenweihnachtsfeiertag"
+        | "ersterweihnachtsfeiertag"
+        | "ersteweihnachtsfeiertag" => Some(Holiday::ChristmasDay),
+        "zweitenweihnachtstag"
+        | "zweiterweihnachtstag"
+        | "zweiteweihnachtstag"
+        | "zweitenweihnachtsfeiertag"
+        | "zweiterweihnachtsfeiertag"
+        | "zweiteweihnachtsfeiertag"
+        | "stephanstag" => Some(Holiday::BoxingDay),
+        "silvester" => Some(Holiday::NewYearsEve),
+        _ => None,
+    }
+}
+
+/// Resolve a holiday from captures to midnight of that day.
+fn resolve_holiday_caps(
+    caps: &regex::Captures,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<DateTime<Utc>> {
+    let year = match caps.name("hyear") {
+        Some(y) => Some(y.as_str().parse::<i32>().ok()?),
+        None => None,
+    };
+    if let Some(name) = caps.name("custom") {
+        return holiday::resolve_custom_holiday(name.as_str(), year, now, tz, config);
+    }
+    let holiday = parse_holiday(caps.name("hol")?.as_str())?;
+    holiday::resolve_holiday(holiday, year, now, tz, config)
+}
+
+/// Ordinal weekday of a month: "am ersten Montag im März", "letzter
+/// Freitag des Monats", "am 2. Dienstag im nächsten Monat" (groups `ord`,
+/// `owd`, `omonth`, `oyear`, `mdir`).
+fn weekday_of_month_pattern() -> String {
+    format!(
+        r"(?:(?:am|de[nr])\s+)?(?P<ord>(?:erst|zweit|dritt|viert|f(?:ü|ue)nft|letzt)e[nr]?|[1-5]\.)\s+(?P<owd>{WEEKDAY_PAT})\s+(?:(?:im|in)\s+(?P<omonth>{MONTH_PAT})\b\.?(?:\s+(?P<oyear>\d{{4}})\b)?|(?:im|des)\s+(?:(?P<mdir>n[äae]chsten|kommenden|letzten|vergangenen|diese[nms]?)\s+)?Monats?\b)"
+    )
+}
+
+fn parse_week_ordinal(s: &str) -> Option<i32> {
+    let lower = s.to_lowercase();
+    if lower.starts_with("letzt") {
+        return Some(-1);
+    }
+    i32::try_from(parse_ordinal_de(&lower)?).ok()
+}
+
+/// Resolve an ordinal weekday of a month from captures to midnight of that day.
+fn resolve_weekday_of_month_caps(
+    caps: &regex::Captures,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<DateTime<Utc>> {
+    let n = parse_week_ordinal(caps.name("ord")?.as_str())?;
+    let weekday = parse_weekday(caps.name("owd")?.as_str())?;
+    let Some(month) = caps.name("omonth") else {
+        let offset = match caps.name("mdir") {
+            Some(d) => period_direction(d.as_str())?,
+            None => 0,
+        };
+        return resolve::resolve_weekday_of_relative_month(n, weekday, offset, now, tz);
+    };
+    let month = parse_month(month.as_str())?;
+    let year = match caps.name("oyear") {
+        Some(y) => Some(y.as_str().parse::<i32>().ok()?),
+        None => None,
+    };
+    resolve::resolve_weekday_of_month(n, weekday, month, year, now, tz, config.year_bias)
+}
+
+/// List of weekdays: "Dienstag", "Montag und Donnerstag", "Montag, Mittwoch und Freitag".
+fn weekday_list_pattern(suffix: &str) -> String {
+    format!(r"(?:{WEEKDAY_PAT}){suffix}(?:(?:\s*,\s*|\s+und\s+)(?:{WEEKDAY_PAT}){suffix})*")
+}
+
+/// Parse every weekday named in a list matched by [`weekday_list_pattern`].
+fn parse_weekday_list(s: &str) -> Vec<chrono::Weekday> {
+    s.split(|c: char| !c.is_alphabetic())
+        .filter_map(|w| parse_weekday(w.trim_end_matches(['s', 'S'])))
+        .collect()
+}
+
+/// Recurring schedule without its time: "jeden Dienstag", "jede zweite Woche",
+/// "alle 3 Tage", "dienstags", "werktags", "täglich" (groups `rord`, `runit`,
+/// `rwds`, `rn`, `runit_pl`, `rpl`, `rwork`, `radv`).
+fn recurrence_pattern() -> String {
+    let list = weekday_list_pattern("");
+    let plural = weekday_list_pattern("s");
+    format!(
+        r"(?:jede[nmrs]?\s+(?:(?P<rord>(?:{ORDINAL_STEM_PAT})e[nmrs]?)\s+)?(?:(?P<runit>Minute|Stunde|Tag|Woche|Monat|Quartal|Jahr)|(?P<rwds>{list}))|alle\s+(?P<rn>{NUM_WORD_PATTERN})\s+(?P<runit_pl>Minuten|Stunden|Tage|Wochen|Monate|Quartale|Jahre)|(?P<rpl>{plural})|(?P<rwork>werktags)|(?P<radv>st(?:ü|ue)ndlich|t(?:ä|ae)glich|w(?:ö|oe)chentlich|monatlich|viertelj(?:ä|ae)hrlich|j(?:ä|ae)hrlich))\b"
+    )
+}
+
+/// Resolve a recurring schedule from captures produced by
+/// [`recurrence_pattern`], with an optional time of day in the `um` groups,
+/// `rhour` ("um 10"), or `nm` ("um Mitternacht").
+fn resolve_recurrence_caps(caps: &regex::Captures) -> Option<ResolvedTime> {
+    let (frequency, weekdays) = if let Some(adv) = caps.name("radv") {
+        let adv = adv
+            .as_str()
+            .to_lowercase()
+            .replace("ä", "ae")
+            .replace("ö", "oe")
+            .replace("ü", "ue");
+        let unit = match adv.as_str() {
+            "stuendlich" => TimeUnit::Hour,
+            "taeglich" => TimeUnit::Day,
+            "woechentlich" => TimeUnit::Week,
+            "monatlich" => TimeUnit::Month,
+            "vierteljaehrlich" => TimeUnit::Quarter,
+            _ => TimeUnit::Year,
+        };
+        (unit, Vec::new())
+    } else if let Some(unit) = caps.name("runit").or(caps.name("runit_pl")) {
+        (parse_unit(unit.as_str())?, Vec::new())
+    } else if caps.name("rwork").is_some() {
+        use chrono::Weekday::*;
+        (TimeUnit::Day, vec![Mon, Tue, Wed, Thu, Fri])
+    } else {
+        let list = caps.name("rwds").or(caps.name("rpl"))?;
+        (TimeUnit::Week, parse_weekday_list(list.as_str()))
+    };
+    let interval = if let Some(ord) = caps.name("rord") {
+        // "jeden ersten Montag" means a day of the month, not every week.
+        match parse_ordinal_de(&ord.as_str().to_lowercase())? {
+            1 => return None,
+            n => n,
+        }
+    } else if let Some(n) = caps.name("rn") {
+        parse_num(n.as_str())?
+    } else {
+        1
+    };
+    let time = if let Some(h) = caps.name("rhour") {
+        Some(NaiveTime::from_hms_opt(parse_num(h.as_str())?, 0, 0)?)
+    } else if let Some(nm) = caps.name("nm") {
+        if nm.as_str().eq_ignore_ascii_case("mitternacht") {
+            Some(NaiveTime::MIN)
+        } else {
+            NaiveTime::from_hms_opt(12, 0, 0)
+        }
+    } else if caps.name("hour").is_some()
+        || caps.name("sp_hour").is_some()
+        || caps.name("sp_uh").is_some()
+    {
+        Some(parse_time(caps)?)
+    } else {
+        None
+    };
+    resolve::resolve_recurrence(frequency, interval, weekdays, time)
+}
+
+/// Part-of-day words: "früh", "Morgen", "Vormittag", "Nachmittag", "Abend", "Nacht".
+const DAY_PART_PAT: &str = r"fr(?:ü|ue)h|morgen|vormittag|nachmittag|abend|nacht";
+
+fn parse_day_part(s: &str) -> Option<DayPart> {
+    match s.to_lowercase().as_str() {
+        "früh" | "frueh" | "morgen" | "vormittag" => Some(DayPart::Morning),
+        "nachmittag" => Some(DayPart::Afternoon),
+        "abend" => Some(DayPart::Evening),
+        "nacht" => Some(DayPart::Night),
+        _ => None,
+    }
+}
+
+/// Noon and midnight words: "Mittag", "mittags", "Mitternacht".
+const NOON_MIDNIGHT_PAT: &str = r"mittags?|mitternacht";
+
+/// Resolve the noon or midnight word in the `nm` group on the day starting at `date`.
+fn resolve_noon_midnight(
+    caps: &regex::Captures,
+    date: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<ResolvedTime> {
+    if caps
+        .name("nm")?
+        .as_str()
+        .eq_ignore_ascii_case("mitternacht")
+    {
+        resolve::resolve_midnight(date, config.midnight, tz)
+    } else {
+        resolve::resolve_time_on_date(date, 12, 0, tz)
+    }
+}
+
 fn weekday_direction(s: &str) -> Option<i64> {
     match s.to_lowercase().as_str() {
         "nächsten" | "naechsten" | "kommenden" => Some(1),
@@ -152,21 +680,42 @@ fn weekday_direction(s: &str) -> Option<i64> {
     }
 }
 
-/// Parse a HH:MM–HH:MM range from captures with groups `fh`, `fm`, `th`, `tm`.
-fn parse_hm_range(caps: &regex::Captures) -> Option<(u32, u32, u32, u32)> {
+/// Resolve a weekday named without nächsten/letzten/diesen to midnight of the
+/// day picked by [`ParserConfig::weekday_bias`].
+fn resolve_bare_weekday(
+    wd: &str,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<DateTime<Utc>> {
+    let weekday = parse_weekday(wd)?;
+    resolve::resolve_weekday_date_with_bias(weekday, now, tz, config.weekday_bias)
+}
+
+/// Parse a HH:MM[:SS]–HH:MM[:SS] range from captures with groups `fh`, `fm`,
+/// `fsec`, `fsubsec`, `th`, `tm`, `tsec`, `tsubsec`.
+fn parse_time_range(caps: &regex::Captures) -> Option<(NaiveTime, NaiveTime)> {
     let fh = caps.name("fh")?.as_str().parse::<u32>().ok()?;
     let fm = caps.name("fm")?.as_str().parse::<u32>().ok()?;
     let th = caps.name("th")?.as_str().parse::<u32>().ok()?;
     let tm = caps.name("tm")?.as_str().parse::<u32>().ok()?;
-    if fh > 23 || fm > 59 || th > 23 || tm > 59 {
-        return None;
-    }
-    Some((fh, fm, th, tm))
+    let from = clock::time_of_day(caps, fh, fm, "fsec", "fsubsec")?;
+    let to = clock::time_of_day(caps, th, tm, "tsec", "tsubsec")?;
+    Some((from, to))
+}
+
+/// Parse a time of day with optional `:SS` seconds from captures (24h format).
+fn parse_time(caps: &regex::Captures) -> Option<NaiveTime> {
+    let (h, m) = parse_hm(caps)?;
+    clock::time_of_day(caps, h, m, "sec", "subsec")
 }
 
 /// Parse hour and optional :MM minute from captures (24h format).
 fn parse_hm(caps: &regex::Captures) -> Option<(u32, u32)> {
-    let h = caps.name("hour")?.as_str().parse::<u32>().ok()?;
+    if caps.name("hour").is_none() {
+        return parse_spoken(caps);
+    }
+    let h = parse_num(caps.name("hour")?.as_str())?;
     let m = caps
         .name("min")
         .and_then(|m| m.as_str().parse::<u32>().ok())
@@ -177,9 +726,232 @@ fn parse_hm(caps: &regex::Captures) -> Option<(u32, u32)> {
     Some((h, m))
 }
 
-fn build_rules() -> Vec<GrammarRule> {
+/// Parse a spoken clock reading from captures produced by [`spoken_time_pattern`].
+fn parse_spoken(caps: &regex::Captures) -> Option<(u32, u32)> {
+    if let Some(h) = caps.name("sp_uh") {
+        let h = h.as_str().parse::<u32>().ok()?;
+        let m = caps.name("sp_um")?.as_str().parse::<u32>().ok()?;
+        if h > 23 || m > 59 {
+            return None;
+        }
+        return Some((h, m));
+    }
+    let hour = parse_num(caps.name("sp_hour")?.as_str())?;
+    // "halb vier" and "viertel vier" count towards the named hour.
+    let offset = if let Some(frac) = caps.name("sp_frac") {
+        let frac: String = frac.as_str().to_lowercase().split_whitespace().collect();
+        match frac.as_str() {
+            "halb" => -30,
+            "viertel" => -45,
+            "dreiviertel" => -15,
+            _ => return None,
+        }
+    } else {
+        let minutes = match caps.name("sp_min")?.as_str().to_lowercase().as_str() {
+            "viertel" => 15,
+            n => parse_num(n)?,
+        };
+        if minutes > 29 {
+            return None;
+        }
+        let minutes = minutes as i32;
+        let offset = match caps.name("sp_rel")?.as_str().to_lowercase().as_str() {
+            "nach" => minutes,
+            _ => -minutes,
+        };
+        if caps.name("sp_half").is_some() {
+            offset - 30
+        } else {
+            offset
+        }
+    };
+    clock::reading(hour, offset)
+}
+
+/// Resolve the direction of a period adjective ("letzte", "nächstes", ...),
+/// ignoring its inflection.
+fn period_direction(s: &str) -> Option<i64> {
+    let s = s.to_lowercase();
+    let stem = s
+        .trim_end_matches(['s', 'n', 'm', 'r'])
+        .trim_end_matches('e');
+    match stem {
+        "dies" | "laufend" | "aktuell" => Some(0),
+        "letzt" | "vorig" | "vergangen" => Some(-1),
+        "nächst" | "naechst" | "nachst" | "kommend" => Some(1),
+        _ => None,
+    }
+}
+
+/// Parse a time unit word (any number and case).
+fn parse_unit(s: &str) -> Option<TimeUnit> {
+    match s.to_lowercase().as_str() {
+        "sekunde" | "sekunden" | "sek" => Some(TimeUnit::Second),
+        "minute" | "minuten" | "min" => Some(TimeUnit::Minute),
+        "stunde" | "stunden" | "std" => Some(TimeUnit::Hour),
+        "tag" | "tage" | "tagen" | "tages" | "tags" => Some(TimeUnit::Day),
+        "woche" | "wochen" => Some(TimeUnit::Week),
+        "monat" | "monate" | "monaten" | "monats" => Some(TimeUnit::Month),
+        "quartal" | "quartale" | "quartalen" | "quartals" => Some(TimeUnit::Quarter),
+        "jahr" | "jahre" | "jahren" | "jahres" | "jahrs" => Some(TimeUnit::Year),
+        _ => None,
+    }
+}
+
+fn parse_boundary(s: &str) -> Option<Boundary> {
+    match s.to_lowercase().as_str() {
+        "anfang" | "beginn" => Some(Boundary::Start),
+        "mitte" => Some(Boundary::Middle),
+        "ende" => Some(Boundary::End),
+        _ => None,
+    }
+}
+
+/// Shared sub-day unit pattern
+const SUBDAY_UNIT_PAT: &str = r"Sekunden?|Minuten?|Stunden?";
+
+/// Shared day-or-longer unit pattern
+const DAY_UNIT_PAT: &str = r"Tag(?:en?)?|Wochen?|Monat(?:en?)?|Jahr(?:en?)?";
+
+/// Parse a fraction word used in durations.
+fn parse_fraction(s: &str) -> Option<f64> {
+    match s.to_lowercase().replace(char::is_whitespace, "").as_str() {
+        "halbe" => Some(0.5),
+        "viertel" => Some(0.25),
+        "dreiviertel" => Some(0.75),
+        _ => None,
+    }
+}
+
+/// Parse a compound "and a half" number word ("anderthalb", "zweieinhalb").
+fn parse_and_a_half(s: &str) -> Option<f64> {
+    let s = s.to_lowercase();
+    if s == "anderthalb" {
+        return Some(1.5);
+    }
+    let whole = s.strip_suffix("einhalb")?;
+    let whole = if whole == "ein" { 1 } else { parse_num(whole)? };
+    Some(f64::from(whole) + 0.5)
+}
+
+/// Parse a duration amount: a decimal number or a number word.
+fn parse_amount(s: &str) -> Option<f64> {
+    duration::parse_decimal(s).or_else(|| parse_num(s).map(f64::from))
+}
+
+/// Resolve a spelled-out duration from captures with group `unit` and any of
+/// `num`, `three`, `frac` (a fraction before the unit, or on its own) and
+/// `frac_post` (a fraction after the unit).
+///
+/// A trailing fraction without an amount counts as one unit plus the fraction.
+fn resolve_spelled_duration(caps: &regex::Captures) -> Option<ResolvedTime> {
+    let frac_post = match caps.name("frac_post") {
+        Some(f) => Some(parse_fraction(f.as_str())?),
+        None => None,
+    };
+    let mut amount = match caps.name("num") {
+        Some(n) => parse_amount(n.as_str())?,
+        None if frac_post.is_some() => 1.0,
+        None => 0.0,
+    };
+    if let Some(f) = caps.name("frac") {
+        let times = if caps.name("three").is_some() {
+            3.0
+        } else {
+            1.0
+        };
+        amount += times * parse_fraction(f.as_str())?;
+    }
+    amount += frac_post.unwrap_or(0.0);
+    if amount <= 0.0 {
+        return None;
+    }
+    let unit = parse_unit(caps.name("unit")?.as_str())?;
+    resolve::resolve_duration(unit, amount)
+}
+
+/// Parse a duration from captures produced by the spelled-out or compact
+/// duration pattern.
+fn parse_duration(caps: &regex::Captures) -> Option<chrono::Duration> {
+    let resolved = if caps.name("unit").is_some() {
+        resolve_spelled_duration(caps)?
+    } else {
+        duration::parse_compact(caps)?
+    };
+    match resolved {
+        ResolvedTime::Duration(d) => Some(d),
+        _ => None,
+    }
+}
+
+/// Resolve the day of a duration-anchored range: a relative day (`day`),
+/// a calendar date (`date`), or today.
+fn resolve_anchor_day(
+    caps: &regex::Captures,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<DateTime<Utc>> {
+    if let Some(day) = caps.name("day") {
+        return resolve::resolve_day_offset(day_keyword_offset(day.as_str())?, now, tz);
+    }
+    if caps.name("date").is_some() {
+        return resolve_date_caps(caps, now, tz, config);
+    }
+    resolve::resolve_day_offset(0, now, tz)
+}
+
+/// Open-range prepositions: "seit", "ab", "bis", "vor", "nach"
+const OPEN_RANGE_PAT: &str = r"(?:seit|ab|nach|vor|bis(?:\s+(?:zum|zur))?)\s+";
+
+fn open_bound(s: &str) -> Option<Bound> {
+    match s.to_lowercase().split_whitespace().next()? {
+        "seit" => Some(Bound::Since),
+        "ab" => Some(Bound::From),
+        "nach" => Some(Bound::After),
+        "bis" => Some(Bound::Until),
+        "vor" => Some(Bound::Before),
+        _ => None,
+    }
+}
+
+/// Offsets from an anchor: "zwei Tage vor", "eine Woche nach dem"
+fn offset_pattern() -> String {
+    format!(
+        r"(?P<num>{NUM_WORD_PATTERN})\s+(?P<unit>{SUBDAY_UNIT_PAT}|{DAY_UNIT_PAT})\s+(?P<op>vor|nach)\s+(?:(?:dem|der)\s+)?"
+    )
+}
+
+fn anchor_offset(caps: &regex::Captures) -> Option<(TimeUnit, i64)> {
+    let amount = i64::from(parse_num(caps.name("num")?.as_str())?);
+    let unit = parse_unit(caps.name("unit")?.as_str())?;
+    if caps.name("op")?.as_str().eq_ignore_ascii_case("vor") {
+        Some((unit, -amount))
+    } else {
+        Some((unit, amount))
+    }
+}
+
+fn build_rules(custom_holidays: &[&str]) -> Vec<GrammarRule> {
     let num = NUM_WORD_PATTERN;
+    let subday = SUBDAY_UNIT_PAT;
+    let days = DAY_UNIT_PAT;
     let wd = WEEKDAY_PAT;
+    let rday = RELATIVE_DAY_PAT;
+    let spoken = spoken_time_pattern();
+    let at_time = at_time_pattern();
+    let at = format!(r"(?:um\s+{spoken}|{at_time})");
+    let hm_range = hm_range_pattern();
+    let date = date_pattern();
+    let spelled_dur = format!(r"(?P<num>\d+[.,]\d+|{num})\s+(?P<unit>{subday}|Std|Min|Sek)\b\.?");
+    let dur = format!(r"(?:{spelled_dur}|{COMPACT_DURATION_PAT})");
+    let start = start_time_pattern();
+    let part = DAY_PART_PAT;
+    let nm = NOON_MIDNIGHT_PAT;
+    let wom = weekday_of_month_pattern();
+    let mday = day_of_month_pattern();
+    let hol = holiday_pattern(custom_holidays);
+    let recur = recurrence_pattern();
 
     vec![
         // ============================================================
@@ -187,17 +959,34 @@ fn build_rules() -> Vec<GrammarRule> {
         //  "letzten Freitag um 15:30 Uhr", "letzten Freitag um 15 Uhr"
         // ============================================================
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\b(?:am\s+)?(?P<dir>n[äae]chsten|kommenden|letzten|vergangenen|diesen)\s+(?P<wd>{wd})\s+um\s+(?P<hour>\d{{1,2}})(?::(?P<min>\d{{2}})(?:\s+Uhr)?|\s+Uhr)\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:am\s+)?(?P<dir>n[äae]chsten|kommenden|letzten|vergangenen|diesen)\s+(?P<wd>{wd})\s+{at}"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, _| {
                 let direction = weekday_direction(caps.name("dir")?.as_str())?;
                 let weekday = parse_weekday(caps.name("wd")?.as_str())?;
-                let (h, m) = parse_hm(caps)?;
+                let time = parse_time(caps)?;
                 let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
-                resolve::resolve_time_on_date(date, h, m, tz)
+                resolve::resolve_clock_time_on_date(date, time, tz)
+            },
+        },
+        // ============================================================
+        //  Combined: Wochentag ohne Richtung + Uhrzeit
+        //  "am Freitag um 15 Uhr", "Montag um 9"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:am\s+)?(?P<wd>{wd}),?\s+(?:{at}|um\s+(?P<rhour>{HOUR_PAT})\b)"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let time = match caps.name("rhour") {
+                    Some(h) => NaiveTime::from_hms_opt(parse_num(h.as_str())?, 0, 0)?,
+                    None => parse_time(caps)?,
+                };
+                let date = resolve_bare_weekday(caps.name("wd")?.as_str(), now, tz, config)?;
+                resolve::resolve_clock_time_on_date(date, time, tz)
             },
         },
         // ============================================================
@@ -205,17 +994,16 @@ fn build_rules() -> Vec<GrammarRule> {
         //  "nächsten Montag von 9:00 bis 11:30", "letzten Freitag 8:30 - 17:00"
         // ============================================================
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\b(?:am\s+)?(?P<dir>n[äae]chsten|kommenden|letzten|vergangenen|diesen)\s+(?P<wd>{wd})\s+von\s+(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}})\s*(?:bis\b|-)\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}})(?:\s*Uhr)?\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:am\s+)?(?P<dir>n[äae]chsten|kommenden|letzten|vergangenen|diesen)\s+(?P<wd>{wd})\s+von\s+(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}}){FROM_SECONDS_PAT}\s*(?:bis\b|-)\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}}){TO_SECONDS_PAT}(?:\s*Uhr)?\b"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let direction = weekday_direction(caps.name("dir")?.as_str())?;
                 let weekday = parse_weekday(caps.name("wd")?.as_str())?;
-                let (fh, fm, th, tm) = parse_hm_range(caps)?;
+                let (from, to) = parse_time_range(caps)?;
                 let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
-                resolve::resolve_time_range_with_minutes_on_date(date, fh, fm, th, tm, tz)
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
             },
         },
         // ============================================================
@@ -223,17 +1011,16 @@ fn build_rules() -> Vec<GrammarRule> {
         //  "letzten Freitag 9:00 - 11:30"
         // ============================================================
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\b(?:am\s+)?(?P<dir>n[äae]chsten|kommenden|letzten|vergangenen|diesen)\s+(?P<wd>{wd})\s+(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}})\s*-\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}})\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:am\s+)?(?P<dir>n[äae]chsten|kommenden|letzten|vergangenen|diesen)\s+(?P<wd>{wd})\s+(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}}){FROM_SECONDS_PAT}\s*-\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}}){TO_SECONDS_PAT}\b"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let direction = weekday_direction(caps.name("dir")?.as_str())?;
                 let weekday = parse_weekday(caps.name("wd")?.as_str())?;
-                let (fh, fm, th, tm) = parse_hm_range(caps)?;
+                let (from, to) = parse_time_range(caps)?;
                 let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
-                resolve::resolve_time_range_with_minutes_on_date(date, fh, fm, th, tm, tz)
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
             },
         },
         // ============================================================
@@ -241,19 +1028,21 @@ fn build_rules() -> Vec<GrammarRule> {
         //  "letzten Freitag von 9 bis 12 Uhr"
         // ============================================================
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\b(?:am\s+)?(?P<dir>n[äae]chsten|kommenden|letzten|vergangenen|diesen)\s+(?P<wd>{wd})\s+von\s+(?P<from>\d{{1,2}})\s+bis\s+(?P<to>\d{{1,2}})(?:\s*Uhr)?\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:am\s+)?(?P<dir>n[äae]chsten|kommenden|letzten|vergangenen|diesen)\s+(?P<wd>{wd})\s+von\s+(?P<from>{HOUR_PAT})\s+bis\s+(?P<to>{HOUR_PAT})(?:\s*Uhr)?\b"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let direction = weekday_direction(caps.name("dir")?.as_str())?;
                 let weekday = parse_weekday(caps.name("wd")?.as_str())?;
-                let from = caps.name("from")?.as_str().parse::<u32>().ok()?;
-                let to = caps.name("to")?.as_str().parse::<u32>().ok()?;
-                if from > 23 || to > 23 { return None; }
+                let from = parse_num(caps.name("from")?.as_str())?;
+                let to = parse_num(caps.name("to")?.as_str())?;
+                if from > 23 || to > 23 {
+                    return None;
+                }
                 let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
-                resolve::resolve_time_range_on_date(date, from, to, tz)
+                let (from, to) = clock::range_ends((from, 0), (to, 0))?;
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
             },
         },
         // ============================================================
@@ -261,142 +1050,382 @@ fn build_rules() -> Vec<GrammarRule> {
         //  "letzten Freitag zwischen 9 und 12 Uhr"
         // ============================================================
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\b(?:am\s+)?(?P<dir>n[äae]chsten|kommenden|letzten|vergangenen|diesen)\s+(?P<wd>{wd})\s+zwischen\s+(?P<from>\d{{1,2}})\s+und\s+(?P<to>\d{{1,2}})\s*(?:Uhr)?\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:am\s+)?(?P<dir>n[äae]chsten|kommenden|letzten|vergangenen|diesen)\s+(?P<wd>{wd})\s+zwischen\s+(?P<from>{HOUR_PAT})\s+und\s+(?P<to>{HOUR_PAT})\s*(?:Uhr)?\b"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let direction = weekday_direction(caps.name("dir")?.as_str())?;
                 let weekday = parse_weekday(caps.name("wd")?.as_str())?;
-                let from = caps.name("from")?.as_str().parse::<u32>().ok()?;
-                let to = caps.name("to")?.as_str().parse::<u32>().ok()?;
-                if from > 23 || to > 23 { return None; }
+                let from = parse_num(caps.name("from")?.as_str())?;
+                let to = parse_num(caps.name("to")?.as_str())?;
+                if from > 23 || to > 23 {
+                    return None;
+                }
                 let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
-                resolve::resolve_time_range_on_date(date, from, to, tz)
+                let (from, to) = clock::range_ends((from, 0), (to, 0))?;
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
             },
         },
-        // --- Combined: "gestern um 15[:30] [Uhr]" ---
+        // --- Combined: "gestern um 15[:30] [Uhr]", "morgen um 8" ---
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\b(?P<day>heute|morgen|gestern)\s+um\s+(?P<hour>\d{1,2})(?::(?P<min>\d{2})(?:\s+Uhr)?|\s+Uhr)\b",
-            )
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?P<day>{rday})\s+(?:{at}|um\s+(?P<rhour>{HOUR_PAT})\b)"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, _| {
                 let offset = day_keyword_offset(caps.name("day")?.as_str())?;
-                let (h, m) = parse_hm(caps)?;
+                let time = match caps.name("rhour") {
+                    Some(h) => NaiveTime::from_hms_opt(parse_num(h.as_str())?, 0, 0)?,
+                    None => parse_time(caps)?,
+                };
                 let date = resolve::resolve_day_offset(offset, now, tz)?;
-                resolve::resolve_time_on_date(date, h, m, tz)
+                resolve::resolve_clock_time_on_date(date, time, tz)
             },
         },
         // --- Combined: "heute von 10:15 bis 13:45 [Uhr]", "heute von 10:15 - 13:45" ---
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\b(?P<day>heute|morgen|gestern)\s+von\s+(?P<fh>\d{1,2}):(?P<fm>\d{2})\s*(?:bis\b|-)\s*(?P<th>\d{1,2}):(?P<tm>\d{2})(?:\s*Uhr)?\b",
-            )
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?P<day>{rday})\s+von\s+(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}}){FROM_SECONDS_PAT}\s*(?:bis\b|-)\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}}){TO_SECONDS_PAT}(?:\s*Uhr)?\b"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let offset = day_keyword_offset(caps.name("day")?.as_str())?;
-                let (fh, fm, th, tm) = parse_hm_range(caps)?;
+                let (from, to) = parse_time_range(caps)?;
                 let date = resolve::resolve_day_offset(offset, now, tz)?;
-                resolve::resolve_time_range_with_minutes_on_date(date, fh, fm, th, tm, tz)
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
             },
         },
         // --- Combined: "heute 10:15 - 13:45" (day + bare dash range) ---
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\b(?P<day>heute|morgen|gestern)\s+(?P<fh>\d{1,2}):(?P<fm>\d{2})\s*-\s*(?P<th>\d{1,2}):(?P<tm>\d{2})\b",
-            )
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?P<day>{rday})\s+(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}}){FROM_SECONDS_PAT}\s*-\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}}){TO_SECONDS_PAT}\b"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let offset = day_keyword_offset(caps.name("day")?.as_str())?;
-                let (fh, fm, th, tm) = parse_hm_range(caps)?;
+                let (from, to) = parse_time_range(caps)?;
                 let date = resolve::resolve_day_offset(offset, now, tz)?;
-                resolve::resolve_time_range_with_minutes_on_date(date, fh, fm, th, tm, tz)
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
             },
         },
         // --- Combined: "gestern von 9 bis 12 Uhr" ---
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\b(?P<day>heute|morgen|gestern)\s+von\s+(?P<from>\d{1,2})\s+bis\s+(?P<to>\d{1,2})\s*Uhr\b",
-            )
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?P<day>{rday})\s+von\s+(?P<from>{HOUR_PAT})\s+bis\s+(?P<to>{HOUR_PAT})\s*Uhr\b"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let offset = day_keyword_offset(caps.name("day")?.as_str())?;
-                let from = caps.name("from")?.as_str().parse::<u32>().ok()?;
-                let to = caps.name("to")?.as_str().parse::<u32>().ok()?;
-                if from > 23 || to > 23 { return None; }
+                let from = parse_num(caps.name("from")?.as_str())?;
+                let to = parse_num(caps.name("to")?.as_str())?;
+                if from > 23 || to > 23 {
+                    return None;
+                }
                 let date = resolve::resolve_day_offset(offset, now, tz)?;
-                resolve::resolve_time_range_on_date(date, from, to, tz)
+                let (from, to) = clock::range_ends((from, 0), (to, 0))?;
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
             },
         },
         // --- Combined: "gestern zwischen 9 und 12 Uhr" ---
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\b(?P<day>heute|morgen|gestern)\s+zwischen\s+(?P<from>\d{1,2})\s+und\s+(?P<to>\d{1,2})\s*(?:Uhr)?\b",
-            )
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?P<day>{rday})\s+zwischen\s+(?P<from>{HOUR_PAT})\s+und\s+(?P<to>{HOUR_PAT})\s*(?:Uhr)?\b"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let offset = day_keyword_offset(caps.name("day")?.as_str())?;
+                let from = parse_num(caps.name("from")?.as_str())?;
+                let to = parse_num(caps.name("to")?.as_str())?;
+                if from > 23 || to > 23 {
+                    return None;
+                }
+                let date = resolve::resolve_day_offset(offset, now, tz)?;
+                let (from, to) = clock::range_ends((from, 0), (to, 0))?;
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
+            },
+        },
+        // ============================================================
+        //  Combined: Weekday + Tageszeit
+        //  "letzten Freitag Abend", "letzten Freitagabend", "nächsten Montag früh"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:am\s+)?(?P<dir>n[äae]chsten|kommenden|letzten|vergangenen|diesen)\s+(?P<wd>{wd})\s*(?P<part>{part})\b"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let direction = weekday_direction(caps.name("dir")?.as_str())?;
+                let weekday = parse_weekday(caps.name("wd")?.as_str())?;
+                let part = parse_day_part(caps.name("part")?.as_str())?;
+                let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
+                resolve::resolve_day_part(date, part, &config.day_parts, tz)
+            },
+        },
+        // ============================================================
+        //  Combined: Wochentag ohne Richtung + Tageszeit
+        //  "am Freitag Abend", "Montagmorgen"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:am\s+)?(?P<wd>{wd})\s*(?P<part>{part})\b"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let part = parse_day_part(caps.name("part")?.as_str())?;
+                let date = resolve_bare_weekday(caps.name("wd")?.as_str(), now, tz, config)?;
+                resolve::resolve_day_part(date, part, &config.day_parts, tz)
+            },
+        },
+        // --- Combined: "heute Abend", "morgen früh", "gestern Nachmittag", "heute Nacht" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b(?P<day>{rday})\s+(?P<part>{part})\b")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let offset = day_keyword_offset(caps.name("day")?.as_str())?;
+                let part = parse_day_part(caps.name("part")?.as_str())?;
+                let date = resolve::resolve_day_offset(offset, now, tz)?;
+                resolve::resolve_day_part(date, part, &config.day_parts, tz)
+            },
+        },
+        // --- Part of day: "letzte Nacht" ---
+        GrammarRule {
+            pattern: RulePattern::new(r"(?i)\bletzte\s+Nacht\b"),
+            kind: ExpressionKind::PartOfDay,
+            resolver: |_, now, tz, config| {
+                let date = resolve::resolve_day_offset(-1, now, tz)?;
+                resolve::resolve_day_part(date, DayPart::Night, &config.day_parts, tz)
+            },
+        },
+        // ============================================================
+        //  Combined: Weekday + Mittag/Mitternacht
+        //  "letzten Freitag um Mitternacht", "nächsten Montagmittag"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:am\s+)?(?P<dir>n[äae]chsten|kommenden|letzten|vergangenen|diesen)\s+(?P<wd>{wd})\s*(?:(?:um|am|zu)\s+)?(?P<nm>{nm})\b"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let direction = weekday_direction(caps.name("dir")?.as_str())?;
+                let weekday = parse_weekday(caps.name("wd")?.as_str())?;
+                let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
+                resolve_noon_midnight(caps, date, tz, config)
+            },
+        },
+        // ============================================================
+        //  Combined: Wochentag ohne Richtung + Mittag/Mitternacht
+        //  "am Freitag um Mitternacht", "Sonntagmittag"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:am\s+)?(?P<wd>{wd})\s*(?:(?:um|am|zu)\s+)?(?P<nm>{nm})\b"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
+                let date = resolve_bare_weekday(caps.name("wd")?.as_str(), now, tz, config)?;
+                resolve_noon_midnight(caps, date, tz, config)
+            },
+        },
+        // --- Combined: "morgen Mittag", "heute um Mitternacht" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?P<day>{rday})\s+(?:(?:um|zu)\s+)?(?P<nm>{nm})\b"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
                 let offset = day_keyword_offset(caps.name("day")?.as_str())?;
-                let from = caps.name("from")?.as_str().parse::<u32>().ok()?;
-                let to = caps.name("to")?.as_str().parse::<u32>().ok()?;
-                if from > 23 || to > 23 { return None; }
                 let date = resolve::resolve_day_offset(offset, now, tz)?;
-                resolve::resolve_time_range_on_date(date, from, to, tz)
+                resolve_noon_midnight(caps, date, tz, config)
+            },
+        },
+        // --- Mittag/Mitternacht heute: "um Mitternacht", "mittags", "gegen Mittag" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:(?:um|am|zu|(?P<approx>{APPROX_PAT}))\s+)?(?P<nm>{nm})\b"
+            )),
+            kind: ExpressionKind::TimeSpecification,
+            resolver: |caps, now, tz, config| {
+                let date = resolve::resolve_day_offset(0, now, tz)?;
+                resolve_noon_midnight(caps, date, tz, config)
             },
         },
         // --- Relative days ---
         GrammarRule {
-            pattern: Regex::new(r"(?i)\b(?P<day>heute|morgen|gestern)\b").unwrap(),
+            pattern: RulePattern::new(format!(r"(?i)\b(?P<day>{rday})\b")),
             kind: ExpressionKind::RelativeDay,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, _| {
                 let offset = day_keyword_offset(caps.name("day")?.as_str())?;
                 resolve::resolve_relative_day(offset, now, tz)
             },
         },
-        // --- Day offset: "vor 3 Tagen" ---
+        // --- Time offset: "vor 2 Stunden" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\bvor\s+(?P<num>{num})\s+(?P<unit>{subday})\b"
+            )),
+            kind: ExpressionKind::RelativeTimeOffset,
+            resolver: |caps, now, tz, _| {
+                let n = parse_num(caps.name("num")?.as_str())? as i64;
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_offset(unit, -n, now, tz)
+            },
+        },
+        // --- Time offset: "in 30 Minuten" ---
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\bvor\s+(?P<num>{num})\s+Tagen?\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(r"(?i)\bin\s+(?P<num>{num})\s+(?P<unit>{subday})\b")),
+            kind: ExpressionKind::RelativeTimeOffset,
+            resolver: |caps, now, tz, _| {
+                let n = parse_num(caps.name("num")?.as_str())? as i64;
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_offset(unit, n, now, tz)
+            },
+        },
+        // --- Day offset: "vor 3 Tagen", "vor einem Monat" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\bvor\s+(?P<num>{num})\s+(?P<unit>{days})\b")),
             kind: ExpressionKind::RelativeDayOffset,
-            resolver: |caps, now, tz| {
-                let n = parse_num(caps.name("num")?.as_str())?;
-                resolve::resolve_relative_day(-(n as i64), now, tz)
+            resolver: |caps, now, tz, _| {
+                let n = parse_num(caps.name("num")?.as_str())? as i64;
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_offset(unit, -n, now, tz)
             },
         },
-        // --- Day offset: "in 3 Tagen" ---
+        // --- Day offset: "in 3 Tagen", "in zwei Wochen" ---
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\bin\s+(?P<num>{num})\s+Tagen?\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(r"(?i)\bin\s+(?P<num>{num})\s+(?P<unit>{days})\b")),
             kind: ExpressionKind::RelativeDayOffset,
-            resolver: |caps, now, tz| {
-                let n = parse_num(caps.name("num")?.as_str())?;
-                resolve::resolve_relative_day(n as i64, now, tz)
+            resolver: |caps, now, tz, _| {
+                let n = parse_num(caps.name("num")?.as_str())? as i64;
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_offset(unit, n, now, tz)
+            },
+        },
+        // ============================================================
+        //  Combined: Tag + Beginn + Dauer: "morgen ab 14 Uhr für 3 Stunden"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:(?P<day>{rday})|(?P<date>{date})),?\s+{start}\s+f(?:ü|ue?)r\s+{dur}"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps)?;
+                let date = resolve_anchor_day(caps, now, tz, config)?;
+                resolve::resolve_time_for_duration(date, time, parse_duration(caps)?, tz)
+            },
+        },
+        // ============================================================
+        //  Time range: Beginn + Dauer: "ab 9 Uhr für 2 Stunden"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{start}\s+f(?:ü|ue?)r\s+{dur}")),
+            kind: ExpressionKind::TimeRange,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps)?;
+                let date = resolve_anchor_day(caps, now, tz, config)?;
+                resolve::resolve_time_for_duration(date, time, parse_duration(caps)?, tz)
+            },
+        },
+        // ============================================================
+        //  Time range: Dauer + Ende: "2 Stunden bis 17 Uhr"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b{dur}\s+bis\s+(?:um\s+)?(?P<hour>{HOUR_PAT})(?::(?P<min>\d{{2}}){SECONDS_PAT})?(?:\s*Uhr)?\b"
+            )),
+            kind: ExpressionKind::TimeRange,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps)?;
+                let date = resolve_anchor_day(caps, now, tz, config)?;
+                resolve::resolve_duration_until_time(date, time, parse_duration(caps)?, tz)
+            },
+        },
+        // --- Duration: "45 Minuten", "2,5 Stunden", "2 Std." ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{spelled_dur}")),
+            kind: ExpressionKind::Duration,
+            resolver: |caps, _, _, _| resolve_spelled_duration(caps),
+        },
+        // --- Duration: "anderthalb Stunden", "zweieinhalb Stunden" ---
+        GrammarRule {
+            pattern: RulePattern::new(
+                r"(?i)\b(?P<half>anderthalb|(?:ein|zwei|drei|vier|f[uü]nf|sechs|sieben|acht|neun|zehn)einhalb)\s+(?P<unit>Stunden?|Minuten?)\b",
+            ),
+            kind: ExpressionKind::Duration,
+            resolver: |caps, _, _, _| {
+                let amount = parse_and_a_half(caps.name("half")?.as_str())?;
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_duration(unit, amount)
             },
         },
+        // --- Duration: "eine halbe Stunde", "eine Viertelstunde", "drei viertel Stunde" ---
+        GrammarRule {
+            pattern: RulePattern::new(
+                r"(?i)\b(?:eine[rn]?\s+)?(?P<frac>halbe|(?:drei\s*)?viertel)\s*(?P<unit>Stunde|Minute)\b",
+            ),
+            kind: ExpressionKind::Duration,
+            resolver: |caps, _, _, _| resolve_spelled_duration(caps),
+        },
+        // --- Duration: "2 Stunden und 30 Minuten", "1 Std. 15 Min." ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?P<h>{num})\s+(?:Stunden?|Std\b\.?)(?:,?\s+und)?\s+(?P<m>{num})\s+(?:Minuten?|Min\b\.?)"
+            )),
+            kind: ExpressionKind::Duration,
+            resolver: |caps, _, _, _| {
+                let h = parse_num(caps.name("h")?.as_str())?;
+                let m = parse_num(caps.name("m")?.as_str())?;
+                duration::hours_minutes(h, m)
+            },
+        },
+        // --- Duration, compact: "2h", "90min", "2h30", "1:30h" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{COMPACT_DURATION_PAT}")),
+            kind: ExpressionKind::Duration,
+            resolver: |caps, _, _, _| duration::parse_compact(caps),
+        },
         // --- Time spec: "um 15[:30] [Uhr]" ---
         GrammarRule {
-            pattern: Regex::new(r"(?i)\bum\s+(?P<hour>\d{1,2})(?::(?P<min>\d{2})(?:\s+Uhr)?|\s+Uhr)\b").unwrap(),
+            pattern: RulePattern::new(format!(r"(?i)\b{at_time}")),
             kind: ExpressionKind::TimeSpecification,
-            resolver: |caps, now, tz| {
-                let (h, m) = parse_hm(caps)?;
+            resolver: |caps, now, tz, _| {
+                let time = parse_time(caps)?;
+                resolve::resolve_clock_time_today(time, now, tz)
+            },
+        },
+        // --- Approximate bare hour: "gegen 9", "etwa um 9" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?P<approx>{APPROX_HOUR_PAT})\s+(?P<hour>{HOUR_PAT})\b(?:\s+(?P<unit>{subday}|{days})\b)?"
+            )),
+            kind: ExpressionKind::TimeSpecification,
+            resolver: |caps, now, tz, _| {
+                // "gegen 3 Stunden" is an amount, not a clock time
+                if caps.name("unit").is_some() {
+                    return None;
+                }
+                let time = parse_time(caps)?;
+                resolve::resolve_clock_time_today(time, now, tz)
+            },
+        },
+        // --- Time spec, gesprochen: "halb vier", "um viertel nach drei", "15 Uhr 30",
+        //     "gegen halb vier" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:(?:um|(?P<approx>{APPROX_PAT}))\s+)?{spoken}"
+            )),
+            kind: ExpressionKind::TimeSpecification,
+            resolver: |caps, now, tz, _| {
+                let (h, m) = parse_spoken(caps)?;
                 resolve::resolve_time_today(h, m, now, tz)
             },
         },
         // --- Time range: "die letzte Stunde/Minute" ---
         GrammarRule {
-            pattern: Regex::new(r"(?i)\b(?:die\s+)?letzte\s+(?P<unit>Stunde|Minute)\b").unwrap(),
+            pattern: RulePattern::new(r"(?i)\b(?:die\s+)?letzte\s+(?P<unit>Stunde|Minute)\b"),
             kind: ExpressionKind::TimeRange,
-            resolver: |caps, now, _tz| {
+            resolver: |caps, now, _tz, _| {
                 let unit = caps.name("unit")?.as_str().to_lowercase();
                 let mapped = match unit.as_str() {
                     "stunde" => "hour",
@@ -408,52 +1437,85 @@ fn build_rules() -> Vec<GrammarRule> {
         },
         // --- Time range: "von 10:15 bis 13:45 [Uhr]", "von 10:15 - 13:45" ---
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\bvon\s+(?P<fh>\d{1,2}):(?P<fm>\d{2})\s*(?:bis\b|-)\s*(?P<th>\d{1,2}):(?P<tm>\d{2})(?:\s*Uhr)?\b",
-            )
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\bvon\s+(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}}){FROM_SECONDS_PAT}\s*(?:bis\b|-)\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}}){TO_SECONDS_PAT}(?:\s*Uhr)?\b",
+            )),
             kind: ExpressionKind::TimeRange,
-            resolver: |caps, now, tz| {
-                let (fh, fm, th, tm) = parse_hm_range(caps)?;
-                resolve::resolve_time_range_with_minutes_today(fh, fm, th, tm, now, tz)
+            resolver: |caps, now, tz, config| {
+                let (from, to) = parse_time_range(caps)?;
+                resolve::resolve_clock_range_today(from, to, now, tz, config.overnight_ranges)
             },
         },
         // --- Time range: "von 9 bis 12 Uhr" ---
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\bvon\s+(?P<from>\d{1,2})\s+bis\s+(?P<to>\d{1,2})\s*Uhr\b",
-            )
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\bvon\s+(?P<from>{HOUR_PAT})\s+bis\s+(?P<to>{HOUR_PAT})\s*Uhr\b"
+            )),
             kind: ExpressionKind::TimeRange,
-            resolver: |caps, now, tz| {
-                let from = caps.name("from")?.as_str().parse::<u32>().ok()?;
-                let to = caps.name("to")?.as_str().parse::<u32>().ok()?;
-                if from > 23 || to > 23 { return None; }
-                resolve::resolve_time_range_today(from, to, now, tz)
+            resolver: |caps, now, tz, config| {
+                let from = parse_num(caps.name("from")?.as_str())?;
+                let to = parse_num(caps.name("to")?.as_str())?;
+                if from > 23 || to > 23 {
+                    return None;
+                }
+                let (from, to) = clock::range_ends((from, 0), (to, 0))?;
+                resolve::resolve_clock_range_today(from, to, now, tz, config.overnight_ranges)
             },
         },
         // --- More Time Ranges ---
         GrammarRule {
-             pattern: Regex::new(
-                 r"(?i)\bzwischen\s+(?P<from>\d{1,2})\s+und\s+(?P<to>\d{1,2})\s*(?:Uhr)?\b",
-             )
-             .unwrap(),
-             kind: ExpressionKind::TimeRange,
-             resolver: |caps, now, tz| {
-                 let from = caps.name("from")?.as_str().parse::<u32>().ok()?;
-                 let to = caps.name("to")?.as_str().parse::<u32>().ok()?;
-                 if from > 23 || to > 23 { return None; }
-                 resolve::resolve_time_range_today(from, to, now, tz)
-             },
-         },
+            pattern: RulePattern::new(format!(
+                r"(?i)\bzwischen\s+(?P<from>{HOUR_PAT})\s+und\s+(?P<to>{HOUR_PAT})\s*(?:Uhr)?\b"
+            )),
+            kind: ExpressionKind::TimeRange,
+            resolver: |caps, now, tz, config| {
+                let from = parse_num(caps.name("from")?.as_str())?;
+                let to = parse_num(caps.name("to")?.as_str())?;
+                if from > 23 || to > 23 {
+                    return None;
+                }
+                let (from, to) = clock::range_ends((from, 0), (to, 0))?;
+                resolve::resolve_clock_range_today(from, to, now, tz, config.overnight_ranges)
+            },
+        },
+        // --- Compact range: "9-12 Uhr", "9.30 - 12", "0930-1200" (only with `compact_ranges`) ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b{COMPACT_RANGE_PAT}(?:\s*Uhr\b)?(?:\s+(?P<unit>{subday}|{days})\b)?"
+            )),
+            kind: ExpressionKind::TimeRange,
+            resolver: |caps, now, tz, config| {
+                if !config.compact_ranges || caps.name("unit").is_some() {
+                    return None;
+                }
+                let (fh, fm, th, tm) = compact_range::parse(caps, config)?;
+                let (from, to) = clock::range_ends((fh, fm), (th, tm))?;
+                resolve::resolve_clock_range_today(from, to, now, tz, config.overnight_ranges)
+            },
+        },
+        // --- Wochentag ohne Richtung: "am Freitag", "Montag" ---
+        GrammarRule {
+            // A weekday in front of a date is part of that date; if the date
+            // rule rejected it ("Montag, den 6. März 2026"), so do we.
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:am\s+)?(?P<bwd>{wd})\b(?P<dtail>,?\s+{date})?"
+            )),
+            kind: ExpressionKind::RelativeDay,
+            resolver: |caps, now, tz, config| {
+                if caps.name("dtail").is_some() {
+                    return None;
+                }
+                let date = resolve_bare_weekday(caps.name("bwd")?.as_str(), now, tz, config)?;
+                resolve::resolve_full_day(date, tz)
+            },
+        },
         // --- Next/Last/This Weekday ---
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\b(?:am\s+)?(?P<dir>n[äae]chsten|kommenden|letzten|vergangenen|diesen)\s+(?P<day>montag|dienstag|mittwoch|donnerstag|freitag|samstag|sonnabend|sonntag)\b"
-            )
-            .unwrap(),
+            pattern: RulePattern::new(
+                r"(?i)\b(?:am\s+)?(?P<dir>n[äae]chsten|kommenden|letzten|vergangenen|diesen)\s+(?P<day>montag|dienstag|mittwoch|donnerstag|freitag|samstag|sonnabend|sonntag)\b",
+            ),
             kind: ExpressionKind::RelativeDay,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, _| {
                 let dir_str = caps.name("dir")?.as_str().to_lowercase();
                 let direction = match dir_str.as_str() {
                     "nächsten" | "naechsten" | "kommenden" => 1,
@@ -465,6 +1527,265 @@ fn build_rules() -> Vec<GrammarRule> {
                 resolve::resolve_weekday(weekday, direction, now, tz)
             },
         },
+        // ============================================================
+        //  Grenze: "Ende der Woche", "Anfang nächsten Monats",
+        //  "gegen Mitte des Jahres", "zum Ende des Tages"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(
+                r"(?i)\b(?:(?:zum|zu|am|gegen)\s+)?(?:(?:den|dem|der)\s+)?(?P<bnd>Anfang|Beginn|Mitte|Ende)\s+(?:(?:des|der|dieses|dieser)\s+)?(?:(?P<dir>(?:n[äa]e?chst|kommend|letzt|vorig|vergangen)e[snmr]?)\s+)?(?P<unit>Tag(?:e?s)?|Woche|Monats?|Quartals?|Jahr(?:e?s)?)\b",
+            ),
+            kind: ExpressionKind::Boundary,
+            resolver: |caps, now, tz, config| {
+                let boundary = parse_boundary(caps.name("bnd")?.as_str())?;
+                let offset = match caps.name("dir") {
+                    Some(d) => period_direction(d.as_str())?,
+                    None => 0,
+                };
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_boundary(
+                    unit,
+                    boundary,
+                    offset,
+                    now,
+                    tz,
+                    config.week_start,
+                    config.business_hours,
+                )
+            },
+        },
+        // ============================================================
+        //  Grenze als Kompositum: "Monatsende", "zum Jahresanfang",
+        //  "am Quartalsende"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(
+                r"(?i)\b(?:(?:zum|zu|am|gegen)\s+)?(?:(?:den|dem|der)\s+)?(?P<unit>Monats|Quartals|Jahres)(?P<bnd>anfang|beginn|mitte|ende)\b",
+            ),
+            kind: ExpressionKind::Boundary,
+            resolver: |caps, now, tz, config| {
+                let boundary = parse_boundary(caps.name("bnd")?.as_str())?;
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_boundary(
+                    unit,
+                    boundary,
+                    0,
+                    now,
+                    tz,
+                    config.week_start,
+                    config.business_hours,
+                )
+            },
+        },
+        // ============================================================
+        //  Geschäftsschluss: "Feierabend", "zum Dienstschluss"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(
+                r"(?i)\b(?:zum\s+)?(?:Feierabend|Dienstschluss|Gesch(?:ä|ae)ftsschluss)\b",
+            ),
+            kind: ExpressionKind::Boundary,
+            resolver: |_, now, tz, config| {
+                resolve::resolve_close_of_business(
+                    TimeUnit::Day,
+                    0,
+                    now,
+                    tz,
+                    config.week_start,
+                    config.business_hours,
+                )
+            },
+        },
+        // ============================================================
+        //  Rest: "für den Rest des Tages", "den Rest der Woche"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(
+                r"(?i)\b(?:f(?:ü|ue)r\s+)?(?:den\s+)?Rest\s+(?:des|der)\s+(?P<unit>Tag(?:e?s)?|Woche|Monats?|Quartals?|Jahr(?:e?s)?)\b",
+            ),
+            kind: ExpressionKind::TimeRange,
+            resolver: |caps, now, tz, config| {
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_rest_of(unit, now, tz, config.week_start)
+            },
+        },
+        // ============================================================
+        //  Ganztägig: "ganztägig", "morgen ganztägig", "heute den ganzen Tag",
+        //  "den ganzen Tag morgen"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:(?:(?P<day>{rday})\s+)?ganzt(?:ä|ae)gig|(?P<day_pre>{rday})\s+(?:den\s+)?ganzen\s+Tag(?:\s+lang)?|(?:den\s+)?ganzen\s+Tag(?:\s+lang)?\s+(?P<day_post>{rday}))\b"
+            )),
+            kind: ExpressionKind::RelativeDay,
+            resolver: |caps, now, tz, _| {
+                let day = caps
+                    .name("day")
+                    .or(caps.name("day_pre"))
+                    .or(caps.name("day_post"));
+                let offset = match day {
+                    Some(d) => day_keyword_offset(d.as_str())?,
+                    None => 0,
+                };
+                resolve::resolve_relative_day(offset, now, tz)
+            },
+        },
+        // ============================================================
+        //  Relative period: "diese Woche", "letzten Monat", "nächstes Jahr",
+        //  "im vorigen Quartal", "in der kommenden Woche"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(
+                r"(?i)\b(?:(?:in\s+der|im)\s+)?(?P<dir>(?:dies|laufend|aktuell|letzt|vorig|vergangen|n[äa]e?chst|kommend)e[snmr]?)\s+(?P<unit>Woche|Monat|Quartal|Jahr)\b",
+            ),
+            kind: ExpressionKind::RelativePeriod,
+            resolver: |caps, now, tz, config| {
+                let offset = period_direction(caps.name("dir")?.as_str())?;
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_period(unit, offset, now, tz, config.week_start)
+            },
+        },
+        // ============================================================
+        //  Combined: ordinaler Wochentag + "um H[:MM] [Uhr]"
+        //  "am ersten Montag im März um 10 Uhr", "letzten Freitag des Monats um 16 Uhr"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{wom},?\s+{at}")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps)?;
+                let date = resolve_weekday_of_month_caps(caps, now, tz, config)?;
+                resolve::resolve_clock_time_on_date(date, time, tz)
+            },
+        },
+        // ============================================================
+        //  Ordinaler Wochentag: "am zweiten Dienstag im Mai",
+        //  "letzter Freitag des Monats", "am 1. Montag im nächsten Monat"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{wom}")),
+            kind: ExpressionKind::WeekdayOfMonth,
+            resolver: |caps, now, tz, config| {
+                let date = resolve_weekday_of_month_caps(caps, now, tz, config)?;
+                resolve::resolve_full_day(date, tz)
+            },
+        },
+        // ============================================================
+        //  Combined: Tag des Monats + "um H[:MM] [Uhr]"
+        //  "am 15. um 10 Uhr", "am dritten um 9:30"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{mday},?\s+{at}")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps)?;
+                let date = resolve_day_of_month_caps(caps, now, tz, config)?;
+                resolve::resolve_clock_time_on_date(date, time, tz)
+            },
+        },
+        // --- Tag des Monats: "am 15.", "zum 3.", "am dritten" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{mday}")),
+            kind: ExpressionKind::DayOfMonth,
+            resolver: |caps, now, tz, config| {
+                let date = resolve_day_of_month_caps(caps, now, tz, config)?;
+                resolve::resolve_full_day(date, tz)
+            },
+        },
+        // ============================================================
+        //  Combined: holiday + time spec
+        //  "Heiligabend um 18 Uhr", "am Ostermontag um 10 Uhr"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:(?:zu|an|am|zum|über)\s+)?{hol},?\s+{at}"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps)?;
+                let date = resolve_holiday_caps(caps, now, tz, config)?;
+                resolve::resolve_clock_time_on_date(date, time, tz)
+            },
+        },
+        // ============================================================
+        //  Holiday: "zu Ostern", "an Pfingsten", "am Ostermontag", "Weihnachten 2027"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b(?:(?:zu|an|am|zum|über)\s+)?{hol}")),
+            kind: ExpressionKind::Holiday,
+            resolver: |caps, now, tz, config| {
+                let date = resolve_holiday_caps(caps, now, tz, config)?;
+                resolve::resolve_full_day(date, tz)
+            },
+        },
+        // ============================================================
+        //  Combined: Datum + Mittag/Mitternacht
+        //  "am 5. März um Mitternacht", "05.03. mittags"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{date},?\s+(?:(?:um|zu)\s+)?(?P<nm>{nm})\b")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let date = resolve_date_caps(caps, now, tz, config)?;
+                resolve_noon_midnight(caps, date, tz, config)
+            },
+        },
+        // ============================================================
+        //  Wiederkehrend: "jeden Dienstag um 10", "jede zweite Woche",
+        //  "täglich um 9 Uhr", "werktags um Mitternacht"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b{recur}(?:,?\s+(?:{at}|um\s+(?P<nm>mittag|mitternacht)\b|um\s+(?P<rhour>{HOUR_PAT})\b))?"
+            )),
+            kind: ExpressionKind::Recurring,
+            resolver: |caps, _now, _tz, _config| resolve_recurrence_caps(caps),
+        },
+        // ============================================================
+        //  Combined: Datum + "um H[:MM] [Uhr]"
+        //  "am 5. März um 15 Uhr", "05.03. um 15:30 Uhr"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{date},?\s+{at}")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps)?;
+                let date = resolve_date_caps(caps, now, tz, config)?;
+                resolve::resolve_clock_time_on_date(date, time, tz)
+            },
+        },
+        // ============================================================
+        //  Combined: Datum + "von HH:MM bis HH:MM"
+        //  "am 5. März von 9:00 bis 11:30", "05.03.2026 9:00 - 17:00"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{date},?\s+{hm_range}")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let (from, to) = parse_time_range(caps)?;
+                let date = resolve_date_caps(caps, now, tz, config)?;
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
+            },
+        },
+        // ============================================================
+        //  Absolute date: "5. März", "am 5. März 2027", "Freitag, den 6. März",
+        //  "05.03.2026", "5.3.", "2026-03-05"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{date}")),
+            kind: ExpressionKind::AbsoluteDate,
+            resolver: |caps, now, tz, config| {
+                // A bare "3/5" is more likely a fraction than a date.
+                if caps.name("prep").is_none()
+                    && caps.name("wd").is_none()
+                    && numeric_date::is_short_slash(caps)
+                {
+                    return None;
+                }
+                let (year, month, day, weekday) = parse_date(caps, config)?;
+                resolve::resolve_absolute_date(year, month, day, weekday, now, tz, config.year_bias)
+            },
+        },
     ]
 }
 
@@ -481,7 +1802,36 @@ impl LanguageParser for German {
         PREFIXES
     }
 
-    fn parse(&self, text: &str, now: DateTime<Utc>, tz: Tz) -> Vec<TimeMatch> {
-        apply_rules(&self.rules, &self.regex_set, text, now, tz)
+    fn set_custom_holidays(&mut self, names: &[&str]) {
+        self.rules = build_rules(names);
+        self.regex_set = build_regex_set(&self.rules);
+    }
+
+    fn parse(
+        &self,
+        text: &str,
+        now: DateTime<Utc>,
+        tz: Tz,
+        config: &ParserConfig,
+    ) -> Vec<TimeMatch> {
+        let parse_in = |text: &str, tz: Tz| {
+            let mut rejected = Vec::new();
+            let matches = apply_rules_with_rejections(
+                &self.rules,
+                &self.regex_set,
+                text,
+                now,
+                tz,
+                config,
+                &mut rejected,
+            );
+            let matches = anchored_offset::apply(text, matches, &self.offsets, tz);
+            let matches =
+                open_range::apply(text, matches, &self.open_range, config, |t, config| {
+                    apply_rules(&self.rules, &self.regex_set, t, now, tz, config)
+                });
+            without_rejected(matches, &rejected)
+        };
+        zone::apply(text, parse_in(text, tz), &self.zones, config, parse_in)
     }
 }
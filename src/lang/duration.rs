@@ -0,0 +1,68 @@
+//! Language-independent compact duration formats.
+//!
+//! Recognizes the abbreviated notations common in time-tracking input:
+//! `2h`, `1.5h`, `1,5 h`, `90min`, `45 s`, `2h30`, `2h 30min` and `1:30h`.
+//! Decades (`90s`, `1990s`) are not read as seconds.
+//! Spelled-out durations (`"45 minutes"`, `"anderthalb Stunden"`) are
+//! handled by each language.
+
+use crate::resolve;
+use crate::types::{ResolvedTime, TimeUnit};
+
+/// Compact duration pattern for embedding in language rules.
+///
+/// Capture groups: `ch`/`cm` for hours followed by minutes (`2h30`),
+/// `kh`/`km` for colon notation (`1:30h`) and `cnum`/`cunit` for a single
+/// amount with a unit abbreviation (`90min`, `1.5h`).
+pub const COMPACT_DURATION_PAT: &str = r"(?:(?P<ch>\d{1,3})\s*h\s*(?P<cm>\d{1,2})(?:\s*(?:min|m)\b|\b)|(?P<kh>\d{1,3}):(?P<km>\d{2})\s*h\b|(?P<cnum>\d+(?:[.,]\d+)?)\s*(?P<cunit>h|hrs?|min|mins|s|secs?)\b)";
+
+/// Parse a decimal amount written with either `.` or `,` as separator.
+pub fn parse_decimal(s: &str) -> Option<f64> {
+    s.replace(',', ".").parse::<f64>().ok()
+}
+
+/// Resolve a duration given as whole hours plus minutes.
+///
+/// Returns `None` if `minutes` >= 60.
+pub fn hours_minutes(hours: u32, minutes: u32) -> Option<ResolvedTime> {
+    if minutes > 59 {
+        return None;
+    }
+    resolve::resolve_duration(TimeUnit::Minute, f64::from(hours * 60 + minutes))
+}
+
+/// Resolve a duration from captures produced by [`COMPACT_DURATION_PAT`].
+pub fn parse_compact(caps: &regex::Captures) -> Option<ResolvedTime> {
+    let num = |name: &str| caps.name(name)?.as_str().parse::<u32>().ok();
+
+    if let Some(h) = num("ch") {
+        return hours_minutes(h, num("cm")?);
+    }
+    if let Some(h) = num("kh") {
+        return hours_minutes(h, num("km")?);
+    }
+
+    let cnum = caps.name("cnum")?;
+    let cunit = caps.name("cunit")?;
+    // "the 90s" and "1990s" are decades, not seconds
+    if cunit.as_str().eq_ignore_ascii_case("s")
+        && cunit.start() == cnum.end()
+        && is_decade(cnum.as_str())
+    {
+        return None;
+    }
+    let amount = parse_decimal(cnum.as_str())?;
+    let unit = match cunit.as_str().to_lowercase().as_str() {
+        "h" | "hr" | "hrs" => TimeUnit::Hour,
+        "min" | "mins" => TimeUnit::Minute,
+        "s" | "sec" | "secs" => TimeUnit::Second,
+        _ => return None,
+    };
+    resolve::resolve_duration(unit, amount)
+}
+
+/// Whether a number names a decade when written with an "s": a multiple of
+/// ten with two or four digits (`90`, `1990`).
+fn is_decade(num: &str) -> bool {
+    matches!(num.len(), 2 | 4) && num.ends_with('0') && num.bytes().all(|b| b.is_ascii_digit())
+}
@@ -1,9 +1,19 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveTime, Utc};
 use chrono_tz::Tz;
-use regex::Regex;
 
-use crate::lang::numbers::parse_number_en;
-use crate::lang::{GrammarRule, LanguageParser, apply_rules};
+use crate::holiday::{self, Holiday};
+use crate::lang::anchored_offset::{self, OffsetRules};
+use crate::lang::clock::{self, FROM_SECONDS_PAT, SECONDS_PAT, TO_SECONDS_PAT};
+use crate::lang::compact_range::{self, COMPACT_RANGE_PAT};
+use crate::lang::duration::{self, COMPACT_DURATION_PAT};
+use crate::lang::numbers::{parse_number_en, parse_ordinal_en};
+use crate::lang::numeric_date::{self, NUMERIC_DATE_PAT};
+use crate::lang::open_range::{self, Bound, OpenRangeRules};
+use crate::lang::zone::{self, CITY_PAT, ZoneRules};
+use crate::lang::{
+    GrammarRule, LanguageParser, RulePattern, apply_rules, apply_rules_with_rejections,
+    build_regex_set, without_rejected, words_before,
+};
 use crate::resolve;
 use crate::types::*;
 
@@ -11,6 +21,8 @@ const KEYWORDS: &[&str] = &[
     "today",
     "tomorrow",
     "yesterday",
+    "day after",
+    "day before",
     "ago",
     "last",
     "hour",
@@ -23,7 +35,6 @@ const KEYWORDS: &[&str] = &[
     "from",
     "at",
     "in",
-    "day",
     "days",
     "minute",
     "minutes",
@@ -36,14 +47,132 @@ const KEYWORDS: &[&str] = &[
     "friday",
     "saturday",
     "sunday",
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+    "jan",
+    "feb",
+    "mar",
+    "apr",
+    "jun",
+    "jul",
+    "aug",
+    "sep",
+    "oct",
+    "nov",
+    "dec",
+    "week",
+    "month",
+    "quarter",
+    "year",
+    "second",
+    "sec",
+    "min",
+    "hr",
+    "morning",
+    "afternoon",
+    "evening",
+    "night",
+    "first",
+    "third",
+    "fourth",
+    "fifth",
+    "sixth",
+    "seventh",
+    "eighth",
+    "ninth",
+    "tenth",
+    "eleventh",
+    "twelfth",
+    "teenth",
+    "tieth",
+    "noon",
+    "midday",
+    "every",
+    "each",
+    "daily",
+    "annually",
+    "past",
+    "to",
+    "till",
+    "half",
+    "christmas",
+    "xmas",
+    "x-mas",
+    "easter",
+    "new year",
+    "epiphany",
+    "three kings",
+    "martin luther king",
+    "mlk",
+    "presidents",
+    "maundy",
+    "holy thursday",
+    "good friday",
+    "may day",
+    "labor day",
+    "labour day",
+    "memorial day",
+    "ascension",
+    "whit",
+    "pentecost",
+    "corpus christi",
+    "juneteenth",
+    "independence day",
+    "fourth of july",
+    "bastille",
+    "assumption",
+    "columbus",
+    "all saints",
+    "veterans",
+    "armistice",
+    "thanksgiving",
+    "boxing day",
+    "start of",
+    "beginning of",
+    "middle of",
+    "end of",
+    "mid",
+    "eod",
+    "cob",
+    "eob",
+    "eow",
+    "eom",
+    "eoq",
+    "eoy",
+    "close of business",
+    "rest of",
+    "all day",
+    "all-day",
+    "around",
+    "about",
+    "approximately",
+    "roughly",
+    "circa",
+    "since",
+    "after",
+    "until",
+    "before",
+    "starting",
+    "lunch",
 ];
 
 const PREFIXES: &[&str] = &[
     "tod", "toda", "tom", "tomo", "tomor", "tomorr", "tomorro", "yes", "yest", "yeste", "yester",
-    "yesterd", "yesterda", "bet", "betw", "betwe", "betwee", "mon", "mond", "monda", "tue", "tues",
-    "tuesd", "tuesda", "wed", "wedn", "wedne", "wednes", "wednesd", "wednesda", "thu", "thur",
-    "thurs", "thursd", "thursda", "fri", "frid", "frida", "sat", "satu", "satur", "saturd",
-    "saturda", "sun", "sund", "sunda",
+    "yesterd", "yesterda", "day a", "day af", "day aft", "day afte", "day b", "day be", "day bef",
+    "day befo", "bet", "betw", "betwe", "betwee", "mon", "mond", "monda", "tue", "tues", "tuesd",
+    "tuesda", "wed", "wedn", "wedne", "wednes", "wednesd", "wednesda", "thu", "thur", "thurs",
+    "thursd", "thursda", "fri", "frid", "frida", "sat", "satu", "satur", "saturd", "saturda",
+    "sun", "sund", "sunda",
 ];
 
 const NUM_WORD_PATTERN: &str = r"(?:\d+|one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve|thirteen|fourteen|fifteen|sixteen|seventeen|eighteen|nineteen|twenty|thirty)";
@@ -51,6 +180,9 @@ const NUM_WORD_PATTERN: &str = r"(?:\d+|one|two|three|four|five|six|seven|eight|
 pub struct English {
     rules: Vec<GrammarRule>,
     regex_set: regex::RegexSet,
+    open_range: OpenRangeRules,
+    offsets: OffsetRules,
+    zones: ZoneRules,
 }
 
 impl Default for English {
@@ -61,17 +193,33 @@ impl Default for English {
 
 impl English {
     pub fn new() -> Self {
-        let rules = build_rules();
-        let regex_set = regex::RegexSet::new(rules.iter().map(|r| r.pattern.as_str())).unwrap();
-        Self { rules, regex_set }
+        let rules = build_rules(&[]);
+        let regex_set = build_regex_set(&rules);
+        let open_range = OpenRangeRules::new(OPEN_RANGE_PAT, open_bound, "at");
+        let offsets = OffsetRules::new(&offset_pattern(), anchor_offset);
+        let zones = ZoneRules::new(&format!(r"{CITY_PAT}\s+time"));
+        Self {
+            rules,
+            regex_set,
+            open_range,
+            offsets,
+            zones,
+        }
     }
 }
 
 fn day_keyword_offset(s: &str) -> Option<i64> {
-    match s.to_lowercase().as_str() {
+    let lower = s
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    match lower.strip_prefix("the ").unwrap_or(&lower) {
         "today" => Some(0),
         "tomorrow" => Some(1),
         "yesterday" => Some(-1),
+        "day after tomorrow" => Some(2),
+        "day before yesterday" => Some(-2),
         _ => None,
     }
 }
@@ -89,15 +237,540 @@ fn parse_weekday(s: &str) -> Option<chrono::Weekday> {
     }
 }
 
+fn parse_month(s: &str) -> Option<u32> {
+    match s.to_lowercase().trim_end_matches('.') {
+        "january" | "jan" => Some(1),
+        "february" | "feb" => Some(2),
+        "march" | "mar" => Some(3),
+        "april" | "apr" => Some(4),
+        "may" => Some(5),
+        "june" | "jun" => Some(6),
+        "july" | "jul" => Some(7),
+        "august" | "aug" => Some(8),
+        "september" | "sept" | "sep" => Some(9),
+        "october" | "oct" => Some(10),
+        "november" | "nov" => Some(11),
+        "december" | "dec" => Some(12),
+        _ => None,
+    }
+}
+
 fn parse_num(s: &str) -> Option<u32> {
-    s.parse::<u32>()
-        .ok()
-        .or_else(|| parse_number_en(&s.to_lowercase()))
+    match s.to_lowercase().as_str() {
+        "a" | "an" => Some(1),
+        lower => s.parse::<u32>().ok().or_else(|| parse_number_en(lower)),
+    }
 }
 
 /// Shared day pattern for weekdays
 const WEEKDAY_PAT: &str = r"monday|tuesday|wednesday|thursday|friday|saturday|sunday";
 
+/// Relative day keywords, longest first ("the day after tomorrow", "today")
+const RELATIVE_DAY_PAT: &str = r"(?:the\s+)?day\s+after\s+tomorrow|(?:the\s+)?day\s+before\s+yesterday|today|tomorrow|yesterday";
+
+/// Shared month pattern (full names and common abbreviations)
+const MONTH_PAT: &str = r"january|february|march|april|may|june|july|august|september|october|november|december|jan|feb|mar|apr|jun|jul|aug|sept|sep|oct|nov|dec";
+
+/// Clock hour as digits or a written-out number ("3", "three")
+const HOUR_PAT: &str = r"\d{1,2}|one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve";
+
+/// Approximation markers: "around", "about", "at approximately"
+const APPROX_PAT: &str = r"(?:at\s+)?(?:around|about|approximately|roughly|circa|ca\.)";
+
+/// Approximation markers that make a bare hour a time: "around 3", "at about 3".
+/// A plain "about 3" is more often a quantity.
+const APPROX_HOUR_PAT: &str = r"(?:at\s+)?around|at\s+(?:about|approximately|roughly)";
+
+/// Meridiem suffix: "am", "pm", "a.m.", "P.M."
+const MERIDIEM_PAT: &str = r"[ap]\.m\.|[ap]\.?m\b";
+
+/// Shared "at H[:MM[:SS]] [am|pm|o'clock]" pattern (groups `hour`, `min`,
+/// `sec`, `subsec`, `ampm`, `sfx`, and `approx` for "around 3pm")
+fn at_time_pattern() -> String {
+    format!(
+        r"(?:at|(?P<approx>{APPROX_PAT}))\s+(?P<hour>{HOUR_PAT})(?::(?P<min>\d{{2}}){SECONDS_PAT}(?:\s*(?P<ampm>{MERIDIEM_PAT})|\b)|\s*(?P<sfx>{MERIDIEM_PAT}|o'?clock\b)|\b)"
+    )
+}
+
+/// Spoken clock reading: "half past three", "quarter to five", "ten past 9pm",
+/// "twenty-five to six" (groups `sp_min`, `sp_unit`, `sp_rel`, `sp_hour`, `sp_ampm`)
+fn spoken_time_pattern() -> String {
+    format!(
+        r"(?P<sp_min>half|(?:a\s+)?quarter|(?:twenty|thirty)[\s-](?:one|two|three|four|five|six|seven|eight|nine)|{NUM_WORD_PATTERN})(?P<sp_unit>\s+minutes?)?\s+(?P<sp_rel>past|after|to|till|before)\s+(?P<sp_hour>{HOUR_PAT})(?:\s*(?P<sp_ampm>{MERIDIEM_PAT}|o'?clock\b)|\b)"
+    )
+}
+
+/// Hour range with optional minutes and am/pm on either end, joined by
+/// `joiner`: "9am to 5pm", "2 and 4pm", "9:30-11am" (groups `from`, `fmin`,
+/// `fmer`, `to`, `tmin`, `tmer`)
+fn clock_range_pattern(hour: &str, joiner: &str) -> String {
+    format!(
+        r"(?P<from>{hour})(?::(?P<fmin>\d{{2}}))?(?:\s*(?P<fmer>{MERIDIEM_PAT}))?{joiner}(?P<to>{hour})(?::(?P<tmin>\d{{2}}))?(?:\s*(?P<tmer>{MERIDIEM_PAT})|\s*o'?clock\b|\b)"
+    )
+}
+
+/// Shared "[from] HH:MM[:SS] to/- HH:MM[:SS]" pattern (groups `fh`, `fm`, `fsec`,
+/// `fsubsec`, `th`, `tm`, `tsec`, `tsubsec`)
+fn hm_range_pattern() -> String {
+    format!(
+        r"(?:from\s+)?(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}}){FROM_SECONDS_PAT}\s*(?:to\b|-)\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}}){TO_SECONDS_PAT}\b"
+    )
+}
+
+/// Shared start-time pattern for duration-anchored ranges (groups `anchor`, `hour`, `min`, `ampm`)
+fn start_time_pattern() -> String {
+    format!(
+        r"(?:(?P<anchor>from|at|starting(?:\s+at)?)\s+)?(?P<hour>{HOUR_PAT})(?::(?P<min>\d{{2}}){SECONDS_PAT})?(?:\s*(?P<ampm>{MERIDIEM_PAT}))?"
+    )
+}
+
+/// Calendar date pattern: month-name dates, month first (`month`, `dom`) or
+/// day first (`dom2`, `month2`), with optional leading weekday (`wd`) and
+/// `year`, or a numeric date (see [`NUMERIC_DATE_PAT`]).
+fn date_pattern() -> String {
+    format!(
+        r"(?:(?:(?P<wd>{WEEKDAY_PAT}),?\s+)?(?:(?P<month>{MONTH_PAT})\.?\s+(?P<dom>\d{{1,2}})(?:st|nd|rd|th)?\b|(?:the\s+)?(?P<dom2>\d{{1,2}})(?:st|nd|rd|th)?(?:\s+of)?\s+(?P<month2>{MONTH_PAT})\b\.?)(?:,?\s+(?P<year>\d{{4}})\b)?|{NUMERIC_DATE_PAT})"
+    )
+}
+
+/// Parse a calendar date from captures produced by [`date_pattern`] into
+/// `(year, month, day, weekday)`.
+fn parse_date(
+    caps: &regex::Captures,
+    config: &ParserConfig,
+) -> Option<(Option<i32>, u32, u32, Option<chrono::Weekday>)> {
+    let Some(month) = caps.name("month").or(caps.name("month2")) else {
+        let order = config.date_order.unwrap_or(DateOrder::MonthDayYear);
+        let date = numeric_date::parse(caps, order)?;
+        return Some((date.year, date.month, date.day, None));
+    };
+    let month = parse_month(month.as_str())?;
+    let day = caps
+        .name("dom")
+        .or(caps.name("dom2"))?
+        .as_str()
+        .parse::<u32>()
+        .ok()?;
+    let year = match caps.name("year") {
+        Some(y) => Some(y.as_str().parse::<i32>().ok()?),
+        None => None,
+    };
+    let weekday = match caps.name("wd") {
+        Some(w) => Some(parse_weekday(w.as_str())?),
+        None => None,
+    };
+    Some((year, month, day, weekday))
+}
+
+/// Words before a lowercase "may" that show it names the month: "by may 5",
+/// "until may 5".
+const MAY_CONTEXT_WORDS: &[&str] = &[
+    "on", "by", "until", "till", "before", "after", "since", "from", "starting",
+];
+
+/// Drop matches that start with the modal verb "may" read as a month: in
+/// "I may 5 go", "may 5" is not a date.
+///
+/// A lowercase "may" before a day counts as the month only with a date
+/// context: an ordinal suffix ("may 5th"), a year ("may 5, 2026"), or a
+/// preposition in front of it ("on may 5", "by may 5"). A capitalized "May"
+/// is always the month.
+fn without_modal_may(text: &str, mut matches: Vec<TimeMatch>) -> Vec<TimeMatch> {
+    matches.retain(|m| {
+        let Some(rest) = text[m.span.start..].strip_prefix("may") else {
+            return true;
+        };
+        let rest = rest.trim_start();
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 {
+            return true;
+        }
+        let tail = rest[digits..].to_lowercase();
+        let year = tail.trim_start_matches(',').trim_start();
+        if ["st", "nd", "rd", "th"].iter().any(|s| tail.starts_with(s))
+            || (year.len() >= 4 && year.bytes().take(4).all(|b| b.is_ascii_digit()))
+        {
+            return true;
+        }
+        let word = &text[words_before(text, m.span.start, 1)..m.span.start];
+        MAY_CONTEXT_WORDS.contains(&word.trim().to_lowercase().as_str())
+    });
+    matches
+}
+
+/// Resolve a calendar date from captures to midnight of that day.
+fn resolve_date_caps(
+    caps: &regex::Captures,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<DateTime<Utc>> {
+    let (year, month, day, weekday) = parse_date(caps, config)?;
+    resolve::resolve_date(year, month, day, weekday, now, tz, config.year_bias)
+}
+
+/// Ordinal words for the days of a month: "first" ... "thirty-first"
+const ORDINAL_WORD_PAT: &str = r"(?:twenty|thirty)[\s-]first|twenty[\s-](?:second|third|fourth|fifth|sixth|seventh|eighth|ninth)|first|second|third|fourth|fifth|sixth|seventh|eighth|ninth|tenth|eleventh|twelfth|thirteenth|fourteenth|fifteenth|sixteenth|seventeenth|eighteenth|nineteenth|twentieth|thirtieth";
+
+/// Day of the month without a month: "the 15th", "on the 3rd", "on the
+/// first" (groups `mday` for digits, `mday_w` for words). Words need "on"
+/// so that "the first" alone stays ordinary text.
+fn day_of_month_pattern() -> String {
+    format!(
+        r"(?:(?:on\s+)?the\s+(?P<mday>\d{{1,2}}(?:st|nd|rd|th))|on\s+the\s+(?P<mday_w>{ORDINAL_WORD_PAT}))\b"
+    )
+}
+
+/// Resolve a day of the month from captures to midnight of that day.
+fn resolve_day_of_month_caps(
+    caps: &regex::Captures,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<DateTime<Utc>> {
+    let day = caps.name("mday").or(caps.name("mday_w"))?;
+    let day = parse_ordinal_en(&day.as_str().to_lowercase())?;
+    resolve::resolve_day_of_month(day, now, tz, config.day_of_month_bias)
+}
+
+/// Holiday names ("Christmas Eve", "Easter Monday", "Thanksgiving")
+const HOLIDAY_PAT: &str = r"new\s+year['’]?s\s+eve|new\s+year['’]?s(?:\s+day)?|feast\s+of\s+(?:the\s+)?epiphany|epiphany\s+day|three\s+kings['’]?\s+day|martin\s+luther\s+king\s+day|mlk\s+day|presidents['’]?\s+day|maundy\s+thursday|holy\s+thursday|good\s+friday|easter\s+monday|easter(?:\s+sunday)?|may\s+day|labou?r\s+day|memorial\s+day|ascension\s+day|whit\s*monday|whit\s*sunday|whitsun|pentecost(?:\s+sunday)?|corpus\s+christi|juneteenth|independence\s+day|fourth\s+of\s+july|bastille\s+day|assumption\s+day|feast\s+of\s+the\s+assumption|columbus\s+day|all\s+saints['’]?(?:\s+day)?|veterans['’]?\s+day|armistice\s+day|thanksgiving(?:\s+day)?|christmas\s+eve|x-?mas\s+eve|christmas(?:\s+day)?|x-?mas|boxing\s+day";
+
+/// Holiday name with an optional year: "Easter 2027" (groups `hol`, `hyear`,
+/// and `custom` for the calendar's custom holidays).
+fn holiday_pattern(custom_holidays: &[&str]) -> String {
+    let custom = holiday::custom_name_pattern(custom_holidays)
+        .map(|p| format!("|{p}"))
+        .unwrap_or_default();
+    format!(r"(?P<hol>{HOLIDAY_PAT}{custom})\b(?:,?\s+(?P<hyear>\d{{4}})\b)?")
+}
+
+fn parse_holiday(s: &str) -> Option<Holiday> {
+    match holiday::name_key(s).as_str() {
+        "newyearseve" => Some(Holiday::NewYearsEve),
+        "newyears" | "newyearsday" => Some(Holiday::NewYearsDay),
+        "feastofepiphany" | "feastoftheepiphany" | "epiphanyday" | "threekingsday" => {
+            Some(Holiday::Epiphany)
+        }
+        "martinlutherkingday" | "mlkday" => Some(Holiday::MartinLutherKingDay),
+        "presidentsday" => Some(Holiday::PresidentsDay),
+        "maundythursday" | "holythursday" => Some(Holiday::MaundyThursday),
+        "goodfriday" => Some(Holiday::GoodFriday),
+        "easter" | "eastersunday" => Some(Holiday::EasterSunday),
+        "eastermonday" => Some(Holiday::EasterMonday),
+        "mayday" | "labourday" => Some(Holiday::LabourDay),
+        "laborday" => Some(Holiday::LaborDay),
+        "memorialday" => Some(Holiday::MemorialDay),
+        "ascensionday" => Some(Holiday::Ascension),
+        "whitmonday" => Some(Holiday::WhitMonday),
+        "whitsunday" | "whitsun" | "pentecost" | "pentecostsunday" => Some(Holiday::Pentecost),
+        "corpuschristi" => Some(Holiday::CorpusChristi),
+        "juneteenth" => Some(Holiday::Juneteenth),
+        "independenceday" | "fourthofjuly" => Some(Holiday::IndependenceDay),
+        "bastilleday" => Some(Holiday::BastilleDay),
+        "assumptionday" | "feastoftheassumption" => Some(Holiday::Assumption),
+        "columbusday" => Some(Holiday::ColumbusDay),
+        "allsaints" | "allsaintsday" => Some(Holiday::AllSaints),
+        "veteransday" | "armisticeday" => Some(Holiday::ArmisticeDay),
+        "thanksgiving" | "thanksgivingday" => Some(Holiday::Thanksgiving),
+        "christmaseve" | "xmaseve" => Some(Holiday::ChristmasEve),
+        "christmas" | "christmasday" | "xmas" => Some(Holiday::ChristmasDay),
+        "boxingday" => Some(Holiday::BoxingDay),
+        _ => None,
+    }
+}
+
+/// Words after a bare hour that show it is a clock time: "at 3 tomorrow",
+/// "around 5 on Friday", "at 9 sharp".
+const HOUR_CONTEXT_WORDS: &[&str] = &[
+    "today",
+    "tonight",
+    "tomorrow",
+    "yesterday",
+    "this",
+    "next",
+    "on",
+    "in",
+    "sharp",
+    "every",
+];
+
+/// Words that can stand before a bare hour: "at 3", "at around 3", "after
+/// 3", "starting at 3".
+const BARE_HOUR_MARKERS: &[&str] = &[
+    "at",
+    "around",
+    "about",
+    "approximately",
+    "roughly",
+    "since",
+    "after",
+    "until",
+    "till",
+    "before",
+    "starting",
+    "from",
+];
+
+/// Drop bare hours that count things: in "at 5 stores", "around 5 people
+/// came" and "after 3 attempts", the number is not a clock time.
+///
+/// A bare hour ("at 3", "around 3") is a time only at the end of a clause,
+/// before punctuation that does not continue a number ("at 3.", "at 3,
+/// then"), before a day or time word ([`HOUR_CONTEXT_WORDS`], a weekday), or
+/// before a capitalized word such as a time zone ("at 3 PST", "at 3 Berlin
+/// time").
+fn without_hour_quantities(text: &str, mut matches: Vec<TimeMatch>) -> Vec<TimeMatch> {
+    matches.retain(|m| {
+        if m.kind != ExpressionKind::TimeSpecification && m.kind != ExpressionKind::OpenRange {
+            return true;
+        }
+        let words: Vec<String> = text[m.span.as_range()]
+            .split_whitespace()
+            .map(str::to_lowercase)
+            .collect();
+        let Some((hour, markers)) = words.split_last() else {
+            return true;
+        };
+        if parse_num(hour).is_none()
+            || markers.is_empty()
+            || !markers
+                .iter()
+                .all(|w| BARE_HOUR_MARKERS.contains(&w.as_str()))
+        {
+            return true;
+        }
+        let rest = text[m.span.end..].trim_start();
+        let mut chars = rest.chars();
+        let Some(next) = chars.next() else {
+            return true;
+        };
+        if !next.is_alphanumeric() {
+            return !chars.next().is_some_and(|c| c.is_ascii_digit());
+        }
+        let word: String = rest.chars().take_while(|c| c.is_alphanumeric()).collect();
+        next.is_uppercase()
+            || HOUR_CONTEXT_WORDS.contains(&word.to_lowercase().as_str())
+            || parse_weekday(&word).is_some()
+    });
+    matches
+}
+
+/// Feast names that also work as noun modifiers: "the Christmas tree",
+/// "Easter eggs", "Thanksgiving dinner".
+const MODIFIER_FEASTS: &[&str] = &["christmas", "xmas", "x-mas", "easter", "thanksgiving"];
+
+/// Words before a bare feast name that show it names the day: "since
+/// Christmas", "two days after Easter".
+const FEAST_CONTEXT_WORDS: &[&str] = &[
+    "on", "at", "over", "for", "by", "until", "till", "before", "after", "since", "from", "to",
+    "around", "during",
+];
+
+/// Drop bare feast names used as noun modifiers: in "the Christmas tree is
+/// up", "the Christmas" is not a date.
+///
+/// A feast name that also works as a modifier ([`MODIFIER_FEASTS`]) followed
+/// by another word counts as the holiday only with a date context: a year
+/// ("Christmas 2026") or a time ("Christmas at 6pm") in the match, or a
+/// preposition in front of it ("on Christmas", "since Christmas"). At the
+/// end of a clause ("see you at Christmas.") it is always the holiday.
+fn without_feast_modifiers(text: &str, mut matches: Vec<TimeMatch>) -> Vec<TimeMatch> {
+    matches.retain(|m| {
+        if m.kind != ExpressionKind::Holiday {
+            return true;
+        }
+        let lower = text[m.span.as_range()].to_lowercase();
+        let name = lower
+            .strip_prefix("the")
+            .filter(|rest| rest.starts_with(char::is_whitespace))
+            .map_or(lower.as_str(), str::trim_start);
+        if !MODIFIER_FEASTS.contains(&name)
+            || !text[m.span.end..]
+                .trim_start()
+                .starts_with(char::is_alphanumeric)
+        {
+            return true;
+        }
+        let word = &text[words_before(text, m.span.start, 1)..m.span.start];
+        FEAST_CONTEXT_WORDS.contains(&word.trim().to_lowercase().as_str())
+    });
+    matches
+}
+
+/// Resolve a holiday from captures to midnight of that day.
+fn resolve_holiday_caps(
+    caps: &regex::Captures,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<DateTime<Utc>> {
+    let year = match caps.name("hyear") {
+        Some(y) => Some(y.as_str().parse::<i32>().ok()?),
+        None => None,
+    };
+    if let Some(name) = caps.name("custom") {
+        return holiday::resolve_custom_holiday(name.as_str(), year, now, tz, config);
+    }
+    let holiday = parse_holiday(caps.name("hol")?.as_str())?;
+    holiday::resolve_holiday(holiday, year, now, tz, config)
+}
+
+/// Ordinal weekday of a month: "the first Monday of March", "last Friday of
+/// the month", "the 2nd Tuesday of next month" (groups `ord`, `owd`,
+/// `omonth`, `oyear`, `mdir`).
+fn weekday_of_month_pattern() -> String {
+    format!(
+        r"(?:the\s+)?(?P<ord>first|second|third|fourth|fifth|last|1st|2nd|3rd|4th|5th)\s+(?P<owd>{WEEKDAY_PAT})\s+(?:of|in)\s+(?:(?P<omonth>{MONTH_PAT})\b\.?(?:,?\s+(?P<oyear>\d{{4}}))?|(?:(?P<mdir>next|last|this)|the)\s+month)\b"
+    )
+}
+
+fn parse_week_ordinal(s: &str) -> Option<i32> {
+    match s.to_lowercase().as_str() {
+        "last" => Some(-1),
+        lower => i32::try_from(parse_ordinal_en(lower)?).ok(),
+    }
+}
+
+/// Resolve an ordinal weekday of a month from captures to midnight of that day.
+fn resolve_weekday_of_month_caps(
+    caps: &regex::Captures,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<DateTime<Utc>> {
+    let n = parse_week_ordinal(caps.name("ord")?.as_str())?;
+    let weekday = parse_weekday(caps.name("owd")?.as_str())?;
+    let Some(month) = caps.name("omonth") else {
+        let offset = match caps.name("mdir") {
+            Some(d) => weekday_direction(d.as_str())?,
+            None => 0,
+        };
+        return resolve::resolve_weekday_of_relative_month(n, weekday, offset, now, tz);
+    };
+    let month = parse_month(month.as_str())?;
+    let year = match caps.name("oyear") {
+        Some(y) => Some(y.as_str().parse::<i32>().ok()?),
+        None => None,
+    };
+    resolve::resolve_weekday_of_month(n, weekday, month, year, now, tz, config.year_bias)
+}
+
+/// List of weekdays, plural allowed: "Monday", "Mondays and Thursdays",
+/// "Monday, Wednesday, and Friday".
+fn weekday_list_pattern() -> String {
+    format!(r"(?:{WEEKDAY_PAT})s?(?:(?:\s*,\s*(?:and\s+)?|\s+and\s+)(?:{WEEKDAY_PAT})s?)*")
+}
+
+/// Parse every weekday named in a list matched by [`weekday_list_pattern`].
+fn parse_weekday_list(s: &str) -> Vec<chrono::Weekday> {
+    s.split(|c: char| !c.is_alphabetic())
+        .filter_map(|w| parse_weekday(w.trim_end_matches(['s', 'S'])))
+        .collect()
+}
+
+/// Recurring schedule without its time: "every Monday", "every other week",
+/// "every 2 days", "every week on Monday and Thursday", "every weekday",
+/// "daily" (groups `other`, `rn`, `runit`, `ron`, `rwds`, `rwork`, `radv`).
+fn recurrence_pattern() -> String {
+    let list = weekday_list_pattern();
+    format!(
+        r"(?:(?:every|each)\s+(?:(?P<other>other)\s+|(?P<rn>{NUM_WORD_PATTERN})\s+)?(?:(?P<runit>minutes?|hours?|days?|weeks?|months?|quarters?|years?)(?:\s+on\s+(?P<ron>{list}))?|(?P<rwds>{list})|(?P<rwork>weekday)s?)|(?P<radv>hourly|daily|weekly|monthly|quarterly|yearly|annually))\b"
+    )
+}
+
+/// Resolve a recurring schedule from captures produced by
+/// [`recurrence_pattern`], with an optional time of day in the `at`
+/// groups ("at 9", "at 9am") or `nm` ("at noon").
+fn resolve_recurrence_caps(caps: &regex::Captures, config: &ParserConfig) -> Option<ResolvedTime> {
+    let (frequency, weekdays) = if let Some(adv) = caps.name("radv") {
+        let unit = match adv.as_str().to_lowercase().as_str() {
+            "hourly" => TimeUnit::Hour,
+            "daily" => TimeUnit::Day,
+            "weekly" => TimeUnit::Week,
+            "monthly" => TimeUnit::Month,
+            "quarterly" => TimeUnit::Quarter,
+            _ => TimeUnit::Year,
+        };
+        (unit, Vec::new())
+    } else if let Some(unit) = caps.name("runit") {
+        let weekdays = caps
+            .name("ron")
+            .map(|l| parse_weekday_list(l.as_str()))
+            .unwrap_or_default();
+        (parse_unit(unit.as_str())?, weekdays)
+    } else if caps.name("rwork").is_some() {
+        use chrono::Weekday::*;
+        (TimeUnit::Day, vec![Mon, Tue, Wed, Thu, Fri])
+    } else {
+        (
+            TimeUnit::Week,
+            parse_weekday_list(caps.name("rwds")?.as_str()),
+        )
+    };
+    let interval = if caps.name("other").is_some() {
+        2
+    } else if let Some(n) = caps.name("rn") {
+        parse_num(n.as_str())?
+    } else {
+        1
+    };
+    let time = if let Some(nm) = caps.name("nm") {
+        if nm.as_str().eq_ignore_ascii_case("midnight") {
+            Some(NaiveTime::MIN)
+        } else {
+            NaiveTime::from_hms_opt(12, 0, 0)
+        }
+    } else if caps.name("hour").is_some() || caps.name("sp_hour").is_some() {
+        Some(parse_time(caps, config)?)
+    } else {
+        None
+    };
+    resolve::resolve_recurrence(frequency, interval, weekdays, time)
+}
+
+/// Part-of-day words: "morning", "afternoon", "evening", "night".
+const DAY_PART_PAT: &str = r"morning|afternoon|evening|night";
+
+fn parse_day_part(s: &str) -> Option<DayPart> {
+    match s.to_lowercase().as_str() {
+        "morning" => Some(DayPart::Morning),
+        "afternoon" => Some(DayPart::Afternoon),
+        "evening" => Some(DayPart::Evening),
+        "night" => Some(DayPart::Night),
+        _ => None,
+    }
+}
+
+/// Noon and midnight words: "noon", "12 noon", "midday", "midnight".
+const NOON_MIDNIGHT_PAT: &str = r"(?:12\s+)?noon|midday|midnight";
+
+/// Resolve the noon or midnight word in the `nm` group on the day starting at `date`.
+fn resolve_noon_midnight(
+    caps: &regex::Captures,
+    date: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<ResolvedTime> {
+    if caps.name("nm")?.as_str().eq_ignore_ascii_case("midnight") {
+        resolve::resolve_midnight(date, config.midnight, tz)
+    } else {
+        resolve::resolve_time_on_date(date, 12, 0, tz)
+    }
+}
+
+/// Resolve a weekday named without next/last/this to midnight of the day
+/// picked by [`ParserConfig::weekday_bias`].
+fn resolve_bare_weekday(
+    wd: &str,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<DateTime<Utc>> {
+    let weekday = parse_weekday(wd)?;
+    resolve::resolve_weekday_date_with_bias(weekday, now, tz, config.weekday_bias)
+}
+
 /// Resolve a weekday direction string to -1, 0, or 1
 fn weekday_direction(s: &str) -> Option<i64> {
     match s.to_lowercase().as_str() {
@@ -108,21 +781,65 @@ fn weekday_direction(s: &str) -> Option<i64> {
     }
 }
 
-/// Resolve hour+ampm to 24h, handling am/pm/o'clock
+/// Resolve a period direction string to -1, 0, or 1
+fn period_direction(s: &str) -> Option<i64> {
+    match s.to_lowercase().as_str() {
+        "this" | "current" => Some(0),
+        "last" | "previous" => Some(-1),
+        "next" | "coming" => Some(1),
+        _ => None,
+    }
+}
+
+fn parse_boundary(s: &str) -> Option<Boundary> {
+    match s.to_lowercase().as_str() {
+        "start" | "beginning" => Some(Boundary::Start),
+        "middle" | "mid" => Some(Boundary::Middle),
+        "end" => Some(Boundary::End),
+        _ => None,
+    }
+}
+
+/// The period a close-of-business abbreviation ends ("EOD", "EOW", ...).
+fn close_of_business_unit(s: &str) -> Option<TimeUnit> {
+    match s {
+        "EOD" | "COB" | "EOB" => Some(TimeUnit::Day),
+        "EOW" => Some(TimeUnit::Week),
+        "EOM" => Some(TimeUnit::Month),
+        "EOQ" => Some(TimeUnit::Quarter),
+        "EOY" => Some(TimeUnit::Year),
+        _ => None,
+    }
+}
+
+/// Resolve hour+am/pm to 24h
 fn resolve_hour(hour: u32, ampm: &str) -> Option<u32> {
-    let h = if ampm.to_lowercase().starts_with("o") {
-        hour
-    } else {
-        resolve::to_24h(hour, ampm)
-    };
+    let h = resolve::to_24h(hour, ampm);
     if h > 23 { None } else { Some(h) }
 }
 
+/// The am/pm captured in group `name`, unless it is "o'clock".
+fn meridiem<'t>(caps: &regex::Captures<'t>, name: &str) -> Option<&'t str> {
+    caps.name(name)
+        .map(|m| m.as_str())
+        .filter(|s| !s.to_lowercase().starts_with('o'))
+}
+
+/// Parse a time of day with optional `:SS` seconds from captures produced by
+/// [`at_time_pattern`] or [`spoken_time_pattern`].
+fn parse_time(caps: &regex::Captures, config: &ParserConfig) -> Option<NaiveTime> {
+    let (h, m) = parse_hm_ampm(caps, config)?;
+    clock::time_of_day(caps, h, m, "sec", "subsec")
+}
+
 /// Parse hour with optional :MM and optional am/pm/o'clock from captures.
 /// Handles both colon form (H:MM with optional am/pm in "ampm" group)
 /// and whole-hour form (H with suffix in "sfx" group).
-fn parse_hm_ampm(caps: &regex::Captures) -> Option<(u32, u32)> {
-    let hour = caps.name("hour")?.as_str().parse::<u32>().ok()?;
+fn parse_hm_ampm(caps: &regex::Captures, config: &ParserConfig) -> Option<(u32, u32)> {
+    let Some(written) = caps.name("hour") else {
+        return parse_spoken(caps, config);
+    };
+    let hour = parse_num(written.as_str())?;
     let min = caps
         .name("min")
         .and_then(|m| m.as_str().parse::<u32>().ok())
@@ -130,48 +847,255 @@ fn parse_hm_ampm(caps: &regex::Captures) -> Option<(u32, u32)> {
     if min > 59 {
         return None;
     }
-    let ampm = caps.name("ampm").or(caps.name("sfx"));
-    let h = match ampm {
-        Some(ap) => resolve_hour(hour, ap.as_str())?,
-        None => {
-            if hour > 23 {
-                return None;
-            }
-            hour
-        }
+    let h = match meridiem(caps, "ampm").or(meridiem(caps, "sfx")) {
+        Some(ap) => resolve_hour(hour, ap)?,
+        None => clock::bare_hour(written.as_str(), hour, config)?,
     };
     Some((h, min))
 }
 
-/// Parse a HH:MM–HH:MM range from captures with groups `fh`, `fm`, `th`, `tm`.
-fn parse_hm_range(caps: &regex::Captures) -> Option<(u32, u32, u32, u32)> {
+/// Parse a spoken clock reading from captures produced by [`spoken_time_pattern`].
+fn parse_spoken(caps: &regex::Captures, config: &ParserConfig) -> Option<(u32, u32)> {
+    let words = caps.name("sp_min")?.as_str().to_lowercase();
+    let minutes = if words == "half" {
+        30
+    } else if words.ends_with("quarter") {
+        15
+    } else {
+        words
+            .split(|c: char| c.is_whitespace() || c == '-')
+            .filter(|w| !w.is_empty())
+            .map(parse_num)
+            .sum::<Option<u32>>()?
+    };
+    if minutes > 59 {
+        return None;
+    }
+    let written = caps.name("sp_hour")?.as_str();
+    let hour = parse_num(written)?;
+    let offset = match caps.name("sp_rel")?.as_str().to_lowercase().as_str() {
+        "past" | "after" => minutes as i32,
+        _ => -(minutes as i32),
+    };
+    let (h, m) = clock::reading(hour, offset)?;
+    match meridiem(caps, "sp_ampm") {
+        Some(ap) => Some((resolve_hour(h, ap)?, m)),
+        None => Some((clock::bare_hour(written, h, config)?, m)),
+    }
+}
+
+/// Parse an hour range from captures produced by [`clock_range_pattern`].
+///
+/// An am/pm written on one end only carries over to the other ("9-11am",
+/// "between 2 and 4pm"), switching halves of the day if the ends would
+/// otherwise be out of order ("from 9 to 5pm" is 9am to 5pm). Without any
+/// am/pm, the start is read with [`clock::bare_hour`] and a 12-hour end follows it on
+/// the same day ("from 9 to 5" is 9:00 to 17:00); an end after a 24-hour
+/// start such as "between 23 and 1" is read on its own.
+fn parse_clock_range(
+    caps: &regex::Captures,
+    config: &ParserConfig,
+) -> Option<(u32, u32, u32, u32)> {
+    let from = caps.name("from")?.as_str();
+    let to = caps.name("to")?.as_str();
+    let (fh, th) = (parse_num(from)?, parse_num(to)?);
+    let minute = |name: &str| match caps.name(name) {
+        Some(m) => m.as_str().parse::<u32>().ok().filter(|m| *m < 60),
+        None => Some(0),
+    };
+    let (fm, tm) = (minute("fmin")?, minute("tmin")?);
+    let (fh, th) = match (meridiem(caps, "fmer"), meridiem(caps, "tmer")) {
+        (Some(f), Some(t)) => (resolve_hour(fh, f)?, resolve_hour(th, t)?),
+        (None, Some(t)) => {
+            let th = resolve_hour(th, t)?;
+            (clock::carry_meridiem(fh, fm, (th, tm), true)?, th)
+        }
+        (Some(f), None) => {
+            let fh = resolve_hour(fh, f)?;
+            (fh, clock::carry_meridiem(th, tm, (fh, fm), false)?)
+        }
+        (None, None) => {
+            let start = clock::bare_hour(from, fh, config)?;
+            let twelve_hour =
+                |written: &str, h: u32| (1..=12).contains(&h) && !written.starts_with('0');
+            let end = if twelve_hour(from, fh) && twelve_hour(to, th) {
+                clock::carry_meridiem(th, tm, (start, fm), false)?
+            } else {
+                clock::bare_hour(to, th, config)?
+            };
+            (start, end)
+        }
+    };
+    Some((fh, fm, th, tm))
+}
+
+/// Parse a HH:MM[:SS]–HH:MM[:SS] range from captures with groups `fh`, `fm`,
+/// `fsec`, `fsubsec`, `th`, `tm`, `tsec`, `tsubsec`.
+fn parse_time_range(caps: &regex::Captures) -> Option<(NaiveTime, NaiveTime)> {
     let fh = caps.name("fh")?.as_str().parse::<u32>().ok()?;
     let fm = caps.name("fm")?.as_str().parse::<u32>().ok()?;
     let th = caps.name("th")?.as_str().parse::<u32>().ok()?;
     let tm = caps.name("tm")?.as_str().parse::<u32>().ok()?;
-    if fh > 23 || fm > 59 || th > 23 || tm > 59 {
-        return None;
+    let from = clock::time_of_day(caps, fh, fm, "fsec", "fsubsec")?;
+    let to = clock::time_of_day(caps, th, tm, "tsec", "tsubsec")?;
+    Some((from, to))
+}
+
+/// Parse a time unit word (singular or plural).
+fn parse_unit(s: &str) -> Option<TimeUnit> {
+    match s.to_lowercase().trim_end_matches('s') {
+        "second" | "sec" => Some(TimeUnit::Second),
+        "minute" | "min" => Some(TimeUnit::Minute),
+        "hour" | "hr" => Some(TimeUnit::Hour),
+        "day" => Some(TimeUnit::Day),
+        "week" => Some(TimeUnit::Week),
+        "month" => Some(TimeUnit::Month),
+        "quarter" => Some(TimeUnit::Quarter),
+        "year" => Some(TimeUnit::Year),
+        _ => None,
     }
-    Some((fh, fm, th, tm))
 }
 
-/// Parse hour and optional :MM minute from captures (24h format, no am/pm).
-fn parse_hm(caps: &regex::Captures) -> Option<(u32, u32)> {
-    let h = caps.name("hour")?.as_str().parse::<u32>().ok()?;
-    let m = caps
-        .name("min")
-        .and_then(|m| m.as_str().parse::<u32>().ok())
-        .unwrap_or(0);
-    if h > 23 || m > 59 {
+/// Shared sub-day unit pattern
+const SUBDAY_UNIT_PAT: &str = r"seconds?|secs?|minutes?|mins?|hours?|hrs?";
+
+/// Shared day-or-longer unit pattern
+const DAY_UNIT_PAT: &str = r"days?|weeks?|months?|years?";
+
+/// Parse a fraction word used in durations.
+fn parse_fraction(s: &str) -> Option<f64> {
+    match s.to_lowercase().as_str() {
+        "half" => Some(0.5),
+        "quarter" | "quarters" => Some(0.25),
+        _ => None,
+    }
+}
+
+/// Parse a duration amount: a decimal number or a number word.
+fn parse_amount(s: &str) -> Option<f64> {
+    duration::parse_decimal(s).or_else(|| parse_num(s).map(f64::from))
+}
+
+/// Resolve a spelled-out duration from captures with group `unit` and any of
+/// `num`, `three`, `frac` (a fraction before the unit, or on its own) and
+/// `frac_post` (a fraction after the unit).
+///
+/// A trailing fraction without an amount counts as one unit plus the fraction.
+fn resolve_spelled_duration(caps: &regex::Captures) -> Option<ResolvedTime> {
+    let frac_post = match caps.name("frac_post") {
+        Some(f) => Some(parse_fraction(f.as_str())?),
+        None => None,
+    };
+    let mut amount = match caps.name("num") {
+        Some(n) => parse_amount(n.as_str())?,
+        None if frac_post.is_some() => 1.0,
+        None => 0.0,
+    };
+    if let Some(f) = caps.name("frac") {
+        let times = if caps.name("three").is_some() {
+            3.0
+        } else {
+            1.0
+        };
+        amount += times * parse_fraction(f.as_str())?;
+    }
+    amount += frac_post.unwrap_or(0.0);
+    if amount <= 0.0 {
         return None;
     }
-    Some((h, m))
+    let unit = parse_unit(caps.name("unit")?.as_str())?;
+    resolve::resolve_duration(unit, amount)
 }
 
-fn build_rules() -> Vec<GrammarRule> {
+/// Parse a duration from captures produced by the spelled-out or compact
+/// duration pattern.
+fn parse_duration(caps: &regex::Captures) -> Option<chrono::Duration> {
+    let resolved = if caps.name("unit").is_some() {
+        resolve_spelled_duration(caps)?
+    } else {
+        duration::parse_compact(caps)?
+    };
+    match resolved {
+        ResolvedTime::Duration(d) => Some(d),
+        _ => None,
+    }
+}
+
+/// Resolve the day of a duration-anchored range: a relative day (`day`),
+/// a calendar date (`date`), or today.
+fn resolve_anchor_day(
+    caps: &regex::Captures,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<DateTime<Utc>> {
+    if let Some(day) = caps.name("day") {
+        return resolve::resolve_day_offset(day_keyword_offset(day.as_str())?, now, tz);
+    }
+    if caps.name("date").is_some() {
+        return resolve_date_caps(caps, now, tz, config);
+    }
+    resolve::resolve_day_offset(0, now, tz)
+}
+
+/// Open-range prepositions: "since", "until", "before", "starting at"
+const OPEN_RANGE_PAT: &str = r"(?:since|after|until|till|before|starting(?:\s+(?:at|on|from))?)\s+";
+
+fn open_bound(s: &str) -> Option<Bound> {
+    match s.to_lowercase().split_whitespace().next()? {
+        "since" => Some(Bound::Since),
+        "starting" => Some(Bound::From),
+        "after" => Some(Bound::After),
+        "until" | "till" => Some(Bound::Until),
+        "before" => Some(Bound::Before),
+        _ => None,
+    }
+}
+
+/// Offsets from an anchor: "two days before", "a week from"
+fn offset_pattern() -> String {
+    format!(
+        r"(?P<num>{NUM_WORD_PATTERN}|an?)\s+(?P<unit>{SUBDAY_UNIT_PAT}|{DAY_UNIT_PAT})\s+(?P<op>before|after|from)\s+"
+    )
+}
+
+fn anchor_offset(caps: &regex::Captures) -> Option<(TimeUnit, i64)> {
+    let amount = i64::from(parse_num(caps.name("num")?.as_str())?);
+    let unit = parse_unit(caps.name("unit")?.as_str())?;
+    if caps.name("op")?.as_str().eq_ignore_ascii_case("before") {
+        Some((unit, -amount))
+    } else {
+        Some((unit, amount))
+    }
+}
+
+fn build_rules(custom_holidays: &[&str]) -> Vec<GrammarRule> {
     // Number pattern for inline use
     let num = NUM_WORD_PATTERN;
+    let subday = SUBDAY_UNIT_PAT;
+    let days = DAY_UNIT_PAT;
+    let amount = format!(r"(?:{num}|an?)");
     let wd = WEEKDAY_PAT;
+    let rday = RELATIVE_DAY_PAT;
+    let spoken = spoken_time_pattern();
+    let at_time = at_time_pattern();
+    let at = format!(r"(?:at\s+{spoken}|{at_time})");
+    let hm_range = hm_range_pattern();
+    let between = clock_range_pattern(num, r"\s+and\s+");
+    let from_to = clock_range_pattern(num, r"\s+to\s+");
+    let dash_range = clock_range_pattern(r"\d{1,2}", r"\s*[-–]\s*");
+    let date = date_pattern();
+    let spelled_dur = format!(
+        r"(?P<num>\d+\.\d+|{amount})\s+(?:and\s+an?\s+(?P<frac>half|quarter)\s+)?(?P<unit>{subday})\b(?:\s+and\s+an?\s+(?P<frac_post>half|quarter)\b)?"
+    );
+    let dur = format!(r"(?:{spelled_dur}|{COMPACT_DURATION_PAT})");
+    let start = start_time_pattern();
+    let part = DAY_PART_PAT;
+    let nm = NOON_MIDNIGHT_PAT;
+    let wom = weekday_of_month_pattern();
+    let mday = day_of_month_pattern();
+    let hol = holiday_pattern(custom_holidays);
+    let recur = recurrence_pattern();
 
     vec![
         // ============================================================
@@ -179,37 +1103,47 @@ fn build_rules() -> Vec<GrammarRule> {
         //  "last Friday at 3:30pm", "next Monday at 15:30", "last Friday at 3pm"
         // ============================================================
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\b(?P<dir>next|last|this)\s+(?P<wd>{wd})\s+at\s+(?P<hour>\d{{1,2}})(?::(?P<min>\d{{2}})(?:\s*(?P<ampm>am|pm))?|\s*(?P<sfx>am|pm|o'?clock))\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?P<dir>next|last|this)\s+(?P<wd>{wd})\s+{at}"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let direction = weekday_direction(caps.name("dir")?.as_str())?;
                 let weekday = parse_weekday(caps.name("wd")?.as_str())?;
-                let (h, m) = parse_hm_ampm(caps)?;
+                let time = parse_time(caps, config)?;
                 let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
-                resolve::resolve_time_on_date(date, h, m, tz)
+                resolve::resolve_clock_time_on_date(date, time, tz)
+            },
+        },
+        // ============================================================
+        //  Combined: bare weekday + time spec
+        //  "on Friday at 3pm", "Monday at 9:30"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b(?:on\s+)?(?P<wd>{wd}),?\s+{at}")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps, config)?;
+                let date = resolve_bare_weekday(caps.name("wd")?.as_str(), now, tz, config)?;
+                resolve::resolve_clock_time_on_date(date, time, tz)
             },
         },
         // ============================================================
         //  Combined: Weekday + between range
-        //  "last Friday between 9 and 12"
+        //  "last Friday between 9 and 12", "next Monday between 2 and 4pm"
         // ============================================================
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\b(?P<dir>next|last|this)\s+(?P<wd>{wd})\s+between\s+(?P<from>{num})\s+and\s+(?P<to>{num})\s*(?:o'?clock)?\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?P<dir>next|last|this)\s+(?P<wd>{wd})\s+between\s+{between}"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let direction = weekday_direction(caps.name("dir")?.as_str())?;
                 let weekday = parse_weekday(caps.name("wd")?.as_str())?;
-                let from = parse_num(caps.name("from")?.as_str())?;
-                let to = parse_num(caps.name("to")?.as_str())?;
-                if from > 23 || to > 23 { return None; }
+                let (fh, fm, th, tm) = parse_clock_range(caps, config)?;
                 let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
-                resolve::resolve_time_range_on_date(date, from, to, tz)
+                let (from, to) = clock::range_ends((fh, fm), (th, tm))?;
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
             },
         },
         // ============================================================
@@ -217,37 +1151,34 @@ fn build_rules() -> Vec<GrammarRule> {
         //  "next Monday 9:00 to 11:30", "last Friday 8:30 - 17:00"
         // ============================================================
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\b(?P<dir>next|last|this)\s+(?P<wd>{wd})\s+(?:from\s+)?(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}})\s*(?:to\b|-)\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}})\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?P<dir>next|last|this)\s+(?P<wd>{wd})\s+{hm_range}"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let direction = weekday_direction(caps.name("dir")?.as_str())?;
                 let weekday = parse_weekday(caps.name("wd")?.as_str())?;
-                let (fh, fm, th, tm) = parse_hm_range(caps)?;
+                let (from, to) = parse_time_range(caps)?;
                 let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
-                resolve::resolve_time_range_with_minutes_on_date(date, fh, fm, th, tm, tz)
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
             },
         },
         // ============================================================
         //  Combined: Weekday + from/to range
-        //  "last Friday from 9 to eleven", "next Monday from 9 to 5"
+        //  "last Friday from 9 to eleven", "next Monday from 9am to 5pm"
         // ============================================================
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\b(?P<dir>next|last|this)\s+(?P<wd>{wd})\s+from\s+(?P<from>{num})\s+to\s+(?P<to>{num})\s*(?:o'?clock)?\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?P<dir>next|last|this)\s+(?P<wd>{wd})\s+from\s+{from_to}"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let direction = weekday_direction(caps.name("dir")?.as_str())?;
                 let weekday = parse_weekday(caps.name("wd")?.as_str())?;
-                let from = parse_num(caps.name("from")?.as_str())?;
-                let to = parse_num(caps.name("to")?.as_str())?;
-                if from > 23 || to > 23 { return None; }
+                let (fh, fm, th, tm) = parse_clock_range(caps, config)?;
                 let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
-                resolve::resolve_time_range_on_date(date, from, to, tz)
+                let (from, to) = clock::range_ends((fh, fm), (th, tm))?;
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
             },
         },
         // ============================================================
@@ -255,35 +1186,28 @@ fn build_rules() -> Vec<GrammarRule> {
         //  "yesterday at 3:30pm", "tomorrow at 15:30", "yesterday at 3pm"
         // ============================================================
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\b(?P<day>today|tomorrow|yesterday)\s+at\s+(?P<hour>\d{1,2})(?::(?P<min>\d{2})(?:\s*(?P<ampm>am|pm))?|\s*(?P<sfx>am|pm|o'?clock))\b"
-            )
-            .unwrap(),
+            pattern: RulePattern::new(format!(r"(?i)\b(?P<day>{rday})\s+{at}")),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let offset = day_keyword_offset(caps.name("day")?.as_str())?;
-                let (h, m) = parse_hm_ampm(caps)?;
+                let time = parse_time(caps, config)?;
                 let date = resolve::resolve_day_offset(offset, now, tz)?;
-                resolve::resolve_time_on_date(date, h, m, tz)
+                resolve::resolve_clock_time_on_date(date, time, tz)
             },
         },
         // ============================================================
         //  Combined: relative day + between range
-        //  "yesterday between 9 and 12 (o'clock)"
+        //  "yesterday between 9 and 12 (o'clock)", "today between 2 and 4pm"
         // ============================================================
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\b(?P<day>today|tomorrow|yesterday)\s+between\s+(?P<from>{num})\s+and\s+(?P<to>{num})\s*(?:o'?clock)?\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(r"(?i)\b(?P<day>{rday})\s+between\s+{between}")),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let offset = day_keyword_offset(caps.name("day")?.as_str())?;
-                let from = parse_num(caps.name("from")?.as_str())?;
-                let to = parse_num(caps.name("to")?.as_str())?;
-                if from > 23 || to > 23 { return None; }
+                let (fh, fm, th, tm) = parse_clock_range(caps, config)?;
                 let date = resolve::resolve_day_offset(offset, now, tz)?;
-                resolve::resolve_time_range_on_date(date, from, to, tz)
+                let (from, to) = clock::range_ends((fh, fm), (th, tm))?;
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
             },
         },
         // ============================================================
@@ -291,156 +1215,720 @@ fn build_rules() -> Vec<GrammarRule> {
         //  "today 8:30 to 9:30", "tomorrow 9:00 - 17:00", "yesterday from 10:15 to 11:45"
         // ============================================================
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\b(?P<day>today|tomorrow|yesterday)\s+(?:from\s+)?(?P<fh>\d{1,2}):(?P<fm>\d{2})\s*(?:to\b|-)\s*(?P<th>\d{1,2}):(?P<tm>\d{2})\b"
-            )
-            .unwrap(),
+            pattern: RulePattern::new(format!(r"(?i)\b(?P<day>{rday})\s+{hm_range}")),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let offset = day_keyword_offset(caps.name("day")?.as_str())?;
-                let (fh, fm, th, tm) = parse_hm_range(caps)?;
+                let (from, to) = parse_time_range(caps)?;
                 let date = resolve::resolve_day_offset(offset, now, tz)?;
-                resolve::resolve_time_range_with_minutes_on_date(date, fh, fm, th, tm, tz)
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
             },
         },
         // ============================================================
         //  Combined: relative day + from/to range
-        //  "yesterday from 9 to 11", "tomorrow from nine to five"
+        //  "yesterday from 9 to 11", "tomorrow from nine to five", "today from 9am to 5pm"
         // ============================================================
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\b(?P<day>today|tomorrow|yesterday)\s+from\s+(?P<from>{num})\s+to\s+(?P<to>{num})\s*(?:o'?clock)?\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(r"(?i)\b(?P<day>{rday})\s+from\s+{from_to}")),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let offset = day_keyword_offset(caps.name("day")?.as_str())?;
-                let from = parse_num(caps.name("from")?.as_str())?;
-                let to = parse_num(caps.name("to")?.as_str())?;
-                if from > 23 || to > 23 { return None; }
+                let (fh, fm, th, tm) = parse_clock_range(caps, config)?;
                 let date = resolve::resolve_day_offset(offset, now, tz)?;
-                resolve::resolve_time_range_on_date(date, from, to, tz)
+                let (from, to) = clock::range_ends((fh, fm), (th, tm))?;
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
+            },
+        },
+        // ============================================================
+        //  Combined: Weekday + part of day
+        //  "last Friday evening", "next Monday morning"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?P<dir>next|last|this)\s+(?P<wd>{wd})\s+(?P<part>{part})\b"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let direction = weekday_direction(caps.name("dir")?.as_str())?;
+                let weekday = parse_weekday(caps.name("wd")?.as_str())?;
+                let part = parse_day_part(caps.name("part")?.as_str())?;
+                let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
+                resolve::resolve_day_part(date, part, &config.day_parts, tz)
+            },
+        },
+        // ============================================================
+        //  Combined: relative day + part of day
+        //  "tomorrow morning", "yesterday afternoon", "tomorrow night"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b(?P<day>{rday})\s+(?P<part>{part})\b")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let offset = day_keyword_offset(caps.name("day")?.as_str())?;
+                let part = parse_day_part(caps.name("part")?.as_str())?;
+                let date = resolve::resolve_day_offset(offset, now, tz)?;
+                resolve::resolve_day_part(date, part, &config.day_parts, tz)
+            },
+        },
+        // --- Part of day: "this morning", "this evening" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\bthis\s+(?P<part>{part})\b")),
+            kind: ExpressionKind::PartOfDay,
+            resolver: |caps, now, tz, config| {
+                let part = parse_day_part(caps.name("part")?.as_str())?;
+                let date = resolve::resolve_day_offset(0, now, tz)?;
+                resolve::resolve_day_part(date, part, &config.day_parts, tz)
+            },
+        },
+        // --- Part of day: "tonight", "last night" ---
+        GrammarRule {
+            pattern: RulePattern::new(r"(?i)\b(?:(?P<tonight>tonight)|last\s+night)\b"),
+            kind: ExpressionKind::PartOfDay,
+            resolver: |caps, now, tz, config| {
+                let offset = if caps.name("tonight").is_some() {
+                    0
+                } else {
+                    -1
+                };
+                let date = resolve::resolve_day_offset(offset, now, tz)?;
+                resolve::resolve_day_part(date, DayPart::Night, &config.day_parts, tz)
+            },
+        },
+        // ============================================================
+        //  Combined: bare weekday + part of day
+        //  "Friday evening", "on Monday morning"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:on\s+)?(?P<wd>{wd})\s+(?P<part>{part})\b"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let part = parse_day_part(caps.name("part")?.as_str())?;
+                let date = resolve_bare_weekday(caps.name("wd")?.as_str(), now, tz, config)?;
+                resolve::resolve_day_part(date, part, &config.day_parts, tz)
+            },
+        },
+        // ============================================================
+        //  Combined: Weekday + noon/midnight
+        //  "next Friday at noon", "last Friday midnight"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?P<dir>next|last|this)\s+(?P<wd>{wd})\s+(?:at\s+)?(?P<nm>{nm})\b"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let direction = weekday_direction(caps.name("dir")?.as_str())?;
+                let weekday = parse_weekday(caps.name("wd")?.as_str())?;
+                let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
+                resolve_noon_midnight(caps, date, tz, config)
+            },
+        },
+        // ============================================================
+        //  Combined: bare weekday + noon/midnight
+        //  "Friday at noon", "on Sunday midnight"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:on\s+)?(?P<wd>{wd})\s+(?:at\s+)?(?P<nm>{nm})\b"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let date = resolve_bare_weekday(caps.name("wd")?.as_str(), now, tz, config)?;
+                resolve_noon_midnight(caps, date, tz, config)
+            },
+        },
+        // ============================================================
+        //  Combined: relative day + noon/midnight
+        //  "tomorrow at noon", "yesterday midnight"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b(?P<day>{rday})\s+(?:at\s+)?(?P<nm>{nm})\b")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let offset = day_keyword_offset(caps.name("day")?.as_str())?;
+                let date = resolve::resolve_day_offset(offset, now, tz)?;
+                resolve_noon_midnight(caps, date, tz, config)
+            },
+        },
+        // --- Noon/midnight today: "at noon", "midnight", "around noon" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:(?:at|(?P<approx>{APPROX_PAT}))\s+)?(?P<nm>{nm})\b"
+            )),
+            kind: ExpressionKind::TimeSpecification,
+            resolver: |caps, now, tz, config| {
+                let date = resolve::resolve_day_offset(0, now, tz)?;
+                resolve_noon_midnight(caps, date, tz, config)
+            },
+        },
+        // --- Lunch: "at lunch", "at lunchtime" (a bare "lunch" is the meal) ---
+        GrammarRule {
+            pattern: RulePattern::new(r"(?i)\bat\s+lunch(?:time)?\b"),
+            kind: ExpressionKind::TimeSpecification,
+            resolver: |_, now, tz, _| {
+                let date = resolve::resolve_day_offset(0, now, tz)?;
+                resolve::resolve_time_on_date(date, 12, 0, tz)
             },
         },
         // --- Relative days ---
         GrammarRule {
-            pattern: Regex::new(r"(?i)\b(?P<day>today|tomorrow|yesterday)\b").unwrap(),
+            pattern: RulePattern::new(format!(r"(?i)\b(?P<day>{rday})\b")),
             kind: ExpressionKind::RelativeDay,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, _| {
                 let offset = day_keyword_offset(caps.name("day")?.as_str())?;
                 resolve::resolve_relative_day(offset, now, tz)
             },
         },
-        // --- Day offset: "in 4 days" ---
+        // --- Time offset: "in 30 minutes", "in an hour" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\bin\s+(?P<num>{amount})\s+(?P<unit>{subday})\b"
+            )),
+            kind: ExpressionKind::RelativeTimeOffset,
+            resolver: |caps, now, tz, _| {
+                let n = parse_num(caps.name("num")?.as_str())? as i64;
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_offset(unit, n, now, tz)
+            },
+        },
+        // --- Time offset: "two hours ago" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?P<num>{amount})\s+(?P<unit>{subday})\s+ago\b"
+            )),
+            kind: ExpressionKind::RelativeTimeOffset,
+            resolver: |caps, now, tz, _| {
+                let n = parse_num(caps.name("num")?.as_str())? as i64;
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_offset(unit, -n, now, tz)
+            },
+        },
+        // --- Day offset: "in 4 days", "in 3 weeks", "in a month" ---
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\bin\s+(?P<num>{num})\s+days?\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\bin\s+(?P<num>{amount})\s+(?P<unit>{days})\b"
+            )),
             kind: ExpressionKind::RelativeDayOffset,
-            resolver: |caps, now, tz| {
-                let n = parse_num(caps.name("num")?.as_str())?;
-                resolve::resolve_relative_day(n as i64, now, tz)
+            resolver: |caps, now, tz, _| {
+                let n = parse_num(caps.name("num")?.as_str())? as i64;
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_offset(unit, n, now, tz)
             },
         },
-        // --- Day offset: "two days ago" ---
+        // --- Day offset: "two days ago", "a year ago" ---
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\b(?P<num>{num})\s+days?\s+ago\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?P<num>{amount})\s+(?P<unit>{days})\s+ago\b"
+            )),
             kind: ExpressionKind::RelativeDayOffset,
-            resolver: |caps, now, tz| {
-                let n = parse_num(caps.name("num")?.as_str())?;
-                resolve::resolve_relative_day(-(n as i64), now, tz)
+            resolver: |caps, now, tz, _| {
+                let n = parse_num(caps.name("num")?.as_str())? as i64;
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_offset(unit, -n, now, tz)
             },
         },
-        // --- Time spec with suffix: "at 3:30pm", "11:30am", "at 3pm", "3 o'clock" ---
+        // ============================================================
+        //  Combined: day + start + duration: "yesterday from 9 for 2 hours", "tomorrow 3pm for 45 minutes"
+        // ============================================================
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\b(?:at\s+)?(?P<hour>\d{1,2})(?::(?P<min>\d{2}))?\s*(?P<ampm>am|pm|o'?clock)\b"
-            )
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:(?P<day>{rday})|(?:on\s+)?(?P<date>{date})),?\s+{start}\s+for\s+{dur}"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                // A bare number needs a preposition, minutes or am/pm to be a time.
+                if caps.name("anchor").is_none()
+                    && caps.name("min").is_none()
+                    && caps.name("ampm").is_none()
+                {
+                    return None;
+                }
+                let time = parse_time(caps, config)?;
+                let date = resolve_anchor_day(caps, now, tz, config)?;
+                resolve::resolve_time_for_duration(date, time, parse_duration(caps)?, tz)
+            },
+        },
+        // ============================================================
+        //  Time range: start + duration: "from 9 for 2 hours", "3pm for 45 minutes"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{start}\s+for\s+{dur}")),
+            kind: ExpressionKind::TimeRange,
+            resolver: |caps, now, tz, config| {
+                // A bare number needs a preposition, minutes or am/pm to be a time.
+                if caps.name("anchor").is_none()
+                    && caps.name("min").is_none()
+                    && caps.name("ampm").is_none()
+                {
+                    return None;
+                }
+                let time = parse_time(caps, config)?;
+                let date = resolve_anchor_day(caps, now, tz, config)?;
+                resolve::resolve_time_for_duration(date, time, parse_duration(caps)?, tz)
+            },
+        },
+        // ============================================================
+        //  Time range: duration + end: "2 hours until 5pm"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b{dur}\s+(?:until|till)\s+(?P<hour>{HOUR_PAT})(?::(?P<min>\d{{2}}){SECONDS_PAT})?(?:\s*(?P<ampm>{MERIDIEM_PAT}))?"
+            )),
+            kind: ExpressionKind::TimeRange,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps, config)?;
+                let date = resolve_anchor_day(caps, now, tz, config)?;
+                resolve::resolve_duration_until_time(date, time, parse_duration(caps)?, tz)
+            },
+        },
+        // --- Duration: "45 minutes", "1.5 hours", "one and a half hours", "an hour and a half" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{spelled_dur}")),
+            kind: ExpressionKind::Duration,
+            resolver: |caps, _, _, _| resolve_spelled_duration(caps),
+        },
+        // --- Duration: "half an hour", "a half-hour", "three quarters of an hour" ---
+        GrammarRule {
+            pattern: RulePattern::new(
+                r"(?i)\b(?:(?P<three>three)\s+|(?:a|one)\s+)?(?P<frac>half|quarters?)(?:\s+of)?(?:\s+an?\s+|[\s-])(?P<unit>hour|minute)\b",
+            ),
+            kind: ExpressionKind::Duration,
+            resolver: |caps, _, _, _| resolve_spelled_duration(caps),
+        },
+        // --- Duration: "2 hours and 30 minutes" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?P<h>{num})\s+(?:hours?|hrs?)(?:,?\s+and)?\s+(?P<m>{num})\s+(?:minutes?|mins?)\b"
+            )),
+            kind: ExpressionKind::Duration,
+            resolver: |caps, _, _, _| {
+                let h = parse_num(caps.name("h")?.as_str())?;
+                let m = parse_num(caps.name("m")?.as_str())?;
+                duration::hours_minutes(h, m)
+            },
+        },
+        // --- Duration, compact: "2h", "90min", "2h30", "1:30h" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{COMPACT_DURATION_PAT}")),
+            kind: ExpressionKind::Duration,
+            resolver: |caps, _, _, _| duration::parse_compact(caps),
+        },
+        // --- Time spec with suffix: "at 3:30pm", "11:30am", "at 3pm", "three o'clock",
+        //     "around 3pm" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:(?:at|(?P<approx>{APPROX_PAT}))\s+)?(?P<hour>{HOUR_PAT})(?::(?P<min>\d{{2}}){SECONDS_PAT})?\s*(?P<ampm>{MERIDIEM_PAT}|o'?clock\b)"
+            )),
             kind: ExpressionKind::TimeSpecification,
-            resolver: |caps, now, tz| {
-                let (h, m) = parse_hm_ampm(caps)?;
-                resolve::resolve_time_today(h, m, now, tz)
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps, config)?;
+                resolve::resolve_clock_time_today(time, now, tz)
+            },
+        },
+        // --- Time spec bare colon: "at 15:30", "at 9:00", "about 9:30" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:at|(?P<approx>{APPROX_PAT}))\s+(?P<hour>\d{{1,2}}):(?P<min>\d{{2}}){SECONDS_PAT}\b"
+            )),
+            kind: ExpressionKind::TimeSpecification,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps, config)?;
+                resolve::resolve_clock_time_today(time, now, tz)
             },
         },
-        // --- Time spec bare colon: "at 15:30", "at 9:00" ---
+        // --- Bare hour: "at 3", "around 3", "at about 9" ---
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\bat\s+(?P<hour>\d{1,2}):(?P<min>\d{2})\b"
-            )
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:(?P<approx>{APPROX_HOUR_PAT})|at)\s+(?P<hour>{HOUR_PAT})\b(?:(?P<tail>[:.]\d)|\s+(?P<unit>{subday}|{days})\b)?"
+            )),
             kind: ExpressionKind::TimeSpecification,
-            resolver: |caps, now, tz| {
-                let (h, m) = parse_hm(caps)?;
+            resolver: |caps, now, tz, config| {
+                // "around 3 hours" is an amount, not a clock time, a plain
+                // "at one" is more often "at one point", and "at 3:60" is
+                // not "at 3"
+                if caps.name("unit").is_some()
+                    || caps.name("tail").is_some()
+                    || (caps.name("approx").is_none()
+                        && !caps
+                            .name("hour")?
+                            .as_str()
+                            .starts_with(|c: char| c.is_ascii_digit()))
+                {
+                    return None;
+                }
+                let time = parse_time(caps, config)?;
+                resolve::resolve_clock_time_today(time, now, tz)
+            },
+        },
+        // --- Time spec spoken: "half past three", "at quarter to five", "ten past 9pm" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b(?P<sp_at>at\s+)?{spoken}")),
+            kind: ExpressionKind::TimeSpecification,
+            resolver: |caps, now, tz, config| {
+                // "nine to five" reads as a range, so a bare number before
+                // "to" needs "at" or "minutes" to be a clock reading.
+                let before = !matches!(
+                    caps.name("sp_rel")?.as_str().to_lowercase().as_str(),
+                    "past" | "after"
+                );
+                if before
+                    && caps.name("sp_at").is_none()
+                    && caps.name("sp_unit").is_none()
+                    && parse_num(caps.name("sp_min")?.as_str()).is_some()
+                {
+                    return None;
+                }
+                let (h, m) = parse_spoken(caps, config)?;
                 resolve::resolve_time_today(h, m, now, tz)
             },
         },
         // --- Time range: "the last hour/minute" ---
         GrammarRule {
-            pattern: Regex::new(r"(?i)\b(?:the\s+)?last\s+(?P<unit>hour|minute)\b").unwrap(),
+            pattern: RulePattern::new(r"(?i)\b(?:the\s+)?last\s+(?P<unit>hour|minute)\b"),
             kind: ExpressionKind::TimeRange,
-            resolver: |caps, now, _tz| {
+            resolver: |caps, now, _tz, _| {
                 let unit = caps.name("unit")?.as_str().to_lowercase();
                 resolve::resolve_last_duration(&unit, now)
             },
         },
-        // --- Time range: "between 9 and 12 (o'clock)" ---
+        // --- Time range: "between 9 and 12 (o'clock)", "between 2 and 4pm" ---
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\bbetween\s+(?P<from>{num})\s+and\s+(?P<to>{num})\s*(?:o'?clock)?\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(r"(?i)\bbetween\s+{between}")),
             kind: ExpressionKind::TimeRange,
-            resolver: |caps, now, tz| {
-                let from = parse_num(caps.name("from")?.as_str())?;
-                let to = parse_num(caps.name("to")?.as_str())?;
-                if from > 23 || to > 23 { return None; }
-                resolve::resolve_time_range_today(from, to, now, tz)
+            resolver: |caps, now, tz, config| {
+                let (fh, fm, th, tm) = parse_clock_range(caps, config)?;
+                let (from, to) = clock::range_ends((fh, fm), (th, tm))?;
+                resolve::resolve_clock_range_today(from, to, now, tz, config.overnight_ranges)
             },
         },
         // --- Time range: "from 8:30 to 9:30", "from 10:00 - 11:30" ---
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\bfrom\s+(?P<fh>\d{1,2}):(?P<fm>\d{2})\s*(?:to\b|-)\s*(?P<th>\d{1,2}):(?P<tm>\d{2})\b"
-            )
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\bfrom\s+(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}}){FROM_SECONDS_PAT}\s*(?:to\b|-)\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}}){TO_SECONDS_PAT}\b"
+            )),
             kind: ExpressionKind::TimeRange,
-            resolver: |caps, now, tz| {
-                let (fh, fm, th, tm) = parse_hm_range(caps)?;
-                resolve::resolve_time_range_with_minutes_today(fh, fm, th, tm, now, tz)
+            resolver: |caps, now, tz, config| {
+                let (from, to) = parse_time_range(caps)?;
+                resolve::resolve_clock_range_today(from, to, now, tz, config.overnight_ranges)
             },
         },
-        // --- Time range: "from 9 to 12 (o'clock)" ---
+        // --- Time range: "from 9 to 12 (o'clock)", "from 9am to 5pm", "from 9:30 to 11am" ---
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\bfrom\s+(?P<from>{num})\s+to\s+(?P<to>{num})\s*(?:o'?clock)?\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(r"(?i)\bfrom\s+{from_to}")),
             kind: ExpressionKind::TimeRange,
-            resolver: |caps, now, tz| {
-                let from = parse_num(caps.name("from")?.as_str())?;
-                let to = parse_num(caps.name("to")?.as_str())?;
-                if from > 23 || to > 23 { return None; }
-                resolve::resolve_time_range_today(from, to, now, tz)
+            resolver: |caps, now, tz, config| {
+                let (fh, fm, th, tm) = parse_clock_range(caps, config)?;
+                let (from, to) = clock::range_ends((fh, fm), (th, tm))?;
+                resolve::resolve_clock_range_today(from, to, now, tz, config.overnight_ranges)
+            },
+        },
+        // --- Time range with am/pm: "9-11am", "9am-5pm", "10:30am – 2pm" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{dash_range}")),
+            kind: ExpressionKind::TimeRange,
+            resolver: |caps, now, tz, config| {
+                // Without am/pm this is a compact range, if enabled at all.
+                if caps.name("fmer").is_none() && caps.name("tmer").is_none() {
+                    return None;
+                }
+                let (fh, fm, th, tm) = parse_clock_range(caps, config)?;
+                let (from, to) = clock::range_ends((fh, fm), (th, tm))?;
+                resolve::resolve_clock_range_today(from, to, now, tz, config.overnight_ranges)
+            },
+        },
+        // --- Compact range: "9-12", "9:15–11:45", "0930-1200" (only with `compact_ranges`) ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b{COMPACT_RANGE_PAT}(?:\s+(?P<unit>{subday}|{days})\b)?"
+            )),
+            kind: ExpressionKind::TimeRange,
+            resolver: |caps, now, tz, config| {
+                if !config.compact_ranges || caps.name("unit").is_some() {
+                    return None;
+                }
+                let (fh, fm, th, tm) = compact_range::parse(caps, config)?;
+                let (from, to) = clock::range_ends((fh, fm), (th, tm))?;
+                resolve::resolve_clock_range_today(from, to, now, tz, config.overnight_ranges)
+            },
+        },
+        // --- Bare weekday: "on Friday", "Monday" ---
+        GrammarRule {
+            // A weekday in front of a date is part of that date; if the date
+            // rule rejected it ("Monday, March 6, 2026"), so do we.
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:on\s+)?(?P<bwd>{wd})\b(?P<dtail>,?\s+{date})?"
+            )),
+            kind: ExpressionKind::RelativeDay,
+            resolver: |caps, now, tz, config| {
+                if caps.name("dtail").is_some() {
+                    return None;
+                }
+                let date = resolve_bare_weekday(caps.name("bwd")?.as_str(), now, tz, config)?;
+                resolve::resolve_full_day(date, tz)
             },
         },
         // --- Next/Last/This Weekday ---
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\b(?P<dir>next|last|this)\s+(?P<day>{wd})\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(r"(?i)\b(?P<dir>next|last|this)\s+(?P<day>{wd})\b")),
             kind: ExpressionKind::RelativeDay,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, _| {
                 let direction = weekday_direction(caps.name("dir")?.as_str())?;
                 let weekday = parse_weekday(caps.name("day")?.as_str())?;
                 resolve::resolve_weekday(weekday, direction, now, tz)
             },
         },
+        // ============================================================
+        //  Boundary: "by end of month", "start of next week",
+        //  "the middle of the year", "mid-month", "end of day"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(
+                r"(?i)\b(?:(?:by|at|in)\s+)?(?:the\s+)?(?:(?P<bnd>start|beginning|middle|end)\s+of\s+(?:the\s+)?|mid-?)(?:(?P<dir>this|current|last|previous|next|coming)\s+)?(?P<unit>day|week|month|quarter|year)\b",
+            ),
+            kind: ExpressionKind::Boundary,
+            resolver: |caps, now, tz, config| {
+                let boundary = match caps.name("bnd") {
+                    Some(b) => parse_boundary(b.as_str())?,
+                    None => Boundary::Middle,
+                };
+                let offset = match caps.name("dir") {
+                    Some(d) => period_direction(d.as_str())?,
+                    None => 0,
+                };
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_boundary(
+                    unit,
+                    boundary,
+                    offset,
+                    now,
+                    tz,
+                    config.week_start,
+                    config.business_hours,
+                )
+            },
+        },
+        // ============================================================
+        //  Close of business: "EOD", "by COB", "EOW", "close of business"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(
+                r"(?i)\b(?:(?:by|at)\s+)?(?:(?P<abbr>(?-i:EOD|COB|EOB|EOW|EOM|EOQ|EOY))|(?:the\s+)?(?:close|end)\s+of\s+(?:the\s+)?business(?:\s+day)?)\b",
+            ),
+            kind: ExpressionKind::Boundary,
+            resolver: |caps, now, tz, config| {
+                let unit = match caps.name("abbr") {
+                    Some(a) => close_of_business_unit(a.as_str())?,
+                    None => TimeUnit::Day,
+                };
+                resolve::resolve_close_of_business(
+                    unit,
+                    0,
+                    now,
+                    tz,
+                    config.week_start,
+                    config.business_hours,
+                )
+            },
+        },
+        // ============================================================
+        //  Rest of a period: "for the rest of the day", "the rest of the week"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(
+                r"(?i)\b(?:for\s+)?(?:the\s+)?rest\s+of\s+(?:the\s+)?(?P<unit>day|week|month|quarter|year)\b",
+            ),
+            kind: ExpressionKind::TimeRange,
+            resolver: |caps, now, tz, config| {
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_rest_of(unit, now, tz, config.week_start)
+            },
+        },
+        // ============================================================
+        //  All day: "all-day", "tomorrow all day", "all day tomorrow"
+        //  (a bare "all day" is too common in prose)
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:(?P<day>{rday})\s+all[\s-]day(?:\s+long)?|all[\s-]day(?:\s+long)?\s+(?P<day_post>{rday})|all-day)\b"
+            )),
+            kind: ExpressionKind::RelativeDay,
+            resolver: |caps, now, tz, _| {
+                let offset = match caps.name("day").or(caps.name("day_post")) {
+                    Some(d) => day_keyword_offset(d.as_str())?,
+                    None => 0,
+                };
+                resolve::resolve_relative_day(offset, now, tz)
+            },
+        },
+        // ============================================================
+        //  Relative period: "this week", "last month", "next year",
+        //  "previous quarter", "the coming week"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(
+                r"(?i)\b(?P<dir>this|current|last|previous|next|coming)\s+(?P<unit>week|month|quarter|year)\b",
+            ),
+            kind: ExpressionKind::RelativePeriod,
+            resolver: |caps, now, tz, config| {
+                let offset = period_direction(caps.name("dir")?.as_str())?;
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_period(unit, offset, now, tz, config.week_start)
+            },
+        },
+        // ============================================================
+        //  Combined: ordinal weekday of month + time spec
+        //  "the first Monday of March at 10am", "last Friday of the month at 4pm"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b(?:on\s+)?{wom},?\s+{at}")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps, config)?;
+                let date = resolve_weekday_of_month_caps(caps, now, tz, config)?;
+                resolve::resolve_clock_time_on_date(date, time, tz)
+            },
+        },
+        // ============================================================
+        //  Ordinal weekday of month
+        //  "the first Monday of March", "last Friday of the month",
+        //  "the second Tuesday of next month", "the 3rd Thursday in May 2027"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b(?:on\s+)?{wom}")),
+            kind: ExpressionKind::WeekdayOfMonth,
+            resolver: |caps, now, tz, config| {
+                let date = resolve_weekday_of_month_caps(caps, now, tz, config)?;
+                resolve::resolve_full_day(date, tz)
+            },
+        },
+        // ============================================================
+        //  Combined: day of month + time spec
+        //  "the 3rd at 9am", "on the 15th at 14:30"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{mday},?\s+{at}")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps, config)?;
+                let date = resolve_day_of_month_caps(caps, now, tz, config)?;
+                resolve::resolve_clock_time_on_date(date, time, tz)
+            },
+        },
+        // ============================================================
+        //  Day of month: "on the 15th", "the 3rd", "on the first"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{mday}")),
+            kind: ExpressionKind::DayOfMonth,
+            resolver: |caps, now, tz, config| {
+                let date = resolve_day_of_month_caps(caps, now, tz, config)?;
+                resolve::resolve_full_day(date, tz)
+            },
+        },
+        // ============================================================
+        //  Combined: holiday + time spec
+        //  "on Christmas Eve at 6pm", "Easter Monday at 10am",
+        //  "Christmas Eve 6pm"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:(?:on|at|over|for)\s+)?(?:the\s+)?{hol},?\s+(?:{at}|(?P<bhour>{HOUR_PAT})(?::(?P<bmin>\d{{2}}))?\s*(?P<bampm>{MERIDIEM_PAT}))"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let time = match caps.name("bhour") {
+                    Some(hour) => {
+                        let hour = resolve_hour(parse_num(hour.as_str())?, &caps["bampm"])?;
+                        let min = caps
+                            .name("bmin")
+                            .map_or(Some(0), |m| m.as_str().parse().ok())?;
+                        NaiveTime::from_hms_opt(hour, min, 0)?
+                    }
+                    None => parse_time(caps, config)?,
+                };
+                let date = resolve_holiday_caps(caps, now, tz, config)?;
+                resolve::resolve_clock_time_on_date(date, time, tz)
+            },
+        },
+        // ============================================================
+        //  Holiday: "on Christmas", "Easter Monday", "Thanksgiving 2027"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b(?:(?:on|at|over|for)\s+)?(?:the\s+)?{hol}")),
+            kind: ExpressionKind::Holiday,
+            resolver: |caps, now, tz, config| {
+                let date = resolve_holiday_caps(caps, now, tz, config)?;
+                resolve::resolve_full_day(date, tz)
+            },
+        },
+        // ============================================================
+        //  Recurring: "every Monday at 9am", "every other week",
+        //  "daily at 9", "every weekday at noon"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b{recur}(?:,?\s+(?:{at}|at\s+(?P<nm>{nm})\b))?"
+            )),
+            kind: ExpressionKind::Recurring,
+            resolver: |caps, _now, _tz, config| resolve_recurrence_caps(caps, config),
+        },
+        // ============================================================
+        //  Combined: date + time spec
+        //  "March 5 at 3pm", "3/5 at 3pm", "2026-03-05 at 15:30"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b(?:on\s+)?{date},?\s+{at}")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps, config)?;
+                let date = resolve_date_caps(caps, now, tz, config)?;
+                resolve::resolve_clock_time_on_date(date, time, tz)
+            },
+        },
+        // ============================================================
+        //  Combined: date + noon/midnight
+        //  "March 5 at noon", "2026-03-05 at midnight"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b(?:on\s+)?{date},?\s+at\s+(?P<nm>{nm})\b")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let date = resolve_date_caps(caps, now, tz, config)?;
+                resolve_noon_midnight(caps, date, tz, config)
+            },
+        },
+        // ============================================================
+        //  Combined: date + HH:MM to/- HH:MM
+        //  "March 5 from 9:00 to 11:30", "3/5/2026 9:00 - 17:00"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b(?:on\s+)?{date},?\s+{hm_range}")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let (from, to) = parse_time_range(caps)?;
+                let date = resolve_date_caps(caps, now, tz, config)?;
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
+            },
+        },
+        // ============================================================
+        //  Absolute date
+        //  "March 5", "Mar. 5th", "March 5, 2027", "Friday, March 6",
+        //  "the 5th of March 2027", "2026-03-05", "3/5/2026", "on 3/5"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b(?:(?P<prep>on)\s+)?{date}")),
+            kind: ExpressionKind::AbsoluteDate,
+            resolver: |caps, now, tz, config| {
+                // A bare "3/5" is more likely a fraction than a date.
+                if caps.name("prep").is_none() && numeric_date::is_short_slash(caps) {
+                    return None;
+                }
+                let (year, month, day, weekday) = parse_date(caps, config)?;
+                resolve::resolve_absolute_date(year, month, day, weekday, now, tz, config.year_bias)
+            },
+        },
     ]
 }
 
@@ -457,7 +1945,43 @@ impl LanguageParser for English {
         PREFIXES
     }
 
-    fn parse(&self, text: &str, now: DateTime<Utc>, tz: Tz) -> Vec<TimeMatch> {
-        apply_rules(&self.rules, &self.regex_set, text, now, tz)
+    fn set_custom_holidays(&mut self, names: &[&str]) {
+        self.rules = build_rules(names);
+        self.regex_set = build_regex_set(&self.rules);
+    }
+
+    fn date_order(&self) -> DateOrder {
+        DateOrder::MonthDayYear
+    }
+
+    fn parse(
+        &self,
+        text: &str,
+        now: DateTime<Utc>,
+        tz: Tz,
+        config: &ParserConfig,
+    ) -> Vec<TimeMatch> {
+        let parse_in = |text: &str, tz: Tz| {
+            let mut rejected = Vec::new();
+            let matches = apply_rules_with_rejections(
+                &self.rules,
+                &self.regex_set,
+                text,
+                now,
+                tz,
+                config,
+                &mut rejected,
+            );
+            let matches = without_modal_may(text, matches);
+            let matches = without_feast_modifiers(text, matches);
+            let matches = anchored_offset::apply(text, matches, &self.offsets, tz);
+            let matches =
+                open_range::apply(text, matches, &self.open_range, config, |t, config| {
+                    apply_rules(&self.rules, &self.regex_set, t, now, tz, config)
+                });
+            let matches = without_hour_quantities(text, matches);
+            without_rejected(matches, &rejected)
+        };
+        zone::apply(text, parse_in(text, tz), &self.zones, config, parse_in)
     }
 }
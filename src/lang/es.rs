@@ -1,9 +1,19 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveTime, Utc};
 use chrono_tz::Tz;
-use regex::Regex;
 
-use crate::lang::numbers::parse_number_es;
-use crate::lang::{GrammarRule, LanguageParser, apply_rules};
+use crate::holiday::{self, Holiday};
+use crate::lang::anchored_offset::{self, OffsetRules};
+use crate::lang::clock::{self, FROM_SECONDS_PAT, SECONDS_PAT, TO_SECONDS_PAT};
+use crate::lang::compact_range::{self, COMPACT_RANGE_PAT};
+use crate::lang::duration::{self, COMPACT_DURATION_PAT};
+use crate::lang::numbers::{parse_number_es, parse_ordinal_es};
+use crate::lang::numeric_date::{self, NUMERIC_DATE_PAT};
+use crate::lang::open_range::{self, Bound, OpenRangeRules};
+use crate::lang::zone::{self, CITY_PAT, ZoneRules};
+use crate::lang::{
+    GrammarRule, LanguageParser, RulePattern, apply_rules, apply_rules_with_rejections,
+    build_regex_set, without_rejected,
+};
 use crate::resolve;
 use crate::types::*;
 
@@ -12,6 +22,8 @@ const KEYWORDS: &[&str] = &[
     "ma\u{f1}ana",
     "manana",
     "ayer",
+    "anteayer",
+    "antier",
     "hace",
     "en",
     "d\u{ed}as",
@@ -40,24 +52,124 @@ const KEYWORDS: &[&str] = &[
     "s\u{e1}bado",
     "sabado",
     "domingo",
+    "enero",
+    "febrero",
+    "marzo",
+    "abril",
+    "mayo",
+    "junio",
+    "julio",
+    "agosto",
+    "septiembre",
+    "setiembre",
+    "octubre",
+    "noviembre",
+    "diciembre",
+    "semana",
+    "mes",
+    "trimestre",
+    "año",
+    "ano",
+    "segundo",
+    "tarde",
+    "noche",
+    "mediodía",
+    "mediodia",
+    "primero",
+    "cada",
+    "todos",
+    "todas",
+    "diariamente",
+    "semanalmente",
+    "mensualmente",
+    "anualmente",
+    "dentro",
+    "año nuevo",
+    "ano nuevo",
+    "reyes",
+    "epifan",
+    "jueves santo",
+    "viernes santo",
+    "pascua",
+    "trabajo",
+    "trabajador",
+    "ascensi",
+    "pentecost",
+    "corpus christi",
+    "asunci",
+    "fiesta nacional",
+    "hispanidad",
+    "todos los santos",
+    "constituci",
+    "inmaculada",
+    "nochebuena",
+    "navidad",
+    "san esteban",
+    "nochevieja",
+    "principio",
+    "inicio",
+    "comienzo",
+    "mediados",
+    "finales",
+    "fin",
+    "final",
+    "resto",
+    "todo el d",
+    "sobre",
+    "hacia",
+    "eso de",
+    "alrededor",
+    "cerca de",
+    "aproximadamente",
+    "desde",
+    "hasta",
+    "partir de",
+    "antes de",
+    "después de",
+    "despues de",
+    // a day of the month and an hour range: "el 15", "de 9 a 11"
+    "el 1",
+    "el 2",
+    "el 3",
+    "el 4",
+    "el 5",
+    "el 6",
+    "el 7",
+    "el 8",
+    "el 9",
+    "de 1",
+    "de 2",
+    "de 3",
+    "de 4",
+    "de 5",
+    "de 6",
+    "de 7",
+    "de 8",
+    "de 9",
 ];
 
 const PREFIXES: &[&str] = &[
-    "hoy", "man", "mana", "mañan", "aye", "ent", "entr", "hac", "últ", "ulti", "ultim", "pró",
-    "pro", "prox", "próx", "próxi", "proxi", "pas", "pasa", "pasad", "est", "este", "lun", "lune",
-    "mar", "mart", "marte", "mié", "mie", "mier", "miérc", "mierc", "jue", "juev", "jueve", "vie",
-    "vier", "viern", "vierne", "sáb", "sab", "sába", "saba", "sábad", "sabad", "dom", "domi",
-    "domin", "doming",
+    "hoy", "man", "mana", "mañan", "aye", "ant", "ante", "antea", "anteay", "anteaye", "ent",
+    "entr", "hac", "últ", "ulti", "ultim", "pró", "pro", "prox", "próx", "próxi", "proxi", "pas",
+    "pasa", "pasad", "est", "este", "lun", "lune", "mar", "mart", "marte", "mié", "mie", "mier",
+    "miérc", "mierc", "jue", "juev", "jueve", "vie", "vier", "viern", "vierne", "sáb", "sab",
+    "sába", "saba", "sábad", "sabad", "dom", "domi", "domin", "doming",
 ];
 
 const NUM_WORD_PATTERN: &str = r"(?:\d+|un|uno|una|dos|tres|cuatro|cinco|seis|siete|ocho|nueve|diez|once|doce|trece|catorce|quince|veinte|treinta)";
 
 fn day_keyword_offset(s: &str) -> Option<i64> {
-    let lower = s.to_lowercase();
+    let lower = s
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
     match lower.as_str() {
         "hoy" => Some(0),
         "ma\u{f1}ana" | "manana" => Some(1),
         "ayer" => Some(-1),
+        "pasado ma\u{f1}ana" | "pasado manana" => Some(2),
+        "anteayer" | "antier" | "antes de ayer" => Some(-2),
         _ => None,
     }
 }
@@ -75,6 +187,24 @@ fn parse_weekday(s: &str) -> Option<chrono::Weekday> {
     }
 }
 
+fn parse_month(s: &str) -> Option<u32> {
+    match s.to_lowercase().trim_end_matches('.') {
+        "enero" | "ene" => Some(1),
+        "febrero" | "feb" => Some(2),
+        "marzo" | "mar" => Some(3),
+        "abril" | "abr" => Some(4),
+        "mayo" | "may" => Some(5),
+        "junio" | "jun" => Some(6),
+        "julio" | "jul" => Some(7),
+        "agosto" | "ago" => Some(8),
+        "septiembre" | "setiembre" | "sept" | "sep" => Some(9),
+        "octubre" | "oct" => Some(10),
+        "noviembre" | "nov" => Some(11),
+        "diciembre" | "dic" => Some(12),
+        _ => None,
+    }
+}
+
 fn parse_num(s: &str) -> Option<u32> {
     s.parse::<u32>()
         .ok()
@@ -84,6 +214,9 @@ fn parse_num(s: &str) -> Option<u32> {
 pub struct Spanish {
     rules: Vec<GrammarRule>,
     regex_set: regex::RegexSet,
+    open_range: OpenRangeRules,
+    offsets: OffsetRules,
+    zones: ZoneRules,
 }
 
 impl Default for Spanish {
@@ -94,15 +227,113 @@ impl Default for Spanish {
 
 impl Spanish {
     pub fn new() -> Self {
-        let rules = build_rules();
-        let regex_set = regex::RegexSet::new(rules.iter().map(|r| r.pattern.as_str())).unwrap();
-        Self { rules, regex_set }
+        let rules = build_rules(&[]);
+        let regex_set = build_regex_set(&rules);
+        let open_range = OpenRangeRules::new(OPEN_RANGE_PAT, open_bound, "a");
+        let offsets = OffsetRules::new(&offset_pattern(), anchor_offset);
+        let zones = ZoneRules::new(&format!(r"hora\s+(?:de\s+)?{CITY_PAT}"));
+        Self {
+            rules,
+            regex_set,
+            open_range,
+            offsets,
+            zones,
+        }
     }
 }
 
 /// Shared weekday pattern (accent-tolerant)
 const WEEKDAY_PAT: &str = r"lunes|martes|mi[eé]rcoles|jueves|viernes|s[aá]bado|domingo";
 
+/// Relative day keywords ("pasado mañana", "hoy", "anteayer")
+const RELATIVE_DAY_PAT: &str =
+    r"pasado\s+ma[ñn]ana|anteayer|antier|antes\s+de\s+ayer|hoy|ma[ñn]ana|ayer";
+
+/// Shared month pattern (full names and common abbreviations)
+const MONTH_PAT: &str = r"enero|febrero|marzo|abril|mayo|junio|julio|agosto|septiembre|setiembre|octubre|noviembre|diciembre|ene|feb|mar|abr|may|jun|jul|ago|sept|sep|oct|nov|dic";
+
+/// Clock hour as digits or a written-out number ("15", "tres", "una")
+const HOUR_PAT: &str = r"\d{1,2}|una|uno|dos|tres|cuatro|cinco|seis|siete|ocho|nueve|diez|once|doce|trece|catorce|quince|diecis[eé]is|diecisiete|dieciocho|diecinueve|veinte|veintiun[ao]|veintid[oó]s|veintitr[eé]s";
+
+/// Approximation markers: "sobre", "hacia", "a eso de", "alrededor de"
+const APPROX_PAT: &str =
+    r"sobre|hacia|a\s+eso\s+de|alrededor\s+de|cerca\s+de|aproximadamente\s+a|como\s+a";
+
+/// Shared "a las H[:MM[:SS]]" pattern (groups `hour`, `min`, `sec`, `subsec`,
+/// and `approx` for "sobre las 3")
+fn at_time_pattern() -> String {
+    format!(
+        r"(?:a|(?P<approx>{APPROX_PAT}))\s+las?\s+(?P<hour>{HOUR_PAT})(?::(?P<min>\d{{2}}){SECONDS_PAT})?\b"
+    )
+}
+
+/// Shared "[de las] HH:MM[:SS] a las/- HH:MM[:SS]" pattern (groups `fh`, `fm`,
+/// `fsec`, `fsubsec`, `th`, `tm`, `tsec`, `tsubsec`)
+fn hm_range_pattern() -> String {
+    format!(
+        r"(?:de(?:\s+las?)?\s+)?(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}}){FROM_SECONDS_PAT}\s*(?:a\s+(?:las?\s+)?|-)\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}}){TO_SECONDS_PAT}\b"
+    )
+}
+
+/// Spoken clock reading: "las tres y media", "las cinco menos cuarto",
+/// "la una y diez" (groups `sp_hour`, `sp_rel`, `sp_min`)
+fn spoken_time_pattern() -> String {
+    format!(
+        r"las?\s+(?P<sp_hour>{HOUR_PAT})\s+(?P<sp_rel>y|menos)\s+(?P<sp_min>media|cuarto|{NUM_WORD_PATTERN})(?:\s+minutos?)?\b"
+    )
+}
+
+/// Shared start-time pattern for duration-anchored ranges (groups `anchor`, `hour`, `min`)
+fn start_time_pattern() -> String {
+    format!(
+        r"(?P<anchor>a|desde|de)\s+las?\s+(?P<hour>{HOUR_PAT})(?::(?P<min>\d{{2}}){SECONDS_PAT})?"
+    )
+}
+
+/// Calendar date pattern: a month-name date (`dom`, `month`, optional `year`)
+/// or a numeric date (see [`NUMERIC_DATE_PAT`]), optionally preceded by a
+/// weekday (`wd`) and/or "el" (`prep`).
+fn date_pattern() -> String {
+    format!(
+        r"(?:(?:el\s+)?(?P<wd>{WEEKDAY_PAT}),?\s+|(?P<prep>el)\s+)?(?:(?:d[ií]a\s+)?(?P<dom>\d{{1,2}})º?\s+de\s+(?P<month>{MONTH_PAT})\b\.?(?:,?\s+(?:del?\s+)?(?P<year>\d{{4}})\b)?|{NUMERIC_DATE_PAT})"
+    )
+}
+
+/// Parse a calendar date from captures produced by [`date_pattern`] into
+/// `(year, month, day, weekday)`.
+fn parse_date(
+    caps: &regex::Captures,
+    config: &ParserConfig,
+) -> Option<(Option<i32>, u32, u32, Option<chrono::Weekday>)> {
+    let weekday = match caps.name("wd") {
+        Some(w) => Some(parse_weekday(w.as_str())?),
+        None => None,
+    };
+    let Some(month) = caps.name("month") else {
+        let order = config.date_order.unwrap_or(DateOrder::DayMonthYear);
+        let date = numeric_date::parse(caps, order)?;
+        return Some((date.year, date.month, date.day, weekday));
+    };
+    let month = parse_month(month.as_str())?;
+    let day = caps.name("dom")?.as_str().parse::<u32>().ok()?;
+    let year = match caps.name("year") {
+        Some(y) => Some(y.as_str().parse::<i32>().ok()?),
+        None => None,
+    };
+    Some((year, month, day, weekday))
+}
+
+/// Resolve a calendar date from captures to midnight of that day.
+fn resolve_date_caps(
+    caps: &regex::Captures,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<DateTime<Utc>> {
+    let (year, month, day, weekday) = parse_date(caps, config)?;
+    resolve::resolve_date(year, month, day, weekday, now, tz, config.year_bias)
+}
+
 fn es_weekday_direction(s: &str) -> Option<i64> {
     let lower = s.to_lowercase();
     match lower.as_str() {
@@ -114,21 +345,276 @@ fn es_weekday_direction(s: &str) -> Option<i64> {
     }
 }
 
-/// Parse a HH:MM–HH:MM range from captures with groups `fh`, `fm`, `th`, `tm`.
-fn parse_hm_range(caps: &regex::Captures) -> Option<(u32, u32, u32, u32)> {
+/// List of weekdays, plural allowed: "viernes", "lunes y jueves",
+/// "sábados y domingos".
+fn weekday_list_pattern() -> String {
+    format!(r"(?:{WEEKDAY_PAT})s?(?:(?:\s*,\s*|\s+y\s+)(?:los\s+)?(?:{WEEKDAY_PAT})s?)*")
+}
+
+/// Parse every weekday named in a list matched by [`weekday_list_pattern`].
+fn parse_weekday_list(s: &str) -> Vec<chrono::Weekday> {
+    s.split(|c: char| !c.is_alphabetic())
+        .filter_map(|w| parse_weekday(w).or_else(|| parse_weekday(w.trim_end_matches(['s', 'S']))))
+        .collect()
+}
+
+/// Recurring schedule without its time: "cada viernes", "todos los lunes y
+/// jueves", "cada dos semanas", "todos los días", "diariamente" (groups `rn`,
+/// `runit`, `rwds`, `radv`).
+fn recurrence_pattern() -> String {
+    let list = weekday_list_pattern();
+    format!(
+        r"(?:(?:cada|tod[oa]s\s+l[oa]s)\s+(?:(?P<rn>{NUM_WORD_PATTERN})\s+)?(?:(?P<runit>minutos?|horas?|d[ií]as?|semanas?|mes(?:es)?|trimestres?|a[ñn]os?)|(?P<rwds>{list}))|(?P<radv>diariamente|semanalmente|mensualmente|anualmente))\b"
+    )
+}
+
+/// Resolve a recurring schedule from captures produced by
+/// [`recurrence_pattern`], with an optional time of day in the `a las`
+/// groups or `nm` ("al mediodía").
+fn resolve_recurrence_caps(caps: &regex::Captures) -> Option<ResolvedTime> {
+    let (frequency, weekdays) = if let Some(adv) = caps.name("radv") {
+        let unit = match adv.as_str().to_lowercase().as_str() {
+            "diariamente" => TimeUnit::Day,
+            "semanalmente" => TimeUnit::Week,
+            "mensualmente" => TimeUnit::Month,
+            _ => TimeUnit::Year,
+        };
+        (unit, Vec::new())
+    } else if let Some(unit) = caps.name("runit") {
+        (parse_unit(unit.as_str())?, Vec::new())
+    } else {
+        (
+            TimeUnit::Week,
+            parse_weekday_list(caps.name("rwds")?.as_str()),
+        )
+    };
+    let interval = match caps.name("rn") {
+        Some(n) => parse_num(n.as_str())?,
+        None => 1,
+    };
+    let time = if let Some(nm) = caps.name("nm") {
+        if nm.as_str().eq_ignore_ascii_case("medianoche") {
+            Some(NaiveTime::MIN)
+        } else {
+            NaiveTime::from_hms_opt(12, 0, 0)
+        }
+    } else if caps.name("hour").is_some() || caps.name("sp_hour").is_some() {
+        Some(parse_time(caps)?)
+    } else {
+        None
+    };
+    resolve::resolve_recurrence(frequency, interval, weekdays, time)
+}
+
+/// Part-of-day words: "mañana", "tarde", "noche".
+const DAY_PART_PAT: &str = r"ma[ñn]ana|tarde|noche";
+
+fn parse_day_part(s: &str) -> Option<DayPart> {
+    match s.to_lowercase().as_str() {
+        "ma\u{f1}ana" | "manana" => Some(DayPart::Morning),
+        "tarde" => Some(DayPart::Afternoon),
+        "noche" => Some(DayPart::Night),
+        _ => None,
+    }
+}
+
+/// Day of the month without a month: "el 15", "el día 15", "el primero"
+/// (group `mday`).
+const DAY_OF_MONTH_PAT: &str = r"el\s+(?:d[ií]a\s+)?(?P<mday>\d{1,2}|primero)\b";
+
+/// Resolve a day of the month from captures to midnight of that day.
+fn resolve_day_of_month_caps(
+    caps: &regex::Captures,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<DateTime<Utc>> {
+    let day = caps.name("mday")?.as_str().to_lowercase();
+    let day = day.parse::<u32>().ok().or_else(|| parse_ordinal_es(&day))?;
+    resolve::resolve_day_of_month(day, now, tz, config.day_of_month_bias)
+}
+
+/// Holiday names ("Navidad", "el Día de Reyes", "Viernes Santo")
+const HOLIDAY_PAT: &str = r"(?:d[ií]a\s+de\s+)?a[ñn]o\s+nuevo|d[ií]a\s+de\s+(?:los\s+)?reyes(?:\s+magos)?|reyes\s+magos|epifan[ií]a|jueves\s+santo|viernes\s+santo|domingo\s+de\s+(?:pascua|resurrecci[óo]n)|lunes\s+de\s+pascua|pascua|d[ií]a\s+del\s+trabajo|d[ií]a\s+del\s+trabajador|ascensi[óo]n|lunes\s+de\s+pentecost[ée]s|pentecost[ée]s|corpus\s+christi|asunci[óo]n|fiesta\s+nacional|d[ií]a\s+de\s+la\s+hispanidad|(?:d[ií]a\s+de\s+)?todos\s+los\s+santos|d[ií]a\s+de\s+la\s+constituci[óo]n|inmaculada(?:\s+concepci[óo]n)?|nochebuena|(?:d[ií]a\s+de\s+)?navidad|san\s+esteban|nochevieja";
+
+/// Holiday name with an optional year: "Easter 2027" (groups `hol`, `hyear`,
+/// and `custom` for the calendar's custom holidays).
+fn holiday_pattern(custom_holidays: &[&str]) -> String {
+    let custom = holiday::custom_name_pattern(custom_holidays)
+        .map(|p| format!("|{p}"))
+        .unwrap_or_default();
+    format!(r"(?P<hol>{HOLIDAY_PAT}{custom})\b(?:,?\s+(?P<hyear>\d{{4}})\b)?")
+}
+
+fn parse_holiday(s: &str) -> Option<Holiday> {
+    match holiday::name_key(s).as_str() {
+        "anonuevo" | "diadeanonuevo" => Some(Holiday::NewYearsDay),
+        "diadereyes" | "diadelosreyes" | "diadereyesmagos" | "diadelosreyesmagos"
+        | "reyesmagos" | "epifania" => Some(Holiday::Epiphany),
+        "juevessanto" => Some(Holiday::MaundyThursday),
+        "viernessanto" => Some(Holiday::GoodFriday),
+        "pascua" | "domingodepascua" | "domingoderesurreccion" => Some(Holiday::EasterSunday),
+        "lunesdepascua" => Some(Holiday::EasterMonday),
+        "diadeltrabajo" | "diadeltrabajador" => Some(Holiday::LabourDay),
+        "ascension" => Some(Holiday::Ascension),
+        "pentecostes" => Some(Holiday::Pentecost),
+        "lunesdepentecostes" => Some(Holiday::WhitMonday),
+        "corpuschristi" => Some(Holiday::CorpusChristi),
+        "asuncion" => Some(Holiday::Assumption),
+        "fiestanacional" | "diadelahispanidad" => Some(Holiday::HispanicDay),
+        "todoslossantos" | "diadetodoslossantos" => Some(Holiday::AllSaints),
+        "diadelaconstitucion" => Some(Holiday::ConstitutionDay),
+        "inmaculada" | "inmaculadaconcepcion" => Some(Holiday::ImmaculateConception),
+        "nochebuena" => Some(Holiday::ChristmasEve),
+        "navidad" | "diadenavidad" => Some(Holiday::ChristmasDay),
+        "sanesteban" => Some(Holiday::BoxingDay),
+        "nochevieja" => Some(Holiday::NewYearsEve),
+        _ => None,
+    }
+}
+
+/// Resolve a holiday from captures to midnight of that day.
+fn resolve_holiday_caps(
+    caps: &regex::Captures,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<DateTime<Utc>> {
+    let year = match caps.name("hyear") {
+        Some(y) => Some(y.as_str().parse::<i32>().ok()?),
+        None => None,
+    };
+    if let Some(name) = caps.name("custom") {
+        return holiday::resolve_custom_holiday(name.as_str(), year, now, tz, config);
+    }
+    let holiday = parse_holiday(caps.name("hol")?.as_str())?;
+    holiday::resolve_holiday(holiday, year, now, tz, config)
+}
+
+/// Ordinal weekday of a month: "el primer lunes del mes", "el último viernes
+/// de marzo", "el segundo martes del mes que viene" (groups `ord`, `owd`,
+/// `omonth`, `oyear`, `mdir`, `mdir_pre`).
+fn weekday_of_month_pattern() -> String {
+    format!(
+        r"(?:el\s+)?(?P<ord>primer|tercer|(?:primer|segund|tercer|cuart|quint|[uú]ltim)o|[1-5](?:er|º|°|o))\s+(?P<owd>{WEEKDAY_PAT})\s+(?:de\s+(?P<omonth>{MONTH_PAT})\b\.?(?:\s+(?:de\s+)?(?P<oyear>\d{{4}})\b)?|del\s+(?:(?P<mdir_pre>pr[oó]ximo|pasado)\s+)?mes(?:\s+(?P<mdir>pasado|anterior|que\s+viene|pr[oó]ximo|siguiente))?\b|de\s+este\s+mes\b)"
+    )
+}
+
+fn parse_week_ordinal(s: &str) -> Option<i32> {
+    match s.to_lowercase().as_str() {
+        "último" | "ultimo" => Some(-1),
+        lower => i32::try_from(parse_ordinal_es(lower)?).ok(),
+    }
+}
+
+/// Resolve an ordinal weekday of a month from captures to midnight of that day.
+fn resolve_weekday_of_month_caps(
+    caps: &regex::Captures,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<DateTime<Utc>> {
+    let n = parse_week_ordinal(caps.name("ord")?.as_str())?;
+    let weekday = parse_weekday(caps.name("owd")?.as_str())?;
+    let Some(month) = caps.name("omonth") else {
+        let offset = match caps.name("mdir").or(caps.name("mdir_pre")) {
+            Some(d) => period_direction(d.as_str())?,
+            None => 0,
+        };
+        return resolve::resolve_weekday_of_relative_month(n, weekday, offset, now, tz);
+    };
+    let month = parse_month(month.as_str())?;
+    let year = match caps.name("oyear") {
+        Some(y) => Some(y.as_str().parse::<i32>().ok()?),
+        None => None,
+    };
+    resolve::resolve_weekday_of_month(n, weekday, month, year, now, tz, config.year_bias)
+}
+
+/// Noon and midnight words: "mediodía", "medianoche".
+const NOON_MIDNIGHT_PAT: &str = r"mediod[ií]a|medianoche";
+
+/// Resolve the noon or midnight word in the `nm` group on the day starting at `date`.
+fn resolve_noon_midnight(
+    caps: &regex::Captures,
+    date: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<ResolvedTime> {
+    if caps.name("nm")?.as_str().eq_ignore_ascii_case("medianoche") {
+        resolve::resolve_midnight(date, config.midnight, tz)
+    } else {
+        resolve::resolve_time_on_date(date, 12, 0, tz)
+    }
+}
+
+/// Resolve a weekday named without próximo/pasado/este to midnight of the day
+/// picked by [`ParserConfig::weekday_bias`].
+fn resolve_bare_weekday(
+    wd: &str,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<DateTime<Utc>> {
+    let weekday = parse_weekday(wd)?;
+    resolve::resolve_weekday_date_with_bias(weekday, now, tz, config.weekday_bias)
+}
+
+/// An hour range with "de … a" (groups `fart`, `from`, `fm`, `tart`, `to`,
+/// `tm` and `unit`): "de la una a las tres", "de las 9 a las 11:30", "de 1 a 3".
+fn hour_range_pattern() -> String {
+    format!(
+        r"de\s+(?:(?P<fart>las?)\s+)?(?P<from>{HOUR_PAT})(?::(?P<fm>\d{{2}}))?\s+a\s+(?:(?P<tart>las?)\s+)?(?P<to>{HOUR_PAT})(?::(?P<tm>\d{{2}}))?\b(?:\s+(?P<unit>{SUBDAY_UNIT_PAT}|{DAY_UNIT_PAT})\b)?"
+    )
+}
+
+/// Parse the ends of a range matched by [`hour_range_pattern`].
+///
+/// Number words need an article ("de dos a tres personas"), and a following
+/// unit makes the range a quantity ("de 1 a 3 días").
+fn parse_hour_range(caps: &regex::Captures) -> Option<(NaiveTime, NaiveTime)> {
+    let digits = |name: &str| {
+        caps.name(name)
+            .is_some_and(|m| m.as_str().bytes().all(|b| b.is_ascii_digit()))
+    };
+    let article = caps.name("fart").is_some() || caps.name("tart").is_some();
+    if caps.name("unit").is_some() || !(article || digits("from") && digits("to")) {
+        return None;
+    }
+    let hm = |hour: &str, min: &str| {
+        let h = parse_num(caps.name(hour)?.as_str())?;
+        let m = caps
+            .name(min)
+            .map_or(Some(0), |m| m.as_str().parse().ok())?;
+        Some((h, m))
+    };
+    clock::range_ends(hm("from", "fm")?, hm("to", "tm")?)
+}
+
+/// Parse a HH:MM[:SS]–HH:MM[:SS] range from captures with groups `fh`, `fm`,
+/// `fsec`, `fsubsec`, `th`, `tm`, `tsec`, `tsubsec`.
+fn parse_time_range(caps: &regex::Captures) -> Option<(NaiveTime, NaiveTime)> {
     let fh = caps.name("fh")?.as_str().parse::<u32>().ok()?;
     let fm = caps.name("fm")?.as_str().parse::<u32>().ok()?;
     let th = caps.name("th")?.as_str().parse::<u32>().ok()?;
     let tm = caps.name("tm")?.as_str().parse::<u32>().ok()?;
-    if fh > 23 || fm > 59 || th > 23 || tm > 59 {
-        return None;
-    }
-    Some((fh, fm, th, tm))
+    let from = clock::time_of_day(caps, fh, fm, "fsec", "fsubsec")?;
+    let to = clock::time_of_day(caps, th, tm, "tsec", "tsubsec")?;
+    Some((from, to))
+}
+
+/// Parse a time of day with optional `:SS` seconds from captures (24h format).
+fn parse_time(caps: &regex::Captures) -> Option<NaiveTime> {
+    let (h, m) = parse_hm(caps)?;
+    clock::time_of_day(caps, h, m, "sec", "subsec")
 }
 
 /// Parse hour and optional :MM minute from captures (24h format).
 fn parse_hm(caps: &regex::Captures) -> Option<(u32, u32)> {
-    let h = caps.name("hour")?.as_str().parse::<u32>().ok()?;
+    if caps.name("hour").is_none() {
+        return parse_spoken(caps);
+    }
+    let h = parse_num(caps.name("hour")?.as_str())?;
     let m = caps
         .name("min")
         .and_then(|m| m.as_str().parse::<u32>().ok())
@@ -139,9 +625,203 @@ fn parse_hm(caps: &regex::Captures) -> Option<(u32, u32)> {
     Some((h, m))
 }
 
-fn build_rules() -> Vec<GrammarRule> {
+/// Parse a spoken clock reading from captures produced by [`spoken_time_pattern`].
+fn parse_spoken(caps: &regex::Captures) -> Option<(u32, u32)> {
+    let hour = parse_num(caps.name("sp_hour")?.as_str())?;
+    let minutes = match caps.name("sp_min")?.as_str().to_lowercase().as_str() {
+        "cuarto" => 15,
+        "media" => 30,
+        n => parse_num(n)?,
+    };
+    if minutes > 59 {
+        return None;
+    }
+    let minutes = minutes as i32;
+    let offset = match caps.name("sp_rel")?.as_str().to_lowercase().as_str() {
+        "menos" => -minutes,
+        _ => minutes,
+    };
+    clock::reading(hour, offset)
+}
+
+/// Resolve the direction of a period qualifier ("pasado", "que viene", ...).
+fn period_direction(s: &str) -> Option<i64> {
+    let s = s.to_lowercase();
+    match s.split_whitespace().collect::<Vec<_>>().join(" ").as_str() {
+        "este" | "esta" => Some(0),
+        "pasado" | "pasada" | "anterior" => Some(-1),
+        "próximo" | "proximo" | "próxima" | "proxima" | "que viene" | "siguiente" => Some(1),
+        _ => None,
+    }
+}
+
+fn parse_boundary(s: &str) -> Option<Boundary> {
+    match s.to_lowercase().as_str() {
+        "principio" | "principios" | "inicio" | "inicios" | "comienzo" | "comienzos" => {
+            Some(Boundary::Start)
+        }
+        "mediados" => Some(Boundary::Middle),
+        "fin" | "final" | "finales" => Some(Boundary::End),
+        _ => None,
+    }
+}
+
+/// Parse a time unit word (singular or plural).
+fn parse_unit(s: &str) -> Option<TimeUnit> {
+    match s.to_lowercase().as_str() {
+        "segundo" | "segundos" | "seg" => Some(TimeUnit::Second),
+        "minuto" | "minutos" | "min" => Some(TimeUnit::Minute),
+        "hora" | "horas" => Some(TimeUnit::Hour),
+        "día" | "días" | "dia" | "dias" => Some(TimeUnit::Day),
+        "semana" | "semanas" => Some(TimeUnit::Week),
+        "mes" | "meses" => Some(TimeUnit::Month),
+        "trimestre" | "trimestres" => Some(TimeUnit::Quarter),
+        "año" | "años" | "ano" | "anos" => Some(TimeUnit::Year),
+        _ => None,
+    }
+}
+
+/// Shared sub-day unit pattern
+const SUBDAY_UNIT_PAT: &str = r"segundos?|minutos?|horas?";
+
+/// Shared day-or-longer unit pattern
+const DAY_UNIT_PAT: &str = r"d[ií]as?|semanas?|mes(?:es)?|a[ñn]os?";
+
+/// Parse a fraction word used in durations.
+fn parse_fraction(s: &str) -> Option<f64> {
+    match s.to_lowercase().as_str() {
+        "media" => Some(0.5),
+        "cuarto" | "cuartos" => Some(0.25),
+        _ => None,
+    }
+}
+
+/// Parse a duration amount: a decimal number or a number word.
+fn parse_amount(s: &str) -> Option<f64> {
+    duration::parse_decimal(s).or_else(|| parse_num(s).map(f64::from))
+}
+
+/// Resolve a spelled-out duration from captures with group `unit` and any of
+/// `num`, `three`, `frac` (a fraction before the unit, or on its own) and
+/// `frac_post` (a fraction after the unit).
+///
+/// A trailing fraction without an amount counts as one unit plus the fraction.
+fn resolve_spelled_duration(caps: &regex::Captures) -> Option<ResolvedTime> {
+    let frac_post = match caps.name("frac_post") {
+        Some(f) => Some(parse_fraction(f.as_str())?),
+        None => None,
+    };
+    let mut amount = match caps.name("num") {
+        Some(n) => parse_amount(n.as_str())?,
+        None if frac_post.is_some() => 1.0,
+        None => 0.0,
+    };
+    if let Some(f) = caps.name("frac") {
+        let times = if caps.name("three").is_some() {
+            3.0
+        } else {
+            1.0
+        };
+        amount += times * parse_fraction(f.as_str())?;
+    }
+    amount += frac_post.unwrap_or(0.0);
+    if amount <= 0.0 {
+        return None;
+    }
+    let unit = parse_unit(caps.name("unit")?.as_str())?;
+    resolve::resolve_duration(unit, amount)
+}
+
+/// Parse a duration from captures produced by the spelled-out or compact
+/// duration pattern.
+fn parse_duration(caps: &regex::Captures) -> Option<chrono::Duration> {
+    let resolved = if caps.name("unit").is_some() {
+        resolve_spelled_duration(caps)?
+    } else {
+        duration::parse_compact(caps)?
+    };
+    match resolved {
+        ResolvedTime::Duration(d) => Some(d),
+        _ => None,
+    }
+}
+
+/// Resolve the day of a duration-anchored range: a relative day (`day`),
+/// a calendar date (`date`), or today.
+fn resolve_anchor_day(
+    caps: &regex::Captures,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<DateTime<Utc>> {
+    if let Some(day) = caps.name("day") {
+        return resolve::resolve_day_offset(day_keyword_offset(day.as_str())?, now, tz);
+    }
+    if caps.name("date").is_some() {
+        return resolve_date_caps(caps, now, tz, config);
+    }
+    resolve::resolve_day_offset(0, now, tz)
+}
+
+/// Open-range prepositions: "desde", "a partir de", "hasta", "antes de", "después de"
+const OPEN_RANGE_PAT: &str = r"(?:desde|hasta|a\s+partir\s+de|antes\s+de|despu[ée]s\s+de)\s+";
+
+fn open_bound(s: &str) -> Option<Bound> {
+    match s.to_lowercase().split_whitespace().next()? {
+        "desde" => Some(Bound::Since),
+        "a" => Some(Bound::From),
+        "después" | "despues" => Some(Bound::After),
+        "hasta" => Some(Bound::Until),
+        "antes" => Some(Bound::Before),
+        _ => None,
+    }
+}
+
+/// Offsets from an anchor: "dos días antes del", "una semana después de"
+fn offset_pattern() -> String {
+    format!(
+        r"(?P<num>{NUM_WORD_PATTERN})\s+(?P<unit>{SUBDAY_UNIT_PAT}|{DAY_UNIT_PAT})\s+(?P<op>antes|despu[eé]s)\s+(?:del|de(?:\s+la)?)\s+"
+    )
+}
+
+fn anchor_offset(caps: &regex::Captures) -> Option<(TimeUnit, i64)> {
+    let amount = i64::from(parse_num(caps.name("num")?.as_str())?);
+    let unit = parse_unit(caps.name("unit")?.as_str())?;
+    if caps.name("op")?.as_str().eq_ignore_ascii_case("antes") {
+        Some((unit, -amount))
+    } else {
+        Some((unit, amount))
+    }
+}
+
+fn build_rules(custom_holidays: &[&str]) -> Vec<GrammarRule> {
     let num = NUM_WORD_PATTERN;
+    let subday = SUBDAY_UNIT_PAT;
+    let days = DAY_UNIT_PAT;
+    let hour_range = hour_range_pattern();
     let wd = WEEKDAY_PAT;
+    let rday = RELATIVE_DAY_PAT;
+    let spoken = spoken_time_pattern();
+    let at_time = at_time_pattern();
+    let at = format!(r"(?:a\s+{spoken}|{at_time})");
+    let hm_range = hm_range_pattern();
+    let date = date_pattern();
+    let spelled_dur = format!(
+        r"(?:(?P<num>\d+[.,]\d+|{num})\s+)?(?P<unit>{subday}|min|seg)\b(?:\s+y\s+(?P<frac_post>media|cuarto)\b)?"
+    );
+    let dur = format!(r"(?:{spelled_dur}|{COMPACT_DURATION_PAT})");
+    let start = start_time_pattern();
+    let part = DAY_PART_PAT;
+    let por_la = r"(?:(?:por|en|a)\s+la\s+)?";
+    let nm = NOON_MIDNIGHT_PAT;
+    let wom = weekday_of_month_pattern();
+    let mday = DAY_OF_MONTH_PAT;
+    let hol = holiday_pattern(custom_holidays);
+    let al = r"(?:(?:al|a(?:\s+la)?)\s+)?";
+    // "el lunes" is a single Monday; "los lunes" means every Monday and is
+    // captured in `los` only so the bare-weekday rules can reject it.
+    let el = r"(?:(?P<los>los)\s+|el\s+)?";
+    let recur = recurrence_pattern();
 
     vec![
         // ============================================================
@@ -149,17 +829,16 @@ fn build_rules() -> Vec<GrammarRule> {
         //  "el próximo lunes a las 3", "el próximo lunes a las 3:30"
         // ============================================================
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\b(?:el\s+)?(?P<dir>pr[oó]ximo|pasado|este)\s+(?P<wd>{wd})\s+a\s+las\s+(?P<hour>\d{{1,2}})(?::(?P<min>\d{{2}}))?"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:el\s+)?(?P<dir>pr[oó]ximo|pasado|este)\s+(?P<wd>{wd})\s+{at}"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, _| {
                 let direction = es_weekday_direction(caps.name("dir")?.as_str())?;
                 let weekday = parse_weekday(caps.name("wd")?.as_str())?;
-                let (h, m) = parse_hm(caps)?;
+                let time = parse_time(caps)?;
                 let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
-                resolve::resolve_time_on_date(date, h, m, tz)
+                resolve::resolve_clock_time_on_date(date, time, tz)
             },
         },
         // ============================================================
@@ -167,17 +846,16 @@ fn build_rules() -> Vec<GrammarRule> {
         //  "el próximo lunes de 10:15 a 13:45", "el próximo lunes de las 9:00 a las 11:30"
         // ============================================================
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\b(?:el\s+)?(?P<dir>pr[oó]ximo|pasado|este)\s+(?P<wd>{wd})\s+de(?:\s+las)?\s+(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}})\s*(?:a\s+(?:las\s+)?|-)\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}})\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:el\s+)?(?P<dir>pr[oó]ximo|pasado|este)\s+(?P<wd>{wd})\s+de(?:\s+las?)?\s+(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}}){FROM_SECONDS_PAT}\s*(?:a\s+(?:las?\s+)?|-)\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}}){TO_SECONDS_PAT}\b"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let direction = es_weekday_direction(caps.name("dir")?.as_str())?;
                 let weekday = parse_weekday(caps.name("wd")?.as_str())?;
-                let (fh, fm, th, tm) = parse_hm_range(caps)?;
+                let (from, to) = parse_time_range(caps)?;
                 let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
-                resolve::resolve_time_range_with_minutes_on_date(date, fh, fm, th, tm, tz)
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
             },
         },
         // ============================================================
@@ -185,19 +863,21 @@ fn build_rules() -> Vec<GrammarRule> {
         //  "el pasado viernes entre las 9 y las 12"
         // ============================================================
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\b(?:el\s+)?(?P<dir>pr[oó]ximo|pasado|este)\s+(?P<wd>{wd})\s+entre\s+las\s+(?P<from>\d{{1,2}})\s+y\s+las\s+(?P<to>\d{{1,2}})\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:el\s+)?(?P<dir>pr[oó]ximo|pasado|este)\s+(?P<wd>{wd})\s+entre\s+las?\s+(?P<from>{HOUR_PAT})\s+y\s+las?\s+(?P<to>{HOUR_PAT})\b"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let direction = es_weekday_direction(caps.name("dir")?.as_str())?;
                 let weekday = parse_weekday(caps.name("wd")?.as_str())?;
-                let from = caps.name("from")?.as_str().parse::<u32>().ok()?;
-                let to = caps.name("to")?.as_str().parse::<u32>().ok()?;
-                if from > 23 || to > 23 { return None; }
+                let from = parse_num(caps.name("from")?.as_str())?;
+                let to = parse_num(caps.name("to")?.as_str())?;
+                if from > 23 || to > 23 {
+                    return None;
+                }
                 let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
-                resolve::resolve_time_range_on_date(date, from, to, tz)
+                let (from, to) = clock::range_ends((from, 0), (to, 0))?;
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
             },
         },
         // ============================================================
@@ -205,17 +885,16 @@ fn build_rules() -> Vec<GrammarRule> {
         //  "el viernes pasado a las 3", "el viernes pasado a las 3:30"
         // ============================================================
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\b(?:el\s+)?(?P<wd>{wd})\s+(?P<dir>pr[oó]ximo|pasado|que\s+viene)\s+a\s+las\s+(?P<hour>\d{{1,2}})(?::(?P<min>\d{{2}}))?"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:el\s+)?(?P<wd>{wd})\s+(?P<dir>pr[oó]ximo|pasado|que\s+viene)\s+{at}"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, _| {
                 let direction = es_weekday_direction(caps.name("dir")?.as_str())?;
                 let weekday = parse_weekday(caps.name("wd")?.as_str())?;
-                let (h, m) = parse_hm(caps)?;
+                let time = parse_time(caps)?;
                 let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
-                resolve::resolve_time_on_date(date, h, m, tz)
+                resolve::resolve_clock_time_on_date(date, time, tz)
             },
         },
         // ============================================================
@@ -223,17 +902,16 @@ fn build_rules() -> Vec<GrammarRule> {
         //  "el viernes pasado de 10:15 a 13:45", "el viernes pasado de las 9:00 a las 11:30"
         // ============================================================
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\b(?:el\s+)?(?P<wd>{wd})\s+(?P<dir>pr[oó]ximo|pasado|que\s+viene)\s+de(?:\s+las)?\s+(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}})\s*(?:a\s+(?:las\s+)?|-)\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}})\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:el\s+)?(?P<wd>{wd})\s+(?P<dir>pr[oó]ximo|pasado|que\s+viene)\s+de(?:\s+las?)?\s+(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}}){FROM_SECONDS_PAT}\s*(?:a\s+(?:las?\s+)?|-)\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}}){TO_SECONDS_PAT}\b"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let direction = es_weekday_direction(caps.name("dir")?.as_str())?;
                 let weekday = parse_weekday(caps.name("wd")?.as_str())?;
-                let (fh, fm, th, tm) = parse_hm_range(caps)?;
+                let (from, to) = parse_time_range(caps)?;
                 let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
-                resolve::resolve_time_range_with_minutes_on_date(date, fh, fm, th, tm, tz)
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
             },
         },
         // ============================================================
@@ -241,17 +919,16 @@ fn build_rules() -> Vec<GrammarRule> {
         //  "el viernes pasado 9:00 - 11:30"
         // ============================================================
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\b(?:el\s+)?(?P<wd>{wd})\s+(?P<dir>pr[oó]ximo|pasado|que\s+viene)\s+(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}})\s*-\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}})\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:el\s+)?(?P<wd>{wd})\s+(?P<dir>pr[oó]ximo|pasado|que\s+viene)\s+(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}}){FROM_SECONDS_PAT}\s*-\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}}){TO_SECONDS_PAT}\b"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let direction = es_weekday_direction(caps.name("dir")?.as_str())?;
                 let weekday = parse_weekday(caps.name("wd")?.as_str())?;
-                let (fh, fm, th, tm) = parse_hm_range(caps)?;
+                let (from, to) = parse_time_range(caps)?;
                 let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
-                resolve::resolve_time_range_with_minutes_on_date(date, fh, fm, th, tm, tz)
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
             },
         },
         // ============================================================
@@ -259,126 +936,416 @@ fn build_rules() -> Vec<GrammarRule> {
         //  "el viernes pasado entre las 9 y las 12"
         // ============================================================
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\b(?:el\s+)?(?P<wd>{wd})\s+(?P<dir>pr[oó]ximo|pasado|que\s+viene)\s+entre\s+las\s+(?P<from>\d{{1,2}})\s+y\s+las\s+(?P<to>\d{{1,2}})\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:el\s+)?(?P<wd>{wd})\s+(?P<dir>pr[oó]ximo|pasado|que\s+viene)\s+entre\s+las?\s+(?P<from>{HOUR_PAT})\s+y\s+las?\s+(?P<to>{HOUR_PAT})\b"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let direction = es_weekday_direction(caps.name("dir")?.as_str())?;
                 let weekday = parse_weekday(caps.name("wd")?.as_str())?;
-                let from = caps.name("from")?.as_str().parse::<u32>().ok()?;
-                let to = caps.name("to")?.as_str().parse::<u32>().ok()?;
-                if from > 23 || to > 23 { return None; }
+                let from = parse_num(caps.name("from")?.as_str())?;
+                let to = parse_num(caps.name("to")?.as_str())?;
+                if from > 23 || to > 23 {
+                    return None;
+                }
                 let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
-                resolve::resolve_time_range_on_date(date, from, to, tz)
+                let (from, to) = clock::range_ends((from, 0), (to, 0))?;
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
+            },
+        },
+        // --- Combined: "el viernes a las 3[:30]", "lunes a las 9" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{el}(?P<wd>{wd}),?\s+{at}")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                if caps.name("los").is_some() {
+                    return None;
+                }
+                let time = parse_time(caps)?;
+                let date = resolve_bare_weekday(caps.name("wd")?.as_str(), now, tz, config)?;
+                resolve::resolve_clock_time_on_date(date, time, tz)
             },
         },
         // --- Combined: "ayer a las 3[:30]" ---
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\b(?P<day>hoy|ma[ñn]ana|ayer)\s+a\s+las\s+(?P<hour>\d{1,2})(?::(?P<min>\d{2}))?\b",
-            )
-            .unwrap(),
+            pattern: RulePattern::new(format!(r"(?i)\b(?P<day>{rday})\s+{at}")),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, _| {
                 let offset = day_keyword_offset(caps.name("day")?.as_str())?;
-                let (h, m) = parse_hm(caps)?;
+                let time = parse_time(caps)?;
                 let date = resolve::resolve_day_offset(offset, now, tz)?;
-                resolve::resolve_time_on_date(date, h, m, tz)
+                resolve::resolve_clock_time_on_date(date, time, tz)
             },
         },
         // --- Combined: "hoy de 10:15 a 13:45", "ayer de las 9:00 a las 11:30" ---
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\b(?P<day>hoy|ma[ñn]ana|ayer)\s+de(?:\s+las)?\s+(?P<fh>\d{1,2}):(?P<fm>\d{2})\s*(?:a\s+(?:las\s+)?|-)\s*(?P<th>\d{1,2}):(?P<tm>\d{2})\b",
-            )
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?P<day>{rday})\s+de(?:\s+las?)?\s+(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}}){FROM_SECONDS_PAT}\s*(?:a\s+(?:las?\s+)?|-)\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}}){TO_SECONDS_PAT}\b"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let offset = day_keyword_offset(caps.name("day")?.as_str())?;
-                let (fh, fm, th, tm) = parse_hm_range(caps)?;
+                let (from, to) = parse_time_range(caps)?;
                 let date = resolve::resolve_day_offset(offset, now, tz)?;
-                resolve::resolve_time_range_with_minutes_on_date(date, fh, fm, th, tm, tz)
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
+            },
+        },
+        // --- Combined: "mañana de 9 a 11", "pasado mañana de la una a las tres" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b(?P<day>{rday})\s+{hour_range}")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let offset = day_keyword_offset(caps.name("day")?.as_str())?;
+                let (from, to) = parse_hour_range(caps)?;
+                let date = resolve::resolve_day_offset(offset, now, tz)?;
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
             },
         },
         // --- Combined: "hoy 10:15 - 13:45" (day + bare dash) ---
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\b(?P<day>hoy|ma[ñn]ana|ayer)\s+(?P<fh>\d{1,2}):(?P<fm>\d{2})\s*-\s*(?P<th>\d{1,2}):(?P<tm>\d{2})\b",
-            )
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?P<day>{rday})\s+(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}}){FROM_SECONDS_PAT}\s*-\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}}){TO_SECONDS_PAT}\b"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let offset = day_keyword_offset(caps.name("day")?.as_str())?;
-                let (fh, fm, th, tm) = parse_hm_range(caps)?;
+                let (from, to) = parse_time_range(caps)?;
                 let date = resolve::resolve_day_offset(offset, now, tz)?;
-                resolve::resolve_time_range_with_minutes_on_date(date, fh, fm, th, tm, tz)
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
             },
         },
         // --- Combined: "ayer entre las 9 y las 12" ---
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\b(?P<day>hoy|ma[ñn]ana|ayer)\s+entre\s+las\s+(?P<from>\d{1,2})\s+y\s+las\s+(?P<to>\d{1,2})\b",
-            )
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?P<day>{rday})\s+entre\s+las?\s+(?P<from>{HOUR_PAT})\s+y\s+las?\s+(?P<to>{HOUR_PAT})\b"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let offset = day_keyword_offset(caps.name("day")?.as_str())?;
+                let from = parse_num(caps.name("from")?.as_str())?;
+                let to = parse_num(caps.name("to")?.as_str())?;
+                if from > 23 || to > 23 {
+                    return None;
+                }
+                let date = resolve::resolve_day_offset(offset, now, tz)?;
+                let (from, to) = clock::range_ends((from, 0), (to, 0))?;
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
+            },
+        },
+        // ============================================================
+        //  Combined: Weekday (pre-positive) + parte del día
+        //  "el pasado viernes por la noche", "el próximo lunes por la mañana"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:el\s+)?(?P<dir>pr[oó]ximo|pasado|este)\s+(?P<wd>{wd})\s+{por_la}(?P<part>{part})\b"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let direction = es_weekday_direction(caps.name("dir")?.as_str())?;
+                let weekday = parse_weekday(caps.name("wd")?.as_str())?;
+                let part = parse_day_part(caps.name("part")?.as_str())?;
+                let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
+                resolve::resolve_day_part(date, part, &config.day_parts, tz)
+            },
+        },
+        // ============================================================
+        //  Combined: Weekday (post-positive) + parte del día
+        //  "el viernes pasado por la noche", "el lunes que viene por la tarde"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:el\s+)?(?P<wd>{wd})\s+(?P<dir>pr[oó]ximo|pasado|que\s+viene)\s+{por_la}(?P<part>{part})\b"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let direction = es_weekday_direction(caps.name("dir")?.as_str())?;
+                let weekday = parse_weekday(caps.name("wd")?.as_str())?;
+                let part = parse_day_part(caps.name("part")?.as_str())?;
+                let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
+                resolve::resolve_day_part(date, part, &config.day_parts, tz)
+            },
+        },
+        // --- Combined: "el lunes por la tarde", "viernes por la noche" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b{el}(?P<wd>{wd})\s+{por_la}(?P<part>{part})\b"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                if caps.name("los").is_some() {
+                    return None;
+                }
+                let part = parse_day_part(caps.name("part")?.as_str())?;
+                let date = resolve_bare_weekday(caps.name("wd")?.as_str(), now, tz, config)?;
+                resolve::resolve_day_part(date, part, &config.day_parts, tz)
+            },
+        },
+        // --- Combined: "mañana por la tarde", "ayer por la noche", "hoy en la mañana" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?P<day>{rday})\s+{por_la}(?P<part>{part})\b"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let offset = day_keyword_offset(caps.name("day")?.as_str())?;
+                let part = parse_day_part(caps.name("part")?.as_str())?;
+                let date = resolve::resolve_day_offset(offset, now, tz)?;
+                resolve::resolve_day_part(date, part, &config.day_parts, tz)
+            },
+        },
+        // --- Part of day: "esta mañana", "esta tarde", "esta noche" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\besta\s+(?P<part>{part})\b")),
+            kind: ExpressionKind::PartOfDay,
+            resolver: |caps, now, tz, config| {
+                let part = parse_day_part(caps.name("part")?.as_str())?;
+                let date = resolve::resolve_day_offset(0, now, tz)?;
+                resolve::resolve_day_part(date, part, &config.day_parts, tz)
+            },
+        },
+        // --- Part of day: "anoche" ---
+        GrammarRule {
+            pattern: RulePattern::new(r"(?i)\banoche\b"),
+            kind: ExpressionKind::PartOfDay,
+            resolver: |_, now, tz, config| {
+                let date = resolve::resolve_day_offset(-1, now, tz)?;
+                resolve::resolve_day_part(date, DayPart::Night, &config.day_parts, tz)
+            },
+        },
+        // ============================================================
+        //  Combined: Weekday (pre-positive) + mediodía/medianoche
+        //  "el próximo lunes al mediodía", "el pasado viernes a medianoche"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:el\s+)?(?P<dir>pr[oó]ximo|pasado|este)\s+(?P<wd>{wd})\s+{al}(?P<nm>{nm})\b"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let direction = es_weekday_direction(caps.name("dir")?.as_str())?;
+                let weekday = parse_weekday(caps.name("wd")?.as_str())?;
+                let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
+                resolve_noon_midnight(caps, date, tz, config)
+            },
+        },
+        // ============================================================
+        //  Combined: Weekday (post-positive) + mediodía/medianoche
+        //  "el viernes pasado a medianoche", "el lunes que viene al mediodía"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:el\s+)?(?P<wd>{wd})\s+(?P<dir>pr[oó]ximo|pasado|que\s+viene)\s+{al}(?P<nm>{nm})\b"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
+                let direction = es_weekday_direction(caps.name("dir")?.as_str())?;
+                let weekday = parse_weekday(caps.name("wd")?.as_str())?;
+                let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
+                resolve_noon_midnight(caps, date, tz, config)
+            },
+        },
+        // --- Combined: "el viernes al mediodía", "domingo a medianoche" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{el}(?P<wd>{wd})\s+{al}(?P<nm>{nm})\b")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                if caps.name("los").is_some() {
+                    return None;
+                }
+                let date = resolve_bare_weekday(caps.name("wd")?.as_str(), now, tz, config)?;
+                resolve_noon_midnight(caps, date, tz, config)
+            },
+        },
+        // --- Combined: "mañana al mediodía", "ayer a medianoche" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b(?P<day>{rday})\s+{al}(?P<nm>{nm})\b")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
                 let offset = day_keyword_offset(caps.name("day")?.as_str())?;
-                let from = caps.name("from")?.as_str().parse::<u32>().ok()?;
-                let to = caps.name("to")?.as_str().parse::<u32>().ok()?;
-                if from > 23 || to > 23 { return None; }
                 let date = resolve::resolve_day_offset(offset, now, tz)?;
-                resolve::resolve_time_range_on_date(date, from, to, tz)
+                resolve_noon_midnight(caps, date, tz, config)
+            },
+        },
+        // --- Mediodía/medianoche de hoy: "al mediodía", "a medianoche",
+        //     "hacia el mediodía" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:(?P<approx>{APPROX_PAT})\s+(?:el\s+|la\s+)?)?{al}(?P<nm>{nm})\b"
+            )),
+            kind: ExpressionKind::TimeSpecification,
+            resolver: |caps, now, tz, config| {
+                let date = resolve::resolve_day_offset(0, now, tz)?;
+                resolve_noon_midnight(caps, date, tz, config)
             },
         },
         // --- Relative days ---
         GrammarRule {
-            pattern: Regex::new(r"(?i)\b(?P<day>hoy|ma[ñn]ana|ayer)\b").unwrap(),
+            pattern: RulePattern::new(format!(r"(?i)\b(?P<day>{rday})\b")),
             kind: ExpressionKind::RelativeDay,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, _| {
                 let offset = day_keyword_offset(caps.name("day")?.as_str())?;
                 resolve::resolve_relative_day(offset, now, tz)
             },
         },
-        // --- Day offset: "hace 2 días" ---
+        // --- Time offset: "hace 2 horas" ---
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\bhace\s+(?P<num>{num})\s+d[ií]as?\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\bhace\s+(?P<num>{num})\s+(?P<unit>{subday})\b"
+            )),
+            kind: ExpressionKind::RelativeTimeOffset,
+            resolver: |caps, now, tz, _| {
+                let n = parse_num(caps.name("num")?.as_str())? as i64;
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_offset(unit, -n, now, tz)
+            },
+        },
+        // --- Time offset: "en 30 minutos", "dentro de una hora" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:en|dentro\s+de)\s+(?P<num>{num})\s+(?P<unit>{subday})\b"
+            )),
+            kind: ExpressionKind::RelativeTimeOffset,
+            resolver: |caps, now, tz, _| {
+                let n = parse_num(caps.name("num")?.as_str())? as i64;
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_offset(unit, n, now, tz)
+            },
+        },
+        // --- Day offset: "hace 2 días", "hace un mes" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\bhace\s+(?P<num>{num})\s+(?P<unit>{days})\b")),
             kind: ExpressionKind::RelativeDayOffset,
-            resolver: |caps, now, tz| {
-                let n = parse_num(caps.name("num")?.as_str())?;
-                resolve::resolve_relative_day(-(n as i64), now, tz)
+            resolver: |caps, now, tz, _| {
+                let n = parse_num(caps.name("num")?.as_str())? as i64;
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_offset(unit, -n, now, tz)
             },
         },
-        // --- Day offset: "en 3 días" ---
+        // --- Day offset: "en 3 días", "dentro de dos semanas" ---
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\ben\s+(?P<num>{num})\s+d[ií]as?\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:en|dentro\s+de)\s+(?P<num>{num})\s+(?P<unit>{days})\b"
+            )),
             kind: ExpressionKind::RelativeDayOffset,
-            resolver: |caps, now, tz| {
-                let n = parse_num(caps.name("num")?.as_str())?;
-                resolve::resolve_relative_day(n as i64, now, tz)
+            resolver: |caps, now, tz, _| {
+                let n = parse_num(caps.name("num")?.as_str())? as i64;
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_offset(unit, n, now, tz)
+            },
+        },
+        // ============================================================
+        //  Combined: día + inicio + duración: "mañana desde las 9 durante 2 horas"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:(?P<day>{rday})|(?P<date>{date})),?\s+{start}\s+(?:por|durante)\s+{dur}"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps)?;
+                let date = resolve_anchor_day(caps, now, tz, config)?;
+                resolve::resolve_time_for_duration(date, time, parse_duration(caps)?, tz)
+            },
+        },
+        // ============================================================
+        //  Time range: inicio + duración: "a las 15 por 45 minutos"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{start}\s+(?:por|durante)\s+{dur}")),
+            kind: ExpressionKind::TimeRange,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps)?;
+                let date = resolve_anchor_day(caps, now, tz, config)?;
+                resolve::resolve_time_for_duration(date, time, parse_duration(caps)?, tz)
+            },
+        },
+        // ============================================================
+        //  Time range: duración + fin: "2 horas hasta las 17"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b{dur}\s+hasta\s+las?\s+(?P<hour>{HOUR_PAT})(?::(?P<min>\d{{2}}){SECONDS_PAT})?\b"
+            )),
+            kind: ExpressionKind::TimeRange,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps)?;
+                let date = resolve_anchor_day(caps, now, tz, config)?;
+                resolve::resolve_duration_until_time(date, time, parse_duration(caps)?, tz)
+            },
+        },
+        // --- Duration: "45 minutos", "1,5 horas", "hora y media", "dos horas y cuarto" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{spelled_dur}")),
+            kind: ExpressionKind::Duration,
+            resolver: |caps, _, _, _| resolve_spelled_duration(caps),
+        },
+        // --- Duration: "media hora", "un cuarto de hora", "tres cuartos de hora" ---
+        GrammarRule {
+            pattern: RulePattern::new(
+                r"(?i)\b(?:(?P<three>tres)\s+|una?\s+)?(?:(?P<frac>media)\s+|(?P<quarter>cuartos?)\s+de\s+)(?P<unit>hora|minuto)\b",
+            ),
+            kind: ExpressionKind::Duration,
+            resolver: |caps, _, _, _| {
+                // "quarter" forms carry the fraction in a separate group
+                let frac = match (caps.name("frac"), caps.name("quarter")) {
+                    (Some(_), _) => 0.5,
+                    (None, Some(_)) => 0.25,
+                    (None, None) => return None,
+                };
+                let times = if caps.name("three").is_some() {
+                    3.0
+                } else {
+                    1.0
+                };
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_duration(unit, times * frac)
             },
         },
+        // --- Duration: "2 horas y 30 minutos" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?P<h>{num})\s+horas?\s+y\s+(?P<m>{num})(?:\s+minutos?)?\b"
+            )),
+            kind: ExpressionKind::Duration,
+            resolver: |caps, _, _, _| {
+                let h = parse_num(caps.name("h")?.as_str())?;
+                let m = parse_num(caps.name("m")?.as_str())?;
+                duration::hours_minutes(h, m)
+            },
+        },
+        // --- Duration, compact: "2h", "90min", "2h30", "1:30h" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{COMPACT_DURATION_PAT}")),
+            kind: ExpressionKind::Duration,
+            resolver: |caps, _, _, _| duration::parse_compact(caps),
+        },
         // --- Time spec: "a las 3[:30]" ---
         GrammarRule {
-            pattern: Regex::new(r"(?i)\ba\s+las\s+(?P<hour>\d{1,2})(?::(?P<min>\d{2}))?\b").unwrap(),
+            pattern: RulePattern::new(format!(r"(?i)\b{at_time}")),
+            kind: ExpressionKind::TimeSpecification,
+            resolver: |caps, now, tz, _| {
+                let time = parse_time(caps)?;
+                resolve::resolve_clock_time_today(time, now, tz)
+            },
+        },
+        // --- Time spec, hablada: "las tres y media", "a las cinco menos cuarto",
+        //     "sobre las tres y media" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:(?:a|(?P<approx>{APPROX_PAT}))\s+)?{spoken}"
+            )),
             kind: ExpressionKind::TimeSpecification,
-            resolver: |caps, now, tz| {
-                let (h, m) = parse_hm(caps)?;
+            resolver: |caps, now, tz, _| {
+                let (h, m) = parse_spoken(caps)?;
                 resolve::resolve_time_today(h, m, now, tz)
             },
         },
         // --- Time range: "la última hora" ---
         GrammarRule {
-            pattern: Regex::new(r"(?i)\b(?:la\s+)?[úu]ltima\s+(?P<unit>hora|minuto)\b").unwrap(),
+            pattern: RulePattern::new(r"(?i)\b(?:la\s+)?[úu]ltima\s+(?P<unit>hora|minuto)\b"),
             kind: ExpressionKind::TimeRange,
-            resolver: |caps, now, _tz| {
+            resolver: |caps, now, _tz, _| {
                 let unit = caps.name("unit")?.as_str().to_lowercase();
                 let mapped = match unit.as_str() {
                     "hora" => "hour",
@@ -390,38 +1357,76 @@ fn build_rules() -> Vec<GrammarRule> {
         },
         // --- Time range: "de las 10:15 a las 13:45" (standalone — "las" triggers prefilter) ---
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\bde\s+las\s+(?P<fh>\d{1,2}):(?P<fm>\d{2})\s+a\s+las\s+(?P<th>\d{1,2}):(?P<tm>\d{2})\b",
-            )
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\bde\s+las?\s+(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}}){FROM_SECONDS_PAT}\s+a\s+las?\s+(?P<th>\d{{1,2}}):(?P<tm>\d{{2}}){TO_SECONDS_PAT}\b",
+            )),
             kind: ExpressionKind::TimeRange,
-            resolver: |caps, now, tz| {
-                let (fh, fm, th, tm) = parse_hm_range(caps)?;
-                resolve::resolve_time_range_with_minutes_today(fh, fm, th, tm, now, tz)
+            resolver: |caps, now, tz, config| {
+                let (from, to) = parse_time_range(caps)?;
+                resolve::resolve_clock_range_today(from, to, now, tz, config.overnight_ranges)
             },
         },
         // --- Time range: "entre las 9 y las 12" ---
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\bentre\s+las\s+(?P<from>\d{1,2})\s+y\s+las\s+(?P<to>\d{1,2})\b",
-            )
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\bentre\s+las?\s+(?P<from>{HOUR_PAT})\s+y\s+las?\s+(?P<to>{HOUR_PAT})\b"
+            )),
             kind: ExpressionKind::TimeRange,
-            resolver: |caps, now, tz| {
-                let from = caps.name("from")?.as_str().parse::<u32>().ok()?;
-                let to = caps.name("to")?.as_str().parse::<u32>().ok()?;
-                if from > 23 || to > 23 { return None; }
-                resolve::resolve_time_range_today(from, to, now, tz)
+            resolver: |caps, now, tz, config| {
+                let from = parse_num(caps.name("from")?.as_str())?;
+                let to = parse_num(caps.name("to")?.as_str())?;
+                if from > 23 || to > 23 {
+                    return None;
+                }
+                let (from, to) = clock::range_ends((from, 0), (to, 0))?;
+                resolve::resolve_clock_range_today(from, to, now, tz, config.overnight_ranges)
+            },
+        },
+        // --- Time range: "de la una a las tres", "de las 9 a las 11:30", "de 1 a 3" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{hour_range}")),
+            kind: ExpressionKind::TimeRange,
+            resolver: |caps, now, tz, config| {
+                let (from, to) = parse_hour_range(caps)?;
+                resolve::resolve_clock_range_today(from, to, now, tz, config.overnight_ranges)
+            },
+        },
+        // --- Compact range: "9-12", "9:15–11:45", "0930-1200" (only with `compact_ranges`) ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b{COMPACT_RANGE_PAT}(?:\s+(?P<unit>{subday}|{days})\b)?"
+            )),
+            kind: ExpressionKind::TimeRange,
+            resolver: |caps, now, tz, config| {
+                if !config.compact_ranges || caps.name("unit").is_some() {
+                    return None;
+                }
+                let (fh, fm, th, tm) = compact_range::parse(caps, config)?;
+                let (from, to) = clock::range_ends((fh, fm), (th, tm))?;
+                resolve::resolve_clock_range_today(from, to, now, tz, config.overnight_ranges)
+            },
+        },
+        // --- Weekday without direction: "el lunes", "viernes" ---
+        GrammarRule {
+            // A weekday in front of a date is part of that date; if the date
+            // rule rejected it ("lunes, 6 de marzo de 2026"), so do we.
+            pattern: RulePattern::new(format!(r"(?i)\b{el}(?P<bwd>{wd})\b(?P<dtail>,?\s+{date})?")),
+            kind: ExpressionKind::RelativeDay,
+            resolver: |caps, now, tz, config| {
+                if caps.name("los").is_some() || caps.name("dtail").is_some() {
+                    return None;
+                }
+                let date = resolve_bare_weekday(caps.name("bwd")?.as_str(), now, tz, config)?;
+                resolve::resolve_full_day(date, tz)
             },
         },
         // --- Next/Last/This Weekday (Pre-positive: "el próximo lunes") ---
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\b(?:el\s+)?(?P<dir>pr[oó]ximo|pasado|este)\s+(?P<day>lunes|martes|mi[eé]rcoles|jueves|viernes|s[aá]bado|domingo)\b"
-            )
-            .unwrap(),
+            pattern: RulePattern::new(
+                r"(?i)\b(?:el\s+)?(?P<dir>pr[oó]ximo|pasado|este)\s+(?P<day>lunes|martes|mi[eé]rcoles|jueves|viernes|s[aá]bado|domingo)\b",
+            ),
             kind: ExpressionKind::RelativeDay,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, _| {
                 let dir_str = caps.name("dir")?.as_str().to_lowercase();
                 let direction = match dir_str.as_str() {
                     "próximo" | "proximo" => 1,
@@ -435,22 +1440,253 @@ fn build_rules() -> Vec<GrammarRule> {
         },
         // --- Next/Last/This Weekday (Post-positive: "el lunes que viene") ---
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\b(?:el\s+)?(?P<day>lunes|martes|mi[eé]rcoles|jueves|viernes|s[aá]bado|domingo)\s+(?P<dir>pr[oó]ximo|pasado|que\s+viene)\b"
-            )
-            .unwrap(),
+            pattern: RulePattern::new(
+                r"(?i)\b(?:el\s+)?(?P<day>lunes|martes|mi[eé]rcoles|jueves|viernes|s[aá]bado|domingo)\s+(?P<dir>pr[oó]ximo|pasado|que\s+viene)\b",
+            ),
             kind: ExpressionKind::RelativeDay,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, _| {
                 let dir_str = caps.name("dir")?.as_str().to_lowercase();
                 let direction = if dir_str.contains("pasado") {
-                     -1
+                    -1
                 } else {
-                     1 // "próximo" or "que viene"
+                    1 // "próximo" or "que viene"
                 };
                 let weekday = parse_weekday(caps.name("day")?.as_str())?;
                 resolve::resolve_weekday(weekday, direction, now, tz)
             },
         },
+        // ============================================================
+        //  Límite: "a finales de semana", "a principios del mes que viene",
+        //  "para el fin de mes", "a mediados de año", "al final del día"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(
+                r"(?i)\b(?:(?:para|a|al|en)\s+)?(?:(?:el|la|los|las)\s+)?(?P<bnd>principios?|inicios?|comienzos?|mediados|finales|final|fin)\s+(?:de\s+(?:la\s+|el\s+)?|del\s+)(?:(?P<dir>pr[oó]xim[oa]|pasad[oa]|est[ae])\s+)?(?P<unit>d[ií]a|semana|mes|trimestre|a[ñn]o)(?:\s+(?P<dir_post>que\s+viene|pasad[oa]|pr[oó]xim[oa]|siguiente|anterior))?\b",
+            ),
+            kind: ExpressionKind::Boundary,
+            resolver: |caps, now, tz, config| {
+                let bnd = caps.name("bnd")?.as_str();
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                // "fin de semana" is the weekend, not the end of the week
+                if bnd.eq_ignore_ascii_case("fin") && unit == TimeUnit::Week {
+                    return None;
+                }
+                let offset = match caps.name("dir").or(caps.name("dir_post")) {
+                    Some(d) => period_direction(d.as_str())?,
+                    None => 0,
+                };
+                resolve::resolve_boundary(
+                    unit,
+                    parse_boundary(bnd)?,
+                    offset,
+                    now,
+                    tz,
+                    config.week_start,
+                    config.business_hours,
+                )
+            },
+        },
+        // ============================================================
+        //  Resto: "el resto del día", "por el resto de la semana"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(
+                r"(?i)\b(?:(?:por|durante)\s+)?(?:el\s+)?resto\s+(?:de\s+la\s+|del\s+)(?P<unit>d[ií]a|semana|mes|trimestre|a[ñn]o)\b",
+            ),
+            kind: ExpressionKind::TimeRange,
+            resolver: |caps, now, tz, config| {
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_rest_of(unit, now, tz, config.week_start)
+            },
+        },
+        // ============================================================
+        //  Todo el día: "mañana todo el día", "todo el día de hoy"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:(?P<day>{rday})\s+todo\s+el\s+d[ií]a|todo\s+el\s+d[ií]a\s+(?:de\s+)?(?P<day_post>{rday}))\b"
+            )),
+            kind: ExpressionKind::RelativeDay,
+            resolver: |caps, now, tz, _| {
+                let offset = match caps.name("day").or(caps.name("day_post")) {
+                    Some(d) => day_keyword_offset(d.as_str())?,
+                    None => 0,
+                };
+                resolve::resolve_relative_day(offset, now, tz)
+            },
+        },
+        // ============================================================
+        //  Relative period: "esta semana", "el próximo mes", "este año"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(
+                r"(?i)\b(?:(?:la|el)\s+)?(?P<dir>est[ae]|pr[oó]xim[oa]|pasad[oa])\s+(?P<unit>semana|mes|trimestre|a[ñn]o)\b",
+            ),
+            kind: ExpressionKind::RelativePeriod,
+            resolver: |caps, now, tz, config| {
+                let offset = period_direction(caps.name("dir")?.as_str())?;
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_period(unit, offset, now, tz, config.week_start)
+            },
+        },
+        // ============================================================
+        //  Relative period: "la semana pasada", "el mes que viene",
+        //  "el año anterior", "el trimestre próximo"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(
+                r"(?i)\b(?:(?:la|el)\s+)?(?P<unit>semana|mes|trimestre|a[ñn]o)\s+(?P<dir>pasad[oa]|anterior|que\s+viene|siguiente|pr[oó]xim[oa])\b",
+            ),
+            kind: ExpressionKind::RelativePeriod,
+            resolver: |caps, now, tz, config| {
+                let offset = period_direction(caps.name("dir")?.as_str())?;
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_period(unit, offset, now, tz, config.week_start)
+            },
+        },
+        // ============================================================
+        //  Combined: día ordinal del mes + "a las H[:MM]"
+        //  "el primer lunes del mes a las 10", "el último viernes de marzo a las 16:00"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{wom},?\s+{at}")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps)?;
+                let date = resolve_weekday_of_month_caps(caps, now, tz, config)?;
+                resolve::resolve_clock_time_on_date(date, time, tz)
+            },
+        },
+        // ============================================================
+        //  Día ordinal del mes: "el primer lunes del mes",
+        //  "el último viernes de marzo", "el segundo martes del mes que viene"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{wom}")),
+            kind: ExpressionKind::WeekdayOfMonth,
+            resolver: |caps, now, tz, config| {
+                let date = resolve_weekday_of_month_caps(caps, now, tz, config)?;
+                resolve::resolve_full_day(date, tz)
+            },
+        },
+        // ============================================================
+        //  Combined: día del mes + "a las H[:MM]"
+        //  "el 15 a las 10", "el día 3 a las 9:30"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{mday},?\s+{at}")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps)?;
+                let date = resolve_day_of_month_caps(caps, now, tz, config)?;
+                resolve::resolve_clock_time_on_date(date, time, tz)
+            },
+        },
+        // --- Día del mes: "el 15", "el día 15", "el primero" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{mday}")),
+            kind: ExpressionKind::DayOfMonth,
+            resolver: |caps, now, tz, config| {
+                let date = resolve_day_of_month_caps(caps, now, tz, config)?;
+                resolve::resolve_full_day(date, tz)
+            },
+        },
+        // ============================================================
+        //  Combined: holiday + time spec
+        //  "en Nochebuena a las 21:00", "el Viernes Santo a las 10"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:(?:el|en|por|para)\s+)?(?:(?:el|la)\s+)?{hol},?\s+{at}"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps)?;
+                let date = resolve_holiday_caps(caps, now, tz, config)?;
+                resolve::resolve_clock_time_on_date(date, time, tz)
+            },
+        },
+        // ============================================================
+        //  Holiday: "en Navidad", "el Día de Reyes", "el Viernes Santo", "Pascua 2027"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:(?:el|en|por|para)\s+)?(?:(?:el|la)\s+)?{hol}"
+            )),
+            kind: ExpressionKind::Holiday,
+            resolver: |caps, now, tz, config| {
+                let date = resolve_holiday_caps(caps, now, tz, config)?;
+                resolve::resolve_full_day(date, tz)
+            },
+        },
+        // ============================================================
+        //  Recurrente: "cada viernes a las 9", "todos los días al mediodía",
+        //  "cada dos semanas", "diariamente a las 8:30"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b{recur}(?:,?\s+(?:{at}|(?:al|a(?:\s+la)?)\s+(?P<nm>{nm})\b))?"
+            )),
+            kind: ExpressionKind::Recurring,
+            resolver: |caps, _now, _tz, _config| resolve_recurrence_caps(caps),
+        },
+        // ============================================================
+        //  Combined: fecha + mediodía/medianoche
+        //  "el 5 de marzo al mediodía", "05/03 a medianoche"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{date},?\s+{al}(?P<nm>{nm})\b")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let date = resolve_date_caps(caps, now, tz, config)?;
+                resolve_noon_midnight(caps, date, tz, config)
+            },
+        },
+        // ============================================================
+        //  Combined: fecha + "a las H[:MM]"
+        //  "el 5 de marzo a las 15", "05/03 a las 15:30"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{date},?\s+{at}")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps)?;
+                let date = resolve_date_caps(caps, now, tz, config)?;
+                resolve::resolve_clock_time_on_date(date, time, tz)
+            },
+        },
+        // ============================================================
+        //  Combined: fecha + "de HH:MM a HH:MM"
+        //  "el 5 de marzo de 9:00 a 11:30", "05/03/2026 9:00 - 17:00"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{date},?\s+{hm_range}")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let (from, to) = parse_time_range(caps)?;
+                let date = resolve_date_caps(caps, now, tz, config)?;
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
+            },
+        },
+        // ============================================================
+        //  Absolute date: "el 5 de marzo", "5 de marzo de 2027",
+        //  "viernes, 6 de marzo", "05/03/2026", "el 5/3", "2026-03-05"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{date}")),
+            kind: ExpressionKind::AbsoluteDate,
+            resolver: |caps, now, tz, config| {
+                // A bare "3/5" is more likely a fraction than a date.
+                if caps.name("prep").is_none()
+                    && caps.name("wd").is_none()
+                    && numeric_date::is_short_slash(caps)
+                {
+                    return None;
+                }
+                let (year, month, day, weekday) = parse_date(caps, config)?;
+                resolve::resolve_absolute_date(year, month, day, weekday, now, tz, config.year_bias)
+            },
+        },
     ]
 }
 
@@ -467,7 +1703,36 @@ impl LanguageParser for Spanish {
         PREFIXES
     }
 
-    fn parse(&self, text: &str, now: DateTime<Utc>, tz: Tz) -> Vec<TimeMatch> {
-        apply_rules(&self.rules, &self.regex_set, text, now, tz)
+    fn set_custom_holidays(&mut self, names: &[&str]) {
+        self.rules = build_rules(names);
+        self.regex_set = build_regex_set(&self.rules);
+    }
+
+    fn parse(
+        &self,
+        text: &str,
+        now: DateTime<Utc>,
+        tz: Tz,
+        config: &ParserConfig,
+    ) -> Vec<TimeMatch> {
+        let parse_in = |text: &str, tz: Tz| {
+            let mut rejected = Vec::new();
+            let matches = apply_rules_with_rejections(
+                &self.rules,
+                &self.regex_set,
+                text,
+                now,
+                tz,
+                config,
+                &mut rejected,
+            );
+            let matches = anchored_offset::apply(text, matches, &self.offsets, tz);
+            let matches =
+                open_range::apply(text, matches, &self.open_range, config, |t, config| {
+                    apply_rules(&self.rules, &self.regex_set, t, now, tz, config)
+                });
+            without_rejected(matches, &rejected)
+        };
+        zone::apply(text, parse_in(text, tz), &self.zones, config, parse_in)
     }
 }
@@ -1,9 +1,19 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveTime, Utc};
 use chrono_tz::Tz;
-use regex::Regex;
 
-use crate::lang::numbers::parse_number_fr;
-use crate::lang::{GrammarRule, LanguageParser, apply_rules};
+use crate::holiday::{self, Holiday};
+use crate::lang::anchored_offset::{self, OffsetRules};
+use crate::lang::clock::{self, FROM_SECONDS_PAT, SECONDS_PAT, TO_SECONDS_PAT};
+use crate::lang::compact_range::{self, COMPACT_RANGE_PAT};
+use crate::lang::duration::{self, COMPACT_DURATION_PAT};
+use crate::lang::numbers::{parse_number_fr, parse_ordinal_fr};
+use crate::lang::numeric_date::{self, NUMERIC_DATE_PAT};
+use crate::lang::open_range::{self, Bound, OpenRangeRules};
+use crate::lang::zone::{self, CITY_PAT, ZoneRules};
+use crate::lang::{
+    GrammarRule, LanguageParser, RulePattern, apply_rules, apply_rules_with_rejections,
+    build_regex_set, without_rejected,
+};
 use crate::resolve;
 use crate::types::*;
 
@@ -12,6 +22,9 @@ const KEYWORDS: &[&str] = &[
     "aujourd",
     "demain",
     "hier",
+    "après-demain",
+    "apres-demain",
+    "avant-hier",
     "il y a",
     "dans",
     "jours",
@@ -35,10 +48,108 @@ const KEYWORDS: &[&str] = &[
     "vendredi",
     "samedi",
     "dimanche",
+    "janvier",
+    "f\u{e9}vrier",
+    "fevrier",
+    "mars",
+    "avril",
+    "mai",
+    "juin",
+    "juillet",
+    "ao\u{fb}t",
+    "aout",
+    "septembre",
+    "octobre",
+    "novembre",
+    "d\u{e9}cembre",
+    "decembre",
+    "janv",
+    "f\u{e9}vr",
+    "fevr",
+    "avr",
+    "juil",
+    "sept",
+    "oct",
+    "nov",
+    "d\u{e9}c",
+    "dec",
+    "semaine",
+    "mois",
+    "trimestre",
+    "année",
+    "annee",
+    "an",
+    "seconde",
+    "matin",
+    "après-midi",
+    "apres-midi",
+    "soir",
+    "nuit",
+    "midi",
+    "minuit",
+    "tous",
+    "toutes",
+    "chaque",
+    "quotidiennement",
+    "hebdomadairement",
+    "mensuellement",
+    "annuellement",
+    "premier",
+    "jour de l",
+    "nouvel an",
+    "piphanie",
+    "jeudi saint",
+    "vendredi saint",
+    "pâques",
+    "paques",
+    "travail",
+    "victoire",
+    "ascension",
+    "pentecôte",
+    "pentecote",
+    "fête-dieu",
+    "fete-dieu",
+    "nationale",
+    "assomption",
+    "toussaint",
+    "armistice",
+    "noël",
+    "noel",
+    "sylvestre",
+    "début",
+    "debut",
+    "milieu",
+    "mi-",
+    "fin",
+    "reste",
+    "toute la journ",
+    "vers",
+    "alentours",
+    "autour",
+    "environ",
+    "sur les",
+    "depuis",
+    "dès",
+    "partir",
+    "jusqu",
+    "avant",
+    "après",
+    "apres",
+    // a day of the month: "le 15"
+    "le 1",
+    "le 2",
+    "le 3",
+    "le 4",
+    "le 5",
+    "le 6",
+    "le 7",
+    "le 8",
+    "le 9",
 ];
 
 const PREFIXES: &[&str] = &[
-    "auj", "aujo", "aujou", "aujour", "aujourd", "dem", "dema", "demai", "hie", "ent", "entr",
+    "auj", "aujo", "aujou", "aujour", "aujourd", "dem", "dema", "demai", "hie", "apr", "aprè",
+    "après", "après-", "apre", "apres", "apres-", "ava", "avan", "avant", "avant-", "ent", "entr",
     "der", "dern", "derni", "pro", "proc", "proch", "procha", "prochai", "lun", "lund", "mar",
     "mard", "mer", "merc", "mercr", "mercre", "mercred", "jeu", "jeud", "ven", "vend", "vendr",
     "vendre", "vendred", "sam", "same", "samed", "dim", "dima", "diman", "dimanc", "dimanch",
@@ -54,6 +165,10 @@ fn day_keyword_offset(s: &str) -> Option<i64> {
         Some(1)
     } else if lower == "hier" {
         Some(-1)
+    } else if lower.starts_with("apr") && lower.ends_with("demain") {
+        Some(2)
+    } else if lower.starts_with("avant") && lower.ends_with("hier") {
+        Some(-2)
     } else {
         None
     }
@@ -72,6 +187,24 @@ fn parse_weekday(s: &str) -> Option<chrono::Weekday> {
     }
 }
 
+fn parse_month(s: &str) -> Option<u32> {
+    match s.to_lowercase().trim_end_matches('.') {
+        "janvier" | "janv" => Some(1),
+        "f\u{e9}vrier" | "fevrier" | "f\u{e9}vr" | "fevr" => Some(2),
+        "mars" => Some(3),
+        "avril" | "avr" => Some(4),
+        "mai" => Some(5),
+        "juin" => Some(6),
+        "juillet" | "juil" => Some(7),
+        "ao\u{fb}t" | "aout" => Some(8),
+        "septembre" | "sept" => Some(9),
+        "octobre" | "oct" => Some(10),
+        "novembre" | "nov" => Some(11),
+        "d\u{e9}cembre" | "decembre" | "d\u{e9}c" | "dec" => Some(12),
+        _ => None,
+    }
+}
+
 fn parse_num(s: &str) -> Option<u32> {
     s.parse::<u32>()
         .ok()
@@ -81,6 +214,9 @@ fn parse_num(s: &str) -> Option<u32> {
 pub struct French {
     rules: Vec<GrammarRule>,
     regex_set: regex::RegexSet,
+    open_range: OpenRangeRules,
+    offsets: OffsetRules,
+    zones: ZoneRules,
 }
 
 impl Default for French {
@@ -91,31 +227,384 @@ impl Default for French {
 
 impl French {
     pub fn new() -> Self {
-        let rules = build_rules();
-        let regex_set = regex::RegexSet::new(rules.iter().map(|r| r.pattern.as_str())).unwrap();
-        Self { rules, regex_set }
+        let rules = build_rules(&[]);
+        let regex_set = build_regex_set(&rules);
+        let open_range = OpenRangeRules::new(OPEN_RANGE_PAT, open_bound, "à");
+        let offsets = OffsetRules::new(&offset_pattern(), anchor_offset);
+        let zones = ZoneRules::new(&format!(r"heure\s+(?:de\s+|d['’]\s*)?{CITY_PAT}"));
+        Self {
+            rules,
+            regex_set,
+            open_range,
+            offsets,
+            zones,
+        }
     }
 }
 
 /// Shared weekday pattern
 const WEEKDAY_PAT: &str = r"lundi|mardi|mercredi|jeudi|vendredi|samedi|dimanche";
 
-/// Parse a HH:MM–HH:MM range from captures with groups `fh`, `fm`, `th`, `tm`.
-fn parse_hm_range(caps: &regex::Captures) -> Option<(u32, u32, u32, u32)> {
+/// Relative day keywords ("après-demain", "aujourd'hui", "avant-hier")
+const RELATIVE_DAY_PAT: &str =
+    r"apr[èe]s[\s-]demain|avant[\s-]hier|aujourd['\u{2019}]hui|demain|hier";
+
+/// Shared month pattern (accent-tolerant, with common abbreviations)
+const MONTH_PAT: &str = r"janvier|f[ée]vrier|mars|avril|mai|juin|juillet|ao[ûu]t|septembre|octobre|novembre|d[ée]cembre|janv|f[ée]vr|avr|juil|sept|oct|nov|d[ée]c";
+
+/// Clock hour as digits or a written-out number ("13", "trois", "dix-sept")
+const HOUR_PAT: &str = r"\d{1,2}|une?|deux|trois|quatre|cinq|six|sept|huit|neuf|dix|onze|douze|treize|quatorze|quinze|seize|dix-sept|dix-huit|dix-neuf|vingt|vingt-et-une?|vingt-deux|vingt-trois";
+
+/// Approximation markers: "vers", "aux alentours de", "à environ"
+const APPROX_PAT: &str =
+    r"vers|aux\s+alentours\s+de|autour\s+de|sur\s+les|environ(?:\s+[àa])?|[àa]\s+environ";
+
+/// Shared "à H[h|:MM[:SS]]" pattern (groups `hour`, `min`, `sec`, `subsec`, and
+/// `approx` for "vers 15h")
+fn at_time_pattern() -> String {
+    format!(
+        r"(?:[àa]|(?P<approx>{APPROX_PAT}))\s+(?P<hour>{HOUR_PAT})(?:[h:](?P<min>\d{{2}}){SECONDS_PAT}|\s*h|\s+heures?)\b"
+    )
+}
+
+/// Shared "[de] HH:MM[:SS] à/- HH:MM[:SS]" pattern (groups `fh`, `fm`, `fsec`,
+/// `fsubsec`, `th`, `tm`, `tsec`, `tsubsec`)
+fn hm_range_pattern() -> String {
+    format!(
+        r"(?:de\s+)?(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}}){FROM_SECONDS_PAT}\s*(?:[àa]|-)\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}}){TO_SECONDS_PAT}\b"
+    )
+}
+
+/// Spoken clock reading: "trois heures et quart", "cinq heures moins le quart",
+/// "15 heures 30", "dix heures moins vingt-cinq" (groups `sp_hour`, `sp_rel`, `sp_min`)
+fn spoken_time_pattern() -> String {
+    format!(
+        r"(?P<sp_hour>{HOUR_PAT})\s+heures?\s+(?:(?P<sp_rel>et|moins)\s+(?:le\s+)?)?(?P<sp_min>quart|demie?|(?:vingt|trente)-(?:et-un|deux|trois|quatre|cinq|six|sept|huit|neuf)|{NUM_WORD_PATTERN})(?:\s+minutes?)?\b"
+    )
+}
+
+/// Shared start-time pattern for duration-anchored ranges (groups `anchor`, `hour`, `min`)
+fn start_time_pattern() -> String {
+    format!(
+        r"(?P<anchor>[àa]|de|d[èe]s)\s+(?P<hour>{HOUR_PAT})(?:[h:](?P<min>\d{{2}}){SECONDS_PAT}|\s*h\b|\s+heures?\b)?"
+    )
+}
+
+/// Calendar date pattern: a month-name date (`dom`, `month`, optional `year`)
+/// or a numeric date (see [`NUMERIC_DATE_PAT`]), optionally preceded by a
+/// weekday (`wd`) and/or "le" (`prep`).
+fn date_pattern() -> String {
+    format!(
+        r"(?:(?:le\s+)?(?P<wd>{WEEKDAY_PAT}),?\s+|(?P<prep>le)\s+)?(?:(?P<dom>\d{{1,2}})(?:er)?\s+(?P<month>{MONTH_PAT})\b\.?(?:\s+(?P<year>\d{{4}})\b)?|{NUMERIC_DATE_PAT})"
+    )
+}
+
+/// Parse a calendar date from captures produced by [`date_pattern`] into
+/// `(year, month, day, weekday)`.
+fn parse_date(
+    caps: &regex::Captures,
+    config: &ParserConfig,
+) -> Option<(Option<i32>, u32, u32, Option<chrono::Weekday>)> {
+    let weekday = match caps.name("wd") {
+        Some(w) => Some(parse_weekday(w.as_str())?),
+        None => None,
+    };
+    let Some(month) = caps.name("month") else {
+        let order = config.date_order.unwrap_or(DateOrder::DayMonthYear);
+        let date = numeric_date::parse(caps, order)?;
+        return Some((date.year, date.month, date.day, weekday));
+    };
+    let month = parse_month(month.as_str())?;
+    let day = caps.name("dom")?.as_str().parse::<u32>().ok()?;
+    let year = match caps.name("year") {
+        Some(y) => Some(y.as_str().parse::<i32>().ok()?),
+        None => None,
+    };
+    Some((year, month, day, weekday))
+}
+
+/// Resolve a calendar date from captures to midnight of that day.
+fn resolve_date_caps(
+    caps: &regex::Captures,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<DateTime<Utc>> {
+    let (year, month, day, weekday) = parse_date(caps, config)?;
+    resolve::resolve_date(year, month, day, weekday, now, tz, config.year_bias)
+}
+
+/// Day of the month without a month: "le 15", "le 1er", "le premier"
+/// (group `mday`).
+const DAY_OF_MONTH_PAT: &str = r"le\s+(?P<mday>\d{1,2}|1er|premier)\b";
+
+/// Resolve a day of the month from captures to midnight of that day.
+fn resolve_day_of_month_caps(
+    caps: &regex::Captures,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<DateTime<Utc>> {
+    let day = caps.name("mday")?.as_str().to_lowercase();
+    let day = day.parse::<u32>().ok().or_else(|| parse_ordinal_fr(&day))?;
+    resolve::resolve_day_of_month(day, now, tz, config.day_of_month_bias)
+}
+
+/// Holiday names ("Noël", "lundi de Pâques", "la Toussaint")
+const HOLIDAY_PAT: &str = r"jour\s+de\s+l['’]an|nouvel\s+an|[ée]piphanie|jeudi\s+saint|vendredi\s+saint|lundi\s+de\s+p[âa]ques|p[âa]ques|f[êe]te\s+du\s+travail|f[êe]te\s+de\s+la\s+victoire|ascension|lundi\s+de\s+pentec[ôo]te|pentec[ôo]te|f[êe]te-dieu|f[êe]te\s+nationale|assomption|toussaint|armistice|r[ée]veillon\s+de\s+no[ëe]l|veille\s+de\s+no[ëe]l|jour\s+de\s+no[ëe]l|no[ëe]l|saint-sylvestre|r[ée]veillon\s+du\s+nouvel\s+an";
+
+/// Holiday name with an optional year: "Easter 2027" (groups `hol`, `hyear`,
+/// and `custom` for the calendar's custom holidays).
+fn holiday_pattern(custom_holidays: &[&str]) -> String {
+    let custom = holiday::custom_name_pattern(custom_holidays)
+        .map(|p| format!("|{p}"))
+        .unwrap_or_default();
+    format!(r"(?P<hol>{HOLIDAY_PAT}{custom})\b(?:,?\s+(?P<hyear>\d{{4}})\b)?")
+}
+
+fn parse_holiday(s: &str) -> Option<Holiday> {
+    match holiday::name_key(s).as_str() {
+        "jourdelan" | "nouvelan" => Some(Holiday::NewYearsDay),
+        "epiphanie" => Some(Holiday::Epiphany),
+        "jeudisaint" => Some(Holiday::MaundyThursday),
+        "vendredisaint" => Some(Holiday::GoodFriday),
+        "paques" => Some(Holiday::EasterSunday),
+        "lundidepaques" => Some(Holiday::EasterMonday),
+        "fetedutravail" => Some(Holiday::LabourDay),
+        "fetedelavictoire" => Some(Holiday::VictoryInEuropeDay),
+        "ascension" => Some(Holiday::Ascension),
+        "pentecote" => Some(Holiday::Pentecost),
+        "lundidepentecote" => Some(Holiday::WhitMonday),
+        "fetedieu" => Some(Holiday::CorpusChristi),
+        "fetenationale" => Some(Holiday::BastilleDay),
+        "assomption" => Some(Holiday::Assumption),
+        "toussaint" => Some(Holiday::AllSaints),
+        "armistice" => Some(Holiday::ArmisticeDay),
+        "reveillondenoel" | "veilledenoel" => Some(Holiday::ChristmasEve),
+        "noel" | "jourdenoel" => Some(Holiday::ChristmasDay),
+        "saintsylvestre" | "reveillondunouvelan" => Some(Holiday::NewYearsEve),
+        _ => None,
+    }
+}
+
+/// Resolve a holiday from captures to midnight of that day.
+fn resolve_holiday_caps(
+    caps: &regex::Captures,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<DateTime<Utc>> {
+    let year = match caps.name("hyear") {
+        Some(y) => Some(y.as_str().parse::<i32>().ok()?),
+        None => None,
+    };
+    if let Some(name) = caps.name("custom") {
+        return holiday::resolve_custom_holiday(name.as_str(), year, now, tz, config);
+    }
+    let holiday = parse_holiday(caps.name("hol")?.as_str())?;
+    holiday::resolve_holiday(holiday, year, now, tz, config)
+}
+
+/// Ordinal weekday of a month: "le premier lundi du mois", "le dernier
+/// vendredi de mars", "le 2e mardi du mois prochain" (groups `ord`, `owd`,
+/// `omonth`, `oyear`, `mdir`).
+fn weekday_of_month_pattern() -> String {
+    format!(
+        r"(?:le\s+)?(?P<ord>premier|1er|deuxi[eè]me|second|troisi[eè]me|quatri[eè]me|cinqui[eè]me|dernier|[2-5](?:e|[eè]me))\s+(?P<owd>{WEEKDAY_PAT})\s+(?:(?:du\s+mois\s+)?d(?:e\s+|['\u{{2019}}])(?P<omonth>{MONTH_PAT})\b\.?(?:\s+(?P<oyear>\d{{4}})\b)?|(?:du|de\s+ce)\s+mois(?:\s+(?P<mdir>prochain|dernier))?\b)"
+    )
+}
+
+fn parse_week_ordinal(s: &str) -> Option<i32> {
+    match s.to_lowercase().as_str() {
+        "dernier" => Some(-1),
+        lower => i32::try_from(parse_ordinal_fr(lower)?).ok(),
+    }
+}
+
+/// Resolve an ordinal weekday of a month from captures to midnight of that day.
+fn resolve_weekday_of_month_caps(
+    caps: &regex::Captures,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<DateTime<Utc>> {
+    let n = parse_week_ordinal(caps.name("ord")?.as_str())?;
+    let weekday = parse_weekday(caps.name("owd")?.as_str())?;
+    let Some(month) = caps.name("omonth") else {
+        let offset = match caps.name("mdir").map(|d| d.as_str().to_lowercase()) {
+            Some(d) if d == "prochain" => 1,
+            Some(_) => -1,
+            None => 0,
+        };
+        return resolve::resolve_weekday_of_relative_month(n, weekday, offset, now, tz);
+    };
+    let month = parse_month(month.as_str())?;
+    let year = match caps.name("oyear") {
+        Some(y) => Some(y.as_str().parse::<i32>().ok()?),
+        None => None,
+    };
+    resolve::resolve_weekday_of_month(n, weekday, month, year, now, tz, config.year_bias)
+}
+
+/// List of weekdays, plural allowed: "lundi", "lundis et jeudis",
+/// "lundi, mercredi et vendredi".
+fn weekday_list_pattern() -> String {
+    format!(r"(?:{WEEKDAY_PAT})s?(?:(?:\s*,\s*|\s+et\s+)(?:les\s+)?(?:{WEEKDAY_PAT})s?)*")
+}
+
+/// Parse every weekday named in a list matched by [`weekday_list_pattern`].
+fn parse_weekday_list(s: &str) -> Vec<chrono::Weekday> {
+    s.split(|c: char| !c.is_alphabetic())
+        .filter_map(|w| parse_weekday(w.trim_end_matches(['s', 'S'])))
+        .collect()
+}
+
+/// Recurring schedule without its time: "tous les lundis", "chaque semaine",
+/// "toutes les deux semaines", "un lundi sur deux", "quotidiennement"
+/// (groups `rn`, `runit`, `rwds`, `rsunit`, `rswd`, `rsur`, `radv`).
+fn recurrence_pattern() -> String {
+    let list = weekday_list_pattern();
+    format!(
+        r"(?:(?:tou(?:s|tes)\s+les|chaque)\s+(?:(?P<rn>{NUM_WORD_PATTERN})\s+)?(?:(?P<runit>minutes?|heures?|jours?|semaines?|mois|trimestres?|ans|ann[ée]es?)|(?P<rwds>{list}))|une?\s+(?:(?P<rsunit>jour|semaine|mois|an|ann[ée]e)|(?P<rswd>{WEEKDAY_PAT}))\s+sur\s+(?P<rsur>{NUM_WORD_PATTERN})|(?P<radv>quotidiennement|hebdomadairement|mensuellement|annuellement))\b"
+    )
+}
+
+/// Resolve a recurring schedule from captures produced by
+/// [`recurrence_pattern`], with an optional time of day in the `à` groups,
+/// `rhour` ("à 10"), or `nm` ("à midi").
+fn resolve_recurrence_caps(caps: &regex::Captures) -> Option<ResolvedTime> {
+    let (frequency, weekdays) = if let Some(adv) = caps.name("radv") {
+        let unit = match adv.as_str().to_lowercase().as_str() {
+            "quotidiennement" => TimeUnit::Day,
+            "hebdomadairement" => TimeUnit::Week,
+            "mensuellement" => TimeUnit::Month,
+            _ => TimeUnit::Year,
+        };
+        (unit, Vec::new())
+    } else if let Some(unit) = caps.name("runit").or(caps.name("rsunit")) {
+        (parse_unit(unit.as_str())?, Vec::new())
+    } else {
+        let list = caps.name("rwds").or(caps.name("rswd"))?;
+        (TimeUnit::Week, parse_weekday_list(list.as_str()))
+    };
+    let interval = match caps.name("rn").or(caps.name("rsur")) {
+        Some(n) => parse_num(n.as_str())?,
+        None => 1,
+    };
+    let time = if let Some(h) = caps.name("rhour") {
+        Some(NaiveTime::from_hms_opt(parse_num(h.as_str())?, 0, 0)?)
+    } else if let Some(nm) = caps.name("nm") {
+        if nm.as_str().eq_ignore_ascii_case("minuit") {
+            Some(NaiveTime::MIN)
+        } else {
+            NaiveTime::from_hms_opt(12, 0, 0)
+        }
+    } else if caps.name("hour").is_some() || caps.name("sp_hour").is_some() {
+        Some(parse_time(caps)?)
+    } else {
+        None
+    };
+    resolve::resolve_recurrence(frequency, interval, weekdays, time)
+}
+
+/// Part-of-day words: "matin(ée)", "après-midi", "soir(ée)", "nuit".
+const DAY_PART_PAT: &str = r"matin(?:[ée]e)?|apr[eè]s-midi|soir(?:[ée]e)?|nuit";
+
+fn parse_day_part(s: &str) -> Option<DayPart> {
+    let lower = s.to_lowercase();
+    if lower.starts_with("matin") {
+        Some(DayPart::Morning)
+    } else if lower.starts_with("apr") {
+        Some(DayPart::Afternoon)
+    } else if lower.starts_with("soir") {
+        Some(DayPart::Evening)
+    } else if lower == "nuit" {
+        Some(DayPart::Night)
+    } else {
+        None
+    }
+}
+
+/// Noon and midnight words: "midi", "minuit".
+const NOON_MIDNIGHT_PAT: &str = r"midi|minuit";
+
+/// Resolve the noon or midnight word in the `nm` group on the day starting at `date`.
+fn resolve_noon_midnight(
+    caps: &regex::Captures,
+    date: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<ResolvedTime> {
+    if caps.name("nm")?.as_str().eq_ignore_ascii_case("minuit") {
+        resolve::resolve_midnight(date, config.midnight, tz)
+    } else {
+        resolve::resolve_time_on_date(date, 12, 0, tz)
+    }
+}
+
+/// Resolve a weekday named without prochain/dernier/ce to midnight of the day
+/// picked by [`ParserConfig::weekday_bias`].
+fn resolve_bare_weekday(
+    day: &str,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<DateTime<Utc>> {
+    let weekday = parse_weekday(day)?;
+    resolve::resolve_weekday_date_with_bias(weekday, now, tz, config.weekday_bias)
+}
+
+/// An hour range with "de … à" (groups `from`, `fu`, `fm`, `to`, `tu`, `tm`):
+/// "de trois à cinq heures", "de 9h à 11h30", "de 22h à 2h".
+fn hour_range_pattern() -> String {
+    format!(
+        r"de\s+(?P<from>{HOUR_PAT})(?P<fu>\s*h(?P<fm>\d{{2}})?|\s+heures?)?\s+[àa]\s+(?P<to>{HOUR_PAT})(?P<tu>\s*h(?P<tm>\d{{2}})?|\s+heures?)?\b"
+    )
+}
+
+/// Parse the ends of a range matched by [`hour_range_pattern`].
+///
+/// An "h" or "heures" on one end at least tells the hours from other
+/// quantities ("de 2 à 3 enfants").
+fn parse_hour_range(caps: &regex::Captures) -> Option<(NaiveTime, NaiveTime)> {
+    if caps.name("fu").is_none() && caps.name("tu").is_none() {
+        return None;
+    }
+    let hm = |hour: &str, min: &str| {
+        let h = parse_num(caps.name(hour)?.as_str())?;
+        let m = caps
+            .name(min)
+            .map_or(Some(0), |m| m.as_str().parse().ok())?;
+        Some((h, m))
+    };
+    clock::range_ends(hm("from", "fm")?, hm("to", "tm")?)
+}
+
+/// Parse a HH:MM[:SS]–HH:MM[:SS] range from captures with groups `fh`, `fm`,
+/// `fsec`, `fsubsec`, `th`, `tm`, `tsec`, `tsubsec`.
+fn parse_time_range(caps: &regex::Captures) -> Option<(NaiveTime, NaiveTime)> {
     let fh = caps.name("fh")?.as_str().parse::<u32>().ok()?;
     let fm = caps.name("fm")?.as_str().parse::<u32>().ok()?;
     let th = caps.name("th")?.as_str().parse::<u32>().ok()?;
     let tm = caps.name("tm")?.as_str().parse::<u32>().ok()?;
-    if fh > 23 || fm > 59 || th > 23 || tm > 59 {
-        return None;
-    }
-    Some((fh, fm, th, tm))
+    let from = clock::time_of_day(caps, fh, fm, "fsec", "fsubsec")?;
+    let to = clock::time_of_day(caps, th, tm, "tsec", "tsubsec")?;
+    Some((from, to))
+}
+
+/// Parse a time of day with optional `:SS` seconds from captures (24h format).
+fn parse_time(caps: &regex::Captures) -> Option<NaiveTime> {
+    let (h, m) = parse_hm(caps)?;
+    clock::time_of_day(caps, h, m, "sec", "subsec")
 }
 
 /// Parse hour and optional minutes from captures (24h format).
 /// Supports both `Xh30` / `X:30` (colon/h with digits) and bare `Xh` forms.
 fn parse_hm(caps: &regex::Captures) -> Option<(u32, u32)> {
-    let h = caps.name("hour")?.as_str().parse::<u32>().ok()?;
+    if caps.name("hour").is_none() {
+        return parse_spoken(caps);
+    }
+    let h = parse_num(caps.name("hour")?.as_str())?;
     let m = caps
         .name("min")
         .and_then(|m| m.as_str().parse::<u32>().ok())
@@ -126,9 +615,204 @@ fn parse_hm(caps: &regex::Captures) -> Option<(u32, u32)> {
     Some((h, m))
 }
 
-fn build_rules() -> Vec<GrammarRule> {
+/// Parse a spoken clock reading from captures produced by [`spoken_time_pattern`].
+fn parse_spoken(caps: &regex::Captures) -> Option<(u32, u32)> {
+    let hour = parse_num(caps.name("sp_hour")?.as_str())?;
+    let minutes = match caps.name("sp_min")?.as_str().to_lowercase().as_str() {
+        "quart" => 15,
+        "demi" | "demie" => 30,
+        words => words
+            .split('-')
+            .filter(|w| *w != "et")
+            .map(parse_num)
+            .sum::<Option<u32>>()?,
+    };
+    if minutes > 59 {
+        return None;
+    }
+    let minutes = minutes as i32;
+    let offset = match caps.name("sp_rel").map(|r| r.as_str().to_lowercase()) {
+        Some(rel) if rel == "moins" => -minutes,
+        _ => minutes,
+    };
+    clock::reading(hour, offset)
+}
+
+/// Parse a time unit word (singular or plural).
+fn parse_unit(s: &str) -> Option<TimeUnit> {
+    match s.to_lowercase().as_str() {
+        "seconde" | "secondes" | "sec" => Some(TimeUnit::Second),
+        "minute" | "minutes" | "min" => Some(TimeUnit::Minute),
+        "heure" | "heures" => Some(TimeUnit::Hour),
+        "jour" | "jours" | "journée" | "journee" => Some(TimeUnit::Day),
+        "semaine" | "semaines" => Some(TimeUnit::Week),
+        "mois" => Some(TimeUnit::Month),
+        "trimestre" | "trimestres" => Some(TimeUnit::Quarter),
+        "an" | "ans" | "année" | "années" | "annee" | "annees" => Some(TimeUnit::Year),
+        _ => None,
+    }
+}
+
+/// Resolve a trailing period direction ("prochain", "dernière") to -1 or 1
+fn period_direction(s: &str) -> Option<i64> {
+    match s.to_lowercase().as_str() {
+        "dernier" | "dernière" | "derniere" | "passé" | "passée" | "passe" | "passee" => {
+            Some(-1)
+        }
+        "prochain" | "prochaine" => Some(1),
+        _ => None,
+    }
+}
+
+fn parse_boundary(s: &str) -> Option<Boundary> {
+    match s.to_lowercase().as_str() {
+        "début" | "debut" => Some(Boundary::Start),
+        "milieu" | "mi" => Some(Boundary::Middle),
+        "fin" => Some(Boundary::End),
+        _ => None,
+    }
+}
+
+/// Shared sub-day unit pattern
+const SUBDAY_UNIT_PAT: &str = r"secondes?|minutes?|heures?";
+
+/// Shared day-or-longer unit pattern
+const DAY_UNIT_PAT: &str = r"jours?|semaines?|mois|ans?|ann[ée]es?";
+
+/// Parse a fraction word used in durations.
+fn parse_fraction(s: &str) -> Option<f64> {
+    match s.to_lowercase().as_str() {
+        "demie" | "demi" => Some(0.5),
+        "quart" | "quarts" => Some(0.25),
+        _ => None,
+    }
+}
+
+/// Parse a duration amount: a decimal number or a number word.
+fn parse_amount(s: &str) -> Option<f64> {
+    duration::parse_decimal(s).or_else(|| parse_num(s).map(f64::from))
+}
+
+/// Resolve a spelled-out duration from captures with group `unit` and any of
+/// `num`, `three`, `frac` (a fraction before the unit, or on its own) and
+/// `frac_post` (a fraction after the unit).
+///
+/// A trailing fraction without an amount counts as one unit plus the fraction.
+fn resolve_spelled_duration(caps: &regex::Captures) -> Option<ResolvedTime> {
+    let frac_post = match caps.name("frac_post") {
+        Some(f) => Some(parse_fraction(f.as_str())?),
+        None => None,
+    };
+    let mut amount = match caps.name("num") {
+        Some(n) => parse_amount(n.as_str())?,
+        None if frac_post.is_some() => 1.0,
+        None => 0.0,
+    };
+    if let Some(f) = caps.name("frac") {
+        let times = if caps.name("three").is_some() {
+            3.0
+        } else {
+            1.0
+        };
+        amount += times * parse_fraction(f.as_str())?;
+    }
+    amount += frac_post.unwrap_or(0.0);
+    if amount <= 0.0 {
+        return None;
+    }
+    let unit = parse_unit(caps.name("unit")?.as_str())?;
+    resolve::resolve_duration(unit, amount)
+}
+
+/// Parse a duration from captures produced by the spelled-out or compact
+/// duration pattern.
+fn parse_duration(caps: &regex::Captures) -> Option<chrono::Duration> {
+    let resolved = if caps.name("unit").is_some() {
+        resolve_spelled_duration(caps)?
+    } else {
+        duration::parse_compact(caps)?
+    };
+    match resolved {
+        ResolvedTime::Duration(d) => Some(d),
+        _ => None,
+    }
+}
+
+/// Resolve the day of a duration-anchored range: a relative day (`day`),
+/// a calendar date (`date`), or today.
+fn resolve_anchor_day(
+    caps: &regex::Captures,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> Option<DateTime<Utc>> {
+    if let Some(day) = caps.name("day") {
+        return resolve::resolve_day_offset(day_keyword_offset(day.as_str())?, now, tz);
+    }
+    if caps.name("date").is_some() {
+        return resolve_date_caps(caps, now, tz, config);
+    }
+    resolve::resolve_day_offset(0, now, tz)
+}
+
+/// Open-range prepositions: "depuis", "à partir de", "jusqu'à", "avant", "après".
+/// "jusqu'" may be followed directly by an anchor starting with "à".
+const OPEN_RANGE_PAT: &str = r"(?:depuis|dès|après|apres|avant)\s+|[àa]\s+partir\s+(?:de\s+|du\s+|d['\u{2019}])|jusqu['\u{2019}](?:(?:à|a|au|aux)\s+)?";
+
+fn open_bound(s: &str) -> Option<Bound> {
+    let s = s.to_lowercase();
+    if s.starts_with("jusqu") {
+        return Some(Bound::Until);
+    }
+    match s.split_whitespace().next()? {
+        "depuis" => Some(Bound::Since),
+        "dès" | "à" | "a" => Some(Bound::From),
+        "après" | "apres" => Some(Bound::After),
+        "avant" => Some(Bound::Before),
+        _ => None,
+    }
+}
+
+/// Offsets from an anchor: "deux jours avant", "une semaine après le"
+fn offset_pattern() -> String {
+    format!(
+        r"(?P<num>{NUM_WORD_PATTERN})\s+(?P<unit>{SUBDAY_UNIT_PAT}|{DAY_UNIT_PAT})\s+(?P<op>avant|apr[eè]s)\s+(?:(?:le|la)\s+|l['\u{{2019}}]\s*)?"
+    )
+}
+
+fn anchor_offset(caps: &regex::Captures) -> Option<(TimeUnit, i64)> {
+    let amount = i64::from(parse_num(caps.name("num")?.as_str())?);
+    let unit = parse_unit(caps.name("unit")?.as_str())?;
+    if caps.name("op")?.as_str().eq_ignore_ascii_case("avant") {
+        Some((unit, -amount))
+    } else {
+        Some((unit, amount))
+    }
+}
+
+fn build_rules(custom_holidays: &[&str]) -> Vec<GrammarRule> {
     let num = NUM_WORD_PATTERN;
+    let subday = SUBDAY_UNIT_PAT;
+    let days = DAY_UNIT_PAT;
+    let hour_range = hour_range_pattern();
     let wd = WEEKDAY_PAT;
+    let rday = RELATIVE_DAY_PAT;
+    let spoken = spoken_time_pattern();
+    let at_time = at_time_pattern();
+    let at = format!(r"(?:à\s+{spoken}|{at_time})");
+    let hm_range = hm_range_pattern();
+    let date = date_pattern();
+    let spelled_dur = format!(
+        r"(?P<num>\d+[.,]\d+|{num})\s+(?P<unit>{subday}|min|sec)\b(?:\s+et\s+(?P<frac_post>demie|quart)\b)?"
+    );
+    let dur = format!(r"(?:{spelled_dur}|{COMPACT_DURATION_PAT})");
+    let start = start_time_pattern();
+    let part = DAY_PART_PAT;
+    let nm = NOON_MIDNIGHT_PAT;
+    let wom = weekday_of_month_pattern();
+    let mday = DAY_OF_MONTH_PAT;
+    let hol = holiday_pattern(custom_holidays);
+    let recur = recurrence_pattern();
 
     vec![
         // ============================================================
@@ -136,21 +820,20 @@ fn build_rules() -> Vec<GrammarRule> {
         //  "vendredi dernier à 13h30", "vendredi dernier à 13:30", "vendredi dernier à 13h"
         // ============================================================
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\b(?:le\s+)?(?P<day>{wd})\s+(?P<dir>prochain|dernier)\s+[àa]\s+(?P<hour>\d{{1,2}})(?:[h:](?P<min>\d{{2}})|\s*h)\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:le\s+)?(?P<day>{wd})\s+(?P<dir>prochain|dernier)\s+{at}"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, _| {
                 let direction = match caps.name("dir")?.as_str().to_lowercase().as_str() {
                     "prochain" => 1,
                     "dernier" => -1,
                     _ => return None,
                 };
                 let weekday = parse_weekday(caps.name("day")?.as_str())?;
-                let (h, m) = parse_hm(caps)?;
+                let time = parse_time(caps)?;
                 let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
-                resolve::resolve_time_on_date(date, h, m, tz)
+                resolve::resolve_clock_time_on_date(date, time, tz)
             },
         },
         // ============================================================
@@ -158,21 +841,20 @@ fn build_rules() -> Vec<GrammarRule> {
         //  "vendredi dernier de 10:15 à 13:45", "vendredi dernier de 9:00 - 11:30"
         // ============================================================
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\b(?:le\s+)?(?P<day>{wd})\s+(?P<dir>prochain|dernier)\s+de\s+(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}})\s*(?:[àa]|-)\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}})\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:le\s+)?(?P<day>{wd})\s+(?P<dir>prochain|dernier)\s+de\s+(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}}){FROM_SECONDS_PAT}\s*(?:[àa]|-)\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}}){TO_SECONDS_PAT}\b"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let direction = match caps.name("dir")?.as_str().to_lowercase().as_str() {
                     "prochain" => 1,
                     "dernier" => -1,
                     _ => return None,
                 };
                 let weekday = parse_weekday(caps.name("day")?.as_str())?;
-                let (fh, fm, th, tm) = parse_hm_range(caps)?;
+                let (from, to) = parse_time_range(caps)?;
                 let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
-                resolve::resolve_time_range_with_minutes_on_date(date, fh, fm, th, tm, tz)
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
             },
         },
         // ============================================================
@@ -180,21 +862,20 @@ fn build_rules() -> Vec<GrammarRule> {
         //  "vendredi dernier 9:00 - 11:30"
         // ============================================================
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\b(?:le\s+)?(?P<day>{wd})\s+(?P<dir>prochain|dernier)\s+(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}})\s*-\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}})\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:le\s+)?(?P<day>{wd})\s+(?P<dir>prochain|dernier)\s+(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}}){FROM_SECONDS_PAT}\s*-\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}}){TO_SECONDS_PAT}\b"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let direction = match caps.name("dir")?.as_str().to_lowercase().as_str() {
                     "prochain" => 1,
                     "dernier" => -1,
                     _ => return None,
                 };
                 let weekday = parse_weekday(caps.name("day")?.as_str())?;
-                let (fh, fm, th, tm) = parse_hm_range(caps)?;
+                let (from, to) = parse_time_range(caps)?;
                 let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
-                resolve::resolve_time_range_with_minutes_on_date(date, fh, fm, th, tm, tz)
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
             },
         },
         // ============================================================
@@ -202,181 +883,499 @@ fn build_rules() -> Vec<GrammarRule> {
         //  "vendredi dernier entre 9 et 12 heures"
         // ============================================================
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\b(?:le\s+)?(?P<day>{wd})\s+(?P<dir>prochain|dernier)\s+entre\s+(?P<from>\d{{1,2}})\s+et\s+(?P<to>\d{{1,2}})\s*(?:heures?)?\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:le\s+)?(?P<day>{wd})\s+(?P<dir>prochain|dernier)\s+entre\s+(?P<from>{HOUR_PAT})\s+et\s+(?P<to>{HOUR_PAT})\s*(?:heures?)?\b"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let direction = match caps.name("dir")?.as_str().to_lowercase().as_str() {
                     "prochain" => 1,
                     "dernier" => -1,
                     _ => return None,
                 };
                 let weekday = parse_weekday(caps.name("day")?.as_str())?;
-                let from = caps.name("from")?.as_str().parse::<u32>().ok()?;
-                let to = caps.name("to")?.as_str().parse::<u32>().ok()?;
-                if from > 23 || to > 23 { return None; }
+                let from = parse_num(caps.name("from")?.as_str())?;
+                let to = parse_num(caps.name("to")?.as_str())?;
+                if from > 23 || to > 23 {
+                    return None;
+                }
                 let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
-                resolve::resolve_time_range_on_date(date, from, to, tz)
+                let (from, to) = clock::range_ends((from, 0), (to, 0))?;
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
             },
         },
         // ============================================================
         //  Combined: "ce lundi à 13h30" / "ce lundi à 13:30" / "ce lundi à 13h"
         // ============================================================
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\bce\s+(?P<day>{wd})\s+[àa]\s+(?P<hour>\d{{1,2}})(?:[h:](?P<min>\d{{2}})|\s*h)\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(r"(?i)\bce\s+(?P<day>{wd})\s+{at}")),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, _| {
                 let weekday = parse_weekday(caps.name("day")?.as_str())?;
-                let (h, m) = parse_hm(caps)?;
+                let time = parse_time(caps)?;
                 let date = resolve::resolve_weekday_date(weekday, 0, now, tz)?;
-                resolve::resolve_time_on_date(date, h, m, tz)
+                resolve::resolve_clock_time_on_date(date, time, tz)
+            },
+        },
+        // ============================================================
+        //  Combined: "vendredi à 15h", "lundi à 9h30"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b(?P<day>{wd}),?\s+{at}")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps)?;
+                let date = resolve_bare_weekday(caps.name("day")?.as_str(), now, tz, config)?;
+                resolve::resolve_clock_time_on_date(date, time, tz)
             },
         },
         // ============================================================
         //  Combined: "ce lundi de 10:15 à 13:45", "ce lundi de 9:00 - 11:30"
         // ============================================================
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\bce\s+(?P<day>{wd})\s+de\s+(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}})\s*(?:[àa]|-)\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}})\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\bce\s+(?P<day>{wd})\s+de\s+(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}}){FROM_SECONDS_PAT}\s*(?:[àa]|-)\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}}){TO_SECONDS_PAT}\b"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let weekday = parse_weekday(caps.name("day")?.as_str())?;
-                let (fh, fm, th, tm) = parse_hm_range(caps)?;
+                let (from, to) = parse_time_range(caps)?;
                 let date = resolve::resolve_weekday_date(weekday, 0, now, tz)?;
-                resolve::resolve_time_range_with_minutes_on_date(date, fh, fm, th, tm, tz)
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
             },
         },
         // ============================================================
         //  Combined: "ce lundi entre 9 et 12 heures"
         // ============================================================
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\bce\s+(?P<day>{wd})\s+entre\s+(?P<from>\d{{1,2}})\s+et\s+(?P<to>\d{{1,2}})\s*(?:heures?)?\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\bce\s+(?P<day>{wd})\s+entre\s+(?P<from>{HOUR_PAT})\s+et\s+(?P<to>{HOUR_PAT})\s*(?:heures?)?\b"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let weekday = parse_weekday(caps.name("day")?.as_str())?;
-                let from = caps.name("from")?.as_str().parse::<u32>().ok()?;
-                let to = caps.name("to")?.as_str().parse::<u32>().ok()?;
-                if from > 23 || to > 23 { return None; }
+                let from = parse_num(caps.name("from")?.as_str())?;
+                let to = parse_num(caps.name("to")?.as_str())?;
+                if from > 23 || to > 23 {
+                    return None;
+                }
                 let date = resolve::resolve_weekday_date(weekday, 0, now, tz)?;
-                resolve::resolve_time_range_on_date(date, from, to, tz)
+                let (from, to) = clock::range_ends((from, 0), (to, 0))?;
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
             },
         },
         // --- Combined: "hier à 13h30" / "hier à 13:30" / "hier à 13h" ---
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\b(?P<day>aujourd['\u{2019}]hui|demain|hier)\s+[àa]\s+(?P<hour>\d{1,2})(?:[h:](?P<min>\d{2})|\s*h)\b",
-            )
-            .unwrap(),
+            pattern: RulePattern::new(format!(r"(?i)\b(?P<day>{rday})\s+{at}")),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, _| {
                 let offset = day_keyword_offset(caps.name("day")?.as_str())?;
-                let (h, m) = parse_hm(caps)?;
+                let time = parse_time(caps)?;
                 let date = resolve::resolve_day_offset(offset, now, tz)?;
-                resolve::resolve_time_on_date(date, h, m, tz)
+                resolve::resolve_clock_time_on_date(date, time, tz)
             },
         },
         // --- Combined: "hier de 10:15 à 13:45", "hier de 9:00 - 11:30" ---
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\b(?P<day>aujourd['\u{2019}]hui|demain|hier)\s+de\s+(?P<fh>\d{1,2}):(?P<fm>\d{2})\s*(?:[àa]|-)\s*(?P<th>\d{1,2}):(?P<tm>\d{2})\b",
-            )
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?P<day>{rday})\s+de\s+(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}}){FROM_SECONDS_PAT}\s*(?:[àa]|-)\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}}){TO_SECONDS_PAT}\b"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let offset = day_keyword_offset(caps.name("day")?.as_str())?;
+                let (from, to) = parse_time_range(caps)?;
+                let date = resolve::resolve_day_offset(offset, now, tz)?;
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
+            },
+        },
+        // --- Combined: "demain de 9h à 11h", "après-demain de trois à cinq heures" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b(?P<day>{rday})\s+{hour_range}")),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let offset = day_keyword_offset(caps.name("day")?.as_str())?;
-                let (fh, fm, th, tm) = parse_hm_range(caps)?;
+                let (from, to) = parse_hour_range(caps)?;
                 let date = resolve::resolve_day_offset(offset, now, tz)?;
-                resolve::resolve_time_range_with_minutes_on_date(date, fh, fm, th, tm, tz)
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
             },
         },
         // --- Combined: "hier 10:15 - 13:45" (day + bare dash) ---
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\b(?P<day>aujourd['\u{2019}]hui|demain|hier)\s+(?P<fh>\d{1,2}):(?P<fm>\d{2})\s*-\s*(?P<th>\d{1,2}):(?P<tm>\d{2})\b",
-            )
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?P<day>{rday})\s+(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}}){FROM_SECONDS_PAT}\s*-\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}}){TO_SECONDS_PAT}\b"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let offset = day_keyword_offset(caps.name("day")?.as_str())?;
-                let (fh, fm, th, tm) = parse_hm_range(caps)?;
+                let (from, to) = parse_time_range(caps)?;
                 let date = resolve::resolve_day_offset(offset, now, tz)?;
-                resolve::resolve_time_range_with_minutes_on_date(date, fh, fm, th, tm, tz)
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
             },
         },
         // --- Combined: "hier entre 9 et 12 heures" ---
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\b(?P<day>aujourd['\u{2019}]hui|demain|hier)\s+entre\s+(?P<from>\d{1,2})\s+et\s+(?P<to>\d{1,2})\s*(?:heures?)?\b",
-            )
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?P<day>{rday})\s+entre\s+(?P<from>{HOUR_PAT})\s+et\s+(?P<to>{HOUR_PAT})\s*(?:heures?)?\b"
+            )),
             kind: ExpressionKind::Combined,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, config| {
                 let offset = day_keyword_offset(caps.name("day")?.as_str())?;
-                let from = caps.name("from")?.as_str().parse::<u32>().ok()?;
-                let to = caps.name("to")?.as_str().parse::<u32>().ok()?;
-                if from > 23 || to > 23 { return None; }
+                let from = parse_num(caps.name("from")?.as_str())?;
+                let to = parse_num(caps.name("to")?.as_str())?;
+                if from > 23 || to > 23 {
+                    return None;
+                }
                 let date = resolve::resolve_day_offset(offset, now, tz)?;
-                resolve::resolve_time_range_on_date(date, from, to, tz)
+                let (from, to) = clock::range_ends((from, 0), (to, 0))?;
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
+            },
+        },
+        // ============================================================
+        //  Combined: Weekday (post-positive) + moment de la journée
+        //  "vendredi dernier au soir", "lundi soir prochain"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:le\s+)?(?P<day>{wd})\s+(?:(?P<dir>prochain|dernier)\s+(?:au\s+)?(?P<part>{part})|(?P<part_pre>{part})\s+(?P<dir_post>prochain|dernier))\b"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let direction = match caps
+                    .name("dir")
+                    .or(caps.name("dir_post"))?
+                    .as_str()
+                    .to_lowercase()
+                    .as_str()
+                {
+                    "prochain" => 1,
+                    "dernier" => -1,
+                    _ => return None,
+                };
+                let weekday = parse_weekday(caps.name("day")?.as_str())?;
+                let part = parse_day_part(caps.name("part").or(caps.name("part_pre"))?.as_str())?;
+                let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
+                resolve::resolve_day_part(date, part, &config.day_parts, tz)
+            },
+        },
+        // --- Combined: "ce lundi soir", "ce vendredi matin" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\bce\s+(?P<day>{wd})\s+(?P<part>{part})\b")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let weekday = parse_weekday(caps.name("day")?.as_str())?;
+                let part = parse_day_part(caps.name("part")?.as_str())?;
+                let date = resolve::resolve_weekday_date(weekday, 0, now, tz)?;
+                resolve::resolve_day_part(date, part, &config.day_parts, tz)
+            },
+        },
+        // --- Combined: "demain matin", "hier soir", "aujourd'hui après-midi" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?P<day>{rday})\s+(?:au\s+)?(?P<part>{part})\b"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let offset = day_keyword_offset(caps.name("day")?.as_str())?;
+                let part = parse_day_part(caps.name("part")?.as_str())?;
+                let date = resolve::resolve_day_offset(offset, now, tz)?;
+                resolve::resolve_day_part(date, part, &config.day_parts, tz)
+            },
+        },
+        // --- Part of day: "ce matin", "cet après-midi", "ce soir", "cette nuit" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b(?:ce|cet|cette)\s+(?P<part>{part})\b")),
+            kind: ExpressionKind::PartOfDay,
+            resolver: |caps, now, tz, config| {
+                let part = parse_day_part(caps.name("part")?.as_str())?;
+                let date = resolve::resolve_day_offset(0, now, tz)?;
+                resolve::resolve_day_part(date, part, &config.day_parts, tz)
+            },
+        },
+        // --- Part of day: "la nuit dernière" ---
+        GrammarRule {
+            pattern: RulePattern::new(r"(?i)\b(?:la\s+)?nuit\s+derni[eè]re\b"),
+            kind: ExpressionKind::PartOfDay,
+            resolver: |_, now, tz, config| {
+                let date = resolve::resolve_day_offset(-1, now, tz)?;
+                resolve::resolve_day_part(date, DayPart::Night, &config.day_parts, tz)
+            },
+        },
+        // ============================================================
+        //  Combined: Weekday (post-positive) + midi/minuit
+        //  "vendredi dernier à minuit", "lundi prochain à midi"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:le\s+)?(?P<day>{wd})\s+(?P<dir>prochain|dernier)\s+(?:à\s+)?(?P<nm>{nm})\b"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let direction = match caps.name("dir")?.as_str().to_lowercase().as_str() {
+                    "prochain" => 1,
+                    "dernier" => -1,
+                    _ => return None,
+                };
+                let weekday = parse_weekday(caps.name("day")?.as_str())?;
+                let date = resolve::resolve_weekday_date(weekday, direction, now, tz)?;
+                resolve_noon_midnight(caps, date, tz, config)
+            },
+        },
+        // --- Combined: "lundi soir", "vendredi matin" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b(?P<day>{wd})\s+(?P<part>{part})\b")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let part = parse_day_part(caps.name("part")?.as_str())?;
+                let date = resolve_bare_weekday(caps.name("day")?.as_str(), now, tz, config)?;
+                resolve::resolve_day_part(date, part, &config.day_parts, tz)
+            },
+        },
+        // --- Combined: "ce vendredi à minuit", "ce lundi midi" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\bce\s+(?P<day>{wd})\s+(?:à\s+)?(?P<nm>{nm})\b"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let weekday = parse_weekday(caps.name("day")?.as_str())?;
+                let date = resolve::resolve_weekday_date(weekday, 0, now, tz)?;
+                resolve_noon_midnight(caps, date, tz, config)
+            },
+        },
+        // --- Combined: "vendredi à minuit", "lundi midi" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b(?P<day>{wd})\s+(?:à\s+)?(?P<nm>{nm})\b")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let date = resolve_bare_weekday(caps.name("day")?.as_str(), now, tz, config)?;
+                resolve_noon_midnight(caps, date, tz, config)
+            },
+        },
+        // --- Combined: "demain à midi", "hier minuit" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b(?P<day>{rday})\s+(?:à\s+)?(?P<nm>{nm})\b")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let offset = day_keyword_offset(caps.name("day")?.as_str())?;
+                let date = resolve::resolve_day_offset(offset, now, tz)?;
+                resolve_noon_midnight(caps, date, tz, config)
+            },
+        },
+        // --- Midi/minuit aujourd'hui: "à midi", "à minuit", "vers midi" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)(?:^|\b)(?:à|(?P<approx>{APPROX_PAT}))\s+(?P<nm>{nm})\b"
+            )),
+            kind: ExpressionKind::TimeSpecification,
+            resolver: |caps, now, tz, config| {
+                let date = resolve::resolve_day_offset(0, now, tz)?;
+                resolve_noon_midnight(caps, date, tz, config)
             },
         },
         // --- Relative days ---
         GrammarRule {
-            pattern: Regex::new(r"(?i)\b(?P<day>aujourd['\u{2019}]hui|demain|hier)\b").unwrap(),
+            pattern: RulePattern::new(format!(r"(?i)\b(?P<day>{rday})\b")),
             kind: ExpressionKind::RelativeDay,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, _| {
                 let offset = day_keyword_offset(caps.name("day")?.as_str())?;
                 resolve::resolve_relative_day(offset, now, tz)
             },
         },
-        // --- Day offset: "il y a 3 jours" ---
+        // --- Time offset: "il y a 2 heures" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\bil\s+y\s+a\s+(?P<num>{num})\s+(?P<unit>{subday})\b"
+            )),
+            kind: ExpressionKind::RelativeTimeOffset,
+            resolver: |caps, now, tz, _| {
+                let n = parse_num(caps.name("num")?.as_str())? as i64;
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_offset(unit, -n, now, tz)
+            },
+        },
+        // --- Time offset: "dans 30 minutes" ---
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\bil\s+y\s+a\s+(?P<num>{num})\s+jours?\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\bdans\s+(?P<num>{num})\s+(?P<unit>{subday})\b"
+            )),
+            kind: ExpressionKind::RelativeTimeOffset,
+            resolver: |caps, now, tz, _| {
+                let n = parse_num(caps.name("num")?.as_str())? as i64;
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_offset(unit, n, now, tz)
+            },
+        },
+        // --- Day offset: "il y a 3 jours", "il y a un mois" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\bil\s+y\s+a\s+(?P<num>{num})\s+(?P<unit>{days})\b"
+            )),
             kind: ExpressionKind::RelativeDayOffset,
-            resolver: |caps, now, tz| {
-                let n = parse_num(caps.name("num")?.as_str())?;
-                resolve::resolve_relative_day(-(n as i64), now, tz)
+            resolver: |caps, now, tz, _| {
+                let n = parse_num(caps.name("num")?.as_str())? as i64;
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_offset(unit, -n, now, tz)
             },
         },
-        // --- Day offset: "dans 3 jours" ---
+        // --- Day offset: "dans 3 jours", "dans 3 semaines" ---
         GrammarRule {
-            pattern: Regex::new(&format!(
-                r"(?i)\bdans\s+(?P<num>{num})\s+jours?\b"
-            ))
-            .unwrap(),
+            pattern: RulePattern::new(format!(r"(?i)\bdans\s+(?P<num>{num})\s+(?P<unit>{days})\b")),
             kind: ExpressionKind::RelativeDayOffset,
-            resolver: |caps, now, tz| {
-                let n = parse_num(caps.name("num")?.as_str())?;
-                resolve::resolve_relative_day(n as i64, now, tz)
+            resolver: |caps, now, tz, _| {
+                let n = parse_num(caps.name("num")?.as_str())? as i64;
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_offset(unit, n, now, tz)
             },
         },
+        // ============================================================
+        //  Combined: jour + début + durée: "demain à 14h pour 3 heures"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:(?P<day>{rday})|(?P<date>{date})),?\s+{start}\s+(?:pour|pendant|durant)\s+{dur}"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps)?;
+                let date = resolve_anchor_day(caps, now, tz, config)?;
+                resolve::resolve_time_for_duration(date, time, parse_duration(caps)?, tz)
+            },
+        },
+        // ============================================================
+        //  Time range: début + durée: "de 9h pendant 2 heures"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{start}\s+(?:pour|pendant|durant)\s+{dur}")),
+            kind: ExpressionKind::TimeRange,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps)?;
+                let date = resolve_anchor_day(caps, now, tz, config)?;
+                resolve::resolve_time_for_duration(date, time, parse_duration(caps)?, tz)
+            },
+        },
+        // ============================================================
+        //  Time range: durée + fin: "2 heures jusqu'à 17h"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b{dur}\s+jusqu['\u{{2019}}]\s*[àa]\s+(?P<hour>{HOUR_PAT})(?:[h:](?P<min>\d{{2}}){SECONDS_PAT}|\s*h\b|\s+heures?\b)?"
+            )),
+            kind: ExpressionKind::TimeRange,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps)?;
+                let date = resolve_anchor_day(caps, now, tz, config)?;
+                resolve::resolve_duration_until_time(date, time, parse_duration(caps)?, tz)
+            },
+        },
+        // --- Time spec, parlée sans "à": "trois heures et quart", "15 heures 30",
+        //     "cinq heures moins le quart". Comes before the durations so that
+        //     the clock reading wins; "pendant deux heures et quart" and
+        //     "une heure et demie" stay durations ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b(?:(?P<cue>pendant|durant|en)\s+)?{spoken}")),
+            kind: ExpressionKind::TimeSpecification,
+            resolver: |caps, now, tz, _| {
+                let counts_back = caps
+                    .name("sp_rel")
+                    .is_some_and(|r| r.as_str().eq_ignore_ascii_case("moins"));
+                if caps.name("cue").is_some()
+                    || (!counts_back && parse_num(caps.name("sp_hour")?.as_str())? == 1)
+                {
+                    return None;
+                }
+                let (h, m) = parse_spoken(caps)?;
+                resolve::resolve_time_today(h, m, now, tz)
+            },
+        },
+        // --- Duration: "45 minutes", "1,5 heure", "une heure et demie",
+        //     "pendant deux heures et quart" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{spelled_dur}")),
+            kind: ExpressionKind::Duration,
+            resolver: |caps, _, _, _| resolve_spelled_duration(caps),
+        },
+        // --- Duration: "une demi-heure", "un quart d'heure", "trois quarts d'heure" ---
+        GrammarRule {
+            pattern: RulePattern::new(
+                r"(?i)\b(?:(?P<three>trois)\s+|une?\s+)?(?:(?P<frac>demi)-?\s*|(?P<quarter>quarts?)\s+d['\u{2019}]\s*)(?P<unit>heure|minute)\b",
+            ),
+            kind: ExpressionKind::Duration,
+            resolver: |caps, _, _, _| {
+                // "quarter" forms carry the fraction in a separate group
+                let frac = match (caps.name("frac"), caps.name("quarter")) {
+                    (Some(_), _) => 0.5,
+                    (None, Some(_)) => 0.25,
+                    (None, None) => return None,
+                };
+                let times = if caps.name("three").is_some() {
+                    3.0
+                } else {
+                    1.0
+                };
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_duration(unit, times * frac)
+            },
+        },
+        // --- Duration: "1 heure 30", "pendant 2 heures 30", "1 heure et 15 minutes" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?P<h>{num})\s+heures?(?:\s+et)?\s+(?P<m>{num})(?:\s+minutes?)?\b"
+            )),
+            kind: ExpressionKind::Duration,
+            resolver: |caps, _, _, _| {
+                let h = parse_num(caps.name("h")?.as_str())?;
+                let m = parse_num(caps.name("m")?.as_str())?;
+                duration::hours_minutes(h, m)
+            },
+        },
+        // --- Time spec, compacte: "14h", "15h30", "9h00:30". Comes before the
+        //     compact durations: "2h" is only a duration after "pendant",
+        //     "durant" or "en" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:(?P<cue>pendant|durant|en)\s+)?(?P<hour>\d{{1,2}})\s*h(?:(?P<min>\d{{2}}){SECONDS_PAT})?\b"
+            )),
+            kind: ExpressionKind::TimeSpecification,
+            resolver: |caps, now, tz, _| {
+                if caps.name("cue").is_some() {
+                    return None;
+                }
+                let time = parse_time(caps)?;
+                resolve::resolve_clock_time_today(time, now, tz)
+            },
+        },
+        // --- Duration, compact: "pendant 2h", "90min", "en 2h30", "1:30h" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{COMPACT_DURATION_PAT}")),
+            kind: ExpressionKind::Duration,
+            resolver: |caps, _, _, _| duration::parse_compact(caps),
+        },
         // --- Time spec: "à 13h30" / "à 13:30" / "à 13h" ---
         GrammarRule {
-            pattern: Regex::new(r"(?i)(?:^|\b)[àa]\s+(?P<hour>\d{1,2})(?:[h:](?P<min>\d{2})|\s*h)\b").unwrap(),
+            pattern: RulePattern::new(format!(r"(?i)(?:^|\b){at_time}")),
             kind: ExpressionKind::TimeSpecification,
-            resolver: |caps, now, tz| {
-                let (h, m) = parse_hm(caps)?;
+            resolver: |caps, now, tz, _| {
+                let time = parse_time(caps)?;
+                resolve::resolve_clock_time_today(time, now, tz)
+            },
+        },
+        // --- Time spec, parlée: "à trois heures et quart", "à 15 heures 30",
+        //     "vers trois heures et demie" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)(?:^|\b)(?:à|(?P<approx>{APPROX_PAT}))\s+{spoken}"
+            )),
+            kind: ExpressionKind::TimeSpecification,
+            resolver: |caps, now, tz, _| {
+                let (h, m) = parse_spoken(caps)?;
                 resolve::resolve_time_today(h, m, now, tz)
             },
         },
         // --- Time range: "la dernière heure" ---
         GrammarRule {
-            pattern: Regex::new(r"(?i)\b(?:la\s+)?derni[èe]re\s+(?P<unit>heure|minute)\b")
-                .unwrap(),
+            pattern: RulePattern::new(r"(?i)\b(?:la\s+)?derni[èe]re\s+(?P<unit>heure|minute)\b"),
             kind: ExpressionKind::TimeRange,
-            resolver: |caps, now, _tz| {
+            resolver: |caps, now, _tz, _| {
                 let unit = caps.name("unit")?.as_str().to_lowercase();
                 let mapped = match unit.as_str() {
                     "heure" => "hour",
@@ -388,38 +1387,62 @@ fn build_rules() -> Vec<GrammarRule> {
         },
         // --- Time range: "de 10:15 à 13:45", "de 9:00 - 11:30" ---
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\bde\s+(?P<fh>\d{1,2}):(?P<fm>\d{2})\s*(?:[àa]|-)\s*(?P<th>\d{1,2}):(?P<tm>\d{2})\b",
-            )
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\bde\s+(?P<fh>\d{{1,2}}):(?P<fm>\d{{2}}){FROM_SECONDS_PAT}\s*(?:[àa]|-)\s*(?P<th>\d{{1,2}}):(?P<tm>\d{{2}}){TO_SECONDS_PAT}\b",
+            )),
             kind: ExpressionKind::TimeRange,
-            resolver: |caps, now, tz| {
-                let (fh, fm, th, tm) = parse_hm_range(caps)?;
-                resolve::resolve_time_range_with_minutes_today(fh, fm, th, tm, now, tz)
+            resolver: |caps, now, tz, config| {
+                let (from, to) = parse_time_range(caps)?;
+                resolve::resolve_clock_range_today(from, to, now, tz, config.overnight_ranges)
             },
         },
         // --- Time range: "entre 9 et 12 heures" ---
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\bentre\s+(?P<from>\d{1,2})\s+et\s+(?P<to>\d{1,2})\s*(?:heures?)?\b",
-            )
-            .unwrap(),
+            pattern: RulePattern::new(format!(
+                r"(?i)\bentre\s+(?P<from>{HOUR_PAT})\s+et\s+(?P<to>{HOUR_PAT})\s*(?:heures?)?\b"
+            )),
+            kind: ExpressionKind::TimeRange,
+            resolver: |caps, now, tz, config| {
+                let from = parse_num(caps.name("from")?.as_str())?;
+                let to = parse_num(caps.name("to")?.as_str())?;
+                if from > 23 || to > 23 {
+                    return None;
+                }
+                let (from, to) = clock::range_ends((from, 0), (to, 0))?;
+                resolve::resolve_clock_range_today(from, to, now, tz, config.overnight_ranges)
+            },
+        },
+        // --- Time range: "de trois à cinq heures", "de 9h à 11h30", "de 22h à 2h" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{hour_range}")),
             kind: ExpressionKind::TimeRange,
-            resolver: |caps, now, tz| {
-                let from = caps.name("from")?.as_str().parse::<u32>().ok()?;
-                let to = caps.name("to")?.as_str().parse::<u32>().ok()?;
-                if from > 23 || to > 23 { return None; }
-                resolve::resolve_time_range_today(from, to, now, tz)
+            resolver: |caps, now, tz, config| {
+                let (from, to) = parse_hour_range(caps)?;
+                resolve::resolve_clock_range_today(from, to, now, tz, config.overnight_ranges)
+            },
+        },
+        // --- Compact range: "14h-16h30", "9:15–11:45", "0930-1200" (only with `compact_ranges`) ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b{COMPACT_RANGE_PAT}(?:\s+(?P<unit>{subday}|{days})\b)?"
+            )),
+            kind: ExpressionKind::TimeRange,
+            resolver: |caps, now, tz, config| {
+                if !config.compact_ranges || caps.name("unit").is_some() {
+                    return None;
+                }
+                let (fh, fm, th, tm) = compact_range::parse(caps, config)?;
+                let (from, to) = clock::range_ends((fh, fm), (th, tm))?;
+                resolve::resolve_clock_range_today(from, to, now, tz, config.overnight_ranges)
             },
         },
         // --- Next/Last/This Weekday (Post-positive: "lundi prochain") ---
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\b(?:le\s+)?(?P<day>lundi|mardi|mercredi|jeudi|vendredi|samedi|dimanche)\s+(?P<dir>prochain|dernier)\b"
-            )
-            .unwrap(),
+            pattern: RulePattern::new(
+                r"(?i)\b(?:le\s+)?(?P<day>lundi|mardi|mercredi|jeudi|vendredi|samedi|dimanche)\s+(?P<dir>prochain|dernier)\b",
+            ),
             kind: ExpressionKind::RelativeDay,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, _| {
                 let dir_str = caps.name("dir")?.as_str().to_lowercase();
                 let direction = match dir_str.as_str() {
                     "prochain" => 1,
@@ -432,12 +1455,11 @@ fn build_rules() -> Vec<GrammarRule> {
         },
         // --- Next/Last Weekday (Pre-positive: "prochain lundi") ---
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\b(?:le\s+)?(?P<dir>prochain|dernier)\s+(?P<day>lundi|mardi|mercredi|jeudi|vendredi|samedi|dimanche)\b"
-            )
-            .unwrap(),
+            pattern: RulePattern::new(
+                r"(?i)\b(?:le\s+)?(?P<dir>prochain|dernier)\s+(?P<day>lundi|mardi|mercredi|jeudi|vendredi|samedi|dimanche)\b",
+            ),
             kind: ExpressionKind::RelativeDay,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, _| {
                 let dir_str = caps.name("dir")?.as_str().to_lowercase();
                 let direction = match dir_str.as_str() {
                     "prochain" => 1,
@@ -448,18 +1470,258 @@ fn build_rules() -> Vec<GrammarRule> {
                 resolve::resolve_weekday(weekday, direction, now, tz)
             },
         },
+        // --- Weekday without direction: "vendredi" ---
+        GrammarRule {
+            // A weekday in front of a date is part of that date; if the date
+            // rule rejected it ("lundi 6 mars 2026"), so do we.
+            pattern: RulePattern::new(format!(r"(?i)\b(?P<day>{wd})\b(?P<dtail>,?\s+{date})?")),
+            kind: ExpressionKind::RelativeDay,
+            resolver: |caps, now, tz, config| {
+                if caps.name("dtail").is_some() {
+                    return None;
+                }
+                let date = resolve_bare_weekday(caps.name("day")?.as_str(), now, tz, config)?;
+                resolve::resolve_full_day(date, tz)
+            },
+        },
         // --- This Weekday: "ce lundi" ---
         GrammarRule {
-            pattern: Regex::new(
-                r"(?i)\bce\s+(?P<day>lundi|mardi|mercredi|jeudi|vendredi|samedi|dimanche)\b"
-            )
-            .unwrap(),
+            pattern: RulePattern::new(
+                r"(?i)\bce\s+(?P<day>lundi|mardi|mercredi|jeudi|vendredi|samedi|dimanche)\b",
+            ),
             kind: ExpressionKind::RelativeDay,
-            resolver: |caps, now, tz| {
+            resolver: |caps, now, tz, _| {
                 let weekday = parse_weekday(caps.name("day")?.as_str())?;
                 resolve::resolve_weekday(weekday, 0, now, tz)
             },
         },
+        // ============================================================
+        //  Limite: "fin de mois", "début de la semaine prochaine",
+        //  "d'ici la fin du mois", "en fin de journée", "mi-semaine"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(
+                r"(?i)(?:\b(?:d['\u{2019}]ici|pour|à|a|au|en)\s+)?(?:\b(?:la|le)\s+)?\b(?P<bnd>d[ée]but|milieu|fin|mi)(?:-|\s+(?:de\s+(?:la\s+|l['\u{2019}])?|du\s+|d['\u{2019}]))(?:(?:ce|cette)\s+)?(?P<unit>journ[ée]e|jour|semaine|mois|trimestre|ann[ée]e)(?:-ci|\s+(?P<dir>prochaine?|derni[eè]re?|pass[ée]e?))?\b",
+            ),
+            kind: ExpressionKind::Boundary,
+            resolver: |caps, now, tz, config| {
+                let boundary = parse_boundary(caps.name("bnd")?.as_str())?;
+                let offset = match caps.name("dir") {
+                    Some(d) => period_direction(d.as_str())?,
+                    None => 0,
+                };
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_boundary(
+                    unit,
+                    boundary,
+                    offset,
+                    now,
+                    tz,
+                    config.week_start,
+                    config.business_hours,
+                )
+            },
+        },
+        // ============================================================
+        //  Reste: "pour le reste de la journée", "le reste du mois"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(
+                r"(?i)\b(?:pour\s+)?(?:le\s+)?reste\s+(?:de\s+la\s+|de\s+l['\u{2019}]|du\s+)(?P<unit>journ[ée]e|semaine|mois|trimestre|ann[ée]e)\b",
+            ),
+            kind: ExpressionKind::TimeRange,
+            resolver: |caps, now, tz, config| {
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_rest_of(unit, now, tz, config.week_start)
+            },
+        },
+        // ============================================================
+        //  Toute la journée: "demain toute la journée", "toute la journée demain"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)(?:\b(?P<day>{rday})\s+toute\s+la\s+journ[ée]e|\btoute\s+la\s+journ[ée]e\s+(?P<day_post>{rday}))\b"
+            )),
+            kind: ExpressionKind::RelativeDay,
+            resolver: |caps, now, tz, _| {
+                let offset = match caps.name("day").or(caps.name("day_post")) {
+                    Some(d) => day_keyword_offset(d.as_str())?,
+                    None => 0,
+                };
+                resolve::resolve_relative_day(offset, now, tz)
+            },
+        },
+        // ============================================================
+        //  Relative period: "cette semaine", "ce mois-ci", "cette année"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(
+                r"(?i)\b(?:cette|ce)\s+(?P<unit>semaine|mois|trimestre|ann[ée]e)(?:-ci)?\b",
+            ),
+            kind: ExpressionKind::RelativePeriod,
+            resolver: |caps, now, tz, config| {
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_period(unit, 0, now, tz, config.week_start)
+            },
+        },
+        // ============================================================
+        //  Relative period: "la semaine dernière", "le mois prochain",
+        //  "l'an dernier", "l'année passée"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(
+                r"(?i)(?:\b(?:la|le)\s+|\bl['\u{2019}]|\b)(?P<unit>semaine|mois|trimestre|ann[ée]e|an)\s+(?P<dir>derni[eè]re?|pass[ée]e?|prochaine?)\b",
+            ),
+            kind: ExpressionKind::RelativePeriod,
+            resolver: |caps, now, tz, config| {
+                let offset = period_direction(caps.name("dir")?.as_str())?;
+                let unit = parse_unit(caps.name("unit")?.as_str())?;
+                resolve::resolve_period(unit, offset, now, tz, config.week_start)
+            },
+        },
+        // ============================================================
+        //  Combined: jour ordinal du mois + "à H[h|:MM]"
+        //  "le premier lundi du mois à 10h", "le dernier vendredi de mars à 16h"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{wom},?\s+{at}")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps)?;
+                let date = resolve_weekday_of_month_caps(caps, now, tz, config)?;
+                resolve::resolve_clock_time_on_date(date, time, tz)
+            },
+        },
+        // ============================================================
+        //  Jour ordinal du mois: "le premier lundi du mois",
+        //  "le dernier vendredi de mars", "le 2e mardi du mois prochain"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{wom}")),
+            kind: ExpressionKind::WeekdayOfMonth,
+            resolver: |caps, now, tz, config| {
+                let date = resolve_weekday_of_month_caps(caps, now, tz, config)?;
+                resolve::resolve_full_day(date, tz)
+            },
+        },
+        // ============================================================
+        //  Combined: jour du mois + "à H[h|:MM]"
+        //  "le 15 à 10h", "le premier à 9h30"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{mday},?\s+{at}")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps)?;
+                let date = resolve_day_of_month_caps(caps, now, tz, config)?;
+                resolve::resolve_clock_time_on_date(date, time, tz)
+            },
+        },
+        // --- Jour du mois: "le 15", "le 1er", "le premier" ---
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{mday}")),
+            kind: ExpressionKind::DayOfMonth,
+            resolver: |caps, now, tz, config| {
+                let date = resolve_day_of_month_caps(caps, now, tz, config)?;
+                resolve::resolve_full_day(date, tz)
+            },
+        },
+        // ============================================================
+        //  Combined: holiday + time spec
+        //  "à Noël à 20h", "le lundi de Pâques à 10h"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:(?:à|pour|le|la)\s+)?(?:la\s+|l['’])?{hol},?\s+{at}"
+            )),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps)?;
+                let date = resolve_holiday_caps(caps, now, tz, config)?;
+                resolve::resolve_clock_time_on_date(date, time, tz)
+            },
+        },
+        // ============================================================
+        //  Holiday: "à Noël", "à la Toussaint", "le lundi de Pâques", "Pâques 2027"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b(?:(?:à|pour|le|la)\s+)?(?:la\s+|l['’])?{hol}"
+            )),
+            kind: ExpressionKind::Holiday,
+            resolver: |caps, now, tz, config| {
+                let date = resolve_holiday_caps(caps, now, tz, config)?;
+                resolve::resolve_full_day(date, tz)
+            },
+        },
+        // ============================================================
+        //  Récurrent: "tous les lundis à 9h", "chaque jour à midi",
+        //  "toutes les deux semaines", "un lundi sur deux à 10"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(
+                r"(?i)\b{recur}(?:,?\s+(?:{at}|à\s+(?P<nm>{nm})\b|à\s+(?P<rhour>{HOUR_PAT})\b))?"
+            )),
+            kind: ExpressionKind::Recurring,
+            resolver: |caps, _now, _tz, _config| resolve_recurrence_caps(caps),
+        },
+        // ============================================================
+        //  Combined: date + "à midi" / "à minuit"
+        //  "le 5 mars à midi", "05/03 à minuit"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{date},?\s+à\s+(?P<nm>{nm})\b")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let date = resolve_date_caps(caps, now, tz, config)?;
+                resolve_noon_midnight(caps, date, tz, config)
+            },
+        },
+        // ============================================================
+        //  Combined: date + "à H[h|:MM]"
+        //  "le 5 mars à 15h", "05/03 à 15h30"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{date},?\s+{at}")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let time = parse_time(caps)?;
+                let date = resolve_date_caps(caps, now, tz, config)?;
+                resolve::resolve_clock_time_on_date(date, time, tz)
+            },
+        },
+        // ============================================================
+        //  Combined: date + "de HH:MM à HH:MM"
+        //  "le 5 mars de 9:00 à 11:30", "05/03/2026 9:00 - 17:00"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{date},?\s+{hm_range}")),
+            kind: ExpressionKind::Combined,
+            resolver: |caps, now, tz, config| {
+                let (from, to) = parse_time_range(caps)?;
+                let date = resolve_date_caps(caps, now, tz, config)?;
+                resolve::resolve_clock_range_on_date(date, from, to, tz, config.overnight_ranges)
+            },
+        },
+        // ============================================================
+        //  Absolute date: "le 5 mars", "1er mars 2027", "vendredi 6 mars",
+        //  "05/03/2026", "le 5/3", "2026-03-05"
+        // ============================================================
+        GrammarRule {
+            pattern: RulePattern::new(format!(r"(?i)\b{date}")),
+            kind: ExpressionKind::AbsoluteDate,
+            resolver: |caps, now, tz, config| {
+                // A bare "3/5" is more likely a fraction than a date.
+                if caps.name("prep").is_none()
+                    && caps.name("wd").is_none()
+                    && numeric_date::is_short_slash(caps)
+                {
+                    return None;
+                }
+                let (year, month, day, weekday) = parse_date(caps, config)?;
+                resolve::resolve_absolute_date(year, month, day, weekday, now, tz, config.year_bias)
+            },
+        },
     ]
 }
 
@@ -476,7 +1738,36 @@ impl LanguageParser for French {
         PREFIXES
     }
 
-    fn parse(&self, text: &str, now: DateTime<Utc>, tz: Tz) -> Vec<TimeMatch> {
-        apply_rules(&self.rules, &self.regex_set, text, now, tz)
+    fn set_custom_holidays(&mut self, names: &[&str]) {
+        self.rules = build_rules(names);
+        self.regex_set = build_regex_set(&self.rules);
+    }
+
+    fn parse(
+        &self,
+        text: &str,
+        now: DateTime<Utc>,
+        tz: Tz,
+        config: &ParserConfig,
+    ) -> Vec<TimeMatch> {
+        let parse_in = |text: &str, tz: Tz| {
+            let mut rejected = Vec::new();
+            let matches = apply_rules_with_rejections(
+                &self.rules,
+                &self.regex_set,
+                text,
+                now,
+                tz,
+                config,
+                &mut rejected,
+            );
+            let matches = anchored_offset::apply(text, matches, &self.offsets, tz);
+            let matches =
+                open_range::apply(text, matches, &self.open_range, config, |t, config| {
+                    apply_rules(&self.rules, &self.regex_set, t, now, tz, config)
+                });
+            without_rejected(matches, &rejected)
+        };
+        zone::apply(text, parse_in(text, tz), &self.zones, config, parse_in)
     }
 }
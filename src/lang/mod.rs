@@ -1,20 +1,69 @@
+pub mod anchored_offset;
+pub mod clock;
+pub mod compact_range;
 pub mod de;
+pub mod duration;
 pub mod en;
 pub mod es;
 pub mod fr;
 pub mod numbers;
+pub mod numeric_date;
+pub mod open_range;
+pub mod zone;
 
-use crate::types::{ExpressionKind, ResolvedTime, TimeMatch};
+use crate::types::{
+    DateOrder, ExpressionKind, OvernightRange, ParserConfig, ResolvedTime, Span, TimeMatch,
+};
 use chrono::{DateTime, Utc};
 use chrono_tz::Tz;
 use regex::Regex;
+use std::ops::Deref;
+use std::sync::OnceLock;
 
-/// A grammar rule: compiled regex + metadata + resolver function.
+/// A grammar rule: regex + metadata + resolver function.
 pub struct GrammarRule {
-    pub pattern: Regex,
+    pub pattern: RulePattern,
     pub kind: ExpressionKind,
-    pub resolver:
-        fn(captures: &regex::Captures, now: DateTime<Utc>, tz: Tz) -> Option<ResolvedTime>,
+    pub resolver: fn(
+        captures: &regex::Captures,
+        now: DateTime<Utc>,
+        tz: Tz,
+        config: &ParserConfig,
+    ) -> Option<ResolvedTime>,
+}
+
+/// The regex of a [`GrammarRule`], compiled the first time the rule is tried.
+///
+/// A language has dozens of rules and most texts need only a few of them, so
+/// compiling every rule up front would make building a scanner slow. The
+/// pattern is still checked when the language is built, as part of the set
+/// from [`build_regex_set`].
+pub struct RulePattern {
+    source: String,
+    regex: OnceLock<Regex>,
+}
+
+impl RulePattern {
+    pub fn new(source: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+            regex: OnceLock::new(),
+        }
+    }
+
+    /// The pattern as written.
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+}
+
+impl Deref for RulePattern {
+    type Target = Regex;
+
+    fn deref(&self) -> &Regex {
+        self.regex
+            .get_or_init(|| Regex::new(&self.source).expect("invalid rule pattern"))
+    }
 }
 
 /// Trait that each language must implement.
@@ -27,8 +76,117 @@ pub trait LanguageParser: Send + Sync {
     /// Keyword prefixes (length >= 3) for partial match detection.
     fn keyword_prefixes(&self) -> &[&str];
 
+    /// Field order for ambiguous numeric dates when
+    /// [`ParserConfig::date_order`] is not set.
+    fn date_order(&self) -> DateOrder {
+        DateOrder::DayMonthYear
+    }
+
+    /// Recognize `names`, the custom holidays of the configured
+    /// [`HolidayCalendar`](crate::holiday::HolidayCalendar), wherever the
+    /// language takes a holiday name: "since Founders Day", "Founders Day at
+    /// 3pm".
+    ///
+    /// Called by the scanner when the calendar has custom holidays. The
+    /// default ignores them.
+    fn set_custom_holidays(&mut self, _names: &[&str]) {}
+
     /// Parse all time expressions from the text.
-    fn parse(&self, text: &str, now: DateTime<Utc>, tz: Tz) -> Vec<TimeMatch>;
+    fn parse(
+        &self,
+        text: &str,
+        now: DateTime<Utc>,
+        tz: Tz,
+        config: &ParserConfig,
+    ) -> Vec<TimeMatch>;
+}
+
+/// Compile the patterns of a language's rules into the set that
+/// [`apply_rules`] uses to pick the rules worth running.
+///
+/// The set only narrows down the rules, so it leaves out word boundaries:
+/// Unicode word boundaries make the lazy DFA give up on any non-ASCII text,
+/// and matching would fall back to the much slower NFA. The set of a
+/// language is also too large for the default DFA cache, so the limit is
+/// raised. Each rule still checks its boundaries on its own. `\d` is read
+/// as an ASCII digit, the only kind the resolvers can parse, which makes the
+/// set about half as costly to compile.
+pub fn build_regex_set(rules: &[GrammarRule]) -> regex::RegexSet {
+    regex::RegexSetBuilder::new(rules.iter().map(|r| set_pattern(r.pattern.as_str())))
+        .dfa_size_limit(1 << 28)
+        .build()
+        .unwrap()
+}
+
+/// A pattern with its word boundary assertions (`\b`, `\B`, `\b{start}`, ...)
+/// removed, which can only make it match more, and `\d` limited to ASCII
+/// digits.
+fn set_pattern(pattern: &str) -> String {
+    let mut out = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('b' | 'B') => {
+                if chars.next_if_eq(&'{').is_some() {
+                    chars.find(|&c| c == '}');
+                }
+            }
+            Some('d') => out.push_str("[0-9]"),
+            Some(next) => {
+                out.push(c);
+                out.push(next);
+            }
+            None => out.push(c),
+        }
+    }
+    out
+}
+
+/// Byte offset where the `count`-th word before `end` starts, or 0 if there
+/// are fewer words.
+///
+/// Passes that look for words in front of a match search only this far
+/// back instead of through all the text before it.
+pub fn words_before(text: &str, end: usize, count: usize) -> usize {
+    let mut words = 0;
+    let mut start = end;
+    let mut in_word = false;
+    for (i, c) in text[..end].char_indices().rev() {
+        if !c.is_whitespace() {
+            in_word = true;
+            start = i;
+        } else if in_word {
+            in_word = false;
+            words += 1;
+            if words == count {
+                return start;
+            }
+        }
+    }
+    0
+}
+
+/// Byte offset where the `count`-th word after `start` ends, or the end of
+/// the text if there are fewer words.
+pub fn words_after(text: &str, start: usize, count: usize) -> usize {
+    let mut words = 0;
+    let mut in_word = false;
+    for (i, c) in text[start..].char_indices() {
+        if !c.is_whitespace() {
+            in_word = true;
+        } else if in_word {
+            in_word = false;
+            words += 1;
+            if words == count {
+                return start + i;
+            }
+        }
+    }
+    text.len()
 }
 
 /// Shared helper: run all grammar rules against text and collect matches.
@@ -38,8 +196,27 @@ pub fn apply_rules(
     text: &str,
     now: DateTime<Utc>,
     tz: Tz,
+    config: &ParserConfig,
+) -> Vec<TimeMatch> {
+    apply_rules_with_rejections(rules, regex_set, text, now, tz, config, &mut Vec::new())
+}
+
+/// Like [`apply_rules`], also collecting in `rejected` the spans of ranges
+/// turned down by [`OvernightRange::Reject`].
+///
+/// No shorter match is reported inside such a span. The passes that run
+/// after the rules can still find one (`"bis 1 Uhr"` in a rejected
+/// `"von 23 bis 1 Uhr"`); [`without_rejected`] drops it.
+pub fn apply_rules_with_rejections(
+    rules: &[GrammarRule],
+    regex_set: &regex::RegexSet,
+    text: &str,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+    rejected: &mut Vec<Span>,
 ) -> Vec<TimeMatch> {
-    use crate::types::{MatchConfidence, Span};
+    use crate::types::MatchConfidence;
 
     let mut matches = Vec::new();
 
@@ -64,7 +241,13 @@ pub fn apply_rules(
                 continue;
             }
 
-            if let Some(resolved) = (rule.resolver)(&caps, now, tz) {
+            if numeric_date::is_dotted_number(text, &caps) {
+                continue;
+            }
+
+            let resolved = (rule.resolver)(&caps, now, tz, config);
+            let overnight = resolved.is_none() && rejected_overnight(rule, &caps, now, tz, config);
+            if resolved.is_some() || overnight {
                 // Remove any shorter matches that this one covers
                 let new_range = range.clone();
                 matches.retain(|tm: &TimeMatch| {
@@ -72,12 +255,22 @@ pub fn apply_rules(
                     !(new_range.start <= s.start && new_range.end >= s.end)
                 });
                 covered.retain(|c| !(new_range.start <= c.start && new_range.end >= c.end));
-
+            }
+            if overnight {
+                rejected.push(Span::new(range.start, range.end));
+                covered.push(range);
+            } else if let Some(resolved) = resolved {
+                let spans_midnight = crate::resolve::spans_midnight(&resolved, tz);
                 matches.push(TimeMatch {
                     span: Span::new(range.start, range.end),
                     confidence: MatchConfidence::Complete,
                     resolved,
                     kind: rule.kind,
+                    date_order: numeric_date::date_order(&caps, config),
+                    tolerance: caps.name("approx").map(|_| config.approximate_tolerance),
+                    spans_midnight,
+                    timezone: None,
+                    zone_name: None,
                 });
                 covered.push(range);
             }
@@ -85,3 +278,28 @@ pub fn apply_rules(
     }
     matches
 }
+
+/// Whether `rule` resolved `caps` to nothing only because they are a range
+/// through midnight and [`ParserConfig::overnight_ranges`] rejects those.
+fn rejected_overnight(
+    rule: &GrammarRule,
+    caps: &regex::Captures,
+    now: DateTime<Utc>,
+    tz: Tz,
+    config: &ParserConfig,
+) -> bool {
+    if config.overnight_ranges != OvernightRange::Reject {
+        return false;
+    }
+    let next_day = ParserConfig {
+        overnight_ranges: OvernightRange::NextDay,
+        ..config.clone()
+    };
+    (rule.resolver)(caps, now, tz, &next_day).is_some()
+}
+
+/// The matches that do not overlap a span in `rejected`.
+pub fn without_rejected(mut matches: Vec<TimeMatch>, rejected: &[Span]) -> Vec<TimeMatch> {
+    matches.retain(|m| !rejected.iter().any(|r| r.overlaps(&m.span)));
+    matches
+}
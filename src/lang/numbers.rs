@@ -42,7 +42,7 @@ pub fn parse_number_en(s: &str) -> Option<u32> {
 
 pub fn parse_number_de(s: &str) -> Option<u32> {
     match s {
-        "ein" | "eins" | "eine" | "einem" | "einen" => Some(1),
+        "ein" | "eins" | "eine" | "einem" | "einen" | "einer" => Some(1),
         "zwei" => Some(2),
         "drei" => Some(3),
         "vier" => Some(4),
@@ -85,8 +85,21 @@ pub fn parse_number_fr(s: &str) -> Option<u32> {
         "quatorze" => Some(14),
         "quinze" => Some(15),
         "seize" => Some(16),
+        "dix-sept" => Some(17),
+        "dix-huit" => Some(18),
+        "dix-neuf" => Some(19),
         "vingt" => Some(20),
+        "vingt-et-un" | "vingt-et-une" => Some(21),
+        "vingt-deux" => Some(22),
+        "vingt-trois" => Some(23),
+        "vingt-quatre" => Some(24),
+        "vingt-cinq" => Some(25),
+        "vingt-six" => Some(26),
+        "vingt-sept" => Some(27),
+        "vingt-huit" => Some(28),
+        "vingt-neuf" => Some(29),
         "trente" => Some(30),
+        "trente-et-un" | "trente-et-une" => Some(31),
         _ => None,
     }
 }
@@ -108,8 +121,132 @@ pub fn parse_number_es(s: &str) -> Option<u32> {
         "trece" => Some(13),
         "catorce" => Some(14),
         "quince" => Some(15),
+        "dieciséis" | "dieciseis" => Some(16),
+        "diecisiete" => Some(17),
+        "dieciocho" => Some(18),
+        "diecinueve" => Some(19),
         "veinte" => Some(20),
+        "veintiuno" | "veintiuna" => Some(21),
+        "veintidós" | "veintidos" => Some(22),
+        "veintitrés" | "veintitres" => Some(23),
         "treinta" => Some(30),
         _ => None,
     }
 }
+
+/// Parse an ordinal word or its abbreviation ("3rd", "dritten", "15.",
+/// "premier", "2e", "primero", "1º") into a u32.
+/// Tries each language table in turn.
+pub fn parse_ordinal(s: &str) -> Option<u32> {
+    let lower = s.to_lowercase();
+    parse_ordinal_en(&lower)
+        .or_else(|| parse_ordinal_de(&lower))
+        .or_else(|| parse_ordinal_fr(&lower))
+        .or_else(|| parse_ordinal_es(&lower))
+}
+
+/// "first" ... "thirty-first", or digits with a suffix ("1st", "22nd", "15th").
+pub fn parse_ordinal_en(s: &str) -> Option<u32> {
+    if let Some(digits) = ["st", "nd", "rd", "th"]
+        .iter()
+        .find_map(|suffix| s.strip_suffix(suffix))
+        .filter(|d| !d.is_empty() && d.bytes().all(|b| b.is_ascii_digit()))
+    {
+        return digits.parse().ok();
+    }
+    if let Some((tens, unit)) = s.split_once(['-', ' ']) {
+        let tens = parse_number_en(tens).filter(|t| *t == 20 || *t == 30)?;
+        let unit = parse_ordinal_en(unit).filter(|u| *u < 10)?;
+        return Some(tens + unit);
+    }
+    match s {
+        "first" => Some(1),
+        "second" => Some(2),
+        "third" => Some(3),
+        "fifth" => Some(5),
+        "eighth" => Some(8),
+        "ninth" => Some(9),
+        "twelfth" => Some(12),
+        _ => match s.strip_suffix("ieth") {
+            // "twentieth" -> "twenty"
+            Some(tens) => parse_number_en(&format!("{tens}y")),
+            None => parse_number_en(s.strip_suffix("th")?),
+        },
+    }
+}
+
+/// "erste(n)" ... "einunddreißigste(n)" in any inflection, or digits with a
+/// trailing dot ("15.").
+pub fn parse_ordinal_de(s: &str) -> Option<u32> {
+    if let Some(digits) = s.strip_suffix('.') {
+        return digits.parse().ok();
+    }
+    let stem = s
+        .trim_end_matches(['n', 'r', 's', 'm'])
+        .trim_end_matches('e');
+    match stem {
+        "erst" => Some(1),
+        "zweit" => Some(2),
+        "dritt" => Some(3),
+        "siebt" | "siebent" => Some(7),
+        "acht" => Some(8),
+        _ => {
+            // "einundzwanzig" = "ein" + "zwanzig"
+            let cardinal = |w: &str| match w.split_once("und") {
+                Some((unit, tens)) => Some(parse_number_de(unit)? + parse_number_de(tens)?),
+                None => parse_number_de(w),
+            };
+            // "-t" up to nineteen ("vierte"), "-st" from twenty ("zwanzigste")
+            stem.strip_suffix('t')
+                .and_then(cardinal)
+                .or_else(|| cardinal(stem.strip_suffix("st")?))
+        }
+    }
+}
+
+/// "premier"/"première", "second(e)", "deuxième" ... "trente-et-unième", or
+/// digits with a suffix ("1er", "2e", "15ème").
+pub fn parse_ordinal_fr(s: &str) -> Option<u32> {
+    match s {
+        "premier" | "première" | "premiere" | "1er" | "1re" | "1ère" => return Some(1),
+        "second" | "seconde" => return Some(2),
+        _ => {}
+    }
+    let base = ["ième", "ieme", "ème", "eme", "e"]
+        .iter()
+        .find_map(|suffix| s.strip_suffix(suffix))?;
+    if let Ok(n) = base.parse() {
+        return Some(n);
+    }
+    match base {
+        "cinqu" => Some(5),
+        "neuv" => Some(9),
+        // "quatrième" -> "quatre", "onzième" -> "onze"
+        _ => parse_number_fr(base).or_else(|| parse_number_fr(&format!("{base}e"))),
+    }
+}
+
+/// "primero"/"primer" ... "décimo" in either gender, or digits with a suffix
+/// ("1º", "1.º", "1ro", "3er").
+pub fn parse_ordinal_es(s: &str) -> Option<u32> {
+    if s.starts_with(|c: char| c.is_ascii_digit()) {
+        return s
+            .trim_end_matches(['º', '°', 'o', 'r', 'e', 'a', 'ª'])
+            .trim_end_matches('.')
+            .parse()
+            .ok();
+    }
+    match s.trim_end_matches(['o', 'a']) {
+        "primer" => Some(1),
+        "segund" => Some(2),
+        "tercer" => Some(3),
+        "cuart" => Some(4),
+        "quint" => Some(5),
+        "sext" => Some(6),
+        "séptim" | "septim" | "sétim" | "setim" => Some(7),
+        "octav" => Some(8),
+        "noven" => Some(9),
+        "décim" | "decim" => Some(10),
+        _ => None,
+    }
+}
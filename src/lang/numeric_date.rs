@@ -0,0 +1,175 @@
+//! Language-independent numeric date formats.
+//!
+//! Recognizes ISO dates (`2026-03-05`), dotted dates (`05.03.2026`, `5.3.`)
+//! and slash dates (`3/5/2026`, `05/03/26`, `3/5`). ISO and dotted dates have
+//! a fixed field order; slash dates are ambiguous and are read in the
+//! [`DateOrder`] configured on [`ParserConfig::date_order`], which each
+//! language fills in with its own convention when left unset. Dotted
+//! numbers that are not dates, such as version numbers and IP addresses, are
+//! left alone (see [`is_dotted_number`]).
+
+use crate::types::{DateOrder, ParserConfig};
+
+/// Numeric date pattern for embedding in language rules.
+///
+/// Capture groups: `iso_y`/`iso_m`/`iso_d` for ISO dates, `dot_d`/`dot_m`/`dot_y`
+/// for dotted dates and `sl_a`/`sl_b`/`sl_c` for slash dates.
+pub const NUMERIC_DATE_PAT: &str = r"(?:(?P<iso_y>\d{4})-(?P<iso_m>\d{1,2})-(?P<iso_d>\d{1,2})\b|(?P<dot_d>\d{1,2})\.(?P<dot_m>\d{1,2})\.(?:(?P<dot_y>\d{4}|\d{2})\b)?\b{end-half}|(?P<sl_a>\d{4}|\d{1,2})/(?P<sl_b>\d{1,2})(?:/(?P<sl_c>\d{4}|\d{2}))?\b)";
+
+/// A numeric date split into its fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumericDate {
+    /// The year, if written. Two-digit years are expanded (`26` → `2026`).
+    pub year: Option<i32>,
+    pub month: u32,
+    pub day: u32,
+    /// The field order the date was read in.
+    pub order: DateOrder,
+}
+
+/// Expand a two-digit year: `00`–`69` → 2000s, `70`–`99` → 1900s.
+fn expand_year(s: &str) -> Option<i32> {
+    let y = s.parse::<i32>().ok()?;
+    Some(match (s.len(), y) {
+        (2, 0..=69) => 2000 + y,
+        (2, _) => 1900 + y,
+        _ => y,
+    })
+}
+
+/// Assign two numbers to `(month, day)` according to `order`, switching to the
+/// other order when only that one yields a valid month.
+fn month_day(a: u32, b: u32, order: DateOrder) -> Option<(u32, u32, DateOrder)> {
+    let (month, day) = match order {
+        DateOrder::DayMonthYear => (b, a),
+        DateOrder::MonthDayYear | DateOrder::YearMonthDay => (a, b),
+    };
+    if (1..=12).contains(&month) {
+        return Some((month, day, order));
+    }
+    let swapped = match order {
+        DateOrder::DayMonthYear => DateOrder::MonthDayYear,
+        DateOrder::MonthDayYear | DateOrder::YearMonthDay => DateOrder::DayMonthYear,
+    };
+    (1..=12).contains(&day).then_some((day, month, swapped))
+}
+
+/// Parse a numeric date from captures produced by [`NUMERIC_DATE_PAT`].
+///
+/// `order` is used for slash dates only. Returns `None` if no numeric date
+/// groups participated in the match or the month is out of range. Day
+/// validity is left to the resolver.
+pub fn parse(caps: &regex::Captures, order: DateOrder) -> Option<NumericDate> {
+    let num = |name: &str| caps.name(name).and_then(|m| m.as_str().parse::<u32>().ok());
+
+    if let Some(y) = caps.name("iso_y") {
+        return Some(NumericDate {
+            year: Some(y.as_str().parse().ok()?),
+            month: num("iso_m").filter(|m| (1..=12).contains(m))?,
+            day: num("iso_d")?,
+            order: DateOrder::YearMonthDay,
+        });
+    }
+
+    if caps.name("dot_d").is_some() {
+        return Some(NumericDate {
+            year: match caps.name("dot_y") {
+                Some(y) => Some(expand_year(y.as_str())?),
+                None => None,
+            },
+            month: num("dot_m").filter(|m| (1..=12).contains(m))?,
+            day: num("dot_d")?,
+            order: DateOrder::DayMonthYear,
+        });
+    }
+
+    let a = caps.name("sl_a")?;
+    let b = num("sl_b")?;
+    let c = caps.name("sl_c");
+
+    // A four-digit first field can only be a year: 2026/03/05.
+    if a.as_str().len() == 4 {
+        return Some(NumericDate {
+            year: Some(a.as_str().parse().ok()?),
+            month: Some(b).filter(|m| (1..=12).contains(m))?,
+            day: num("sl_c")?,
+            order: DateOrder::YearMonthDay,
+        });
+    }
+
+    let a_num = a.as_str().parse::<u32>().ok()?;
+    match c {
+        // Two-digit year first, e.g. 26/03/05 in a year-first locale.
+        Some(c) if order == DateOrder::YearMonthDay && c.as_str().len() == 2 => Some(NumericDate {
+            year: Some(expand_year(a.as_str())?),
+            month: Some(b).filter(|m| (1..=12).contains(m))?,
+            day: c.as_str().parse().ok()?,
+            order: DateOrder::YearMonthDay,
+        }),
+        Some(c) => {
+            let (month, day, order) = month_day(a_num, b, order)?;
+            Some(NumericDate {
+                year: Some(expand_year(c.as_str())?),
+                month,
+                day,
+                order,
+            })
+        }
+        None => {
+            let (month, day, order) = month_day(a_num, b, order)?;
+            Some(NumericDate {
+                year: None,
+                month,
+                day,
+                order,
+            })
+        }
+    }
+}
+
+/// Words that introduce a version number rather than a date (`version 1.2.30`).
+const VERSION_WORDS: &[&str] = &["version", "versión", "release", "build"];
+
+/// Returns `true` if the dotted date in `caps` is part of a number that is not
+/// a date: a longer dotted number such as an IP address (`10.1.10.10`), or a
+/// version number after a word like "version".
+///
+/// `text` is the text the captures were taken from.
+pub fn is_dotted_number(text: &str, caps: &regex::Captures) -> bool {
+    let (Some(d), Some(m)) = (caps.name("dot_d"), caps.name("dot_m")) else {
+        return false;
+    };
+    // Past the dot after the month, or past the year
+    let end = caps.name("dot_y").map_or(m.end() + 1, |y| y.end());
+
+    if text[..d.start()]
+        .chars()
+        .next_back()
+        .is_some_and(|c| c.is_ascii_digit() || c == '.')
+    {
+        return true;
+    }
+    let mut after = text[end..].chars();
+    if after.next() == Some('.') && after.next().is_some_and(|c| c.is_ascii_digit()) {
+        return true;
+    }
+    text[..d.start()]
+        .split_whitespace()
+        .next_back()
+        .is_some_and(|word| VERSION_WORDS.contains(&word.to_lowercase().as_str()))
+}
+
+/// Returns `true` if the captures hold a slash date without a year (`3/5`).
+///
+/// These are easily confused with fractions, so languages only accept them
+/// standalone after a preposition, or when combined with a time.
+pub fn is_short_slash(caps: &regex::Captures) -> bool {
+    caps.name("sl_a").is_some() && caps.name("sl_c").is_none()
+}
+
+/// The field order a match was read in, if it contains a numeric date.
+///
+/// Used to annotate [`TimeMatch::date_order`](crate::types::TimeMatch::date_order).
+pub fn date_order(caps: &regex::Captures, config: &ParserConfig) -> Option<DateOrder> {
+    parse(caps, config.date_order.unwrap_or(DateOrder::DayMonthYear)).map(|d| d.order)
+}
@@ -0,0 +1,203 @@
+//! Language-independent handling of open-ended ranges.
+//!
+//! `"since Monday"`, `"until 5pm"`, `"ab 14 Uhr"` or `"hasta las 5"` put a
+//! preposition in front of an expression that is recognized on its own.
+//! Instead of repeating every anchor after every preposition, each language
+//! lists its prepositions in an [`OpenRangeRules`], and [`apply`] extends the
+//! matches of its other rules backwards over them.
+//!
+//! Some times only stand alone with their own marker (`"um 14 Uhr"`,
+//! `"a las 5"`). After a preposition that marker is dropped (`"ab 14 Uhr"`),
+//! so the few words after the preposition are parsed again with the marker
+//! put back.
+
+use std::cell::OnceCell;
+
+use regex::Regex;
+
+use crate::lang::{words_after, words_before};
+use crate::types::{
+    DateBias, ExpressionKind, MatchConfidence, ParserConfig, ResolvedTime, Span, TimeMatch,
+};
+
+/// The most words a preposition takes ("à partir de").
+const PREPOSITION_WORDS: usize = 3;
+
+/// The most words of a time that needs its marker ("las tres y cuarto de la
+/// tarde").
+const MARKED_TIME_WORDS: usize = 8;
+
+/// Which end of the anchor a preposition keeps, and which way the range is open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bound {
+    /// From the start of an anchor in the past on: "since Monday", "seit gestern".
+    Since,
+    /// From the start of an anchor in the future on: "starting Monday", "ab 14 Uhr".
+    From,
+    /// From the end of the anchor on: "after Monday", "nach 15 Uhr".
+    After,
+    /// Up to the end of the anchor: "until Friday", "bis Freitag".
+    Until,
+    /// Up to the start of the anchor: "before Friday", "vor 15 Uhr".
+    Before,
+}
+
+/// The open-range prepositions of a language.
+pub struct OpenRangeRules {
+    /// A preposition, including its trailing whitespace, at the end of the
+    /// text before an anchor (group `op`).
+    before_anchor: Regex,
+    /// Any preposition in the text (group `op`).
+    anywhere: Regex,
+    /// Maps the `op` text to its bound.
+    classify: fn(&str) -> Option<Bound>,
+    /// The word that introduces a clock time on its own ("at", "um", "à", "a").
+    at_marker: &'static str,
+}
+
+impl OpenRangeRules {
+    /// Build the rules from a pattern of prepositions, each including the
+    /// whitespace that follows it.
+    pub fn new(
+        prepositions: &str,
+        classify: fn(&str) -> Option<Bound>,
+        at_marker: &'static str,
+    ) -> Self {
+        Self {
+            before_anchor: Regex::new(&format!(r"(?i)\b(?P<op>{prepositions})$")).unwrap(),
+            anywhere: Regex::new(&format!(r"(?i)\b(?P<op>{prepositions})")).unwrap(),
+            classify,
+            at_marker,
+        }
+    }
+}
+
+/// Turn matches preceded by a preposition into open ranges.
+///
+/// `reparse` runs the language's rules on a piece of text with the given
+/// configuration. It re-resolves anchors whose occurrence the preposition
+/// decides ("since" looks back, "until" ahead) and finds times that need their marker.
+pub fn apply(
+    text: &str,
+    mut matches: Vec<TimeMatch>,
+    rules: &OpenRangeRules,
+    config: &ParserConfig,
+    reparse: impl Fn(&str, &ParserConfig) -> Vec<TimeMatch>,
+) -> Vec<TimeMatch> {
+    // The configurations of looking back and ahead, built on first use
+    let (past, future) = (OnceCell::new(), OnceCell::new());
+    let config_for = |bound: Bound| {
+        let cell = if bound == Bound::Since {
+            &past
+        } else {
+            &future
+        };
+        cell.get_or_init(|| biased(config, bound))
+    };
+
+    // Anchors recognized on their own
+    for i in 0..matches.len() {
+        let anchor = &matches[i];
+        let window = words_before(text, anchor.span.start, PREPOSITION_WORDS);
+        let Some(caps) = rules
+            .before_anchor
+            .captures(&text[window..anchor.span.start])
+        else {
+            continue;
+        };
+        let op = caps.name("op").unwrap();
+        let op_start = window + op.start();
+        let prep = Span::new(op_start, anchor.span.start);
+        if matches.iter().any(|m| m.span.overlaps(&prep)) {
+            continue;
+        }
+        let Some(bound) = (rules.classify)(op.as_str()) else {
+            continue;
+        };
+        let anchor_text = &text[anchor.span.as_range()];
+        let resolved = reparse(anchor_text, config_for(bound))
+            .into_iter()
+            .find(|m| m.span == Span::new(0, anchor_text.len()))
+            .map_or_else(|| anchor.resolved.clone(), |m| m.resolved);
+        let open = TimeMatch {
+            resolved,
+            ..anchor.clone()
+        };
+        if let Some(open) = open_range(&open, bound, op_start, anchor.span.end) {
+            matches[i] = open;
+        }
+    }
+
+    // Times that need their marker: "ab 14 Uhr" is parsed as "um 14 Uhr"
+    for caps in rules.anywhere.captures_iter(text) {
+        let op = caps.name("op").unwrap();
+        if matches
+            .iter()
+            .any(|m| m.span.start <= op.start() && m.span.end > op.start())
+        {
+            continue;
+        }
+        let Some(bound) = (rules.classify)(op.as_str()) else {
+            continue;
+        };
+        let end = words_after(text, op.end(), MARKED_TIME_WORDS);
+        let marked = format!("{} {}", rules.at_marker, &text[op.end()..end]);
+        let marker_len = rules.at_marker.len() + 1;
+        let Some(anchor) = reparse(&marked, config_for(bound))
+            .into_iter()
+            .find(|m| m.span.start == 0 && m.span.end > marker_len)
+        else {
+            continue;
+        };
+        let end = op.end() + anchor.span.end - marker_len;
+        if let Some(open) = open_range(&anchor, bound, op.start(), end) {
+            matches.retain(|m| !(open.span.start <= m.span.start && open.span.end >= m.span.end));
+            matches.push(open);
+        }
+    }
+    matches
+}
+
+/// The configuration to resolve an anchor with: "since Friday" looks back,
+/// "until Friday" ahead. Only biases left at [`DateBias::Nearest`] are changed.
+fn biased(config: &ParserConfig, bound: Bound) -> ParserConfig {
+    let bias = match bound {
+        Bound::Since => DateBias::Past,
+        _ => DateBias::Future,
+    };
+    let pick = |b: DateBias| if b == DateBias::Nearest { bias } else { b };
+    ParserConfig {
+        year_bias: pick(config.year_bias),
+        day_of_month_bias: pick(config.day_of_month_bias),
+        weekday_bias: pick(config.weekday_bias),
+        ..config.clone()
+    }
+}
+
+/// The open range for an anchor behind a preposition, spanning `start..end`
+/// of the original text.
+fn open_range(anchor: &TimeMatch, bound: Bound, start: usize, end: usize) -> Option<TimeMatch> {
+    if anchor.confidence != MatchConfidence::Complete {
+        return None;
+    }
+    let (from, to) = match anchor.resolved {
+        ResolvedTime::Point(t) => (t, t),
+        ResolvedTime::Range { start, end } => (start, end),
+        _ => return None,
+    };
+    let resolved = match bound {
+        Bound::Since | Bound::From => ResolvedTime::Since(from),
+        Bound::After => ResolvedTime::Since(to),
+        Bound::Until => ResolvedTime::Until(to),
+        Bound::Before => ResolvedTime::Until(from),
+    };
+    Some(TimeMatch {
+        span: Span::new(start, end),
+        resolved,
+        kind: ExpressionKind::OpenRange,
+        spans_midnight: false,
+        timezone: None,
+        zone_name: None,
+        ..anchor.clone()
+    })
+}
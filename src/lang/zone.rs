@@ -0,0 +1,271 @@
+//! Language-independent handling of time zone mentions.
+//!
+//! `"9am PST"`, `"15 Uhr MESZ"`, `"14h UTC+2"` or `"3pm Europe/London"` name
+//! the zone a time is meant in. A zone named right after a match replaces
+//! the configured one for that match: [`apply`] parses the matched text
+//! again in the named zone and extends the match over the mention.
+//! IANA names, abbreviations and UTC offsets are written the same way in
+//! every language; each language adds its wording for the time of a city
+//! (`"New York time"`, `"heure de Paris"`) in a [`ZoneRules`].
+//!
+//! Matches carry their zone as a [`Tz`], along with the mention as written
+//! in [`TimeMatch::zone_name`]. A fixed UTC offset is resolved
+//! in the `Etc/GMT` zone of that offset, or in a zone that has kept the
+//! offset all year for decades (`"UTC+5:30"` in `Asia/Kolkata`). An offset
+//! no such zone has, such as `"UTC+3:30"`, cannot be resolved; the
+//! expression it follows is dropped rather than resolved in the wrong zone.
+
+use chrono_tz::{TZ_VARIANTS, Tz};
+use regex::Regex;
+
+use crate::types::{ParserConfig, ResolvedTime, Span, TimeMatch};
+
+/// Zone designators common to all languages (groups `iana`, `sign`, `oh`,
+/// `om` and `abbr`). Case-sensitive, so that abbreviations are only
+/// recognized in capitals.
+const DESIGNATOR_PAT: &str = r"(?P<iana>[A-Z][A-Za-z_]+(?:/[A-Z][A-Za-z_-]+(?:[+-]\d{1,2})?){1,2})|(?:UTC|GMT)\s*(?P<sign>[+\-−])\s*(?P<oh>\d{1,2})(?::?(?P<om>\d{2}))?|(?P<abbr>[A-Z]{2,5})";
+
+/// A city name of up to three capitalized words, for embedding in a
+/// language's city phrase (group `city`).
+pub const CITY_PAT: &str = r"(?-i:(?P<city>\p{Lu}[\p{L}'.]*(?:[\s-]\p{Lu}[\p{L}'.]*){0,2}))";
+
+/// The zone mentions of a language.
+pub struct ZoneRules {
+    /// A zone mention at the start of the text after a match.
+    after_match: Regex,
+}
+
+impl ZoneRules {
+    /// Build the rules from a language's phrase for the time of a city,
+    /// which embeds [`CITY_PAT`].
+    pub fn new(city_phrase: &str) -> Self {
+        Self {
+            after_match: Regex::new(&format!(r"^\s*(?:{DESIGNATOR_PAT}|(?i:{city_phrase}))\b"))
+                .unwrap(),
+        }
+    }
+}
+
+/// What a zone mention names.
+enum Mention {
+    /// A zone to resolve the match in.
+    Zone(Tz),
+    /// A fixed UTC offset without a zone to stand for it.
+    UnsupportedOffset,
+}
+
+/// Re-resolve matches followed by a zone mention in that zone.
+///
+/// `reparse` runs the language's rules on a piece of text in the given zone.
+/// Durations and recurrences do not depend on a zone and are left alone,
+/// as are mentions that do not name a known zone. Matches followed by an
+/// offset that cannot be resolved are dropped.
+pub fn apply(
+    text: &str,
+    mut matches: Vec<TimeMatch>,
+    rules: &ZoneRules,
+    config: &ParserConfig,
+    reparse: impl Fn(&str, Tz) -> Vec<TimeMatch>,
+) -> Vec<TimeMatch> {
+    let mut unsupported = Vec::new();
+    for m in &mut matches {
+        if matches!(
+            m.resolved,
+            ResolvedTime::Duration(_) | ResolvedTime::Recurrence(_)
+        ) {
+            continue;
+        }
+        let Some(caps) = rules.after_match.captures(&text[m.span.end..]) else {
+            continue;
+        };
+        let tz = match mentioned_zone(&caps, config) {
+            Some(Mention::Zone(tz)) => tz,
+            Some(Mention::UnsupportedOffset) => {
+                unsupported.push(m.span.clone());
+                continue;
+            }
+            None => continue,
+        };
+        let expr = &text[m.span.start..m.span.end];
+        let Some(found) = reparse(expr, tz)
+            .into_iter()
+            .find(|r| r.span == Span::new(0, expr.len()))
+        else {
+            continue;
+        };
+        let mention = caps.get(0).unwrap();
+        let end = m.span.end + mention.end();
+        *m = TimeMatch {
+            span: Span::new(m.span.start, end),
+            timezone: Some(tz),
+            zone_name: Some(mention.as_str().trim_start().to_string()),
+            ..found
+        };
+    }
+    matches.retain(|m| !unsupported.contains(&m.span));
+    matches
+}
+
+/// What a mention matched by [`ZoneRules`] names, or `None` if it is not a
+/// zone.
+fn mentioned_zone(caps: &regex::Captures, config: &ParserConfig) -> Option<Mention> {
+    let fixed =
+        |minutes: i32| Some(offset_zone(minutes).map_or(Mention::UnsupportedOffset, Mention::Zone));
+    if let Some(name) = caps.name("iana") {
+        name.as_str().parse().ok().map(Mention::Zone)
+    } else if let Some(hours) = caps.name("oh") {
+        let hours: i32 = hours.as_str().parse().ok()?;
+        let minutes: i32 = caps
+            .name("om")
+            .map_or(Some(0), |m| m.as_str().parse().ok())?;
+        let sign = if caps.name("sign")?.as_str() == "+" {
+            1
+        } else {
+            -1
+        };
+        fixed(sign * (hours * 60 + minutes))
+    } else if let Some(abbr) = caps.name("abbr") {
+        let abbr = abbr.as_str();
+        if let Some((_, tz)) = config.zone_abbreviations.iter().find(|(a, _)| a == abbr) {
+            return Some(Mention::Zone(*tz));
+        }
+        match abbreviation_offset(abbr) {
+            Some(minutes) => fixed(minutes),
+            None => region_zone(abbr).map(Mention::Zone),
+        }
+    } else {
+        city_zone(caps.name("city")?.as_str()).map(Mention::Zone)
+    }
+}
+
+/// The zone for a UTC offset in minutes east of UTC: `Etc/GMT-2` for
+/// `"UTC+2"`, or a zone without daylight saving time for the offsets of
+/// whole countries that are not whole hours, such as `"UTC+5:30"` for India.
+///
+/// Returns `None` for any other offset.
+fn offset_zone(minutes: i32) -> Option<Tz> {
+    let hours = minutes / 60;
+    if minutes % 60 == 0 {
+        // The sign of the Etc zones is inverted: Etc/GMT-2 is two hours ahead
+        return match hours {
+            0 => Some(Tz::UTC),
+            1..=14 => format!("Etc/GMT-{hours}").parse().ok(),
+            -12..=-1 => format!("Etc/GMT+{}", -hours).parse().ok(),
+            _ => None,
+        };
+    }
+    match minutes {
+        270 => Some(Tz::Asia__Kabul),
+        330 => Some(Tz::Asia__Kolkata),
+        345 => Some(Tz::Asia__Kathmandu),
+        390 => Some(Tz::Asia__Yangon),
+        570 => Some(Tz::Australia__Darwin),
+        -570 => Some(Tz::Pacific__Marquesas),
+        _ => None,
+    }
+}
+
+/// The UTC offset, in minutes east of UTC, named by the abbreviation of a
+/// standard or daylight saving time.
+///
+/// Each abbreviation names one offset whatever the date: `"3pm CET"` is
+/// 14:00 UTC even in summer, and `"9am PDT"` is 16:00 UTC even in winter.
+/// Several abbreviations are used for more than one zone; the table picks
+/// the most common reading, which [`ParserConfig::zone_abbreviations`] can
+/// override.
+fn abbreviation_offset(abbr: &str) -> Option<i32> {
+    let hours = match abbr {
+        "UTC" | "GMT" | "WET" | "WEZ" => 0,
+        "WEST" | "WESZ" | "BST" | "CET" | "MEZ" | "WAT" => 1,
+        "CEST" | "MESZ" | "EET" | "OEZ" | "CAT" | "SAST" => 2,
+        "EEST" | "OESZ" | "MSK" | "EAT" => 3,
+        "GST" => 4,
+        "ICT" => 7,
+        "SGT" | "HKT" | "AWST" => 8,
+        "JST" | "KST" => 9,
+        "AEST" => 10,
+        "AEDT" => 11,
+        "NZST" => 12,
+        "NZDT" => 13,
+        "BRT" | "ADT" => -3,
+        "AST" | "EDT" => -4,
+        "EST" | "CDT" => -5,
+        "CST" | "MDT" => -6,
+        "MST" | "PDT" => -7,
+        "PST" | "AKDT" => -8,
+        "AKST" => -9,
+        "HST" => -10,
+        "IST" => return Some(330),
+        "ACST" => return Some(570),
+        "ACDT" => return Some(630),
+        "NST" => return Some(-210),
+        "NDT" => return Some(-150),
+        _ => return None,
+    };
+    Some(hours * 60)
+}
+
+/// The zone named by an abbreviation of a region's local time, which
+/// switches between standard and daylight saving time: `"3pm ET"` is 3pm
+/// in New York whatever the date.
+fn region_zone(abbr: &str) -> Option<Tz> {
+    match abbr {
+        "ET" => Some(Tz::America__New_York),
+        "CT" => Some(Tz::America__Chicago),
+        "MT" => Some(Tz::America__Denver),
+        "PT" => Some(Tz::America__Los_Angeles),
+        _ => None,
+    }
+}
+
+/// The zone for a city or region name: a city in an IANA zone name
+/// (`"New York"`, `"Paris"`), or a common name for a city or region
+/// without its own zone (`"San Francisco"`, `"München"`, `"Pacific"`).
+///
+/// German adjectives such as `"Berliner"` or `"New Yorker"` are found
+/// without their ending.
+fn city_zone(name: &str) -> Option<Tz> {
+    let name = name.to_lowercase();
+    let lookup = |name: &str| {
+        alias_zone(name).or_else(|| {
+            TZ_VARIANTS.iter().copied().find(|tz| {
+                tz.name()
+                    .rsplit('/')
+                    .next()
+                    .is_some_and(|city| city.replace('_', " ").eq_ignore_ascii_case(name))
+            })
+        })
+    };
+    lookup(&name).or_else(|| lookup(name.strip_suffix("er")?))
+}
+
+/// Common names of cities and regions that are not part of a zone name,
+/// in any of the supported languages.
+fn alias_zone(name: &str) -> Option<Tz> {
+    match name {
+        "pacific" | "san francisco" | "seattle" | "los ángeles" => Some(Tz::America__Los_Angeles),
+        "mountain" => Some(Tz::America__Denver),
+        "central" | "dallas" | "houston" => Some(Tz::America__Chicago),
+        "eastern" | "washington" | "boston" | "miami" | "atlanta" | "nueva york" => {
+            Some(Tz::America__New_York)
+        }
+        "londres" => Some(Tz::Europe__London),
+        "lissabon" | "lisbonne" | "lisboa" => Some(Tz::Europe__Lisbon),
+        "central european" | "münchen" | "munich" | "frankfurt" | "hamburg" | "köln" => {
+            Some(Tz::Europe__Berlin)
+        }
+        "wien" | "vienne" | "viena" => Some(Tz::Europe__Vienna),
+        "zürich" | "genf" | "geneva" | "genève" | "ginebra" => Some(Tz::Europe__Zurich),
+        "brüssel" | "bruxelles" | "bruselas" => Some(Tz::Europe__Brussels),
+        "rom" | "roma" => Some(Tz::Europe__Rome),
+        "warschau" | "varsovie" | "varsovia" => Some(Tz::Europe__Warsaw),
+        "prag" | "praga" => Some(Tz::Europe__Prague),
+        "athen" | "athènes" | "atenas" => Some(Tz::Europe__Athens),
+        "moskau" | "moscou" | "moscú" => Some(Tz::Europe__Moscow),
+        "mumbai" | "delhi" | "new delhi" | "bangalore" | "bengaluru" => Some(Tz::Asia__Kolkata),
+        "beijing" | "peking" | "pékin" | "pekín" | "shenzhen" => Some(Tz::Asia__Shanghai),
+        "tokio" => Some(Tz::Asia__Tokyo),
+        "singapur" | "singapour" => Some(Tz::Asia__Singapore),
+        _ => None,
+    }
+}
@@ -1,4 +1,6 @@
+pub mod holiday;
 pub mod lang;
+pub mod recurrence;
 pub mod resolve;
 pub mod scanner;
 pub mod types;
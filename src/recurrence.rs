@@ -0,0 +1,160 @@
+//! Concrete occurrences of a [`Recurrence`].
+//!
+//! A recurrence only describes a schedule (`"every other Tuesday at 10"`).
+//! [`Recurrence::occurrences`] turns it into points in time, starting from a
+//! given instant and interpreting dates and times in the user's timezone.
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc, Weekday};
+use chrono_tz::Tz;
+
+use crate::types::{Recurrence, TimeUnit};
+
+/// How far the iterator looks ahead for the next occurrence, in days, for an
+/// interval of one; the search reaches further for longer intervals and for
+/// quarters. Eight years covers a yearly Feb 29 schedule.
+const MAX_GAP_DAYS: i64 = 366 * 8;
+
+impl Recurrence {
+    /// Iterate over the occurrences of this schedule at or after `from`.
+    ///
+    /// Dates and the time of day are interpreted in `tz`. The first matching
+    /// day sets the phase for intervals greater than one, so `"every other
+    /// week"` repeats every second week counted from the first occurrence.
+    /// Weeks start on `week_start`, usually the scanner's
+    /// [`ParserConfig::week_start`](crate::types::ParserConfig::week_start);
+    /// it decides whether a Sunday and the next Monday share a week.
+    ///
+    /// Without explicit weekdays, a weekly schedule repeats on the weekday of
+    /// `from`, a monthly or quarterly one on its day of the month (skipping
+    /// months that are too short), and a yearly one on its month and day.
+    /// Sub-day frequencies (hours, minutes, seconds) step from `from` itself
+    /// and ignore the time of day.
+    ///
+    /// The iterator is endless for any valid schedule; it stops only when the
+    /// dates overflow or no occurrence is found within eight years.
+    pub fn occurrences(&self, from: DateTime<Utc>, tz: Tz, week_start: Weekday) -> Occurrences {
+        Occurrences {
+            recurrence: self.clone(),
+            from,
+            tz,
+            week_start,
+            next_day: Some(from.with_timezone(&tz).date_naive()),
+            next_instant: Some(from),
+            first_period: None,
+        }
+    }
+}
+
+/// Iterator over the occurrences of a [`Recurrence`], created by
+/// [`Recurrence::occurrences`].
+#[derive(Debug, Clone)]
+pub struct Occurrences {
+    recurrence: Recurrence,
+    from: DateTime<Utc>,
+    tz: Tz,
+    week_start: Weekday,
+    /// Next local day to examine (calendar frequencies).
+    next_day: Option<NaiveDate>,
+    /// Next candidate instant (sub-day frequencies).
+    next_instant: Option<DateTime<Utc>>,
+    /// Period index of the first occurrence, used to apply the interval.
+    first_period: Option<i64>,
+}
+
+impl Occurrences {
+    fn next_sub_day(&mut self, unit_seconds: i64) -> Option<DateTime<Utc>> {
+        let step = Duration::try_seconds(unit_seconds * i64::from(self.recurrence.interval))?;
+        let start = self.next_instant?;
+        let mut candidate = start;
+        while candidate - start <= Duration::days(8) {
+            self.next_instant = candidate.checked_add_signed(step);
+            let weekday = candidate.with_timezone(&self.tz).weekday();
+            if self.recurrence.weekdays.is_empty() || self.recurrence.weekdays.contains(&weekday) {
+                return Some(candidate);
+            }
+            candidate = self.next_instant?;
+        }
+        self.next_instant = None;
+        None
+    }
+
+    fn next_calendar(&mut self) -> Option<DateTime<Utc>> {
+        let r = &self.recurrence;
+        let origin = self.from.with_timezone(&self.tz).date_naive();
+        let (period_len, limit) = match r.frequency {
+            TimeUnit::Quarter => (3 * i64::from(r.interval), 3 * MAX_GAP_DAYS),
+            _ => (i64::from(r.interval), MAX_GAP_DAYS),
+        };
+        let limit = limit * i64::from(r.interval);
+
+        let start = self.next_day?;
+        let mut day = start;
+        while (day - start).num_days() <= limit {
+            self.next_day = day.succ_opt();
+            if matches_day(r, day, origin)
+                && let Some(at) = occurrence_on(r, day, self.tz)
+                && at >= self.from
+            {
+                let period = period_index(r.frequency, day, self.week_start);
+                let first = *self.first_period.get_or_insert(period);
+                if (period - first) % period_len == 0 {
+                    return Some(at);
+                }
+            }
+            day = self.next_day?;
+        }
+        self.next_day = None;
+        None
+    }
+}
+
+impl Iterator for Occurrences {
+    type Item = DateTime<Utc>;
+
+    fn next(&mut self) -> Option<DateTime<Utc>> {
+        if self.recurrence.interval == 0 {
+            return None;
+        }
+        match self.recurrence.frequency {
+            TimeUnit::Second => self.next_sub_day(1),
+            TimeUnit::Minute => self.next_sub_day(60),
+            TimeUnit::Hour => self.next_sub_day(3_600),
+            _ => self.next_calendar(),
+        }
+    }
+}
+
+/// Whether `day` is one of the days the schedule falls on, ignoring the interval.
+fn matches_day(r: &Recurrence, day: NaiveDate, origin: NaiveDate) -> bool {
+    if !r.weekdays.is_empty() {
+        return r.weekdays.contains(&day.weekday());
+    }
+    match r.frequency {
+        TimeUnit::Week => day.weekday() == origin.weekday(),
+        TimeUnit::Month | TimeUnit::Quarter => day.day() == origin.day(),
+        TimeUnit::Year => day.month() == origin.month() && day.day() == origin.day(),
+        _ => true,
+    }
+}
+
+/// The occurrence on a local day: its time of day, or midnight.
+///
+/// Returns `None` if that local time is skipped by a DST change.
+fn occurrence_on(r: &Recurrence, day: NaiveDate, tz: Tz) -> Option<DateTime<Utc>> {
+    let local = day.and_time(r.time.unwrap_or_default());
+    Some(local.and_local_timezone(tz).earliest()?.with_timezone(&Utc))
+}
+
+/// Sequential number of the day, week, month or year containing `day`, with
+/// weeks starting on `week_start`.
+fn period_index(unit: TimeUnit, day: NaiveDate, week_start: Weekday) -> i64 {
+    match unit {
+        TimeUnit::Week => {
+            let days = i64::from(day.num_days_from_ce());
+            (days - i64::from(day.weekday().days_since(week_start))).div_euclid(7)
+        }
+        TimeUnit::Month | TimeUnit::Quarter => i64::from(day.year()) * 12 + i64::from(day.month0()),
+        TimeUnit::Year => i64::from(day.year()),
+        _ => i64::from(day.num_days_from_ce()),
+    }
+}
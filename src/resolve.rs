@@ -1,17 +1,27 @@
-use chrono::{DateTime, Duration, Utc};
+use chrono::{DateTime, Datelike, Duration, Months, NaiveDate, NaiveTime, Utc};
 use chrono_tz::Tz;
 
-use crate::types::ResolvedTime;
+use crate::types::{
+    Boundary, BusinessHours, DateBias, DayPart, DayParts, HourInference, Midnight, OvernightRange,
+    Recurrence, ResolvedTime, TimeUnit,
+};
 
 /// Resolve a relative day offset to midnight (00:00:00) of that day in the user's timezone.
 ///
+/// Days are counted on the local calendar, so the result is correct across DST changes.
 /// Returns `None` if the resulting date cannot be represented (e.g., overflow).
 pub fn resolve_day_offset(days: i64, now: DateTime<Utc>, tz: Tz) -> Option<DateTime<Utc>> {
-    let target = now.checked_add_signed(Duration::days(days))?;
-    let local_date = target.with_timezone(&tz).date_naive();
-    let midnight_local = local_date.and_hms_opt(0, 0, 0)?;
+    let today = now.with_timezone(&tz).date_naive();
+    let local_date = today.checked_add_signed(Duration::days(days))?;
+    local_midnight(local_date, tz)
+}
+
+/// Convert a local calendar date to midnight (00:00:00) of that day in the user's timezone.
+///
+/// Returns `None` if midnight does not exist on that day (e.g., skipped by a DST change).
+fn local_midnight(date: NaiveDate, tz: Tz) -> Option<DateTime<Utc>> {
     Some(
-        midnight_local
+        date.and_hms_opt(0, 0, 0)?
             .and_local_timezone(tz)
             .earliest()?
             .with_timezone(&Utc),
@@ -37,13 +47,7 @@ pub fn resolve_time_on_date(
     minute: u32,
     tz: Tz,
 ) -> Option<ResolvedTime> {
-    let local_date = date.with_timezone(&tz).date_naive();
-    let local_time = local_date.and_hms_opt(hour, minute, 0)?;
-    let utc = local_time
-        .and_local_timezone(tz)
-        .earliest()?
-        .with_timezone(&Utc);
-    Some(ResolvedTime::Point(utc))
+    resolve_clock_time_on_date(date, NaiveTime::from_hms_opt(hour, minute, 0)?, tz)
 }
 
 /// Set time-of-day on the same date as `now`, in the user's timezone.
@@ -58,6 +62,29 @@ pub fn resolve_time_today(
     resolve_time_on_date(now, hour, minute, tz)
 }
 
+/// Set a time of day with seconds, and possibly fractions of a second, on a
+/// given date, interpreting it in the user's timezone.
+///
+/// Returns `None` if the local time does not exist on that day.
+pub fn resolve_clock_time_on_date(
+    date: DateTime<Utc>,
+    time: NaiveTime,
+    tz: Tz,
+) -> Option<ResolvedTime> {
+    local_time_on(date.with_timezone(&tz).date_naive(), time, tz)
+}
+
+/// Set a time of day with seconds on the same date as `now`, in the user's timezone.
+///
+/// Returns `None` if the local time does not exist today.
+pub fn resolve_clock_time_today(
+    time: NaiveTime,
+    now: DateTime<Utc>,
+    tz: Tz,
+) -> Option<ResolvedTime> {
+    resolve_clock_time_on_date(now, time, tz)
+}
+
 /// Resolve "the last hour/minute" as a range ending at `now`.
 ///
 /// Supported unit strings: `"hour"`, `"minute"`.
@@ -74,6 +101,8 @@ pub fn resolve_last_duration(unit: &str, now: DateTime<Utc>) -> Option<ResolvedT
 
 /// Resolve "between X and Y o'clock" on a given date, in the user's timezone.
 ///
+/// A range whose end is earlier than its start ends on the following day;
+/// see [`resolve_clock_range_on_date`] to reject it instead.
 /// Returns `None` if `from_hour` >= 24 or `to_hour` >= 24.
 pub fn resolve_time_range_on_date(
     date: DateTime<Utc>,
@@ -81,18 +110,7 @@ pub fn resolve_time_range_on_date(
     to_hour: u32,
     tz: Tz,
 ) -> Option<ResolvedTime> {
-    let local_date = date.with_timezone(&tz).date_naive();
-    let start = local_date
-        .and_hms_opt(from_hour, 0, 0)?
-        .and_local_timezone(tz)
-        .earliest()?
-        .with_timezone(&Utc);
-    let end = local_date
-        .and_hms_opt(to_hour, 0, 0)?
-        .and_local_timezone(tz)
-        .earliest()?
-        .with_timezone(&Utc);
-    Some(ResolvedTime::Range { start, end })
+    resolve_time_range_with_minutes_on_date(date, from_hour, 0, to_hour, 0, tz)
 }
 
 /// Resolve "between X and Y" on the same date as `now`, in the user's timezone.
@@ -109,6 +127,8 @@ pub fn resolve_time_range_today(
 
 /// Resolve a time range with minute precision on a given date, in the user's timezone.
 ///
+/// See [`resolve_clock_range_on_date`] for ranges whose end is earlier in
+/// the day than their start.
 /// Returns `None` if any hour >= 24 or minute >= 60.
 pub fn resolve_time_range_with_minutes_on_date(
     date: DateTime<Utc>,
@@ -118,18 +138,9 @@ pub fn resolve_time_range_with_minutes_on_date(
     to_min: u32,
     tz: Tz,
 ) -> Option<ResolvedTime> {
-    let local_date = date.with_timezone(&tz).date_naive();
-    let start = local_date
-        .and_hms_opt(from_hour, from_min, 0)?
-        .and_local_timezone(tz)
-        .earliest()?
-        .with_timezone(&Utc);
-    let end = local_date
-        .and_hms_opt(to_hour, to_min, 0)?
-        .and_local_timezone(tz)
-        .earliest()?
-        .with_timezone(&Utc);
-    Some(ResolvedTime::Range { start, end })
+    let from = NaiveTime::from_hms_opt(from_hour, from_min, 0)?;
+    let to = NaiveTime::from_hms_opt(to_hour, to_min, 0)?;
+    resolve_clock_range_on_date(date, from, to, tz, OvernightRange::NextDay)
 }
 
 /// Resolve a time range with minute precision on today's date, in the user's timezone.
@@ -146,13 +157,78 @@ pub fn resolve_time_range_with_minutes_today(
     resolve_time_range_with_minutes_on_date(now, from_hour, from_min, to_hour, to_min, tz)
 }
 
+/// Resolve a range between two times of day, possibly with seconds, on a
+/// given date, in the user's timezone.
+///
+/// If the end is earlier in the day than the start (`"from 22:00 to 2:00"`),
+/// it falls on the following local day, or the range is rejected, as
+/// selected by `overnight`. Both ends are computed from their local time, so
+/// a DST change during the night is accounted for.
+/// Returns `None` if an end does not exist on its local day.
+pub fn resolve_clock_range_on_date(
+    date: DateTime<Utc>,
+    from: NaiveTime,
+    to: NaiveTime,
+    tz: Tz,
+    overnight: OvernightRange,
+) -> Option<ResolvedTime> {
+    let local_date = date.with_timezone(&tz).date_naive();
+    let end_date = if to < from {
+        match overnight {
+            OvernightRange::NextDay => local_date.succ_opt()?,
+            OvernightRange::Reject => return None,
+        }
+    } else {
+        local_date
+    };
+    let ResolvedTime::Point(start) = local_time_on(local_date, from, tz)? else {
+        return None;
+    };
+    let ResolvedTime::Point(end) = local_time_on(end_date, to, tz)? else {
+        return None;
+    };
+    Some(ResolvedTime::Range { start, end })
+}
+
+/// Resolve a range between two times of day, possibly with seconds, on
+/// today's date, in the user's timezone.
+///
+/// Returns `None` if an end does not exist on its local day.
+pub fn resolve_clock_range_today(
+    from: NaiveTime,
+    to: NaiveTime,
+    now: DateTime<Utc>,
+    tz: Tz,
+    overnight: OvernightRange,
+) -> Option<ResolvedTime> {
+    resolve_clock_range_on_date(now, from, to, tz, overnight)
+}
+
+/// Whether `resolved` is a range of part of a day that runs past a local
+/// midnight into the following day, such as `"from 22:00 to 2:00"` or
+/// `"tonight"`.
+///
+/// Ranges that start or end exactly at midnight, such as full days, do not
+/// span it.
+pub fn spans_midnight(resolved: &ResolvedTime, tz: Tz) -> bool {
+    let ResolvedTime::Range { start, end } = resolved else {
+        return false;
+    };
+    let (start, end) = (start.with_timezone(&tz), end.with_timezone(&tz));
+    start.time() != NaiveTime::MIN
+        && end.time() != NaiveTime::MIN
+        && start.date_naive().succ_opt() == Some(end.date_naive())
+}
+
 /// Convert 12-hour time to 24-hour.
 ///
 /// - `"pm"` with hour < 12 adds 12 (e.g., 3pm → 15).
 /// - `"am"` with hour == 12 returns 0 (midnight).
 /// - All other cases return the hour unchanged.
+///
+/// Dotted and uppercase forms (`"p.m."`, `"A.M."`) are accepted.
 pub fn to_24h(hour: u32, ampm: &str) -> u32 {
-    let ampm_lower = ampm.to_lowercase();
+    let ampm_lower = ampm.to_lowercase().replace('.', "");
     if ampm_lower == "pm" && hour < 12 {
         hour + 12
     } else if ampm_lower == "am" && hour == 12 {
@@ -162,6 +238,33 @@ pub fn to_24h(hour: u32, ampm: &str) -> u32 {
     }
 }
 
+/// Read an hour written without am/pm according to `inference`.
+///
+/// Hours outside 1–12 are unambiguous and returned unchanged. Business hours
+/// include their closing hour: with 09:00–17:00, `"at 5"` is 17:00.
+pub fn infer_hour(hour: u32, inference: HourInference, business: BusinessHours) -> u32 {
+    if !(1..=12).contains(&hour) {
+        return hour;
+    }
+    let (am, pm) = (hour % 12, hour % 12 + 12);
+    match inference {
+        HourInference::AsWritten => hour,
+        HourInference::PmUpTo(last) if hour <= last => pm,
+        HourInference::PmUpTo(_) => hour,
+        HourInference::BusinessHours => {
+            let within = |h: u32| {
+                NaiveTime::from_hms_opt(h, 0, 0)
+                    .is_some_and(|t| business.start <= t && t <= business.end)
+            };
+            match (within(am), within(pm)) {
+                (true, false) => am,
+                (false, true) => pm,
+                _ => hour,
+            }
+        }
+    }
+}
+
 /// Compute the day-offset for a weekday relative to `now`, using the user's timezone
 /// to determine the current day of week.
 ///
@@ -219,3 +322,592 @@ pub fn resolve_weekday_date(
     let true_offset = weekday_offset(weekday, direction, now, tz)?;
     resolve_day_offset(true_offset, now, tz)
 }
+
+/// Resolve a weekday written without a direction to midnight of that day.
+///
+/// The coming occurrence (within six days) is used for [`DateBias::Future`],
+/// the most recent one for [`DateBias::Past`], and the closer of the two for
+/// [`DateBias::Nearest`]. When today is `weekday`, every bias resolves to today.
+pub fn resolve_weekday_date_with_bias(
+    weekday: chrono::Weekday,
+    now: DateTime<Utc>,
+    tz: Tz,
+    bias: DateBias,
+) -> Option<DateTime<Utc>> {
+    let ahead = weekday_offset(weekday, 0, now, tz)?;
+    let offset = match bias {
+        DateBias::Future => ahead,
+        DateBias::Past if ahead > 0 => ahead - 7,
+        DateBias::Past => 0,
+        DateBias::Nearest if ahead > 3 => ahead - 7,
+        DateBias::Nearest => ahead,
+    };
+    resolve_day_offset(offset, now, tz)
+}
+
+/// Pick the calendar date for a month and day written without a year.
+///
+/// Candidate years around `today` are filtered by `weekday` (if given) and
+/// the one selected by `bias` is returned. Leap days are looked up in the
+/// surrounding leap years.
+fn pick_year(
+    month: u32,
+    day: u32,
+    weekday: Option<chrono::Weekday>,
+    today: NaiveDate,
+    bias: DateBias,
+) -> Option<NaiveDate> {
+    let year = today.year();
+    let candidates = (year - 4..=year + 4)
+        .filter_map(|y| NaiveDate::from_ymd_opt(y, month, day))
+        .filter(|d| weekday.is_none_or(|wd| d.weekday() == wd));
+    pick_by_bias(candidates, today, bias)
+}
+
+/// Pick the candidate date selected by `bias` relative to `today`.
+fn pick_by_bias(
+    candidates: impl Iterator<Item = NaiveDate>,
+    today: NaiveDate,
+    bias: DateBias,
+) -> Option<NaiveDate> {
+    match bias {
+        DateBias::Past => candidates.filter(|d| *d <= today).max(),
+        DateBias::Future => candidates.filter(|d| *d >= today).min(),
+        DateBias::Nearest => candidates.min_by_key(|d| (*d - today).num_days().abs()),
+    }
+}
+
+/// Resolve a calendar date to midnight of that day (for combining with time specs).
+///
+/// When `year` is `None`, the year is chosen according to `bias`, relative to the
+/// current date in the user's timezone. When `weekday` is given it must agree with
+/// the date; without a year it is used to narrow down the candidate years.
+///
+/// Returns `None` if the date does not exist (e.g., February 30) or the weekday
+/// does not match.
+pub fn resolve_date(
+    year: Option<i32>,
+    month: u32,
+    day: u32,
+    weekday: Option<chrono::Weekday>,
+    now: DateTime<Utc>,
+    tz: Tz,
+    bias: DateBias,
+) -> Option<DateTime<Utc>> {
+    let date = match year {
+        Some(y) => {
+            let date = NaiveDate::from_ymd_opt(y, month, day)?;
+            if weekday.is_some_and(|wd| date.weekday() != wd) {
+                return None;
+            }
+            date
+        }
+        None => {
+            let today = now.with_timezone(&tz).date_naive();
+            pick_year(month, day, weekday, today, bias)?
+        }
+    };
+    local_midnight(date, tz)
+}
+
+/// Resolve a calendar date to a full-day range (midnight to midnight in the user's timezone).
+///
+/// See [`resolve_date`] for how a missing year and the optional weekday are handled.
+pub fn resolve_absolute_date(
+    year: Option<i32>,
+    month: u32,
+    day: u32,
+    weekday: Option<chrono::Weekday>,
+    now: DateTime<Utc>,
+    tz: Tz,
+    bias: DateBias,
+) -> Option<ResolvedTime> {
+    let start = resolve_date(year, month, day, weekday, now, tz, bias)?;
+    resolve_full_day(start, tz)
+}
+
+/// Extend midnight of a day to a full-day range (midnight to midnight in the user's timezone).
+///
+/// Returns `None` if the date arithmetic overflows.
+pub fn resolve_full_day(start: DateTime<Utc>, tz: Tz) -> Option<ResolvedTime> {
+    let next = start.with_timezone(&tz).date_naive().succ_opt()?;
+    let end = local_midnight(next, tz)?;
+    Some(ResolvedTime::Range { start, end })
+}
+
+/// Resolve a day of the month written without a month to midnight of that day.
+///
+/// Candidates are taken from the months around the current one in the user's
+/// timezone, skipping months too short for `day` (`"the 31st"` in February).
+/// The one selected by `bias` is returned. Returns `None` if `day` is not
+/// between 1 and 31.
+pub fn resolve_day_of_month(
+    day: u32,
+    now: DateTime<Utc>,
+    tz: Tz,
+    bias: DateBias,
+) -> Option<DateTime<Utc>> {
+    if !(1..=31).contains(&day) {
+        return None;
+    }
+    let today = now.with_timezone(&tz).date_naive();
+    let first = today.with_day(1)?;
+    let candidates = (-2..=2).filter_map(|offset| add_months(first, offset)?.with_day(day));
+    local_midnight(pick_by_bias(candidates, today, bias)?, tz)
+}
+
+/// Find the `n`th `weekday` of a month: 1 = first, 2 = second, ..., -1 = last.
+///
+/// Returns `None` if the month has no such weekday (e.g., a fifth Monday in a
+/// month with only four) or `n` is out of range.
+pub fn nth_weekday_of_month(
+    year: i32,
+    month: u32,
+    weekday: chrono::Weekday,
+    n: i32,
+) -> Option<NaiveDate> {
+    match n {
+        1..=5 => NaiveDate::from_weekday_of_month_opt(year, month, weekday, u8::try_from(n).ok()?),
+        -1 => {
+            let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+            let last = add_months(first, 1)?.pred_opt()?;
+            let back =
+                (last.weekday().num_days_from_monday() + 7 - weekday.num_days_from_monday()) % 7;
+            last.checked_sub_signed(Duration::days(i64::from(back)))
+        }
+        _ => None,
+    }
+}
+
+/// Resolve the `n`th `weekday` of a named month to midnight of that day.
+///
+/// When `year` is `None`, the occurrence is chosen according to `bias`,
+/// relative to the current date in the user's timezone (see [`resolve_date`]).
+/// Returns `None` if the month has no such weekday.
+pub fn resolve_weekday_of_month(
+    n: i32,
+    weekday: chrono::Weekday,
+    month: u32,
+    year: Option<i32>,
+    now: DateTime<Utc>,
+    tz: Tz,
+    bias: DateBias,
+) -> Option<DateTime<Utc>> {
+    let date = match year {
+        Some(y) => nth_weekday_of_month(y, month, weekday, n)?,
+        None => {
+            let today = now.with_timezone(&tz).date_naive();
+            let year = today.year();
+            let candidates =
+                (year - 1..=year + 1).filter_map(|y| nth_weekday_of_month(y, month, weekday, n));
+            pick_by_bias(candidates, today, bias)?
+        }
+    };
+    local_midnight(date, tz)
+}
+
+/// Resolve a date that recurs once a year, such as a holiday, to midnight of that day.
+///
+/// `date_in` gives the date in a year, or `None` if there is none that year.
+/// When `year` is `None`, the occurrence is chosen according to `bias`,
+/// relative to the current date in the user's timezone (see [`resolve_date`]).
+pub fn resolve_yearly(
+    date_in: impl Fn(i32) -> Option<NaiveDate>,
+    year: Option<i32>,
+    now: DateTime<Utc>,
+    tz: Tz,
+    bias: DateBias,
+) -> Option<DateTime<Utc>> {
+    let date = match year {
+        Some(y) => date_in(y)?,
+        None => {
+            let today = now.with_timezone(&tz).date_naive();
+            let year = today.year();
+            pick_by_bias((year - 1..=year + 1).filter_map(&date_in), today, bias)?
+        }
+    };
+    local_midnight(date, tz)
+}
+
+/// Resolve the `n`th `weekday` of a month relative to now to midnight of that day.
+///
+/// `offset` is the number of months from the current one: 0 = this month,
+/// 1 = next month, -1 = last month. Returns `None` if the month has no such weekday.
+pub fn resolve_weekday_of_relative_month(
+    n: i32,
+    weekday: chrono::Weekday,
+    offset: i64,
+    now: DateTime<Utc>,
+    tz: Tz,
+) -> Option<DateTime<Utc>> {
+    let today = now.with_timezone(&tz).date_naive();
+    let month = add_months(today.with_day(1)?, offset)?;
+    let date = nth_weekday_of_month(month.year(), month.month(), weekday, n)?;
+    local_midnight(date, tz)
+}
+
+/// Shift a date by `months` (negative moves backwards), clamping to the end of the month.
+fn add_months(date: NaiveDate, months: i64) -> Option<NaiveDate> {
+    let n = Months::new(u32::try_from(months.unsigned_abs()).ok()?);
+    if months < 0 {
+        date.checked_sub_months(n)
+    } else {
+        date.checked_add_months(n)
+    }
+}
+
+/// Resolve a relative calendar period to a full range in the user's timezone.
+///
+/// `offset` counts periods from the current one: 0 = "this week", -1 = "last month",
+/// 1 = "next year". Weeks begin on `week_start`; months, quarters and years begin on
+/// their first day. Both bounds are the local midnights that start those days,
+/// so a period ending on a DST change still ends at 00:00 local time.
+///
+/// Returns `None` for units shorter than a week or if the date arithmetic overflows.
+pub fn resolve_period(
+    unit: TimeUnit,
+    offset: i64,
+    now: DateTime<Utc>,
+    tz: Tz,
+    week_start: chrono::Weekday,
+) -> Option<ResolvedTime> {
+    let today = now.with_timezone(&tz).date_naive();
+    let (start, end) = period_dates(unit, offset, today, week_start)?;
+    let start = local_midnight(start, tz)?;
+    let end = local_midnight(end, tz)?;
+    Some(ResolvedTime::Range { start, end })
+}
+
+/// First day and the day after the last day of the week, month, quarter or
+/// year `offset` periods away from the one containing `today`.
+///
+/// Returns `None` for units shorter than a week.
+fn period_dates(
+    unit: TimeUnit,
+    offset: i64,
+    today: NaiveDate,
+    week_start: chrono::Weekday,
+) -> Option<(NaiveDate, NaiveDate)> {
+    match unit {
+        TimeUnit::Week => {
+            let back = today.weekday().days_since(week_start) as i64;
+            let start = today.checked_sub_signed(Duration::days(back))?;
+            let start = start.checked_add_signed(Duration::weeks(offset))?;
+            Some((start, start.checked_add_signed(Duration::weeks(1))?))
+        }
+        TimeUnit::Second | TimeUnit::Minute | TimeUnit::Hour | TimeUnit::Day => None,
+        TimeUnit::Month | TimeUnit::Quarter | TimeUnit::Year => {
+            let (first_month, months) = match unit {
+                TimeUnit::Month => (today.month(), 1),
+                TimeUnit::Quarter => ((today.month() - 1) / 3 * 3 + 1, 3),
+                _ => (1, 12),
+            };
+            let current = NaiveDate::from_ymd_opt(today.year(), first_month, 1)?;
+            let start = add_months(current, offset.checked_mul(months)?)?;
+            Some((start, add_months(start, months)?))
+        }
+    }
+}
+
+/// Set a local time of day on a local date.
+fn local_time_on(date: NaiveDate, time: NaiveTime, tz: Tz) -> Option<ResolvedTime> {
+    let utc = date
+        .and_time(time)
+        .and_local_timezone(tz)
+        .earliest()?
+        .with_timezone(&Utc);
+    Some(ResolvedTime::Point(utc))
+}
+
+/// Resolve the start, middle or end of a day or calendar period.
+///
+/// `offset` counts periods from the current one: 0 = this week, 1 = next
+/// week, -1 = last week. For a day, the start and end are points at the
+/// start and end of business and the middle is noon. For longer periods the
+/// result is the full day of the first day, the middle day (the 15th of a
+/// month, the third day of a week) or the last day.
+///
+/// Returns `None` for units shorter than a day.
+pub fn resolve_boundary(
+    unit: TimeUnit,
+    boundary: Boundary,
+    offset: i64,
+    now: DateTime<Utc>,
+    tz: Tz,
+    week_start: chrono::Weekday,
+    hours: BusinessHours,
+) -> Option<ResolvedTime> {
+    let today = now.with_timezone(&tz).date_naive();
+    if unit == TimeUnit::Day {
+        let date = today.checked_add_signed(Duration::days(offset))?;
+        let time = match boundary {
+            Boundary::Start => hours.start,
+            Boundary::Middle => NaiveTime::from_hms_opt(12, 0, 0)?,
+            Boundary::End => hours.end,
+        };
+        return local_time_on(date, time, tz);
+    }
+    let (start, end) = period_dates(unit, offset, today, week_start)?;
+    let date = match boundary {
+        Boundary::Start => start,
+        Boundary::Middle => {
+            let half = (end - start).num_days() / 2;
+            start.checked_add_signed(Duration::days((half - 1).max(0)))?
+        }
+        Boundary::End => end.pred_opt()?,
+    };
+    resolve_full_day(local_midnight(date, tz)?, tz)
+}
+
+/// Resolve the close of business of a day or calendar period ("EOD", "EOW").
+///
+/// The result is the end of business on the last weekday (Monday to Friday)
+/// of the period, or on the day itself for [`TimeUnit::Day`]. `offset`
+/// counts periods from the current one.
+///
+/// Returns `None` for units shorter than a day.
+pub fn resolve_close_of_business(
+    unit: TimeUnit,
+    offset: i64,
+    now: DateTime<Utc>,
+    tz: Tz,
+    week_start: chrono::Weekday,
+    hours: BusinessHours,
+) -> Option<ResolvedTime> {
+    let today = now.with_timezone(&tz).date_naive();
+    let date = if unit == TimeUnit::Day {
+        today.checked_add_signed(Duration::days(offset))?
+    } else {
+        let (start, end) = period_dates(unit, offset, today, week_start)?;
+        start
+            .iter_days()
+            .take_while(|d| *d < end)
+            .filter(|d| d.weekday().num_days_from_monday() < 5)
+            .last()?
+    };
+    local_time_on(date, hours.end, tz)
+}
+
+/// Resolve the rest of the current day or calendar period: from `now` to its end.
+///
+/// Returns `None` for units shorter than a day.
+pub fn resolve_rest_of(
+    unit: TimeUnit,
+    now: DateTime<Utc>,
+    tz: Tz,
+    week_start: chrono::Weekday,
+) -> Option<ResolvedTime> {
+    let today = now.with_timezone(&tz).date_naive();
+    let end = if unit == TimeUnit::Day {
+        today.succ_opt()?
+    } else {
+        period_dates(unit, 0, today, week_start)?.1
+    };
+    Some(ResolvedTime::Range {
+        start: now,
+        end: local_midnight(end, tz)?,
+    })
+}
+
+/// Resolve "in N units" / "N units ago" relative to `now`.
+///
+/// Seconds, minutes and hours resolve to a `Point` shifted from `now`. Days and
+/// longer units resolve to the full day they land on in the user's timezone;
+/// month, quarter and year offsets are clamped to the end of the target month
+/// (January 31 + 1 month = February 28).
+///
+/// Returns `None` if the arithmetic overflows.
+pub fn resolve_offset(
+    unit: TimeUnit,
+    amount: i64,
+    now: DateTime<Utc>,
+    tz: Tz,
+) -> Option<ResolvedTime> {
+    let duration = match unit {
+        TimeUnit::Second => Duration::try_seconds(amount)?,
+        TimeUnit::Minute => Duration::try_minutes(amount)?,
+        TimeUnit::Hour => Duration::try_hours(amount)?,
+        TimeUnit::Day => return resolve_relative_day(amount, now, tz),
+        TimeUnit::Week => return resolve_relative_day(amount.checked_mul(7)?, now, tz),
+        TimeUnit::Month | TimeUnit::Quarter | TimeUnit::Year => {
+            let months = match unit {
+                TimeUnit::Month => amount,
+                TimeUnit::Quarter => amount.checked_mul(3)?,
+                _ => amount.checked_mul(12)?,
+            };
+            let today = now.with_timezone(&tz).date_naive();
+            let target = add_months(today, months)?;
+            return resolve_relative_day((target - today).num_days(), now, tz);
+        }
+    };
+    Some(ResolvedTime::Point(now.checked_add_signed(duration)?))
+}
+
+/// Resolve an offset of `amount` units from an anchor, as in "two days
+/// before Friday" or "a week from Monday at 9".
+///
+/// A full-day anchor resolves like [`resolve_offset`] counted from that day.
+/// A point anchor keeps its local time of day for days and longer units and
+/// is shifted directly for seconds, minutes and hours.
+///
+/// Returns `None` for other anchors, for units shorter than a day on a
+/// full-day anchor, or if the arithmetic overflows.
+pub fn resolve_anchored_offset(
+    anchor: &ResolvedTime,
+    unit: TimeUnit,
+    amount: i64,
+    tz: Tz,
+) -> Option<ResolvedTime> {
+    let sub_day = matches!(unit, TimeUnit::Second | TimeUnit::Minute | TimeUnit::Hour);
+    match *anchor {
+        ResolvedTime::Point(t) if sub_day => resolve_offset(unit, amount, t, tz),
+        ResolvedTime::Point(t) => {
+            let ResolvedTime::Range { start: day, .. } = resolve_offset(unit, amount, t, tz)?
+            else {
+                return None;
+            };
+            resolve_clock_time_on_date(day, t.with_timezone(&tz).time(), tz)
+        }
+        ResolvedTime::Range { start, .. }
+            if !sub_day && resolve_relative_day(0, start, tz).as_ref() == Some(anchor) =>
+        {
+            resolve_offset(unit, amount, start, tz)
+        }
+        _ => None,
+    }
+}
+
+/// Resolve an amount of a time unit to a [`ResolvedTime::Duration`].
+///
+/// Fractional amounts are rounded to the nearest second. Only units of fixed
+/// length (seconds to weeks) are supported.
+///
+/// Returns `None` for months, quarters and years, or for a negative or
+/// non-finite amount.
+pub fn resolve_duration(unit: TimeUnit, amount: f64) -> Option<ResolvedTime> {
+    if !amount.is_finite() || amount < 0.0 {
+        return None;
+    }
+    let seconds_per_unit = match unit {
+        TimeUnit::Second => 1.0,
+        TimeUnit::Minute => 60.0,
+        TimeUnit::Hour => 3_600.0,
+        TimeUnit::Day => 86_400.0,
+        TimeUnit::Week => 604_800.0,
+        TimeUnit::Month | TimeUnit::Quarter | TimeUnit::Year => return None,
+    };
+    let seconds = (amount * seconds_per_unit).round();
+    if seconds > i64::MAX as f64 {
+        return None;
+    }
+    Some(ResolvedTime::Duration(Duration::try_seconds(
+        seconds as i64,
+    )?))
+}
+
+/// Resolve a range starting at a time of day on `date` and lasting `duration`.
+///
+/// The start is computed with [`resolve_clock_time_on_date`].
+/// Returns `None` if the start does not exist or the end overflows.
+pub fn resolve_time_for_duration(
+    date: DateTime<Utc>,
+    time: NaiveTime,
+    duration: Duration,
+    tz: Tz,
+) -> Option<ResolvedTime> {
+    let ResolvedTime::Point(start) = resolve_clock_time_on_date(date, time, tz)? else {
+        return None;
+    };
+    let end = start.checked_add_signed(duration)?;
+    Some(ResolvedTime::Range { start, end })
+}
+
+/// Resolve a range lasting `duration` and ending at a time of day on `date`.
+///
+/// The end is computed with [`resolve_clock_time_on_date`].
+/// Returns `None` if the end does not exist or the start overflows.
+pub fn resolve_duration_until_time(
+    date: DateTime<Utc>,
+    time: NaiveTime,
+    duration: Duration,
+    tz: Tz,
+) -> Option<ResolvedTime> {
+    let ResolvedTime::Point(end) = resolve_clock_time_on_date(date, time, tz)? else {
+        return None;
+    };
+    let start = end.checked_sub_signed(duration)?;
+    Some(ResolvedTime::Range { start, end })
+}
+
+/// Resolve a repeating schedule to a [`ResolvedTime::Recurrence`].
+///
+/// `time` is the local time of day, if one was given.
+/// Returns `None` if `interval` is zero.
+pub fn resolve_recurrence(
+    frequency: TimeUnit,
+    interval: u32,
+    weekdays: Vec<chrono::Weekday>,
+    time: Option<NaiveTime>,
+) -> Option<ResolvedTime> {
+    if interval == 0 {
+        return None;
+    }
+    Some(ResolvedTime::Recurrence(Recurrence {
+        frequency,
+        interval,
+        weekdays,
+        time,
+    }))
+}
+
+/// Resolve the midnight of a given date, in the user's timezone.
+///
+/// With [`Midnight::EndOfDay`] this is 00:00 of the following day.
+/// Returns `None` if the date arithmetic overflows.
+pub fn resolve_midnight(date: DateTime<Utc>, rule: Midnight, tz: Tz) -> Option<ResolvedTime> {
+    let local_date = date.with_timezone(&tz).date_naive();
+    let day = match rule {
+        Midnight::StartOfDay => local_date,
+        Midnight::EndOfDay => local_date.succ_opt()?,
+    };
+    Some(ResolvedTime::Point(local_midnight(day, tz)?))
+}
+
+/// Resolve a part of the day on a given date, in the user's timezone.
+///
+/// The hours are taken from `parts`. A part that does not end after it starts
+/// (such as the default night, 22:00–06:00) ends on the following day.
+/// Returns `None` if a boundary hour is greater than 24.
+pub fn resolve_day_part(
+    date: DateTime<Utc>,
+    part: DayPart,
+    parts: &DayParts,
+    tz: Tz,
+) -> Option<ResolvedTime> {
+    let (from, to) = parts.bounds(part);
+    if from > 24 || to > 24 {
+        return None;
+    }
+    let local_date = date.with_timezone(&tz).date_naive();
+    let at_hour = |day: NaiveDate, hour: u32| -> Option<DateTime<Utc>> {
+        let (day, hour) = if hour == 24 {
+            (day.succ_opt()?, 0)
+        } else {
+            (day, hour)
+        };
+        Some(
+            day.and_hms_opt(hour, 0, 0)?
+                .and_local_timezone(tz)
+                .earliest()?
+                .with_timezone(&Utc),
+        )
+    };
+    let start = at_hour(local_date, from)?;
+    let end_date = if to <= from {
+        local_date.succ_opt()?
+    } else {
+        local_date
+    };
+    let end = at_hour(end_date, to)?;
+    Some(ResolvedTime::Range { start, end })
+}
@@ -14,10 +14,27 @@ pub struct TimeExpressionScanner {
 }
 
 impl TimeExpressionScanner {
-    pub fn new(languages: Vec<Box<dyn LanguageParser>>, config: ParserConfig) -> Self {
+    pub fn new(mut languages: Vec<Box<dyn LanguageParser>>, config: ParserConfig) -> Self {
+        let custom_names: Vec<&str> = config
+            .holidays
+            .as_deref()
+            .map(|c| {
+                c.custom_holidays()
+                    .iter()
+                    .map(|h| h.name.as_str())
+                    .collect()
+            })
+            .unwrap_or_default();
+        if !custom_names.is_empty() {
+            for lang in &mut languages {
+                lang.set_custom_holidays(&custom_names);
+            }
+        }
+
         let all_keywords: Vec<&str> = languages
             .iter()
             .flat_map(|lang| lang.keywords().iter().copied())
+            .chain(custom_names.iter().copied())
             .collect();
 
         let all_prefixes: Vec<&str> = languages
@@ -56,7 +73,7 @@ impl TimeExpressionScanner {
     /// Times entered by the user are interpreted in the given timezone.
     /// The resolved output remains in UTC.
     pub fn scan_with_tz(&self, text: &str, now: DateTime<Utc>, tz: Tz) -> Vec<TimeMatch> {
-        let has_keywords = self.keyword_filter.find(text).is_some();
+        let has_keywords = self.keyword_filter.find(text).is_some() || has_digit_shape(text);
         let has_prefixes = self.config.report_partial && self.prefix_filter.find(text).is_some();
 
         if !has_keywords && !has_prefixes {
@@ -67,7 +84,11 @@ impl TimeExpressionScanner {
 
         if has_keywords {
             for lang in &self.languages {
-                matches.extend(lang.parse(text, now, tz));
+                let config = ParserConfig {
+                    date_order: Some(self.config.date_order.unwrap_or(lang.date_order())),
+                    ..self.config.clone()
+                };
+                matches.extend(lang.parse(text, now, tz, &config));
             }
         }
 
@@ -119,6 +140,11 @@ impl TimeExpressionScanner {
                             confidence: MatchConfidence::Partial,
                             resolved: ResolvedTime::Point(chrono::Utc::now()),
                             kind: ExpressionKind::RelativeDay,
+                            date_order: None,
+                            tolerance: None,
+                            spans_midnight: false,
+                            timezone: None,
+                            zone_name: None,
                         });
                         return; // Only report one partial match
                     }
@@ -159,3 +185,48 @@ impl TimeExpressionScanner {
         result
     }
 }
+
+/// Unit abbreviations and ordinal suffixes that may follow a number (`2h`,
+/// `90 min`, `2 Std.`, `15th`, `1er`, `15º`).
+const NUMBER_SUFFIXES: &[&str] = &[
+    "h", "hr", "hrs", "s", "sec", "secs", "min", "mins", "std", "st", "nd", "rd", "th", "er", "º",
+];
+
+/// Whether the text holds digits shaped like a date, a clock time, a compact
+/// range, a compact duration or an ordinal (`5.3.`, `3/5`, `15:30`, `9-17`,
+/// `2h`, `5 p.m.`, `15th`, `am 15.`).
+///
+/// These need no keyword to be recognized. Checking for their shape instead
+/// of any digit keeps text that merely holds a number on the fast path.
+fn has_digit_shape(text: &str) -> bool {
+    text.char_indices()
+        .filter(|(_, c)| c.is_ascii_digit())
+        .any(|(i, _)| {
+            let rest = &text[i + 1..];
+            let mut chars = rest.chars();
+            match chars.next() {
+                Some(':' | '.' | ',' | '/') if chars.next().is_some_and(|c| c.is_ascii_digit()) => {
+                    return true;
+                }
+                // A German ordinal: "am 15."
+                Some('.') if rest[1..].chars().next().is_none_or(char::is_whitespace) => {
+                    return true;
+                }
+                _ => {}
+            }
+
+            let rest = rest.trim_start();
+            if let Some(to) = rest.strip_prefix(['-', '–', '—']) {
+                return to.trim_start().starts_with(|c: char| c.is_ascii_digit());
+            }
+            if rest.starts_with(['a', 'A', 'p', 'P']) && rest[1..].starts_with('.') {
+                return true;
+            }
+            let end = rest
+                .find(|c: char| !c.is_alphabetic())
+                .unwrap_or(rest.len());
+            NUMBER_SUFFIXES
+                .iter()
+                .any(|suffix| rest[..end].eq_ignore_ascii_case(suffix))
+        })
+}
@@ -1,6 +1,9 @@
 use chrono::{DateTime, Utc};
 use chrono_tz::Tz;
 use std::ops::Range;
+use std::sync::Arc;
+
+use crate::holiday::HolidayCalendar;
 
 /// A byte-offset span identifying a substring within the input text.
 ///
@@ -50,15 +53,18 @@ impl Span {
 
 /// The resolved concrete time derived from a parsed time expression.
 ///
-/// Every matched expression resolves to either a single point in time
-/// or a time range with an inclusive start and exclusive end.
+/// Every matched expression resolves to a single point in time, a time
+/// range with an inclusive start and exclusive end, or a length of time
+/// that is not anchored to the calendar.
 ///
 /// # Examples
 ///
 /// - `"yesterday at 3pm"` resolves to `ResolvedTime::Point(2026-02-06T15:00:00Z)`
 /// - `"the last hour"` resolves to `ResolvedTime::Range { start: now - 1h, end: now }`
 /// - `"today"` resolves to `ResolvedTime::Range { start: 00:00, end: 00:00+1d }`
+/// - `"1:30h"` resolves to `ResolvedTime::Duration(90min)`
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum ResolvedTime {
     /// A single point in time.
     ///
@@ -76,6 +82,52 @@ pub enum ResolvedTime {
         /// Exclusive end of the time range.
         end: DateTime<Utc>,
     },
+
+    /// A length of time without a position on the calendar.
+    ///
+    /// Produced by expressions like `"2h"`, `"45 minutes"`,
+    /// `"anderthalb Stunden"`, `"une heure et demie"`, or `"hora y media"`.
+    Duration(chrono::Duration),
+
+    /// A repeating schedule.
+    ///
+    /// Produced by expressions like `"every Monday"`, `"daily at 9"`,
+    /// `"jeden Dienstag um 10"`, `"tous les lundis"`, or `"cada viernes"`.
+    /// Use [`Recurrence::occurrences`] to list the concrete points in time.
+    Recurrence(Recurrence),
+
+    /// A time range with an inclusive start and no end.
+    ///
+    /// Produced by expressions like `"since Monday"`, `"after 3pm"`,
+    /// `"ab 14 Uhr"`, `"depuis lundi"`, or `"desde ayer"`.
+    Since(DateTime<Utc>),
+
+    /// A time range with an exclusive end and no start.
+    ///
+    /// Produced by expressions like `"until 5pm"`, `"before lunch"`,
+    /// `"bis Freitag"`, `"jusqu'à demain"`, or `"hasta las 5"`.
+    Until(DateTime<Utc>),
+}
+
+/// A repeating schedule such as `"every other week"` or `"jeden Dienstag um 10"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recurrence {
+    /// The unit the schedule repeats in: [`TimeUnit::Day`] for daily,
+    /// [`TimeUnit::Week`] for weekly, and so on.
+    pub frequency: TimeUnit,
+
+    /// Number of `frequency` units between occurrences: 1 for `"every week"`,
+    /// 2 for `"every other week"`. Never zero.
+    pub interval: u32,
+
+    /// Days of the week the schedule falls on, in the order given.
+    ///
+    /// Empty means no restriction; a weekly schedule then repeats on the
+    /// weekday it starts on.
+    pub weekdays: Vec<chrono::Weekday>,
+
+    /// Local time of day of each occurrence, or `None` for the start of the day.
+    pub time: Option<chrono::NaiveTime>,
 }
 
 /// A complete match result: the text span where the time expression was found,
@@ -92,6 +144,7 @@ pub enum ResolvedTime {
 /// Use [`confidence`](TimeMatch::confidence) to distinguish between complete matches
 /// and partial matches (the user is still typing).
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct TimeMatch {
     /// The byte range in the original input text that was matched.
     pub span: Span,
@@ -110,6 +163,77 @@ pub struct TimeMatch {
 
     /// The category of time expression that was matched.
     pub kind: ExpressionKind,
+
+    /// The field order used to read a numeric date in this match, if any.
+    ///
+    /// `Some` only for matches containing a numeric date such as
+    /// `"05.03.2026"` or `"3/5"`. Lets a GUI show how an ambiguous slash date
+    /// was interpreted.
+    pub date_order: Option<DateOrder>,
+
+    /// How far the meant time may lie from the resolved one, if the
+    /// expression was hedged.
+    ///
+    /// `Some` for approximate times such as `"around 3pm"`, `"gegen 15 Uhr"`,
+    /// `"vers 15h"` or `"sobre las 3"`, set to
+    /// [`ParserConfig::approximate_tolerance`]. A GUI can show these as
+    /// `"~15:00"`; [`widened`](TimeMatch::widened) applies the tolerance.
+    pub tolerance: Option<chrono::Duration>,
+
+    /// Whether the resolved range runs past a local midnight into the
+    /// following day.
+    ///
+    /// `true` for overnight ranges such as `"from 22:00 to 2:00"`,
+    /// `"von 23 bis 1 Uhr"` or `"tonight"`; see
+    /// [`resolve::spans_midnight`](crate::resolve::spans_midnight).
+    pub spans_midnight: bool,
+
+    /// The time zone named in the text and used to resolve this match, if
+    /// any.
+    ///
+    /// `Some` for expressions followed by a zone such as `"9am PST"`,
+    /// `"um 15 Uhr MESZ"`, `"14h UTC+2"` or `"3pm New York time"`; they are
+    /// resolved in that zone instead of [`ParserConfig::timezone`]. `None`
+    /// when the configured zone was used.
+    ///
+    /// An abbreviation or UTC offset names a fixed offset and is reported
+    /// with the zone of that offset (`Etc/GMT+8` for `"PST"`);
+    /// [`zone_name`](TimeMatch::zone_name) keeps the designator as written.
+    pub timezone: Option<Tz>,
+
+    /// The zone mention as written in the text, if any: `"PST"`, `"MESZ"`,
+    /// `"UTC+2"`, `"Europe/London"` or `"New York time"`.
+    ///
+    /// `Some` exactly when [`timezone`](TimeMatch::timezone) is, so a GUI
+    /// can show the zone the way the user wrote it.
+    pub zone_name: Option<String>,
+}
+
+impl TimeMatch {
+    /// The resolved time, widened by the [`tolerance`](TimeMatch::tolerance)
+    /// on both sides.
+    ///
+    /// A point becomes a range centered on it, a range grows at both ends
+    /// and an open range at its one bound. Exact matches, durations and
+    /// recurrences are returned unchanged.
+    pub fn widened(&self) -> ResolvedTime {
+        let Some(tolerance) = self.tolerance else {
+            return self.resolved.clone();
+        };
+        match self.resolved {
+            ResolvedTime::Point(t) => ResolvedTime::Range {
+                start: t - tolerance,
+                end: t + tolerance,
+            },
+            ResolvedTime::Range { start, end } => ResolvedTime::Range {
+                start: start - tolerance,
+                end: end + tolerance,
+            },
+            ResolvedTime::Since(start) => ResolvedTime::Since(start - tolerance),
+            ResolvedTime::Until(end) => ResolvedTime::Until(end + tolerance),
+            _ => self.resolved.clone(),
+        }
+    }
 }
 
 /// Confidence level of a match, indicating whether the parser has seen a
@@ -138,6 +262,7 @@ pub enum MatchConfidence {
 /// This enum lets callers distinguish the structural form of a match, which
 /// can be useful for UI presentation or further processing.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum ExpressionKind {
     /// A bare relative day keyword.
     ///
@@ -145,13 +270,21 @@ pub enum ExpressionKind {
     /// Resolves to a full-day range (midnight to midnight).
     RelativeDay,
 
-    /// A relative day offset with a numeric component.
+    /// A relative offset of days or longer units with a numeric component.
     ///
-    /// Examples: `"in 4 days"`, `"two days ago"`, `"vor 3 Tagen"`,
-    /// `"il y a 3 jours"`, `"hace 2 días"`.
-    /// Resolves to a full-day range.
+    /// Examples: `"in 4 days"`, `"two days ago"`, `"in 3 weeks"`,
+    /// `"vor 3 Tagen"`, `"il y a un mois"`, `"hace 2 años"`.
+    /// Resolves to a full-day range. Month and year offsets are clamped to
+    /// the end of the target month (`Jan 31` + 1 month = `Feb 28`).
     RelativeDayOffset,
 
+    /// A relative offset of seconds, minutes or hours with a numeric component.
+    ///
+    /// Examples: `"in 30 minutes"`, `"two hours ago"`, `"vor 2 Stunden"`,
+    /// `"dans 10 secondes"`, `"hace una hora"`.
+    /// Resolves to a single point in time.
+    RelativeTimeOffset,
+
     /// A specific time of day (on the current date unless combined).
     ///
     /// Examples: `"at 3pm"`, `"13 o'clock"`, `"um 15 Uhr"`, `"à 13h"`,
@@ -166,17 +299,315 @@ pub enum ExpressionKind {
     /// Resolves to a range with start and end.
     TimeRange,
 
-    /// A combined expression pairing a relative day with a time specification
-    /// or time range.
+    /// A combined expression pairing a relative day with a time specification,
+    /// time range, or part of the day.
     ///
-    /// Examples: `"yesterday at 3pm"`, `"gestern um 15 Uhr"`,
+    /// Examples: `"yesterday at 3pm"`, `"gestern um 15 Uhr"`, `"tomorrow morning"`,
     /// `"tomorrow between 9 and 12"`, `"hier à 13h"`, `"ayer a las 3"`.
     /// Resolves to either a point or range on the specified day.
     Combined,
+
+    /// A calendar date written with a month name, optionally with a year
+    /// and a leading weekday.
+    ///
+    /// Examples: `"March 5"`, `"Friday, March 6"`, `"5th of March 2027"`,
+    /// `"am 5. März"`, `"le 5 mars"`, `"el 5 de marzo"`.
+    /// Resolves to a full-day range.
+    AbsoluteDate,
+
+    /// A part of the day, on today's date unless combined with a day.
+    ///
+    /// Examples: `"this morning"`, `"tonight"`, `"last night"`, `"ce soir"`,
+    /// `"esta tarde"`, `"anoche"`.
+    /// Resolves to a range bounded by [`ParserConfig::day_parts`].
+    PartOfDay,
+
+    /// A standalone length of time.
+    ///
+    /// Examples: `"2h"`, `"1:30h"`, `"90 min"`, `"an hour and a half"`,
+    /// `"eine Viertelstunde"`, `"une demi-heure"`, `"dos horas y cuarto"`.
+    /// Resolves to a duration.
+    Duration,
+
+    /// A calendar period relative to the current one.
+    ///
+    /// Examples: `"this week"`, `"last month"`, `"next year"`,
+    /// `"letzte Woche"`, `"le mois dernier"`, `"el año que viene"`.
+    /// Resolves to a range covering the whole period.
+    RelativePeriod,
+
+    /// An ordinal weekday of a named or relative month.
+    ///
+    /// Examples: `"the first Monday of March"`, `"last Friday of the month"`,
+    /// `"am zweiten Dienstag im Mai"`, `"le premier lundi du mois"`,
+    /// `"el último viernes de marzo"`.
+    /// Resolves to a full-day range.
+    WeekdayOfMonth,
+
+    /// A day of the month written without a month.
+    ///
+    /// Examples: `"on the 15th"`, `"am 15."`, `"am dritten"`, `"le 15"`,
+    /// `"le premier"`, `"el día 15"`.
+    /// Resolves to a full-day range in the month picked by
+    /// [`ParserConfig::day_of_month_bias`].
+    DayOfMonth,
+
+    /// A repeating schedule, optionally with a time of day.
+    ///
+    /// Examples: `"every Monday"`, `"every other week"`, `"daily at 9"`,
+    /// `"jeden Dienstag um 10"`, `"tous les lundis"`, `"cada viernes"`.
+    /// Resolves to a [`ResolvedTime::Recurrence`].
+    Recurring,
+
+    /// The start, middle or end of a day or calendar period, or a close of
+    /// business.
+    ///
+    /// Examples: `"by end of month"`, `"start of next week"`, `"EOD"`,
+    /// `"Ende der Woche"`, `"fin de mois"`, `"a finales de semana"`.
+    /// Resolves to a point for a day (see [`ParserConfig::business_hours`])
+    /// and to a full-day range for longer periods.
+    Boundary,
+
+    /// A named holiday, built in or defined by the configured calendar.
+    ///
+    /// Examples: `"Christmas"`, `"Easter Monday"`, `"zu Ostern"`,
+    /// `"à Noël"`, `"el Día de Reyes"`.
+    /// Resolves to a full-day range; without a year, the occurrence is
+    /// picked by [`ParserConfig::year_bias`].
+    Holiday,
+
+    /// A range open at one end: a day, date or time after a preposition
+    /// such as "since", "until", "before" or "after".
+    ///
+    /// Examples: `"since Monday"`, `"until 5pm"`, `"seit gestern"`,
+    /// `"ab 14 Uhr"`, `"jusqu'à demain"`, `"hasta las 5"`.
+    /// Resolves to a [`ResolvedTime::Since`] or [`ResolvedTime::Until`].
+    OpenRange,
+
+    /// A length of time counted from a day, date or time before or after it.
+    ///
+    /// Examples: `"three days after Christmas"`, `"a week from Monday"`,
+    /// `"eine Woche nach dem 5. März"`, `"deux jours avant vendredi"`,
+    /// `"dos días después del lunes"`.
+    /// Resolves to a full-day range, or to a point if the anchor has a time.
+    AnchoredOffset,
+}
+
+/// A part of the day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DayPart {
+    /// `"morning"`, `"Morgen"`, `"matin"`, `"mañana"`.
+    Morning,
+
+    /// `"afternoon"`, `"Nachmittag"`, `"après-midi"`, `"tarde"`.
+    Afternoon,
+
+    /// `"evening"`, `"Abend"`, `"soir"`.
+    Evening,
+
+    /// `"night"`, `"tonight"`, `"Nacht"`, `"nuit"`, `"noche"`.
+    Night,
+}
+
+/// Local-time boundaries of the parts of the day, as `(start_hour, end_hour)`.
+///
+/// The end hour is exclusive and may be `24`. A part whose end is not after
+/// its start (the default night, `22`–`6`) ends on the following day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayParts {
+    /// Defaults to `(6, 12)`.
+    pub morning: (u32, u32),
+
+    /// Defaults to `(12, 18)`.
+    pub afternoon: (u32, u32),
+
+    /// Defaults to `(18, 22)`.
+    pub evening: (u32, u32),
+
+    /// Defaults to `(22, 6)`.
+    pub night: (u32, u32),
+}
+
+impl DayParts {
+    /// The `(start_hour, end_hour)` boundaries of `part`.
+    pub fn bounds(&self, part: DayPart) -> (u32, u32) {
+        match part {
+            DayPart::Morning => self.morning,
+            DayPart::Afternoon => self.afternoon,
+            DayPart::Evening => self.evening,
+            DayPart::Night => self.night,
+        }
+    }
+}
+
+impl Default for DayParts {
+    fn default() -> Self {
+        Self {
+            morning: (6, 12),
+            afternoon: (12, 18),
+            evening: (18, 22),
+            night: (22, 6),
+        }
+    }
+}
+
+/// The local working hours, used for `"EOD"`, `"close of business"` and the
+/// start and end of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BusinessHours {
+    /// Start of the working day. Defaults to 09:00.
+    pub start: chrono::NaiveTime,
+
+    /// End of the working day. Defaults to 17:00.
+    pub end: chrono::NaiveTime,
+}
+
+impl Default for BusinessHours {
+    fn default() -> Self {
+        Self {
+            start: chrono::NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            end: chrono::NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+        }
+    }
+}
+
+/// Which part of a day or calendar period a boundary expression refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Boundary {
+    /// `"start of next week"`, `"Anfang des Monats"`, `"début de mois"`.
+    Start,
+
+    /// `"mid-month"`, `"Mitte der Woche"`, `"a mediados de mes"`.
+    Middle,
+
+    /// `"end of month"`, `"Ende der Woche"`, `"fin de mois"`, `"a finales de semana"`.
+    End,
+}
+
+/// A unit of clock or calendar time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TimeUnit {
+    /// One second.
+    Second,
+
+    /// One minute.
+    Minute,
+
+    /// One hour.
+    Hour,
+
+    /// One calendar day.
+    Day,
+
+    /// Seven days, starting on [`ParserConfig::week_start`].
+    Week,
+
+    /// A calendar month.
+    Month,
+
+    /// Three calendar months, starting in January, April, July or October.
+    Quarter,
+
+    /// A calendar year.
+    Year,
+}
+
+/// The order of the day, month and year fields in a numeric date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DateOrder {
+    /// Day first: `05.03.2026`, `05/03/2026` (most of Europe, Latin America).
+    DayMonthYear,
+
+    /// Month first: `3/5/2026` (United States).
+    MonthDayYear,
+
+    /// Year first: `2026-03-05` (ISO 8601, East Asia).
+    YearMonthDay,
+}
+
+/// Which occurrence to pick when an expression leaves part of a date open.
+///
+/// Used for example when a calendar date is written without a year
+/// (`"March 5"`): the same month and day occur once every year, and the
+/// bias decides which of them is meant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum DateBias {
+    /// Prefer the most recent occurrence on or before today.
+    ///
+    /// Suits time-tracking input, which mostly refers to the past.
+    Past,
+
+    /// Prefer the next occurrence on or after today.
+    ///
+    /// Suits scheduling input, which mostly refers to the future.
+    Future,
+
+    /// Prefer the occurrence closest to today, in either direction.
+    #[default]
+    Nearest,
+}
+
+/// Which end of a day `"midnight"` refers to.
+///
+/// `"Friday midnight"`, `"morgen um Mitternacht"` and a bare `"midnight"`
+/// (meaning today's) are resolved with this rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum Midnight {
+    /// 00:00 at the start of the day: `"Friday midnight"` is the night
+    /// from Thursday into Friday.
+    StartOfDay,
+
+    /// 00:00 at the end of the day: `"Friday midnight"` is the night
+    /// from Friday into Saturday.
+    #[default]
+    EndOfDay,
+}
+
+/// How to read an hour from 1 to 12 written without `"am"` or `"pm"`, such as
+/// `"at 3"` or `"from 9 to 5"`.
+///
+/// Applies to English and to compact ranges (`"9-5"`) in every language;
+/// the other languages otherwise use the 24-hour clock. Hours written with a
+/// leading zero (`"at 03:00"`) are always read as written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum HourInference {
+    /// On the 24-hour clock: `"at 3"` is 03:00.
+    #[default]
+    AsWritten,
+
+    /// Hours from 1 up to and including the given hour are in the afternoon:
+    /// with `PmUpTo(7)`, `"at 3"` is 15:00 and `"at 8"` is 08:00.
+    PmUpTo(u32),
+
+    /// The reading that falls within [`ParserConfig::business_hours`]: with
+    /// the default 09:00–17:00, `"at 3"` is 15:00 and `"at 10"` is 10:00.
+    /// Hours with both or neither reading inside are read as written.
+    BusinessHours,
+}
+
+/// What to do with a time range whose end is earlier in the day than its
+/// start, such as `"from 22:00 to 2:00"` or `"von 23 bis 1 Uhr"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum OvernightRange {
+    /// The range ends on the following day: `"from 22:00 to 2:00"` lasts
+    /// four hours.
+    #[default]
+    NextDay,
+
+    /// The expression is not recognized as a range.
+    Reject,
 }
 
 /// Configuration for the [`TimeExpressionScanner`](crate::scanner::TimeExpressionScanner).
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct ParserConfig {
     /// Whether to report partial (prefix) matches while the user is typing.
     ///
@@ -197,6 +628,96 @@ pub struct ParserConfig {
     /// interpreted in this timezone. The resolved output remains in UTC.
     /// Defaults to `Tz::UTC`.
     pub timezone: Tz,
+
+    /// Which year to pick for calendar dates written without one.
+    ///
+    /// `"March 5"` is resolved to the occurrence selected by this bias
+    /// relative to the current local date. Defaults to [`DateBias::Nearest`].
+    pub year_bias: DateBias,
+
+    /// Which month to pick for a day of the month written without one.
+    ///
+    /// `"the 15th"` is resolved to the occurrence selected by this bias among
+    /// the current, previous and next months; months too short for the day
+    /// are skipped. Defaults to [`DateBias::Nearest`].
+    pub day_of_month_bias: DateBias,
+
+    /// Which occurrence to pick for a weekday written without next/last/this.
+    ///
+    /// `"on Friday"` is resolved to this week's Friday or the one before or
+    /// after, as selected by this bias: the coming one for
+    /// [`DateBias::Future`], the most recent for [`DateBias::Past`], and the
+    /// closer of the two for [`DateBias::Nearest`]. When today is that
+    /// weekday, every bias resolves to today. Defaults to [`DateBias::Nearest`].
+    pub weekday_bias: DateBias,
+
+    /// How to read ambiguous slash dates such as `"3/5/2026"`.
+    ///
+    /// When `None`, each language uses its own convention: month first for
+    /// English, day first for German, French and Spanish. ISO (`2026-03-05`)
+    /// and dotted (`05.03.2026`) dates always use their fixed order, and a
+    /// slash date whose first field cannot be a month (`13/5`) is read the
+    /// other way round. Defaults to `None`.
+    pub date_order: Option<DateOrder>,
+
+    /// The first day of the week.
+    ///
+    /// Determines the range of week expressions such as `"this week"` or
+    /// `"letzte Woche"`. Defaults to [`Weekday::Mon`](chrono::Weekday::Mon).
+    pub week_start: chrono::Weekday,
+
+    /// The hours covered by parts of the day such as `"this morning"` or
+    /// `"heute Abend"`. Defaults to [`DayParts::default`].
+    pub day_parts: DayParts,
+
+    /// Which midnight a day's `"midnight"` refers to.
+    ///
+    /// Decides whether `"Friday midnight"` is the start or the end of Friday.
+    /// Defaults to [`Midnight::EndOfDay`].
+    pub midnight: Midnight,
+
+    /// The working hours that `"EOD"`, `"close of business"`, `"Feierabend"`
+    /// and the start and end of a day refer to. Defaults to
+    /// [`BusinessHours::default`], 09:00 to 17:00.
+    pub business_hours: BusinessHours,
+
+    /// The tolerance reported for approximate times such as `"around 3pm"`
+    /// or `"gegen 15 Uhr"` in [`TimeMatch::tolerance`]. Defaults to 15
+    /// minutes.
+    pub approximate_tolerance: chrono::Duration,
+
+    /// How to resolve time ranges that end earlier in the day than they
+    /// start. Defaults to [`OvernightRange::NextDay`].
+    pub overnight_ranges: OvernightRange,
+
+    /// Whether to recognize compact time ranges such as `"9-12"`,
+    /// `"9:15–11:45"` or `"0930-1200"`, as written in timesheets.
+    ///
+    /// These are easily confused with page numbers or scores in prose, so
+    /// they are only matched when enabled. Ranges with a preposition
+    /// (`"from 9:00 - 11:30"`) or a relative day (`"today 10:15 - 13:45"`)
+    /// are always matched. Defaults to `false`.
+    pub compact_ranges: bool,
+
+    /// How to read English hours and the hours of compact ranges written
+    /// without `"am"` or `"pm"`. Defaults to [`HourInference::AsWritten`].
+    pub hour_inference: HourInference,
+
+    /// The holiday calendar used to recognize and date holiday names.
+    ///
+    /// `"Christmas"`, `"Ostermontag"` or `"el Día de Reyes"` are recognized
+    /// only if the calendar observes them, and its custom holidays are
+    /// recognized by name. When `None`, every built-in
+    /// [`Holiday`](crate::holiday::Holiday) is recognized. Defaults to `None`.
+    pub holidays: Option<Arc<dyn HolidayCalendar>>,
+
+    /// Time zone abbreviations to recognize after a time, such as
+    /// `("CST", Tz::Asia__Shanghai)`.
+    ///
+    /// These take precedence over the built-in table, which reads abbreviations
+    /// used for several zones the most common way: `"CST"` is US Central
+    /// time and `"IST"` India Standard Time. Defaults to empty.
+    pub zone_abbreviations: Vec<(String, Tz)>,
 }
 
 impl Default for ParserConfig {
@@ -205,6 +726,20 @@ impl Default for ParserConfig {
             report_partial: true,
             max_matches: 10,
             timezone: Tz::UTC,
+            year_bias: DateBias::Nearest,
+            day_of_month_bias: DateBias::Nearest,
+            weekday_bias: DateBias::Nearest,
+            date_order: None,
+            week_start: chrono::Weekday::Mon,
+            day_parts: DayParts::default(),
+            midnight: Midnight::EndOfDay,
+            business_hours: BusinessHours::default(),
+            approximate_tolerance: chrono::Duration::minutes(15),
+            overnight_ranges: OvernightRange::NextDay,
+            compact_ranges: false,
+            hour_inference: HourInference::AsWritten,
+            holidays: None,
+            zone_abbreviations: Vec::new(),
         }
     }
 }
@@ -0,0 +1,191 @@
+use chrono::{TimeZone, Utc};
+use clockwords::{
+    DateBias, ExpressionKind, ParserConfig, ResolvedTime, Span, TimeExpressionScanner,
+    scanner_for_languages,
+};
+
+/// Saturday Feb 7, 2026 14:30:00 UTC
+fn now() -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, 7, 14, 30, 0).unwrap()
+}
+
+/// Helper: create an English scanner with a specific year bias.
+fn scanner_with_bias(bias: DateBias) -> TimeExpressionScanner {
+    let languages: Vec<Box<dyn clockwords::lang::LanguageParser>> =
+        vec![Box::new(clockwords::lang::en::English::new())];
+    let config = ParserConfig {
+        year_bias: bias,
+        ..Default::default()
+    };
+    TimeExpressionScanner::new(languages, config)
+}
+
+fn day(y: i32, m: u32, d: u32) -> ResolvedTime {
+    let start = Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap();
+    ResolvedTime::Range {
+        start,
+        end: start + chrono::Duration::days(1),
+    }
+}
+
+/// Test expecting exactly one absolute date match covering the given day.
+macro_rules! date_test {
+    ($name:ident, $lang:expr, $input:expr, $y:expr, $m:expr, $d:expr) => {
+        #[test]
+        fn $name() {
+            let s = scanner_for_languages(&[$lang]);
+            let m = s.scan($input, now());
+            assert_eq!(
+                m.len(),
+                1,
+                "expected 1 match for {:?}, got {}",
+                $input,
+                m.len()
+            );
+            assert_eq!(m[0].kind, ExpressionKind::AbsoluteDate);
+            assert_eq!(m[0].span, Span::new(0, $input.len()));
+            assert_eq!(m[0].resolved, day($y, $m, $d));
+        }
+    };
+}
+
+// ================================================================
+//  English
+// ================================================================
+
+date_test!(en_march_5, "en", "March 5", 2026, 3, 5);
+date_test!(en_mar_dot_5th, "en", "Mar. 5th", 2026, 3, 5);
+date_test!(en_march_5_comma_year, "en", "March 5, 2027", 2027, 3, 5);
+date_test!(en_5th_of_march_year, "en", "5th of March 2027", 2027, 3, 5);
+date_test!(en_the_5th_of_march, "en", "the 5th of March", 2026, 3, 5);
+date_test!(en_5_march, "en", "5 March", 2026, 3, 5);
+date_test!(en_friday_march_6, "en", "Friday, March 6", 2026, 3, 6);
+date_test!(en_dec_24_nearest_is_past, "en", "Dec 24", 2025, 12, 24);
+
+// "Friday, March 5": March 5 is a Thursday in 2026 but a Friday in 2027,
+// so the weekday selects the year.
+date_test!(en_weekday_selects_year, "en", "Friday, March 5", 2027, 3, 5);
+
+#[test]
+fn en_weekday_mismatch_with_year_rejected() {
+    let s = scanner_for_languages(&["en"]);
+    let m = s.scan("Monday, March 6, 2026", now());
+    assert_eq!(m.len(), 0);
+}
+
+#[test]
+fn en_invalid_date_rejected() {
+    let s = scanner_for_languages(&["en"]);
+    let m = s.scan("February 30", now());
+    assert_eq!(m.len(), 0);
+}
+
+#[test]
+fn en_embedded_in_sentence() {
+    let s = scanner_for_languages(&["en"]);
+    let text = "The report is due March 5, 2027 at the latest";
+    let m = s.scan(text, now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(&text[m[0].span.as_range()], "March 5, 2027");
+    assert_eq!(m[0].resolved, day(2027, 3, 5));
+}
+
+date_test!(en_capitalized_may, "en", "May 5", 2026, 5, 5);
+date_test!(en_lowercase_may_ordinal, "en", "may 5th", 2026, 5, 5);
+date_test!(en_lowercase_may_year, "en", "may 5, 2027", 2027, 5, 5);
+date_test!(en_on_lowercase_may, "en", "on may 5", 2026, 5, 5);
+
+#[test]
+fn en_modal_may_is_not_a_date() {
+    let s = scanner_for_languages(&["en"]);
+    assert!(s.scan("I may 5 go", now()).is_empty());
+
+    let text = "due by may 5";
+    let m = s.scan(text, now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(&text[m[0].span.as_range()], "may 5");
+    assert_eq!(m[0].resolved, day(2026, 5, 5));
+}
+
+// ================================================================
+//  Year bias
+// ================================================================
+
+#[test]
+fn bias_past_picks_previous_year() {
+    let m = scanner_with_bias(DateBias::Past).scan("March 5", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].resolved, day(2025, 3, 5));
+}
+
+#[test]
+fn bias_future_picks_next_year() {
+    let m = scanner_with_bias(DateBias::Future).scan("January 10", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].resolved, day(2027, 1, 10));
+}
+
+#[test]
+fn bias_includes_today() {
+    for bias in [DateBias::Past, DateBias::Future, DateBias::Nearest] {
+        let m = scanner_with_bias(bias).scan("February 7", now());
+        assert_eq!(m.len(), 1);
+        assert_eq!(m[0].resolved, day(2026, 2, 7), "bias {bias:?}");
+    }
+}
+
+#[test]
+fn bias_leap_day_uses_leap_year() {
+    let m = scanner_with_bias(DateBias::Future).scan("February 29", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].resolved, day(2028, 2, 29));
+}
+
+// ================================================================
+//  German
+// ================================================================
+
+date_test!(de_am_5_maerz, "de", "am 5. März", 2026, 3, 5);
+date_test!(de_5_maerz_ohne_punkt, "de", "5 Maerz", 2026, 3, 5);
+date_test!(de_5_maerz_jahr, "de", "5. März 2027", 2027, 3, 5);
+date_test!(
+    de_freitag_den_6_maerz,
+    "de",
+    "Freitag, den 6. März",
+    2026,
+    3,
+    6
+);
+date_test!(de_24_dez, "de", "24. Dez.", 2025, 12, 24);
+
+// ================================================================
+//  French
+// ================================================================
+
+date_test!(fr_le_5_mars, "fr", "le 5 mars", 2026, 3, 5);
+date_test!(fr_1er_janvier_annee, "fr", "1er janvier 2027", 2027, 1, 1);
+date_test!(fr_vendredi_6_mars, "fr", "vendredi 6 mars", 2026, 3, 6);
+date_test!(fr_15_aout_sans_accent, "fr", "15 aout", 2025, 8, 15);
+
+// ================================================================
+//  Spanish
+// ================================================================
+
+date_test!(es_el_5_de_marzo, "es", "el 5 de marzo", 2026, 3, 5);
+date_test!(
+    es_5_de_marzo_de_2027,
+    "es",
+    "5 de marzo de 2027",
+    2027,
+    3,
+    5
+);
+date_test!(
+    es_viernes_6_de_marzo,
+    "es",
+    "viernes, 6 de marzo",
+    2026,
+    3,
+    6
+);
+date_test!(es_el_dia_1_de_enero, "es", "el día 1 de enero", 2026, 1, 1);
@@ -0,0 +1,166 @@
+use chrono::{TimeZone, Utc};
+use clockwords::{ExpressionKind, ResolvedTime, Span, scanner_for_languages};
+
+/// Saturday Feb 7, 2026 14:30:00 UTC
+fn now() -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, 7, 14, 30, 0).unwrap()
+}
+
+/// Full day in UTC, given as (year, month, day).
+fn day(y: i32, m: u32, d: u32) -> ResolvedTime {
+    let start = Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap();
+    ResolvedTime::Range {
+        start,
+        end: start + chrono::Duration::days(1),
+    }
+}
+
+/// Point in time on the given day of February 2026, in UTC.
+fn at(d: u32, h: u32, min: u32) -> ResolvedTime {
+    ResolvedTime::Point(Utc.with_ymd_and_hms(2026, 2, d, h, min, 0).unwrap())
+}
+
+/// Test expecting exactly one anchored offset covering the whole input.
+macro_rules! offset_test {
+    ($name:ident, $lang:expr, $input:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            let s = scanner_for_languages(&[$lang]);
+            let m = s.scan($input, now());
+            assert_eq!(
+                m.len(),
+                1,
+                "expected 1 match for {:?}, got {}",
+                $input,
+                m.len()
+            );
+            assert_eq!(m[0].kind, ExpressionKind::AnchoredOffset);
+            assert_eq!(m[0].span, Span::new(0, $input.len()));
+            assert_eq!(m[0].resolved, $expected);
+        }
+    };
+}
+
+// ================================================================
+//  English
+// ================================================================
+
+offset_test!(
+    en_days_after_christmas,
+    "en",
+    "three days after Christmas",
+    day(2025, 12, 28)
+);
+offset_test!(
+    en_days_before_next_friday,
+    "en",
+    "two days before next Friday",
+    day(2026, 2, 18)
+);
+offset_test!(
+    en_week_from_monday,
+    "en",
+    "a week from Monday",
+    day(2026, 2, 16)
+);
+offset_test!(
+    en_weeks_from_today,
+    "en",
+    "two weeks from today",
+    day(2026, 2, 21)
+);
+offset_test!(
+    en_month_after_clamped,
+    "en",
+    "a month after January 31",
+    day(2026, 2, 28)
+);
+offset_test!(
+    en_days_keep_time,
+    "en",
+    "two days after tomorrow at 3pm",
+    at(10, 15, 0)
+);
+offset_test!(
+    en_hours_before_time,
+    "en",
+    "2 hours before 5pm",
+    at(7, 15, 0)
+);
+
+// ================================================================
+//  German, French, Spanish
+// ================================================================
+
+offset_test!(
+    de_week_after_date,
+    "de",
+    "eine Woche nach dem 5. März",
+    day(2026, 3, 12)
+);
+offset_test!(
+    de_days_before_weekday,
+    "de",
+    "zwei Tage vor Freitag",
+    day(2026, 2, 4)
+);
+offset_test!(
+    de_hours_after_time,
+    "de",
+    "2 Stunden nach morgen um 9 Uhr",
+    at(8, 11, 0)
+);
+offset_test!(
+    fr_days_before_weekday,
+    "fr",
+    "deux jours avant vendredi",
+    day(2026, 2, 4)
+);
+offset_test!(
+    fr_week_after_date,
+    "fr",
+    "une semaine après le 5 mars",
+    day(2026, 3, 12)
+);
+offset_test!(
+    es_days_after_weekday,
+    "es",
+    "dos días después del lunes",
+    day(2026, 2, 11)
+);
+offset_test!(
+    es_week_before_holiday,
+    "es",
+    "una semana antes de Navidad",
+    day(2025, 12, 18)
+);
+
+// ================================================================
+//  Embedding and rejection
+// ================================================================
+
+#[test]
+fn embedded_in_sentence() {
+    let s = scanner_for_languages(&["en"]);
+    let text = "let's meet three days after Christmas please";
+    let m = s.scan(text, now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(&text[m[0].span.as_range()], "three days after Christmas");
+    assert_eq!(m[0].resolved, day(2025, 12, 28));
+}
+
+#[test]
+fn anchor_with_zone() {
+    let s = scanner_for_languages(&["en"]);
+    let m = s.scan("2 days before Friday at 9am PST", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].kind, ExpressionKind::AnchoredOffset);
+    assert_eq!(m[0].resolved, at(4, 17, 0));
+}
+
+#[test]
+fn hours_from_whole_day_are_not_an_offset() {
+    let s = scanner_for_languages(&["en"]);
+    let m = s.scan("three hours after Monday", now());
+    assert!(m.iter().all(|m| m.kind != ExpressionKind::AnchoredOffset));
+}
@@ -0,0 +1,178 @@
+use chrono::{TimeZone, Utc};
+use clockwords::{ExpressionKind, ResolvedTime, Span, scanner_for_languages};
+
+/// Saturday Feb 7, 2026 14:30:00 UTC
+fn now() -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, 7, 14, 30, 0).unwrap()
+}
+
+/// Range between two times given as (day of February 2026, hour, minute).
+fn range(from: (u32, u32, u32), to: (u32, u32, u32)) -> ResolvedTime {
+    ResolvedTime::Range {
+        start: Utc
+            .with_ymd_and_hms(2026, 2, from.0, from.1, from.2, 0)
+            .unwrap(),
+        end: Utc.with_ymd_and_hms(2026, 2, to.0, to.1, to.2, 0).unwrap(),
+    }
+}
+
+/// Test expecting exactly one match of the given kind covering the whole input.
+macro_rules! anchored_test {
+    ($name:ident, $lang:expr, $input:expr, $kind:expr, $from:expr, $to:expr) => {
+        #[test]
+        fn $name() {
+            let s = scanner_for_languages(&[$lang]);
+            let m = s.scan($input, now());
+            assert_eq!(
+                m.len(),
+                1,
+                "expected 1 match for {:?}, got {}",
+                $input,
+                m.len()
+            );
+            assert_eq!(m[0].kind, $kind);
+            assert_eq!(m[0].span, Span::new(0, $input.len()));
+            assert_eq!(m[0].resolved, range($from, $to));
+        }
+    };
+}
+
+// ================================================================
+//  English
+// ================================================================
+
+anchored_test!(
+    en_yesterday_from_9_for_2_hours,
+    "en",
+    "yesterday from 9 for 2 hours",
+    ExpressionKind::Combined,
+    (6, 9, 0),
+    (6, 11, 0)
+);
+anchored_test!(
+    en_3pm_for_45_minutes,
+    "en",
+    "3pm for 45 minutes",
+    ExpressionKind::TimeRange,
+    (7, 15, 0),
+    (7, 15, 45)
+);
+anchored_test!(
+    en_at_9_30_for_1_30h,
+    "en",
+    "at 9:30 for 1:30h",
+    ExpressionKind::TimeRange,
+    (7, 9, 30),
+    (7, 11, 0)
+);
+anchored_test!(
+    en_date_from_for_hour_and_a_half,
+    "en",
+    "Feb 3 from 10am for an hour and a half",
+    ExpressionKind::Combined,
+    (3, 10, 0),
+    (3, 11, 30)
+);
+anchored_test!(
+    en_2_hours_until_5pm,
+    "en",
+    "2 hours until 5pm",
+    ExpressionKind::TimeRange,
+    (7, 15, 0),
+    (7, 17, 0)
+);
+
+#[test]
+fn en_bare_number_is_not_a_start_time() {
+    let s = scanner_for_languages(&["en"]);
+    let m = s.scan("9 for 2 hours", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].kind, ExpressionKind::Duration);
+}
+
+// ================================================================
+//  German
+// ================================================================
+
+anchored_test!(
+    de_morgen_ab_14_fuer_3_stunden,
+    "de",
+    "morgen ab 14 Uhr für 3 Stunden",
+    ExpressionKind::Combined,
+    (8, 14, 0),
+    (8, 17, 0)
+);
+anchored_test!(
+    de_von_9_fuer_90_min,
+    "de",
+    "von 9:00 für 90 min",
+    ExpressionKind::TimeRange,
+    (7, 9, 0),
+    (7, 10, 30)
+);
+anchored_test!(
+    de_stunden_bis_17_uhr,
+    "de",
+    "2 Stunden bis 17 Uhr",
+    ExpressionKind::TimeRange,
+    (7, 15, 0),
+    (7, 17, 0)
+);
+
+// ================================================================
+//  French
+// ================================================================
+
+anchored_test!(
+    fr_demain_a_14h_pour_3_heures,
+    "fr",
+    "demain à 14h pour 3 heures",
+    ExpressionKind::Combined,
+    (8, 14, 0),
+    (8, 17, 0)
+);
+anchored_test!(
+    fr_de_9h_pendant_une_heure_et_demie,
+    "fr",
+    "de 9h pendant une heure et demie",
+    ExpressionKind::TimeRange,
+    (7, 9, 0),
+    (7, 10, 30)
+);
+anchored_test!(
+    fr_heures_jusqu_a_17h,
+    "fr",
+    "2 heures jusqu'à 17h",
+    ExpressionKind::TimeRange,
+    (7, 15, 0),
+    (7, 17, 0)
+);
+
+// ================================================================
+//  Spanish
+// ================================================================
+
+anchored_test!(
+    es_ayer_desde_las_9_durante_2_horas,
+    "es",
+    "ayer desde las 9 durante 2 horas",
+    ExpressionKind::Combined,
+    (6, 9, 0),
+    (6, 11, 0)
+);
+anchored_test!(
+    es_a_las_15_por_45_minutos,
+    "es",
+    "a las 15 por 45 minutos",
+    ExpressionKind::TimeRange,
+    (7, 15, 0),
+    (7, 15, 45)
+);
+anchored_test!(
+    es_hora_y_media_hasta_las_17,
+    "es",
+    "hora y media hasta las 17",
+    ExpressionKind::TimeRange,
+    (7, 15, 30),
+    (7, 17, 0)
+);
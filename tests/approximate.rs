@@ -0,0 +1,256 @@
+use chrono::{Duration, TimeZone, Utc};
+use clockwords::{
+    ExpressionKind, ParserConfig, ResolvedTime, Span, TimeExpressionScanner, scanner_for_languages,
+};
+
+/// Saturday Feb 7, 2026 14:30:00 UTC
+fn now() -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, 7, 14, 30, 0).unwrap()
+}
+
+/// Point in time for the given date in 2026 and time.
+fn at(m: u32, d: u32, h: u32, min: u32) -> ResolvedTime {
+    ResolvedTime::Point(Utc.with_ymd_and_hms(2026, m, d, h, min, 0).unwrap())
+}
+
+/// Test expecting exactly one approximate match of the given kind covering
+/// the whole input.
+macro_rules! approx_test {
+    ($name:ident, $lang:expr, $input:expr, $kind:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            let s = scanner_for_languages(&[$lang]);
+            let m = s.scan($input, now());
+            assert_eq!(
+                m.len(),
+                1,
+                "expected 1 match for {:?}, got {}",
+                $input,
+                m.len()
+            );
+            assert_eq!(m[0].kind, $kind);
+            assert_eq!(m[0].span, Span::new(0, $input.len()));
+            assert_eq!(m[0].resolved, $expected);
+            assert_eq!(m[0].tolerance, Some(Duration::minutes(15)));
+        }
+    };
+}
+
+// ================================================================
+//  English
+// ================================================================
+
+approx_test!(
+    en_around_3,
+    "en",
+    "around 3",
+    ExpressionKind::TimeSpecification,
+    at(2, 7, 3, 0)
+);
+approx_test!(
+    en_about_9_30,
+    "en",
+    "about 9:30",
+    ExpressionKind::TimeSpecification,
+    at(2, 7, 9, 30)
+);
+approx_test!(
+    en_around_3pm,
+    "en",
+    "around 3pm",
+    ExpressionKind::TimeSpecification,
+    at(2, 7, 15, 0)
+);
+approx_test!(
+    en_around_noon,
+    "en",
+    "around noon",
+    ExpressionKind::TimeSpecification,
+    at(2, 7, 12, 0)
+);
+approx_test!(
+    en_tomorrow_around_3pm,
+    "en",
+    "tomorrow around 3pm",
+    ExpressionKind::Combined,
+    at(2, 8, 15, 0)
+);
+
+#[test]
+fn en_exact_time_has_no_tolerance() {
+    let s = scanner_for_languages(&["en"]);
+    let m = s.scan("at 3pm", now());
+    assert_eq!(m[0].tolerance, None);
+    assert_eq!(m[0].widened(), at(2, 7, 15, 0));
+}
+
+#[test]
+fn en_quantities_are_not_times() {
+    let s = scanner_for_languages(&["en"]);
+    assert!(s.scan("about 3 people", now()).is_empty());
+    let m = s.scan("in around 3 hours", now());
+    assert!(
+        m.iter()
+            .all(|m| m.kind != ExpressionKind::TimeSpecification)
+    );
+}
+
+#[test]
+fn en_counted_things_are_not_times() {
+    let s = scanner_for_languages(&["en"]);
+    for text in [
+        "we stopped at 5 stores",
+        "look at 2 options",
+        "around 5 people came",
+        "after 3 attempts it worked",
+        "wait until 10 items are done",
+        "ping 10.1.10.10 at 10.1.10.10",
+    ] {
+        assert!(s.scan(text, now()).is_empty(), "{text:?}");
+    }
+}
+
+#[test]
+fn en_bare_hour_in_time_context() {
+    let s = scanner_for_languages(&["en"]);
+    for (text, hour) in [
+        ("see you at 3", 3),
+        ("at 3, then lunch", 3),
+        ("around 5.", 5),
+        ("around 5 on Friday", 5),
+        ("at 9 sharp", 9),
+    ] {
+        let m = s.scan(text, now());
+        assert!(!m.is_empty(), "{text:?}");
+        assert_eq!(m[0].resolved, at(2, 7, hour, 0), "{text:?}");
+    }
+}
+
+// ================================================================
+//  German
+// ================================================================
+
+approx_test!(
+    de_gegen_15_uhr,
+    "de",
+    "gegen 15 Uhr",
+    ExpressionKind::TimeSpecification,
+    at(2, 7, 15, 0)
+);
+approx_test!(
+    de_etwa_um_9,
+    "de",
+    "etwa um 9",
+    ExpressionKind::TimeSpecification,
+    at(2, 7, 9, 0)
+);
+approx_test!(
+    de_um_ungefaehr_9_30,
+    "de",
+    "um ungefähr 9:30",
+    ExpressionKind::TimeSpecification,
+    at(2, 7, 9, 30)
+);
+approx_test!(
+    de_gegen_halb_vier,
+    "de",
+    "gegen halb vier",
+    ExpressionKind::TimeSpecification,
+    at(2, 7, 3, 30)
+);
+approx_test!(
+    de_morgen_gegen_15_uhr,
+    "de",
+    "morgen gegen 15 Uhr",
+    ExpressionKind::Combined,
+    at(2, 8, 15, 0)
+);
+
+// ================================================================
+//  French
+// ================================================================
+
+approx_test!(
+    fr_vers_15h,
+    "fr",
+    "vers 15h",
+    ExpressionKind::TimeSpecification,
+    at(2, 7, 15, 0)
+);
+approx_test!(
+    fr_aux_alentours_de_9h30,
+    "fr",
+    "aux alentours de 9h30",
+    ExpressionKind::TimeSpecification,
+    at(2, 7, 9, 30)
+);
+approx_test!(
+    fr_vers_midi,
+    "fr",
+    "vers midi",
+    ExpressionKind::TimeSpecification,
+    at(2, 7, 12, 0)
+);
+approx_test!(
+    fr_demain_vers_15h,
+    "fr",
+    "demain vers 15h",
+    ExpressionKind::Combined,
+    at(2, 8, 15, 0)
+);
+
+// ================================================================
+//  Spanish
+// ================================================================
+
+approx_test!(
+    es_sobre_las_3,
+    "es",
+    "sobre las 3",
+    ExpressionKind::TimeSpecification,
+    at(2, 7, 3, 0)
+);
+approx_test!(
+    es_hacia_las_5,
+    "es",
+    "hacia las 5",
+    ExpressionKind::TimeSpecification,
+    at(2, 7, 5, 0)
+);
+approx_test!(
+    es_a_eso_de_las_5,
+    "es",
+    "a eso de las 5",
+    ExpressionKind::TimeSpecification,
+    at(2, 7, 5, 0)
+);
+approx_test!(
+    es_manana_sobre_las_10_30,
+    "es",
+    "mañana sobre las 10:30",
+    ExpressionKind::Combined,
+    at(2, 8, 10, 30)
+);
+
+// ================================================================
+//  Tolerance
+// ================================================================
+
+#[test]
+fn tolerance_is_configurable_and_widens() {
+    let config = ParserConfig {
+        approximate_tolerance: Duration::minutes(30),
+        ..ParserConfig::default()
+    };
+    let s =
+        TimeExpressionScanner::new(vec![Box::new(clockwords::lang::en::English::new())], config);
+    let m = s.scan("around 3pm", now());
+    assert_eq!(m[0].tolerance, Some(Duration::minutes(30)));
+    assert_eq!(
+        m[0].widened(),
+        ResolvedTime::Range {
+            start: Utc.with_ymd_and_hms(2026, 2, 7, 14, 30, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2026, 2, 7, 15, 30, 0).unwrap(),
+        }
+    );
+}
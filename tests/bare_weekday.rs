@@ -0,0 +1,233 @@
+use chrono::{TimeZone, Utc};
+use clockwords::{
+    DateBias, ExpressionKind, ParserConfig, ResolvedTime, Span, TimeExpressionScanner,
+    scanner_for_languages,
+};
+
+/// Saturday Feb 7, 2026 14:30:00 UTC
+fn now() -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, 7, 14, 30, 0).unwrap()
+}
+
+/// Full-day range for the given day of February 2026.
+fn day(d: u32) -> ResolvedTime {
+    let start = Utc.with_ymd_and_hms(2026, 2, d, 0, 0, 0).unwrap();
+    ResolvedTime::Range {
+        start,
+        end: start + chrono::Duration::days(1),
+    }
+}
+
+/// Point in time on the given day of February 2026.
+fn at(d: u32, h: u32, min: u32) -> ResolvedTime {
+    ResolvedTime::Point(Utc.with_ymd_and_hms(2026, 2, d, h, min, 0).unwrap())
+}
+
+/// Helper: create an English scanner with the given weekday bias.
+fn scanner_with_bias(bias: DateBias) -> TimeExpressionScanner {
+    let config = ParserConfig {
+        weekday_bias: bias,
+        ..ParserConfig::default()
+    };
+    TimeExpressionScanner::new(vec![Box::new(clockwords::lang::en::English::new())], config)
+}
+
+/// Test expecting exactly one match of the given kind covering the whole input.
+macro_rules! weekday_test {
+    ($name:ident, $lang:expr, $input:expr, $kind:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            let s = scanner_for_languages(&[$lang]);
+            let m = s.scan($input, now());
+            assert_eq!(
+                m.len(),
+                1,
+                "expected 1 match for {:?}, got {}",
+                $input,
+                m.len()
+            );
+            assert_eq!(m[0].kind, $kind);
+            assert_eq!(m[0].span, Span::new(0, $input.len()));
+            assert_eq!(m[0].resolved, $expected);
+        }
+    };
+}
+
+// ================================================================
+//  English (default bias: nearest)
+// ================================================================
+
+weekday_test!(
+    en_on_friday,
+    "en",
+    "on Friday",
+    ExpressionKind::RelativeDay,
+    day(6)
+);
+weekday_test!(
+    en_monday,
+    "en",
+    "Monday",
+    ExpressionKind::RelativeDay,
+    day(9)
+);
+weekday_test!(
+    en_monday_at_9am,
+    "en",
+    "Monday at 9am",
+    ExpressionKind::Combined,
+    at(9, 9, 0)
+);
+weekday_test!(
+    en_friday_evening,
+    "en",
+    "Friday evening",
+    ExpressionKind::Combined,
+    ResolvedTime::Range {
+        start: Utc.with_ymd_and_hms(2026, 2, 6, 18, 0, 0).unwrap(),
+        end: Utc.with_ymd_and_hms(2026, 2, 6, 22, 0, 0).unwrap(),
+    }
+);
+weekday_test!(
+    en_sunday_at_noon,
+    "en",
+    "Sunday at noon",
+    ExpressionKind::Combined,
+    at(8, 12, 0)
+);
+
+#[test]
+fn en_in_sentence() {
+    let s = scanner_for_languages(&["en"]);
+    let text = "see you on Tuesday";
+    let m = s.scan(text, now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(&text[m[0].span.as_range()], "on Tuesday");
+    assert_eq!(m[0].resolved, day(10));
+}
+
+#[test]
+fn en_plural_is_not_a_day() {
+    let s = scanner_for_languages(&["en"]);
+    assert!(s.scan("Mondays are slow", now()).is_empty());
+}
+
+// ================================================================
+//  German
+// ================================================================
+
+weekday_test!(
+    de_am_freitag,
+    "de",
+    "am Freitag",
+    ExpressionKind::RelativeDay,
+    day(6)
+);
+weekday_test!(
+    de_montag_um_9,
+    "de",
+    "Montag um 9",
+    ExpressionKind::Combined,
+    at(9, 9, 0)
+);
+weekday_test!(
+    de_am_dienstag_um_10_30_uhr,
+    "de",
+    "am Dienstag um 10:30 Uhr",
+    ExpressionKind::Combined,
+    at(10, 10, 30)
+);
+weekday_test!(
+    de_montagabend,
+    "de",
+    "Montagabend",
+    ExpressionKind::Combined,
+    ResolvedTime::Range {
+        start: Utc.with_ymd_and_hms(2026, 2, 9, 18, 0, 0).unwrap(),
+        end: Utc.with_ymd_and_hms(2026, 2, 9, 22, 0, 0).unwrap(),
+    }
+);
+
+// ================================================================
+//  French
+// ================================================================
+
+weekday_test!(
+    fr_vendredi,
+    "fr",
+    "vendredi",
+    ExpressionKind::RelativeDay,
+    day(6)
+);
+weekday_test!(
+    fr_lundi_a_9h,
+    "fr",
+    "lundi à 9h",
+    ExpressionKind::Combined,
+    at(9, 9, 0)
+);
+weekday_test!(
+    fr_mardi_a_midi,
+    "fr",
+    "mardi à midi",
+    ExpressionKind::Combined,
+    at(10, 12, 0)
+);
+
+// ================================================================
+//  Spanish
+// ================================================================
+
+weekday_test!(
+    es_el_lunes,
+    "es",
+    "el lunes",
+    ExpressionKind::RelativeDay,
+    day(9)
+);
+weekday_test!(
+    es_el_viernes_a_las_9,
+    "es",
+    "el viernes a las 9",
+    ExpressionKind::Combined,
+    at(6, 9, 0)
+);
+
+#[test]
+fn es_los_lunes_is_not_a_day() {
+    let s = scanner_for_languages(&["es"]);
+    assert!(s.scan("los lunes voy al gimnasio", now()).is_empty());
+}
+
+// ================================================================
+//  Bias
+// ================================================================
+
+#[test]
+fn bias_future_and_past() {
+    let m = scanner_with_bias(DateBias::Future).scan("on Friday", now());
+    assert_eq!(m[0].resolved, day(13));
+
+    let m = scanner_with_bias(DateBias::Past).scan("Monday", now());
+    assert_eq!(m[0].resolved, day(2));
+}
+
+#[test]
+fn today_resolves_to_today_for_every_bias() {
+    for bias in [DateBias::Past, DateBias::Future, DateBias::Nearest] {
+        let m = scanner_with_bias(bias).scan("Saturday", now());
+        assert_eq!(m[0].resolved, day(7), "{bias:?}");
+    }
+}
+
+#[test]
+fn directed_weekday_ignores_bias() {
+    let m = scanner_with_bias(DateBias::Past).scan("next Monday", now());
+    assert_eq!(m[0].resolved, day(16));
+}
+
+#[test]
+fn weekday_mismatch_with_date_rejected() {
+    let s = scanner_for_languages(&["fr"]);
+    assert!(s.scan("lundi 6 mars 2026", now()).is_empty());
+}
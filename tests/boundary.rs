@@ -0,0 +1,385 @@
+use chrono::{NaiveTime, TimeZone, Utc};
+use clockwords::{
+    BusinessHours, ExpressionKind, ParserConfig, ResolvedTime, Span, TimeExpressionScanner,
+    scanner_for_languages,
+};
+
+/// Saturday Feb 7, 2026 14:30:00 UTC
+fn now() -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, 7, 14, 30, 0).unwrap()
+}
+
+/// Full-day range for the given date.
+fn day(y: i32, m: u32, d: u32) -> ResolvedTime {
+    let start = Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap();
+    ResolvedTime::Range {
+        start,
+        end: start + chrono::Duration::days(1),
+    }
+}
+
+/// Point in time for the given date in 2026 and time.
+fn at(m: u32, d: u32, h: u32, min: u32) -> ResolvedTime {
+    ResolvedTime::Point(Utc.with_ymd_and_hms(2026, m, d, h, min, 0).unwrap())
+}
+
+/// Range from now until midnight at the start of the given date in 2026.
+fn until(m: u32, d: u32) -> ResolvedTime {
+    ResolvedTime::Range {
+        start: now(),
+        end: Utc.with_ymd_and_hms(2026, m, d, 0, 0, 0).unwrap(),
+    }
+}
+
+/// Test expecting exactly one match of the given kind covering the whole input.
+macro_rules! bnd_test {
+    ($name:ident, $lang:expr, $input:expr, $kind:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            let s = scanner_for_languages(&[$lang]);
+            let m = s.scan($input, now());
+            assert_eq!(
+                m.len(),
+                1,
+                "expected 1 match for {:?}, got {}",
+                $input,
+                m.len()
+            );
+            assert_eq!(m[0].kind, $kind);
+            assert_eq!(m[0].span, Span::new(0, $input.len()));
+            assert_eq!(m[0].resolved, $expected);
+        }
+    };
+}
+
+// ================================================================
+//  English
+// ================================================================
+
+bnd_test!(
+    en_by_end_of_month,
+    "en",
+    "by end of month",
+    ExpressionKind::Boundary,
+    day(2026, 2, 28)
+);
+bnd_test!(
+    en_start_of_next_week,
+    "en",
+    "start of next week",
+    ExpressionKind::Boundary,
+    day(2026, 2, 9)
+);
+bnd_test!(
+    en_mid_month,
+    "en",
+    "mid-month",
+    ExpressionKind::Boundary,
+    day(2026, 2, 14)
+);
+bnd_test!(
+    en_end_of_next_quarter,
+    "en",
+    "at the end of next quarter",
+    ExpressionKind::Boundary,
+    day(2026, 6, 30)
+);
+bnd_test!(
+    en_beginning_of_the_year,
+    "en",
+    "the beginning of the year",
+    ExpressionKind::Boundary,
+    day(2026, 1, 1)
+);
+bnd_test!(
+    en_end_of_the_day,
+    "en",
+    "end of the day",
+    ExpressionKind::Boundary,
+    at(2, 7, 17, 0)
+);
+bnd_test!(
+    en_eod,
+    "en",
+    "EOD",
+    ExpressionKind::Boundary,
+    at(2, 7, 17, 0)
+);
+bnd_test!(
+    en_by_cob,
+    "en",
+    "by COB",
+    ExpressionKind::Boundary,
+    at(2, 7, 17, 0)
+);
+bnd_test!(
+    en_eow,
+    "en",
+    "EOW",
+    ExpressionKind::Boundary,
+    at(2, 6, 17, 0)
+);
+bnd_test!(
+    en_eom,
+    "en",
+    "EOM",
+    ExpressionKind::Boundary,
+    at(2, 27, 17, 0)
+);
+bnd_test!(
+    en_close_of_business,
+    "en",
+    "close of business",
+    ExpressionKind::Boundary,
+    at(2, 7, 17, 0)
+);
+bnd_test!(
+    en_rest_of_the_day,
+    "en",
+    "for the rest of the day",
+    ExpressionKind::TimeRange,
+    until(2, 8)
+);
+bnd_test!(
+    en_rest_of_the_week,
+    "en",
+    "the rest of the week",
+    ExpressionKind::TimeRange,
+    until(2, 9)
+);
+bnd_test!(
+    en_all_day_adjective,
+    "en",
+    "all-day",
+    ExpressionKind::RelativeDay,
+    day(2026, 2, 7)
+);
+bnd_test!(
+    en_all_day_tomorrow,
+    "en",
+    "all day tomorrow",
+    ExpressionKind::RelativeDay,
+    day(2026, 2, 8)
+);
+
+#[test]
+fn en_lowercase_abbreviation_is_not_matched() {
+    let s = scanner_for_languages(&["en"]);
+    assert!(s.scan("the cob salad", now()).is_empty());
+}
+
+#[test]
+fn en_bare_all_day_is_not_matched() {
+    let s = scanner_for_languages(&["en"]);
+    assert!(s.scan("we talked all day", now()).is_empty());
+}
+
+// ================================================================
+//  German
+// ================================================================
+
+bnd_test!(
+    de_ende_der_woche,
+    "de",
+    "Ende der Woche",
+    ExpressionKind::Boundary,
+    day(2026, 2, 8)
+);
+bnd_test!(
+    de_anfang_naechsten_monats,
+    "de",
+    "Anfang nächsten Monats",
+    ExpressionKind::Boundary,
+    day(2026, 3, 1)
+);
+bnd_test!(
+    de_mitte_des_jahres,
+    "de",
+    "Mitte des Jahres",
+    ExpressionKind::Boundary,
+    day(2026, 7, 1)
+);
+bnd_test!(
+    de_zum_monatsende,
+    "de",
+    "zum Monatsende",
+    ExpressionKind::Boundary,
+    day(2026, 2, 28)
+);
+bnd_test!(
+    de_feierabend,
+    "de",
+    "Feierabend",
+    ExpressionKind::Boundary,
+    at(2, 7, 17, 0)
+);
+bnd_test!(
+    de_rest_des_tages,
+    "de",
+    "für den Rest des Tages",
+    ExpressionKind::TimeRange,
+    until(2, 8)
+);
+bnd_test!(
+    de_ganztaegig,
+    "de",
+    "ganztägig",
+    ExpressionKind::RelativeDay,
+    day(2026, 2, 7)
+);
+bnd_test!(
+    de_heute_den_ganzen_tag,
+    "de",
+    "heute den ganzen Tag",
+    ExpressionKind::RelativeDay,
+    day(2026, 2, 7)
+);
+bnd_test!(
+    de_morgen_ganztaegig,
+    "de",
+    "morgen ganztägig",
+    ExpressionKind::RelativeDay,
+    day(2026, 2, 8)
+);
+
+#[test]
+fn de_wochenende_is_not_a_boundary() {
+    let s = scanner_for_languages(&["de"]);
+    assert!(s.scan("am Wochenende", now()).is_empty());
+}
+
+// ================================================================
+//  French
+// ================================================================
+
+bnd_test!(
+    fr_fin_de_mois,
+    "fr",
+    "fin de mois",
+    ExpressionKind::Boundary,
+    day(2026, 2, 28)
+);
+bnd_test!(
+    fr_debut_de_la_semaine_prochaine,
+    "fr",
+    "début de la semaine prochaine",
+    ExpressionKind::Boundary,
+    day(2026, 2, 9)
+);
+bnd_test!(
+    fr_d_ici_la_fin_du_mois,
+    "fr",
+    "d'ici la fin du mois",
+    ExpressionKind::Boundary,
+    day(2026, 2, 28)
+);
+bnd_test!(
+    fr_en_fin_de_journee,
+    "fr",
+    "en fin de journée",
+    ExpressionKind::Boundary,
+    at(2, 7, 17, 0)
+);
+bnd_test!(
+    fr_reste_de_la_journee,
+    "fr",
+    "le reste de la journée",
+    ExpressionKind::TimeRange,
+    until(2, 8)
+);
+bnd_test!(
+    fr_toute_la_journee,
+    "fr",
+    "demain toute la journée",
+    ExpressionKind::RelativeDay,
+    day(2026, 2, 8)
+);
+
+// ================================================================
+//  Spanish
+// ================================================================
+
+bnd_test!(
+    es_a_finales_de_semana,
+    "es",
+    "a finales de semana",
+    ExpressionKind::Boundary,
+    day(2026, 2, 8)
+);
+bnd_test!(
+    es_a_principios_del_mes_que_viene,
+    "es",
+    "a principios del mes que viene",
+    ExpressionKind::Boundary,
+    day(2026, 3, 1)
+);
+bnd_test!(
+    es_a_mediados_de_ano,
+    "es",
+    "a mediados de año",
+    ExpressionKind::Boundary,
+    day(2026, 7, 1)
+);
+bnd_test!(
+    es_al_final_del_dia,
+    "es",
+    "al final del día",
+    ExpressionKind::Boundary,
+    at(2, 7, 17, 0)
+);
+bnd_test!(
+    es_el_resto_del_dia,
+    "es",
+    "el resto del día",
+    ExpressionKind::TimeRange,
+    until(2, 8)
+);
+bnd_test!(
+    es_todo_el_dia_de_hoy,
+    "es",
+    "todo el día de hoy",
+    ExpressionKind::RelativeDay,
+    day(2026, 2, 7)
+);
+
+#[test]
+fn es_fin_de_semana_is_the_weekend() {
+    let s = scanner_for_languages(&["es"]);
+    assert!(s.scan("el fin de semana", now()).is_empty());
+}
+
+// ================================================================
+//  Business hours
+// ================================================================
+
+#[test]
+fn business_hours_are_configurable() {
+    let config = ParserConfig {
+        business_hours: BusinessHours {
+            start: NaiveTime::from_hms_opt(8, 30, 0).unwrap(),
+            end: NaiveTime::from_hms_opt(18, 0, 0).unwrap(),
+        },
+        ..ParserConfig::default()
+    };
+    let s =
+        TimeExpressionScanner::new(vec![Box::new(clockwords::lang::en::English::new())], config);
+    assert_eq!(s.scan("EOD", now())[0].resolved, at(2, 7, 18, 0));
+    assert_eq!(
+        s.scan("start of the day", now())[0].resolved,
+        at(2, 7, 8, 30)
+    );
+    assert_eq!(
+        s.scan("middle of the day", now())[0].resolved,
+        at(2, 7, 12, 0)
+    );
+}
+
+#[test]
+fn end_of_day_in_timezone() {
+    let config = ParserConfig {
+        timezone: clockwords::Tz::Europe__Berlin,
+        ..ParserConfig::default()
+    };
+    let s = TimeExpressionScanner::new(vec![Box::new(clockwords::lang::de::German::new())], config);
+    assert_eq!(s.scan("zum Feierabend", now())[0].resolved, at(2, 7, 16, 0));
+}
@@ -232,6 +232,24 @@ time_test!(
     now()
 );
 
+time_test!(
+    fr_bare_15h30,
+    "fr",
+    "réunion 15h30",
+    ExpressionKind::TimeSpecification,
+    Utc.with_ymd_and_hms(2026, 2, 7, 15, 30, 0).unwrap(),
+    now()
+);
+
+time_test!(
+    fr_bare_14h,
+    "fr",
+    "14h",
+    ExpressionKind::TimeSpecification,
+    Utc.with_ymd_and_hms(2026, 2, 7, 14, 0, 0).unwrap(),
+    now()
+);
+
 time_test!(
     fr_hier_a_13h30,
     "fr",
@@ -114,7 +114,7 @@ range_test!(
     "this Wednesday from nine to five",
     ExpressionKind::Combined,
     Utc.with_ymd_and_hms(2026, 2, 11, 9, 0, 0).unwrap(),
-    Utc.with_ymd_and_hms(2026, 2, 11, 5, 0, 0).unwrap()
+    Utc.with_ymd_and_hms(2026, 2, 11, 17, 0, 0).unwrap()
 );
 
 range_test!(
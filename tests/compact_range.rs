@@ -0,0 +1,219 @@
+use chrono::{TimeZone, Utc};
+use clockwords::lang::LanguageParser;
+use clockwords::{
+    ExpressionKind, HourInference, ParserConfig, ResolvedTime, Span, TimeExpressionScanner,
+};
+
+/// Saturday Feb 7, 2026 14:30:00 UTC
+fn now() -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, 7, 14, 30, 0).unwrap()
+}
+
+/// Range between two times on Feb 7, 2026, given as (hour, minute).
+fn range(from: (u32, u32), to: (u32, u32)) -> ResolvedTime {
+    ResolvedTime::Range {
+        start: Utc.with_ymd_and_hms(2026, 2, 7, from.0, from.1, 0).unwrap(),
+        end: Utc.with_ymd_and_hms(2026, 2, 7, to.0, to.1, 0).unwrap(),
+    }
+}
+
+/// Helper: create a scanner for one language with compact ranges enabled.
+fn compact_scanner(lang: &str) -> TimeExpressionScanner {
+    let language: Box<dyn LanguageParser> = match lang {
+        "en" => Box::new(clockwords::lang::en::English::new()),
+        "de" => Box::new(clockwords::lang::de::German::new()),
+        "fr" => Box::new(clockwords::lang::fr::French::new()),
+        _ => Box::new(clockwords::lang::es::Spanish::new()),
+    };
+    let config = ParserConfig {
+        compact_ranges: true,
+        ..ParserConfig::default()
+    };
+    TimeExpressionScanner::new(vec![language], config)
+}
+
+/// Test expecting exactly one compact range at the start of the input.
+macro_rules! compact_test {
+    ($name:ident, $lang:expr, $input:expr, $len:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            let s = compact_scanner($lang);
+            let m = s.scan($input, now());
+            assert_eq!(
+                m.len(),
+                1,
+                "expected 1 match for {:?}, got {}",
+                $input,
+                m.len()
+            );
+            assert_eq!(m[0].kind, ExpressionKind::TimeRange);
+            assert_eq!(m[0].span, Span::new(0, $len));
+            assert_eq!(m[0].resolved, $expected);
+        }
+    };
+}
+
+// ================================================================
+//  Formats
+// ================================================================
+
+compact_test!(
+    en_hours,
+    "en",
+    "9-12 refactoring",
+    4,
+    range((9, 0), (12, 0))
+);
+compact_test!(
+    en_en_dash,
+    "en",
+    "9:15–11:45 review",
+    "9:15–11:45".len(),
+    range((9, 15), (11, 45))
+);
+compact_test!(
+    en_spaced_em_dash,
+    "en",
+    "9:15 — 11:45",
+    "9:15 — 11:45".len(),
+    range((9, 15), (11, 45))
+);
+compact_test!(
+    en_military,
+    "en",
+    "0930-1200 standup",
+    9,
+    range((9, 30), (12, 0))
+);
+compact_test!(
+    en_dotted_to_hour,
+    "en",
+    "9.30 - 12 code review",
+    9,
+    range((9, 30), (12, 0))
+);
+compact_test!(de_uhr, "de", "9-12 Uhr", 8, range((9, 0), (12, 0)));
+compact_test!(
+    de_dotted,
+    "de",
+    "9.30 - 12 Besprechung",
+    9,
+    range((9, 30), (12, 0))
+);
+compact_test!(
+    fr_h_notation,
+    "fr",
+    "14h-16h30 réunion",
+    9,
+    range((14, 0), (16, 30))
+);
+compact_test!(
+    es_en_dash,
+    "es",
+    "9:00–12:30 reunión",
+    "9:00–12:30".len(),
+    range((9, 0), (12, 30))
+);
+
+#[test]
+fn overnight_compact_range() {
+    let s = compact_scanner("en");
+    let m = s.scan("22-2 on call", now());
+    assert_eq!(
+        m[0].resolved,
+        ResolvedTime::Range {
+            start: Utc.with_ymd_and_hms(2026, 2, 7, 22, 0, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2026, 2, 8, 2, 0, 0).unwrap(),
+        }
+    );
+    assert!(m[0].spans_midnight);
+}
+
+// ================================================================
+//  Hours without am/pm
+// ================================================================
+
+compact_test!(
+    en_twelve_hour_end,
+    "en",
+    "9-5 at the office",
+    3,
+    range((9, 0), (17, 0))
+);
+compact_test!(
+    de_twelve_hour_end,
+    "de",
+    "8-4 Schicht",
+    3,
+    range((8, 0), (16, 0))
+);
+compact_test!(
+    en_leading_zero_is_24_hour,
+    "en",
+    "09-17 shift",
+    5,
+    range((9, 0), (17, 0))
+);
+
+#[test]
+fn compact_range_follows_hour_inference() {
+    let config = ParserConfig {
+        compact_ranges: true,
+        hour_inference: HourInference::PmUpTo(7),
+        ..ParserConfig::default()
+    };
+    let s =
+        TimeExpressionScanner::new(vec![Box::new(clockwords::lang::en::English::new())], config);
+    let m = s.scan("1-3 standup", now());
+    assert_eq!(m[0].resolved, range((13, 0), (15, 0)));
+    let m = s.scan("9-12 standup", now());
+    assert_eq!(m[0].resolved, range((9, 0), (12, 0)));
+}
+
+// ================================================================
+//  Rejections
+// ================================================================
+
+#[test]
+fn disabled_by_default() {
+    let s = clockwords::scanner_for_languages(&["en"]);
+    assert!(s.scan("pages 9-12", now()).is_empty());
+}
+
+#[test]
+fn iso_date_is_not_a_range() {
+    let s = compact_scanner("en");
+    let m = s.scan("on 2026-03-05", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].kind, ExpressionKind::AbsoluteDate);
+}
+
+#[test]
+fn quantity_is_not_a_range() {
+    let s = compact_scanner("en");
+    let m = s.scan("2-3 days", now());
+    assert!(m.iter().all(|m| m.kind != ExpressionKind::TimeRange));
+}
+
+#[test]
+fn invalid_times_are_not_a_range() {
+    let s = compact_scanner("en");
+    assert!(s.scan("1990-1995", now()).is_empty());
+    assert!(s.scan("9-25", now()).is_empty());
+}
+
+#[test]
+fn year_range_is_not_a_range() {
+    let s = compact_scanner("en");
+    for text in ["2025-2026", "from 1998–2004", "2019 - 2020"] {
+        assert!(
+            s.scan(text, now())
+                .iter()
+                .all(|m| m.kind != ExpressionKind::TimeRange),
+            "{text:?} matched as a time range"
+        );
+    }
+    // A military range that is not a pair of years still is one
+    let m = s.scan("0930-1200", now());
+    assert_eq!(m[0].kind, ExpressionKind::TimeRange);
+}
@@ -0,0 +1,216 @@
+use chrono::{TimeZone, Utc};
+use clockwords::{
+    ExpressionKind, OvernightRange, ParserConfig, ResolvedTime, Span, TimeExpressionScanner,
+    scanner_for_languages,
+};
+
+/// Saturday Feb 7, 2026 14:30:00 UTC
+fn now() -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, 7, 14, 30, 0).unwrap()
+}
+
+/// Range between two times in February 2026, given as (day, hour, minute).
+fn range(from: (u32, u32, u32), to: (u32, u32, u32)) -> ResolvedTime {
+    ResolvedTime::Range {
+        start: Utc
+            .with_ymd_and_hms(2026, 2, from.0, from.1, from.2, 0)
+            .unwrap(),
+        end: Utc.with_ymd_and_hms(2026, 2, to.0, to.1, to.2, 0).unwrap(),
+    }
+}
+
+/// Test expecting exactly one match of the given kind covering the whole
+/// input, spanning midnight.
+macro_rules! overnight_test {
+    ($name:ident, $lang:expr, $input:expr, $kind:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            let s = scanner_for_languages(&[$lang]);
+            let m = s.scan($input, now());
+            assert_eq!(
+                m.len(),
+                1,
+                "expected 1 match for {:?}, got {}",
+                $input,
+                m.len()
+            );
+            assert_eq!(m[0].kind, $kind);
+            assert_eq!(m[0].span, Span::new(0, $input.len()));
+            assert_eq!(m[0].resolved, $expected);
+            assert!(m[0].spans_midnight, "{:?} should span midnight", $input);
+        }
+    };
+}
+
+// ================================================================
+//  English
+// ================================================================
+
+overnight_test!(
+    en_from_22_to_2,
+    "en",
+    "from 22:00 to 2:00",
+    ExpressionKind::TimeRange,
+    range((7, 22, 0), (8, 2, 0))
+);
+overnight_test!(
+    en_between_23_and_1,
+    "en",
+    "between 23 and 1",
+    ExpressionKind::TimeRange,
+    range((7, 23, 0), (8, 1, 0))
+);
+overnight_test!(
+    en_yesterday_dash_range,
+    "en",
+    "yesterday 21:30 - 00:15",
+    ExpressionKind::Combined,
+    range((6, 21, 30), (7, 0, 15))
+);
+
+// ================================================================
+//  German
+// ================================================================
+
+overnight_test!(
+    de_von_23_bis_1_uhr,
+    "de",
+    "von 23 bis 1 Uhr",
+    ExpressionKind::TimeRange,
+    range((7, 23, 0), (8, 1, 0))
+);
+overnight_test!(
+    de_gestern_von_22_bis_6_uhr,
+    "de",
+    "gestern von 22 bis 6 Uhr",
+    ExpressionKind::Combined,
+    range((6, 22, 0), (7, 6, 0))
+);
+
+// ================================================================
+//  French
+// ================================================================
+
+overnight_test!(
+    fr_de_22_a_2,
+    "fr",
+    "de 22:00 à 02:00",
+    ExpressionKind::TimeRange,
+    range((7, 22, 0), (8, 2, 0))
+);
+overnight_test!(
+    fr_hier_de_22_30_a_1,
+    "fr",
+    "hier de 22:30 à 01:00",
+    ExpressionKind::Combined,
+    range((6, 22, 30), (7, 1, 0))
+);
+
+// ================================================================
+//  Spanish
+// ================================================================
+
+overnight_test!(
+    es_de_las_22_a_las_2,
+    "es",
+    "de las 22:00 a las 02:00",
+    ExpressionKind::TimeRange,
+    range((7, 22, 0), (8, 2, 0))
+);
+overnight_test!(
+    es_ayer_entre_las_22_y_las_2,
+    "es",
+    "ayer entre las 22 y las 2",
+    ExpressionKind::Combined,
+    range((6, 22, 0), (7, 2, 0))
+);
+
+// ================================================================
+//  Flag
+// ================================================================
+
+#[test]
+fn same_day_range_does_not_span_midnight() {
+    let s = scanner_for_languages(&["en"]);
+    let m = s.scan("from 9:00 to 12:00", now());
+    assert_eq!(m[0].resolved, range((7, 9, 0), (7, 12, 0)));
+    assert!(!m[0].spans_midnight);
+}
+
+#[test]
+fn range_ending_at_midnight_does_not_span_it() {
+    let s = scanner_for_languages(&["en"]);
+    let m = s.scan("from 22:00 to 24:00", now());
+    assert!(m.iter().all(|m| !m.spans_midnight));
+}
+
+#[test]
+fn full_day_does_not_span_midnight() {
+    let s = scanner_for_languages(&["en"]);
+    let m = s.scan("today", now());
+    assert!(!m[0].spans_midnight);
+}
+
+#[test]
+fn night_spans_midnight() {
+    let s = scanner_for_languages(&["en"]);
+    let m = s.scan("tonight", now());
+    assert_eq!(m[0].kind, ExpressionKind::PartOfDay);
+    assert!(m[0].spans_midnight);
+}
+
+// ================================================================
+//  Configuration and timezones
+// ================================================================
+
+#[test]
+fn reject_overnight_ranges() {
+    let config = ParserConfig {
+        overnight_ranges: OvernightRange::Reject,
+        ..ParserConfig::default()
+    };
+    let s =
+        TimeExpressionScanner::new(vec![Box::new(clockwords::lang::en::English::new())], config);
+    let m = s.scan("from 22:00 to 2:00", now());
+    assert!(
+        m.iter()
+            .all(|m| !matches!(m.resolved, ResolvedTime::Range { .. }))
+    );
+
+    let m = s.scan("from 9:00 to 12:00", now());
+    assert_eq!(m[0].resolved, range((7, 9, 0), (7, 12, 0)));
+}
+
+#[test]
+fn rejected_overnight_range_leaves_no_open_range() {
+    let config = ParserConfig {
+        overnight_ranges: OvernightRange::Reject,
+        ..ParserConfig::default()
+    };
+    let s = TimeExpressionScanner::new(vec![Box::new(clockwords::lang::de::German::new())], config);
+    assert!(s.scan("von 23 bis 1 Uhr", now()).is_empty());
+    assert!(s.scan("morgen von 23 bis 1 Uhr", now()).is_empty());
+    let m = s.scan("bis 1 Uhr", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].kind, ExpressionKind::OpenRange);
+}
+
+#[test]
+fn overnight_range_across_dst_change() {
+    // Clocks in Berlin go forward from 02:00 to 03:00 on Mar 29, 2026.
+    let config = ParserConfig {
+        timezone: clockwords::Tz::Europe__Berlin,
+        ..ParserConfig::default()
+    };
+    let s = TimeExpressionScanner::new(vec![Box::new(clockwords::lang::de::German::new())], config);
+    let now = Utc.with_ymd_and_hms(2026, 3, 28, 12, 0, 0).unwrap();
+    let m = s.scan("von 22 bis 6 Uhr", now);
+    assert_eq!(
+        m[0].resolved,
+        ResolvedTime::Range {
+            start: Utc.with_ymd_and_hms(2026, 3, 28, 21, 0, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2026, 3, 29, 4, 0, 0).unwrap(),
+        }
+    );
+    assert!(m[0].spans_midnight);
+}
@@ -0,0 +1,240 @@
+use chrono::{TimeZone, Utc};
+use clockwords::lang::numbers::parse_ordinal;
+use clockwords::{
+    DateBias, ExpressionKind, ParserConfig, ResolvedTime, Span, TimeExpressionScanner,
+    scanner_for_languages,
+};
+
+/// Saturday Feb 7, 2026 14:30:00 UTC
+fn now() -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, 7, 14, 30, 0).unwrap()
+}
+
+/// Full-day range for the given date in 2026.
+fn day(m: u32, d: u32) -> ResolvedTime {
+    let start = Utc.with_ymd_and_hms(2026, m, d, 0, 0, 0).unwrap();
+    ResolvedTime::Range {
+        start,
+        end: start + chrono::Duration::days(1),
+    }
+}
+
+/// Point in time for the given date in 2026 and time.
+fn at(m: u32, d: u32, h: u32, min: u32) -> ResolvedTime {
+    ResolvedTime::Point(Utc.with_ymd_and_hms(2026, m, d, h, min, 0).unwrap())
+}
+
+/// Helper: create an English scanner with the given day-of-month bias.
+fn scanner_with_bias(bias: DateBias) -> TimeExpressionScanner {
+    let config = ParserConfig {
+        day_of_month_bias: bias,
+        ..ParserConfig::default()
+    };
+    TimeExpressionScanner::new(vec![Box::new(clockwords::lang::en::English::new())], config)
+}
+
+/// Test expecting exactly one match of the given kind covering the whole input.
+macro_rules! mday_test {
+    ($name:ident, $lang:expr, $input:expr, $kind:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            let s = scanner_for_languages(&[$lang]);
+            let m = s.scan($input, now());
+            assert_eq!(
+                m.len(),
+                1,
+                "expected 1 match for {:?}, got {}",
+                $input,
+                m.len()
+            );
+            assert_eq!(m[0].kind, $kind);
+            assert_eq!(m[0].span, Span::new(0, $input.len()));
+            assert_eq!(m[0].resolved, $expected);
+        }
+    };
+}
+
+// ================================================================
+//  Ordinal parsing
+// ================================================================
+
+#[test]
+fn ordinals() {
+    let cases = [
+        ("21st", 21),
+        ("3rd", 3),
+        ("twelfth", 12),
+        ("twentieth", 20),
+        ("twenty-first", 21),
+        ("thirty first", 31),
+        ("dritten", 3),
+        ("siebte", 7),
+        ("neunzehnten", 19),
+        ("einunddreißigsten", 31),
+        ("15.", 15),
+        ("premier", 1),
+        ("2e", 2),
+        ("quatrième", 4),
+        ("dix-septième", 17),
+        ("vingt-et-unième", 21),
+        ("primero", 1),
+        ("tercer", 3),
+        ("1º", 1),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(parse_ordinal(input), Some(expected), "{input}");
+    }
+    assert_eq!(parse_ordinal("twenty"), None);
+}
+
+// ================================================================
+//  English
+// ================================================================
+
+mday_test!(
+    en_on_the_15th,
+    "en",
+    "on the 15th",
+    ExpressionKind::DayOfMonth,
+    day(2, 15)
+);
+mday_test!(
+    en_on_the_first,
+    "en",
+    "on the first",
+    ExpressionKind::DayOfMonth,
+    day(2, 1)
+);
+mday_test!(
+    en_on_the_twenty_first,
+    "en",
+    "on the twenty-first",
+    ExpressionKind::DayOfMonth,
+    day(2, 21)
+);
+mday_test!(
+    en_the_3rd_at_9am,
+    "en",
+    "the 3rd at 9am",
+    ExpressionKind::Combined,
+    at(2, 3, 9, 0)
+);
+
+#[test]
+fn en_the_first_alone_is_not_a_date() {
+    let s = scanner_for_languages(&["en"]);
+    assert!(s.scan("the first time", now()).is_empty());
+}
+
+// ================================================================
+//  German
+// ================================================================
+
+mday_test!(
+    de_am_15,
+    "de",
+    "am 15.",
+    ExpressionKind::DayOfMonth,
+    day(2, 15)
+);
+mday_test!(
+    de_am_dritten,
+    "de",
+    "am dritten",
+    ExpressionKind::DayOfMonth,
+    day(2, 3)
+);
+mday_test!(
+    de_am_15_um_10_uhr,
+    "de",
+    "am 15. um 10 Uhr",
+    ExpressionKind::Combined,
+    at(2, 15, 10, 0)
+);
+mday_test!(
+    de_am_15_3_stays_a_date,
+    "de",
+    "am 15.3.",
+    ExpressionKind::AbsoluteDate,
+    day(3, 15)
+);
+
+// ================================================================
+//  French
+// ================================================================
+
+mday_test!(
+    fr_le_15,
+    "fr",
+    "le 15",
+    ExpressionKind::DayOfMonth,
+    day(2, 15)
+);
+mday_test!(
+    fr_le_premier,
+    "fr",
+    "le premier",
+    ExpressionKind::DayOfMonth,
+    day(2, 1)
+);
+mday_test!(
+    fr_le_15_a_10h,
+    "fr",
+    "le 15 à 10h",
+    ExpressionKind::Combined,
+    at(2, 15, 10, 0)
+);
+
+// ================================================================
+//  Spanish
+// ================================================================
+
+mday_test!(
+    es_el_dia_15,
+    "es",
+    "el día 15",
+    ExpressionKind::DayOfMonth,
+    day(2, 15)
+);
+mday_test!(
+    es_el_primero,
+    "es",
+    "el primero",
+    ExpressionKind::DayOfMonth,
+    day(2, 1)
+);
+mday_test!(
+    es_el_15_a_las_10_30,
+    "es",
+    "el 15 a las 10:30",
+    ExpressionKind::Combined,
+    at(2, 15, 10, 30)
+);
+
+// ================================================================
+//  Bias
+// ================================================================
+
+#[test]
+fn bias_past_and_future() {
+    let m = scanner_with_bias(DateBias::Past).scan("on the 15th", now());
+    assert_eq!(m[0].resolved, day(1, 15));
+
+    let m = scanner_with_bias(DateBias::Future).scan("on the 3rd", now());
+    assert_eq!(m[0].resolved, day(3, 3));
+}
+
+#[test]
+fn bias_skips_short_months() {
+    let m = scanner_with_bias(DateBias::Future).scan("on the 30th", now());
+    assert_eq!(m[0].resolved, day(3, 30));
+
+    let m = scanner_with_bias(DateBias::Nearest).scan("on the 31st", now());
+    assert_eq!(m[0].resolved, day(1, 31));
+}
+
+#[test]
+fn invalid_day_does_not_match() {
+    let s = scanner_for_languages(&["en"]);
+    assert!(s.scan("on the 32nd", now()).is_empty());
+}
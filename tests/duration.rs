@@ -0,0 +1,168 @@
+use chrono::{Duration, TimeZone, Utc};
+use clockwords::{ExpressionKind, ResolvedTime, Span, scanner_for_languages};
+
+/// Saturday Feb 7, 2026 14:30:00 UTC
+fn now() -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, 7, 14, 30, 0).unwrap()
+}
+
+/// Test expecting exactly one duration match of the given number of minutes.
+macro_rules! duration_test {
+    ($name:ident, $lang:expr, $input:expr, $minutes:expr) => {
+        #[test]
+        fn $name() {
+            let s = scanner_for_languages(&[$lang]);
+            let m = s.scan($input, now());
+            assert_eq!(
+                m.len(),
+                1,
+                "expected 1 match for {:?}, got {}",
+                $input,
+                m.len()
+            );
+            assert_eq!(m[0].kind, ExpressionKind::Duration);
+            assert_eq!(m[0].span, Span::new(0, $input.len()));
+            assert_eq!(
+                m[0].resolved,
+                ResolvedTime::Duration(Duration::minutes($minutes))
+            );
+        }
+    };
+}
+
+// ================================================================
+//  Compact
+// ================================================================
+
+duration_test!(compact_2h, "en", "2h", 120);
+duration_test!(compact_decimal_dot, "en", "1.5h", 90);
+duration_test!(compact_decimal_comma, "de", "1,5 h", 90);
+duration_test!(compact_90min, "fr", "90min", 90);
+duration_test!(compact_90_min, "es", "90 min", 90);
+duration_test!(compact_2h30, "de", "2h30", 150);
+duration_test!(compact_2h_30min, "en", "2h 30min", 150);
+duration_test!(compact_colon, "en", "1:30h", 90);
+
+#[test]
+fn compact_seconds() {
+    let s = scanner_for_languages(&["en"]);
+    let m = s.scan("45s", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].resolved, ResolvedTime::Duration(Duration::seconds(45)));
+}
+
+#[test]
+fn fr_compact_needs_duration_cue() {
+    let s = scanner_for_languages(&["fr"]);
+    for (text, minutes) in [("pendant 2h", 120), ("en 2h30", 150), ("durant 45min", 45)] {
+        let m = s.scan(text, now());
+        assert_eq!(m.len(), 1, "expected 1 match for {text:?}");
+        assert_eq!(m[0].kind, ExpressionKind::Duration);
+        assert_eq!(
+            m[0].resolved,
+            ResolvedTime::Duration(Duration::minutes(minutes))
+        );
+    }
+}
+
+#[test]
+fn decades_are_not_seconds() {
+    let s = scanner_for_languages(&["en"]);
+    for text in ["back in the 90s", "the 1990s", "in the 80s"] {
+        assert!(s.scan(text, now()).is_empty(), "{text:?} matched");
+    }
+}
+
+// ================================================================
+//  English
+// ================================================================
+
+duration_test!(en_45_minutes, "en", "45 minutes", 45);
+duration_test!(en_decimal_hours, "en", "1.5 hours", 90);
+duration_test!(en_hour_and_a_half, "en", "an hour and a half", 90);
+duration_test!(en_one_and_a_half_hours, "en", "one and a half hours", 90);
+duration_test!(en_half_an_hour, "en", "half an hour", 30);
+duration_test!(en_a_half_hour, "en", "a half-hour", 30);
+duration_test!(en_three_quarters, "en", "three quarters of an hour", 45);
+duration_test!(en_hours_and_minutes, "en", "2 hours and 30 minutes", 150);
+
+// ================================================================
+//  German
+// ================================================================
+
+duration_test!(de_45_minuten, "de", "45 Minuten", 45);
+duration_test!(de_decimal_comma, "de", "2,5 Stunden", 150);
+duration_test!(de_std_abbrev, "de", "2 Std.", 120);
+duration_test!(de_anderthalb, "de", "anderthalb Stunden", 90);
+duration_test!(de_zweieinhalb, "de", "zweieinhalb Stunden", 150);
+duration_test!(de_halbe_stunde, "de", "eine halbe Stunde", 30);
+duration_test!(de_viertelstunde, "de", "eine Viertelstunde", 15);
+duration_test!(de_dreiviertelstunde, "de", "eine Dreiviertelstunde", 45);
+duration_test!(
+    de_stunden_und_minuten,
+    "de",
+    "2 Stunden und 30 Minuten",
+    150
+);
+
+// ================================================================
+//  French
+// ================================================================
+
+duration_test!(fr_45_minutes, "fr", "45 minutes", 45);
+duration_test!(fr_heure_et_demie, "fr", "une heure et demie", 90);
+duration_test!(fr_heure_et_quart, "fr", "une heure et quart", 75);
+duration_test!(fr_demi_heure, "fr", "une demi-heure", 30);
+duration_test!(fr_quart_d_heure, "fr", "un quart d'heure", 15);
+duration_test!(fr_trois_quarts, "fr", "trois quarts d'heure", 45);
+duration_test!(fr_heure_minutes, "fr", "1 heure 30", 90);
+
+// ================================================================
+//  Spanish
+// ================================================================
+
+duration_test!(es_45_minutos, "es", "45 minutos", 45);
+duration_test!(es_hora_y_media, "es", "hora y media", 90);
+duration_test!(es_horas_y_cuarto, "es", "dos horas y cuarto", 135);
+duration_test!(es_media_hora, "es", "media hora", 30);
+duration_test!(es_cuarto_de_hora, "es", "un cuarto de hora", 15);
+duration_test!(es_tres_cuartos, "es", "tres cuartos de hora", 45);
+duration_test!(es_horas_y_minutos, "es", "2 horas y 30 minutos", 150);
+
+// ================================================================
+//  Interaction with other expressions
+// ================================================================
+
+#[test]
+fn duration_in_sentence() {
+    let s = scanner_for_languages(&["en"]);
+    let text = "spent 1:30h on code review";
+    let m = s.scan(text, now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(&text[m[0].span.as_range()], "1:30h");
+}
+
+#[test]
+fn offset_takes_precedence_over_duration() {
+    let s = scanner_for_languages(&["en", "de"]);
+    for text in ["in 30 minutes", "vor 2 Stunden"] {
+        let m = s.scan(text, now());
+        assert_eq!(m.len(), 1, "{text}");
+        assert_eq!(m[0].kind, ExpressionKind::RelativeTimeOffset, "{text}");
+    }
+}
+
+#[test]
+fn time_spec_takes_precedence_over_compact() {
+    let s = scanner_for_languages(&["fr"]);
+    let m = s.scan("à 13h30", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].kind, ExpressionKind::TimeSpecification);
+}
+
+#[test]
+fn bare_unit_is_not_a_duration() {
+    let s = scanner_for_languages(&["es"]);
+    assert_eq!(s.scan("una hora", now()).len(), 1);
+    assert_eq!(s.scan("la hora", now()).len(), 0);
+}
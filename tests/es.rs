@@ -151,6 +151,31 @@ range_test!(
     chrono::Utc.with_ymd_and_hms(2026, 2, 7, 12, 0, 0).unwrap()
 );
 
+range_test!(
+    es_de_la_una_a_las_tres,
+    "es",
+    "de la una a las tres",
+    ExpressionKind::TimeRange,
+    chrono::Utc.with_ymd_and_hms(2026, 2, 7, 1, 0, 0).unwrap(),
+    chrono::Utc.with_ymd_and_hms(2026, 2, 7, 3, 0, 0).unwrap()
+);
+
+range_test!(
+    es_de_1_a_3,
+    "es",
+    "de 1 a 3",
+    ExpressionKind::TimeRange,
+    chrono::Utc.with_ymd_and_hms(2026, 2, 7, 1, 0, 0).unwrap(),
+    chrono::Utc.with_ymd_and_hms(2026, 2, 7, 3, 0, 0).unwrap()
+);
+
+#[test]
+fn es_de_a_quantity_is_not_a_range() {
+    let s = scanner_for_languages(&["es"]);
+    assert!(s.scan("de 1 a 3 d\u{ed}as", now()).is_empty());
+    assert!(s.scan("de dos a tres personas", now()).is_empty());
+}
+
 // --- Combined ---
 
 point_test!(
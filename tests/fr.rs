@@ -153,6 +153,30 @@ range_test!(
     chrono::Utc.with_ymd_and_hms(2026, 2, 7, 12, 0, 0).unwrap()
 );
 
+range_test!(
+    fr_de_trois_a_cinq_heures,
+    "fr",
+    "de trois \u{e0} cinq heures",
+    ExpressionKind::TimeRange,
+    chrono::Utc.with_ymd_and_hms(2026, 2, 7, 3, 0, 0).unwrap(),
+    chrono::Utc.with_ymd_and_hms(2026, 2, 7, 5, 0, 0).unwrap()
+);
+
+range_test!(
+    fr_de_9h_a_11h30,
+    "fr",
+    "de 9h \u{e0} 11h30",
+    ExpressionKind::TimeRange,
+    chrono::Utc.with_ymd_and_hms(2026, 2, 7, 9, 0, 0).unwrap(),
+    chrono::Utc.with_ymd_and_hms(2026, 2, 7, 11, 30, 0).unwrap()
+);
+
+#[test]
+fn fr_de_a_without_hours_is_not_a_range() {
+    let s = scanner_for_languages(&["fr"]);
+    assert!(s.scan("de 2 \u{e0} 3 enfants", now()).is_empty());
+}
+
 // --- Combined ---
 
 point_test!(
@@ -0,0 +1,371 @@
+use std::sync::Arc;
+
+use chrono::{NaiveDate, TimeZone, Utc, Weekday};
+use clockwords::holiday::{
+    CustomCalendar, France, GermanState, Germany, Holiday, HolidayCalendar, HolidayRule, Spain,
+    UnitedStates, easter_sunday,
+};
+use clockwords::{
+    DateBias, ExpressionKind, ParserConfig, ResolvedTime, Span, TimeExpressionScanner,
+    scanner_for_languages,
+};
+
+/// Saturday Feb 7, 2026 14:30:00 UTC
+fn now() -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, 7, 14, 30, 0).unwrap()
+}
+
+/// Full-day range for the given date.
+fn day(y: i32, m: u32, d: u32) -> ResolvedTime {
+    let start = Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap();
+    ResolvedTime::Range {
+        start,
+        end: start + chrono::Duration::days(1),
+    }
+}
+
+/// Point in time for the given date and hour.
+fn at(y: i32, m: u32, d: u32, h: u32) -> ResolvedTime {
+    ResolvedTime::Point(Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap())
+}
+
+/// Helper: create a scanner for one language with the given holiday calendar.
+fn scanner_with_calendar(
+    lang: Box<dyn clockwords::lang::LanguageParser>,
+    calendar: impl HolidayCalendar + 'static,
+) -> TimeExpressionScanner {
+    let config = ParserConfig {
+        holidays: Some(Arc::new(calendar)),
+        ..ParserConfig::default()
+    };
+    TimeExpressionScanner::new(vec![lang], config)
+}
+
+/// Test expecting exactly one match of the given kind covering the whole input.
+macro_rules! holiday_test {
+    ($name:ident, $lang:expr, $input:expr, $kind:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            let s = scanner_for_languages(&[$lang]);
+            let m = s.scan($input, now());
+            assert_eq!(
+                m.len(),
+                1,
+                "expected 1 match for {:?}, got {}",
+                $input,
+                m.len()
+            );
+            assert_eq!(m[0].kind, $kind);
+            assert_eq!(m[0].span, Span::new(0, $input.len()));
+            assert_eq!(m[0].resolved, $expected);
+        }
+    };
+}
+
+// ================================================================
+//  Dates
+// ================================================================
+
+#[test]
+fn computus() {
+    let d = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+    assert_eq!(easter_sunday(1818), d(1818, 3, 22));
+    assert_eq!(easter_sunday(2024), d(2024, 3, 31));
+    assert_eq!(easter_sunday(2025), d(2025, 4, 20));
+    assert_eq!(easter_sunday(2026), d(2026, 4, 5));
+    assert_eq!(easter_sunday(2038), d(2038, 4, 25));
+    assert_eq!(easter_sunday(1500), None);
+}
+
+#[test]
+fn holiday_rules() {
+    let d = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+    assert_eq!(Holiday::Thanksgiving.rule().date(2026), d(2026, 11, 26));
+    assert_eq!(Holiday::MemorialDay.rule().date(2026), d(2026, 5, 25));
+    assert_eq!(Holiday::CorpusChristi.rule().date(2026), d(2026, 6, 4));
+    assert_eq!(Holiday::RepentanceDay.rule().date(2026), d(2026, 11, 18));
+    assert_eq!(Holiday::RepentanceDay.rule().date(2028), d(2028, 11, 22));
+    let rule = HolidayRule::WeekdayBefore {
+        month: 11,
+        day: 23,
+        weekday: Weekday::Wed,
+    };
+    assert_eq!(rule.date(2022), d(2022, 11, 16));
+}
+
+// ================================================================
+//  English
+// ================================================================
+
+holiday_test!(
+    en_on_christmas,
+    "en",
+    "on Christmas",
+    ExpressionKind::Holiday,
+    day(2025, 12, 25)
+);
+holiday_test!(
+    en_easter_monday,
+    "en",
+    "Easter Monday",
+    ExpressionKind::Holiday,
+    day(2026, 4, 6)
+);
+holiday_test!(
+    en_thanksgiving_2027,
+    "en",
+    "Thanksgiving 2027",
+    ExpressionKind::Holiday,
+    day(2027, 11, 25)
+);
+holiday_test!(
+    en_christmas_eve_at_6pm,
+    "en",
+    "on Christmas Eve at 6pm",
+    ExpressionKind::Combined,
+    at(2025, 12, 24, 18)
+);
+
+#[test]
+fn en_epiphany_needs_a_feast() {
+    let s = scanner_for_languages(&["en"]);
+    assert!(s.scan("I had an epiphany", now()).is_empty());
+}
+
+#[test]
+fn en_feast_name_as_noun_modifier() {
+    let s = scanner_for_languages(&["en"]);
+    assert!(s.scan("the Christmas tree is up", now()).is_empty());
+    assert!(s.scan("Easter eggs for the kids", now()).is_empty());
+
+    let m = s.scan("Thanksgiving dinner on Thanksgiving", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].span, Span::new(20, 35));
+
+    let m = s.scan("the Christmas 2026 party", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].resolved, day(2026, 12, 25));
+
+    let m = s.scan("see you at Christmas.", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].resolved, day(2025, 12, 25));
+}
+
+holiday_test!(
+    en_christmas_eve_6pm,
+    "en",
+    "Christmas Eve 6pm",
+    ExpressionKind::Combined,
+    at(2025, 12, 24, 18)
+);
+
+// ================================================================
+//  German
+// ================================================================
+
+holiday_test!(
+    de_zu_ostern,
+    "de",
+    "zu Ostern",
+    ExpressionKind::Holiday,
+    day(2026, 4, 5)
+);
+holiday_test!(
+    de_an_pfingsten,
+    "de",
+    "an Pfingsten",
+    ExpressionKind::Holiday,
+    day(2026, 5, 24)
+);
+holiday_test!(
+    de_mariae_himmelfahrt,
+    "de",
+    "Mariä Himmelfahrt",
+    ExpressionKind::Holiday,
+    day(2025, 8, 15)
+);
+holiday_test!(
+    de_heiligabend_um_18_uhr,
+    "de",
+    "Heiligabend um 18 Uhr",
+    ExpressionKind::Combined,
+    at(2025, 12, 24, 18)
+);
+
+// ================================================================
+//  French
+// ================================================================
+
+holiday_test!(
+    fr_a_noel,
+    "fr",
+    "à Noël",
+    ExpressionKind::Holiday,
+    day(2025, 12, 25)
+);
+holiday_test!(
+    fr_le_lundi_de_paques,
+    "fr",
+    "le lundi de Pâques",
+    ExpressionKind::Holiday,
+    day(2026, 4, 6)
+);
+holiday_test!(
+    fr_a_la_toussaint_a_10h,
+    "fr",
+    "à la Toussaint à 10h",
+    ExpressionKind::Combined,
+    at(2025, 11, 1, 10)
+);
+
+// ================================================================
+//  Spanish
+// ================================================================
+
+holiday_test!(
+    es_el_dia_de_reyes,
+    "es",
+    "el Día de Reyes",
+    ExpressionKind::Holiday,
+    day(2026, 1, 6)
+);
+holiday_test!(
+    es_el_viernes_santo,
+    "es",
+    "el Viernes Santo",
+    ExpressionKind::Holiday,
+    day(2026, 4, 3)
+);
+holiday_test!(
+    es_nochebuena_a_las_21,
+    "es",
+    "en Nochebuena a las 21:00",
+    ExpressionKind::Combined,
+    at(2025, 12, 24, 21)
+);
+
+// ================================================================
+//  Calendars
+// ================================================================
+
+#[test]
+fn year_bias_picks_the_occurrence() {
+    let config = ParserConfig {
+        year_bias: DateBias::Future,
+        ..ParserConfig::default()
+    };
+    let s =
+        TimeExpressionScanner::new(vec![Box::new(clockwords::lang::en::English::new())], config);
+    let m = s.scan("Christmas", now());
+    assert_eq!(m[0].resolved, day(2026, 12, 25));
+}
+
+#[test]
+fn regional_calendars() {
+    assert!(UnitedStates.observes(Holiday::Thanksgiving));
+    assert!(!France.observes(Holiday::Thanksgiving));
+    assert!(Spain.observes(Holiday::Epiphany));
+    assert!(Germany::default().observes(Holiday::CorpusChristi));
+    assert!(Germany::state(GermanState::Bayern).observes(Holiday::CorpusChristi));
+    assert!(!Germany::state(GermanState::Berlin).observes(Holiday::CorpusChristi));
+    assert!(Germany::state(GermanState::Berlin).observes(Holiday::EasterMonday));
+}
+
+#[test]
+fn unobserved_holiday_is_not_recognized() {
+    let s = scanner_with_calendar(
+        Box::new(clockwords::lang::de::German::new()),
+        Germany::state(GermanState::Berlin),
+    );
+    assert!(s.scan("an Fronleichnam", now()).is_empty());
+    assert_eq!(s.scan("am Ostermontag", now()).len(), 1);
+
+    let s = scanner_with_calendar(Box::new(clockwords::lang::en::English::new()), France);
+    assert!(s.scan("Thanksgiving", now()).is_empty());
+}
+
+#[test]
+fn custom_holidays() {
+    let calendar = CustomCalendar::extending(Germany::state(GermanState::Bayern))
+        .with_holiday("Betriebsausflug", HolidayRule::Fixed { month: 6, day: 12 })
+        .with_holiday("Firmenjubiläum", HolidayRule::Fixed { month: 3, day: 2 });
+    let s = scanner_with_calendar(Box::new(clockwords::lang::de::German::new()), calendar);
+
+    let m = s.scan("Ich bin am Betriebsausflug 2026 nicht da", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].kind, ExpressionKind::Holiday);
+    assert_eq!(m[0].resolved, day(2026, 6, 12));
+
+    let m = s.scan("firmenjubiläum", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].resolved, day(2026, 3, 2));
+
+    let m = s.scan("an Fronleichnam", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].resolved, day(2026, 6, 4));
+}
+
+#[test]
+fn custom_calendar_without_base() {
+    let calendar = CustomCalendar::new().with_holiday(
+        "Founders Day",
+        HolidayRule::NthWeekday {
+            month: 9,
+            weekday: Weekday::Fri,
+            n: 1,
+        },
+    );
+    let s = scanner_with_calendar(Box::new(clockwords::lang::en::English::new()), calendar);
+    let m = s.scan("see you on Founders Day", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].resolved, day(2025, 9, 5));
+    assert!(s.scan("Christmas", now()).is_empty());
+}
+
+#[test]
+fn custom_holidays_take_part_in_every_pass() {
+    let calendar =
+        CustomCalendar::new().with_holiday("Founders Day", HolidayRule::Fixed { month: 9, day: 4 });
+    let s = scanner_with_calendar(Box::new(clockwords::lang::en::English::new()), calendar);
+    let only = |text: &str| {
+        let m = s.scan(text, now());
+        assert_eq!(m.len(), 1, "expected 1 match for {text:?}, got {m:?}");
+        assert_eq!(m[0].span, Span::new(0, text.len()));
+        m.into_iter().next().unwrap()
+    };
+
+    let m = only("Founders Day at 3pm");
+    assert_eq!(m.kind, ExpressionKind::Combined);
+    assert_eq!(m.resolved, at(2025, 9, 4, 15));
+
+    let m = only("since Founders Day");
+    assert_eq!(m.kind, ExpressionKind::OpenRange);
+    assert_eq!(
+        m.resolved,
+        ResolvedTime::Since(Utc.with_ymd_and_hms(2025, 9, 4, 0, 0, 0).unwrap())
+    );
+
+    let m = only("two days after Founders Day");
+    assert_eq!(m.kind, ExpressionKind::AnchoredOffset);
+    assert_eq!(m.resolved, day(2025, 9, 6));
+
+    let m = only("Founders Day 9am PST");
+    assert_eq!(m.kind, ExpressionKind::Combined);
+    assert_eq!(m.resolved, at(2025, 9, 4, 17));
+}
+
+#[test]
+fn custom_holidays_in_german() {
+    let calendar = CustomCalendar::new()
+        .with_holiday("Betriebsausflug", HolidayRule::Fixed { month: 6, day: 12 });
+    let s = scanner_with_calendar(Box::new(clockwords::lang::de::German::new()), calendar);
+
+    let m = s.scan("am Betriebsausflug um 9 Uhr", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].kind, ExpressionKind::Combined);
+    assert_eq!(m[0].resolved, at(2026, 6, 12, 9));
+
+    let m = s.scan("bis zum Betriebsausflug", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].kind, ExpressionKind::OpenRange);
+}
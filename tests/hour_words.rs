@@ -0,0 +1,206 @@
+use chrono::{TimeZone, Utc};
+use clockwords::{ExpressionKind, ResolvedTime, Span, scanner_for_languages};
+
+/// Saturday Feb 7, 2026 14:30:00 UTC
+fn now() -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, 7, 14, 30, 0).unwrap()
+}
+
+/// Point in time given as (day of February 2026, hour).
+fn at(day: u32, hour: u32) -> ResolvedTime {
+    ResolvedTime::Point(Utc.with_ymd_and_hms(2026, 2, day, hour, 0, 0).unwrap())
+}
+
+/// Range between two whole hours on Feb 7, 2026.
+fn hours(from: u32, to: u32) -> ResolvedTime {
+    ResolvedTime::Range {
+        start: Utc.with_ymd_and_hms(2026, 2, 7, from, 0, 0).unwrap(),
+        end: Utc.with_ymd_and_hms(2026, 2, 7, to, 0, 0).unwrap(),
+    }
+}
+
+/// Test expecting exactly one match of the given kind covering the whole input.
+macro_rules! hour_test {
+    ($name:ident, $lang:expr, $input:expr, $kind:expr, $resolved:expr) => {
+        #[test]
+        fn $name() {
+            let s = scanner_for_languages(&[$lang]);
+            let m = s.scan($input, now());
+            assert_eq!(
+                m.len(),
+                1,
+                "expected 1 match for {:?}, got {}",
+                $input,
+                m.len()
+            );
+            assert_eq!(m[0].kind, $kind);
+            assert_eq!(m[0].span, Span::new(0, $input.len()));
+            assert_eq!(m[0].resolved, $resolved);
+        }
+    };
+}
+
+// ================================================================
+//  English
+// ================================================================
+
+hour_test!(
+    en_at_three_pm,
+    "en",
+    "at three pm",
+    ExpressionKind::TimeSpecification,
+    at(7, 15)
+);
+hour_test!(
+    en_eleven_oclock,
+    "en",
+    "eleven o'clock",
+    ExpressionKind::TimeSpecification,
+    at(7, 11)
+);
+hour_test!(
+    en_yesterday_at_seven_am,
+    "en",
+    "yesterday at seven am",
+    ExpressionKind::Combined,
+    at(6, 7)
+);
+hour_test!(
+    en_two_hours_until_five_pm,
+    "en",
+    "2 hours until five pm",
+    ExpressionKind::TimeRange,
+    hours(15, 17)
+);
+
+// ================================================================
+//  German
+// ================================================================
+
+hour_test!(
+    de_um_drei_uhr,
+    "de",
+    "um drei Uhr",
+    ExpressionKind::TimeSpecification,
+    at(7, 3)
+);
+hour_test!(
+    de_um_fuenfzehn_uhr,
+    "de",
+    "um fünfzehn Uhr",
+    ExpressionKind::TimeSpecification,
+    at(7, 15)
+);
+hour_test!(
+    de_gestern_um_acht_uhr,
+    "de",
+    "gestern um acht Uhr",
+    ExpressionKind::Combined,
+    at(6, 8)
+);
+hour_test!(
+    de_von_neun_bis_elf_uhr,
+    "de",
+    "von neun bis elf Uhr",
+    ExpressionKind::TimeRange,
+    hours(9, 11)
+);
+
+// ================================================================
+//  French
+// ================================================================
+
+hour_test!(
+    fr_a_trois_heures,
+    "fr",
+    "à trois heures",
+    ExpressionKind::TimeSpecification,
+    at(7, 3)
+);
+hour_test!(
+    fr_a_une_heure,
+    "fr",
+    "à une heure",
+    ExpressionKind::TimeSpecification,
+    at(7, 1)
+);
+hour_test!(
+    fr_a_dix_sept_heures,
+    "fr",
+    "à dix-sept heures",
+    ExpressionKind::TimeSpecification,
+    at(7, 17)
+);
+hour_test!(
+    fr_a_15_heures,
+    "fr",
+    "à 15 heures",
+    ExpressionKind::TimeSpecification,
+    at(7, 15)
+);
+hour_test!(
+    fr_hier_a_huit_heures,
+    "fr",
+    "hier à huit heures",
+    ExpressionKind::Combined,
+    at(6, 8)
+);
+hour_test!(
+    fr_entre_neuf_et_onze_heures,
+    "fr",
+    "entre neuf et onze heures",
+    ExpressionKind::TimeRange,
+    hours(9, 11)
+);
+
+// ================================================================
+//  Spanish
+// ================================================================
+
+hour_test!(
+    es_a_la_una,
+    "es",
+    "a la una",
+    ExpressionKind::TimeSpecification,
+    at(7, 1)
+);
+hour_test!(
+    es_a_las_tres,
+    "es",
+    "a las tres",
+    ExpressionKind::TimeSpecification,
+    at(7, 3)
+);
+hour_test!(
+    es_a_las_diecisiete,
+    "es",
+    "a las diecisiete",
+    ExpressionKind::TimeSpecification,
+    at(7, 17)
+);
+hour_test!(
+    es_ayer_a_la_una,
+    "es",
+    "ayer a la una",
+    ExpressionKind::Combined,
+    at(6, 1)
+);
+hour_test!(
+    es_entre_la_una_y_las_tres,
+    "es",
+    "entre la una y las tres",
+    ExpressionKind::TimeRange,
+    hours(1, 3)
+);
+
+// ================================================================
+//  Interaction with other expressions
+// ================================================================
+
+#[test]
+fn offset_is_not_a_time() {
+    let s = scanner_for_languages(&["fr"]);
+    let m = s.scan("il y a trois heures", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].kind, ExpressionKind::RelativeTimeOffset);
+}
@@ -0,0 +1,251 @@
+use chrono::{NaiveTime, TimeZone, Utc};
+use clockwords::resolve::{infer_hour, to_24h};
+use clockwords::{
+    BusinessHours, ExpressionKind, HourInference, ParserConfig, ResolvedTime, Span,
+    TimeExpressionScanner, scanner_for_languages,
+};
+
+/// Saturday Feb 7, 2026 14:30:00 UTC
+fn now() -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, 7, 14, 30, 0).unwrap()
+}
+
+/// Point in time on the given day of February 2026.
+fn at(d: u32, h: u32, min: u32) -> ResolvedTime {
+    ResolvedTime::Point(Utc.with_ymd_and_hms(2026, 2, d, h, min, 0).unwrap())
+}
+
+/// Range between two times on the given day of February 2026, given as
+/// (hour, minute).
+fn range(d: u32, from: (u32, u32), to: (u32, u32)) -> ResolvedTime {
+    ResolvedTime::Range {
+        start: Utc.with_ymd_and_hms(2026, 2, d, from.0, from.1, 0).unwrap(),
+        end: Utc.with_ymd_and_hms(2026, 2, d, to.0, to.1, 0).unwrap(),
+    }
+}
+
+/// Helper: create an English scanner with the given hour inference.
+fn scanner_with_inference(inference: HourInference) -> TimeExpressionScanner {
+    let config = ParserConfig {
+        hour_inference: inference,
+        ..ParserConfig::default()
+    };
+    TimeExpressionScanner::new(vec![Box::new(clockwords::lang::en::English::new())], config)
+}
+
+/// Test expecting exactly one English match of the given kind covering the
+/// whole input.
+macro_rules! mer_test {
+    ($name:ident, $input:expr, $kind:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            let s = scanner_for_languages(&["en"]);
+            let m = s.scan($input, now());
+            assert_eq!(
+                m.len(),
+                1,
+                "expected 1 match for {:?}, got {}",
+                $input,
+                m.len()
+            );
+            assert_eq!(m[0].kind, $kind);
+            assert_eq!(m[0].span, Span::new(0, $input.len()));
+            assert_eq!(m[0].resolved, $expected);
+        }
+    };
+}
+
+// ================================================================
+//  Resolver
+// ================================================================
+
+#[test]
+fn dotted_meridiem() {
+    assert_eq!(to_24h(3, "p.m."), 15);
+    assert_eq!(to_24h(12, "A.M."), 0);
+    assert_eq!(to_24h(12, "PM"), 12);
+}
+
+#[test]
+fn hour_inference() {
+    let hours = BusinessHours::default();
+    assert_eq!(infer_hour(3, HourInference::AsWritten, hours), 3);
+    assert_eq!(infer_hour(7, HourInference::PmUpTo(7), hours), 19);
+    assert_eq!(infer_hour(8, HourInference::PmUpTo(7), hours), 8);
+    assert_eq!(infer_hour(12, HourInference::PmUpTo(7), hours), 12);
+    assert_eq!(infer_hour(15, HourInference::PmUpTo(7), hours), 15);
+    assert_eq!(infer_hour(3, HourInference::BusinessHours, hours), 15);
+    assert_eq!(infer_hour(10, HourInference::BusinessHours, hours), 10);
+    assert_eq!(infer_hour(12, HourInference::BusinessHours, hours), 12);
+    assert_eq!(infer_hour(5, HourInference::BusinessHours, hours), 17);
+    // Neither 8:00 nor 20:00 is within 09:00–17:00
+    assert_eq!(infer_hour(8, HourInference::BusinessHours, hours), 8);
+}
+
+// ================================================================
+//  Meridiem forms
+// ================================================================
+
+mer_test!(
+    dotted_pm,
+    "3 p.m.",
+    ExpressionKind::TimeSpecification,
+    at(7, 15, 0)
+);
+mer_test!(
+    uppercase_dotted_pm,
+    "3 P.M.",
+    ExpressionKind::TimeSpecification,
+    at(7, 15, 0)
+);
+mer_test!(
+    at_dotted_with_minutes,
+    "at 3:30 p.m.",
+    ExpressionKind::TimeSpecification,
+    at(7, 15, 30)
+);
+mer_test!(
+    tomorrow_at_dotted_am,
+    "tomorrow at 6 a.m.",
+    ExpressionKind::Combined,
+    at(8, 6, 0)
+);
+
+// ================================================================
+//  Ranges
+// ================================================================
+
+mer_test!(
+    from_am_to_pm,
+    "from 9am to 5pm",
+    ExpressionKind::TimeRange,
+    range(7, (9, 0), (17, 0))
+);
+mer_test!(
+    dash_shared_am,
+    "9-11am",
+    ExpressionKind::TimeRange,
+    range(7, (9, 0), (11, 0))
+);
+mer_test!(
+    dash_both_ends,
+    "9am-5pm",
+    ExpressionKind::TimeRange,
+    range(7, (9, 0), (17, 0))
+);
+mer_test!(
+    between_shared_pm,
+    "between 2 and 4pm",
+    ExpressionKind::TimeRange,
+    range(7, (14, 0), (16, 0))
+);
+mer_test!(
+    pm_end_switches_start_to_am,
+    "from 9 to 5pm",
+    ExpressionKind::TimeRange,
+    range(7, (9, 0), (17, 0))
+);
+mer_test!(
+    am_start_switches_end_to_pm,
+    "from 9:30am to 2",
+    ExpressionKind::TimeRange,
+    range(7, (9, 30), (14, 0))
+);
+mer_test!(
+    combined_weekday_range,
+    "next Monday from 9am to 5pm",
+    ExpressionKind::Combined,
+    range(16, (9, 0), (17, 0))
+);
+mer_test!(
+    combined_day_between,
+    "yesterday between 2 and 4:30pm",
+    ExpressionKind::Combined,
+    range(6, (14, 0), (16, 30))
+);
+
+#[test]
+fn pm_start_carries_into_next_day() {
+    let s = scanner_for_languages(&["en"]);
+    let m = s.scan("from 10pm to 2", now());
+    assert_eq!(
+        m[0].resolved,
+        ResolvedTime::Range {
+            start: Utc.with_ymd_and_hms(2026, 2, 7, 22, 0, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2026, 2, 8, 2, 0, 0).unwrap(),
+        }
+    );
+}
+
+#[test]
+fn dash_range_without_meridiem_needs_compact_ranges() {
+    let s = scanner_for_languages(&["en"]);
+    assert!(s.scan("pages 9-11", now()).is_empty());
+}
+
+// ================================================================
+//  Hours without am/pm
+// ================================================================
+
+mer_test!(
+    at_bare_hour,
+    "at 3",
+    ExpressionKind::TimeSpecification,
+    at(7, 3, 0)
+);
+mer_test!(
+    tomorrow_at_bare_hour,
+    "tomorrow at 9",
+    ExpressionKind::Combined,
+    at(8, 9, 0)
+);
+
+#[test]
+fn at_word_is_not_a_time() {
+    let s = scanner_for_languages(&["en"]);
+    assert!(s.scan("at one point", now()).is_empty());
+}
+
+#[test]
+fn pm_up_to_inference() {
+    let s = scanner_with_inference(HourInference::PmUpTo(7));
+    assert_eq!(s.scan("at 3", now())[0].resolved, at(7, 15, 0));
+    assert_eq!(s.scan("at 8", now())[0].resolved, at(7, 8, 0));
+    assert_eq!(s.scan("half past three", now())[0].resolved, at(7, 15, 30));
+    assert_eq!(
+        s.scan("from nine to five", now())[0].resolved,
+        range(7, (9, 0), (17, 0))
+    );
+    // A meridiem or a leading zero is always taken as written
+    assert_eq!(s.scan("at 3am", now())[0].resolved, at(7, 3, 0));
+    assert_eq!(s.scan("at 03:00", now())[0].resolved, at(7, 3, 0));
+}
+
+#[test]
+fn business_hours_inference() {
+    let s = scanner_with_inference(HourInference::BusinessHours);
+    assert_eq!(s.scan("tomorrow at 2", now())[0].resolved, at(8, 14, 0));
+    assert_eq!(s.scan("at 10:30", now())[0].resolved, at(7, 10, 30));
+    // The closing hour is within business hours
+    assert_eq!(s.scan("at 5", now())[0].resolved, at(7, 17, 0));
+    assert_eq!(
+        s.scan("from 9 to 5", now())[0].resolved,
+        range(7, (9, 0), (17, 0))
+    );
+    assert_eq!(
+        s.scan("from 1 to 5", now())[0].resolved,
+        range(7, (13, 0), (17, 0))
+    );
+
+    let config = ParserConfig {
+        hour_inference: HourInference::BusinessHours,
+        business_hours: BusinessHours {
+            start: NaiveTime::from_hms_opt(13, 0, 0).unwrap(),
+            end: NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+        },
+        ..ParserConfig::default()
+    };
+    let s =
+        TimeExpressionScanner::new(vec![Box::new(clockwords::lang::en::English::new())], config);
+    assert_eq!(s.scan("at 8", now())[0].resolved, at(7, 20, 0));
+}
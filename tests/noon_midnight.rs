@@ -0,0 +1,266 @@
+use chrono::{TimeZone, Utc};
+use clockwords::{
+    ExpressionKind, Midnight, ParserConfig, ResolvedTime, Span, TimeExpressionScanner,
+    scanner_for_languages,
+};
+
+/// Saturday Feb 7, 2026 14:30:00 UTC
+fn now() -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, 7, 14, 30, 0).unwrap()
+}
+
+/// Point in time given as (day of February 2026, hour).
+fn point(day: u32, hour: u32) -> ResolvedTime {
+    ResolvedTime::Point(Utc.with_ymd_and_hms(2026, 2, day, hour, 0, 0).unwrap())
+}
+
+/// Helper: create a scanner for one language with a specific config.
+fn scanner_with_config(
+    lang: Box<dyn clockwords::lang::LanguageParser>,
+    config: ParserConfig,
+) -> TimeExpressionScanner {
+    TimeExpressionScanner::new(vec![lang], config)
+}
+
+/// Test expecting exactly one match of the given kind covering the whole input.
+macro_rules! noon_test {
+    ($name:ident, $lang:expr, $input:expr, $kind:expr, $day:expr, $hour:expr) => {
+        #[test]
+        fn $name() {
+            let s = scanner_for_languages(&[$lang]);
+            let m = s.scan($input, now());
+            assert_eq!(
+                m.len(),
+                1,
+                "expected 1 match for {:?}, got {}",
+                $input,
+                m.len()
+            );
+            assert_eq!(m[0].kind, $kind);
+            assert_eq!(m[0].span, Span::new(0, $input.len()));
+            assert_eq!(m[0].resolved, point($day, $hour));
+        }
+    };
+}
+
+// ================================================================
+//  English
+// ================================================================
+
+noon_test!(
+    en_at_noon,
+    "en",
+    "at noon",
+    ExpressionKind::TimeSpecification,
+    7,
+    12
+);
+noon_test!(
+    en_12_noon,
+    "en",
+    "12 noon",
+    ExpressionKind::TimeSpecification,
+    7,
+    12
+);
+noon_test!(
+    en_midnight,
+    "en",
+    "midnight",
+    ExpressionKind::TimeSpecification,
+    8,
+    0
+);
+noon_test!(
+    en_tomorrow_at_noon,
+    "en",
+    "tomorrow at noon",
+    ExpressionKind::Combined,
+    8,
+    12
+);
+noon_test!(
+    en_last_friday_midnight,
+    "en",
+    "last Friday midnight",
+    ExpressionKind::Combined,
+    7,
+    0
+);
+noon_test!(
+    en_date_at_midday,
+    "en",
+    "Feb 3 at midday",
+    ExpressionKind::Combined,
+    3,
+    12
+);
+
+// ================================================================
+//  German
+// ================================================================
+
+noon_test!(
+    de_um_mitternacht,
+    "de",
+    "um Mitternacht",
+    ExpressionKind::TimeSpecification,
+    8,
+    0
+);
+noon_test!(
+    de_mittags,
+    "de",
+    "mittags",
+    ExpressionKind::TimeSpecification,
+    7,
+    12
+);
+noon_test!(
+    de_morgen_mittag,
+    "de",
+    "morgen Mittag",
+    ExpressionKind::Combined,
+    8,
+    12
+);
+noon_test!(
+    de_letzten_freitag_um_mitternacht,
+    "de",
+    "letzten Freitag um Mitternacht",
+    ExpressionKind::Combined,
+    7,
+    0
+);
+noon_test!(
+    de_letzten_freitagmittag,
+    "de",
+    "letzten Freitagmittag",
+    ExpressionKind::Combined,
+    6,
+    12
+);
+
+#[test]
+fn de_nachmittag_is_not_noon() {
+    let s = scanner_for_languages(&["de"]);
+    let m = s.scan("heute Nachmittag", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].kind, ExpressionKind::Combined);
+    assert!(matches!(m[0].resolved, ResolvedTime::Range { .. }));
+}
+
+// ================================================================
+//  French
+// ================================================================
+
+noon_test!(
+    fr_a_midi,
+    "fr",
+    "à midi",
+    ExpressionKind::TimeSpecification,
+    7,
+    12
+);
+noon_test!(
+    fr_a_minuit,
+    "fr",
+    "à minuit",
+    ExpressionKind::TimeSpecification,
+    8,
+    0
+);
+noon_test!(
+    fr_demain_a_midi,
+    "fr",
+    "demain à midi",
+    ExpressionKind::Combined,
+    8,
+    12
+);
+noon_test!(
+    fr_vendredi_dernier_a_minuit,
+    "fr",
+    "vendredi dernier à minuit",
+    ExpressionKind::Combined,
+    7,
+    0
+);
+
+#[test]
+fn fr_apres_midi_is_not_noon() {
+    let s = scanner_for_languages(&["fr"]);
+    let m = s.scan("cet après-midi", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].kind, ExpressionKind::PartOfDay);
+}
+
+// ================================================================
+//  Spanish
+// ================================================================
+
+noon_test!(
+    es_al_mediodia,
+    "es",
+    "al mediodía",
+    ExpressionKind::TimeSpecification,
+    7,
+    12
+);
+noon_test!(
+    es_a_medianoche,
+    "es",
+    "a medianoche",
+    ExpressionKind::TimeSpecification,
+    8,
+    0
+);
+noon_test!(
+    es_manana_al_mediodia,
+    "es",
+    "mañana al mediodía",
+    ExpressionKind::Combined,
+    8,
+    12
+);
+noon_test!(
+    es_viernes_pasado_a_la_medianoche,
+    "es",
+    "el viernes pasado a la medianoche",
+    ExpressionKind::Combined,
+    7,
+    0
+);
+
+// ================================================================
+//  Midnight rule
+// ================================================================
+
+#[test]
+fn midnight_start_of_day() {
+    let config = ParserConfig {
+        midnight: Midnight::StartOfDay,
+        ..ParserConfig::default()
+    };
+    let s = scanner_with_config(Box::new(clockwords::lang::en::English::new()), config);
+    let m = s.scan("last Friday midnight", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].resolved, point(6, 0));
+
+    let m = s.scan("midnight", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].resolved, point(7, 0));
+}
+
+#[test]
+fn midnight_in_local_timezone() {
+    let config = ParserConfig {
+        timezone: chrono_tz::Europe::Berlin,
+        ..ParserConfig::default()
+    };
+    let s = scanner_with_config(Box::new(clockwords::lang::de::German::new()), config);
+    let m = s.scan("morgen um Mitternacht", now());
+    assert_eq!(m.len(), 1);
+    // 00:00 Berlin (CET, UTC+1) on Feb 9 = 23:00 UTC on Feb 8
+    assert_eq!(m[0].resolved, point(8, 23));
+}
@@ -0,0 +1,324 @@
+use chrono::{TimeZone, Utc};
+use clockwords::{
+    DateOrder, ExpressionKind, ParserConfig, ResolvedTime, Span, TimeExpressionScanner,
+    scanner_for_languages,
+};
+
+/// Saturday Feb 7, 2026 14:30:00 UTC
+fn now() -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, 7, 14, 30, 0).unwrap()
+}
+
+/// Helper: create a scanner for one language with an explicit date order.
+fn scanner_with_order(lang: &str, order: DateOrder) -> TimeExpressionScanner {
+    let languages: Vec<Box<dyn clockwords::lang::LanguageParser>> = match lang {
+        "en" => vec![Box::new(clockwords::lang::en::English::new())],
+        "de" => vec![Box::new(clockwords::lang::de::German::new())],
+        _ => unreachable!(),
+    };
+    let config = ParserConfig {
+        date_order: Some(order),
+        ..Default::default()
+    };
+    TimeExpressionScanner::new(languages, config)
+}
+
+fn day(y: i32, m: u32, d: u32) -> ResolvedTime {
+    let start = Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap();
+    ResolvedTime::Range {
+        start,
+        end: start + chrono::Duration::days(1),
+    }
+}
+
+/// Test expecting exactly one numeric date match covering the given day.
+macro_rules! numeric_test {
+    ($name:ident, $lang:expr, $input:expr, $y:expr, $m:expr, $d:expr, $order:expr) => {
+        #[test]
+        fn $name() {
+            let s = scanner_for_languages(&[$lang]);
+            let m = s.scan($input, now());
+            assert_eq!(
+                m.len(),
+                1,
+                "expected 1 match for {:?}, got {}",
+                $input,
+                m.len()
+            );
+            assert_eq!(m[0].kind, ExpressionKind::AbsoluteDate);
+            assert_eq!(m[0].span, Span::new(0, $input.len()));
+            assert_eq!(m[0].resolved, day($y, $m, $d));
+            assert_eq!(m[0].date_order, Some($order));
+        }
+    };
+}
+
+// ================================================================
+//  Fixed-order formats
+// ================================================================
+
+numeric_test!(
+    iso_en,
+    "en",
+    "2026-03-05",
+    2026,
+    3,
+    5,
+    DateOrder::YearMonthDay
+);
+numeric_test!(
+    iso_de,
+    "de",
+    "2026-03-05",
+    2026,
+    3,
+    5,
+    DateOrder::YearMonthDay
+);
+numeric_test!(
+    dotted_full_year,
+    "de",
+    "05.03.2026",
+    2026,
+    3,
+    5,
+    DateOrder::DayMonthYear
+);
+numeric_test!(
+    dotted_two_digit_year,
+    "de",
+    "5.3.27",
+    2027,
+    3,
+    5,
+    DateOrder::DayMonthYear
+);
+numeric_test!(
+    dotted_no_year,
+    "de",
+    "5.3.",
+    2026,
+    3,
+    5,
+    DateOrder::DayMonthYear
+);
+numeric_test!(
+    slash_year_first,
+    "fr",
+    "2026/03/05",
+    2026,
+    3,
+    5,
+    DateOrder::YearMonthDay
+);
+
+// ================================================================
+//  Slash dates: locale default order
+// ================================================================
+
+numeric_test!(
+    en_slash_is_month_first,
+    "en",
+    "3/5/2026",
+    2026,
+    3,
+    5,
+    DateOrder::MonthDayYear
+);
+numeric_test!(
+    de_slash_is_day_first,
+    "de",
+    "5/3/2026",
+    2026,
+    3,
+    5,
+    DateOrder::DayMonthYear
+);
+numeric_test!(
+    fr_slash_is_day_first,
+    "fr",
+    "05/03/26",
+    2026,
+    3,
+    5,
+    DateOrder::DayMonthYear
+);
+numeric_test!(
+    es_el_slash_no_year,
+    "es",
+    "el 5/3",
+    2026,
+    3,
+    5,
+    DateOrder::DayMonthYear
+);
+numeric_test!(
+    en_on_slash_no_year,
+    "en",
+    "on 3/5",
+    2026,
+    3,
+    5,
+    DateOrder::MonthDayYear
+);
+
+// Day > 12 in the month position: the other reading is the only valid one.
+numeric_test!(
+    en_swaps_when_month_invalid,
+    "en",
+    "25/12/2026",
+    2026,
+    12,
+    25,
+    DateOrder::DayMonthYear
+);
+
+// ================================================================
+//  Configured order
+// ================================================================
+
+#[test]
+fn config_order_overrides_language_default() {
+    let m = scanner_with_order("en", DateOrder::DayMonthYear).scan("3/5/2026", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].resolved, day(2026, 5, 3));
+    assert_eq!(m[0].date_order, Some(DateOrder::DayMonthYear));
+
+    let m = scanner_with_order("de", DateOrder::MonthDayYear).scan("5/3/2026", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].resolved, day(2026, 5, 3));
+}
+
+#[test]
+fn config_order_does_not_affect_dotted_dates() {
+    let m = scanner_with_order("de", DateOrder::MonthDayYear).scan("05.03.2026", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].resolved, day(2026, 3, 5));
+    assert_eq!(m[0].date_order, Some(DateOrder::DayMonthYear));
+}
+
+#[test]
+fn month_name_dates_have_no_date_order() {
+    let s = scanner_for_languages(&["en"]);
+    let m = s.scan("March 5", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].date_order, None);
+}
+
+// ================================================================
+//  Rejections
+// ================================================================
+
+#[test]
+fn bare_short_slash_rejected() {
+    let s = scanner_for_languages(&["en"]);
+    assert_eq!(s.scan("about 3/5 of them", now()).len(), 0);
+}
+
+#[test]
+fn invalid_numeric_date_rejected() {
+    let s = scanner_for_languages(&["en", "de"]);
+    assert_eq!(s.scan("2026-02-30", now()).len(), 0);
+    assert_eq!(s.scan("13/13/2026", now()).len(), 0);
+    assert_eq!(s.scan("32.01.2026", now()).len(), 0);
+}
+
+#[test]
+fn version_numbers_are_not_dates() {
+    let s = scanner_for_languages(&["en", "de"]);
+    assert_eq!(s.scan("upgrade to version 1.2.30", now()).len(), 0);
+    assert_eq!(s.scan("release 2.1.10 is out", now()).len(), 0);
+    assert_eq!(s.scan("Version 1.2.30 ist da", now()).len(), 0);
+}
+
+#[test]
+fn ip_addresses_are_not_dates() {
+    let s = scanner_for_languages(&["en", "de"]);
+    assert_eq!(s.scan("server at 10.1.10.10", now()).len(), 0);
+    assert_eq!(s.scan("ping 5.10.1.10 now", now()).len(), 0);
+    assert_eq!(s.scan("Server unter 192.168.1.10", now()).len(), 0);
+}
+
+// ================================================================
+//  Combined with time
+// ================================================================
+
+#[test]
+fn de_dotted_with_time() {
+    let s = scanner_for_languages(&["de"]);
+    let text = "05.03. um 15 Uhr";
+    let m = s.scan(text, now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].kind, ExpressionKind::Combined);
+    assert_eq!(m[0].span, Span::new(0, text.len()));
+    assert_eq!(
+        m[0].resolved,
+        ResolvedTime::Point(Utc.with_ymd_and_hms(2026, 3, 5, 15, 0, 0).unwrap())
+    );
+    assert_eq!(m[0].date_order, Some(DateOrder::DayMonthYear));
+}
+
+#[test]
+fn en_short_slash_with_time() {
+    let s = scanner_for_languages(&["en"]);
+    let text = "3/5 at 3pm";
+    let m = s.scan(text, now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].kind, ExpressionKind::Combined);
+    assert_eq!(m[0].span, Span::new(0, text.len()));
+    assert_eq!(
+        m[0].resolved,
+        ResolvedTime::Point(Utc.with_ymd_and_hms(2026, 3, 5, 15, 0, 0).unwrap())
+    );
+}
+
+#[test]
+fn en_month_name_with_time() {
+    let s = scanner_for_languages(&["en"]);
+    let text = "March 5 at 9:30am";
+    let m = s.scan(text, now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].kind, ExpressionKind::Combined);
+    assert_eq!(
+        m[0].resolved,
+        ResolvedTime::Point(Utc.with_ymd_and_hms(2026, 3, 5, 9, 30, 0).unwrap())
+    );
+}
+
+#[test]
+fn iso_with_time_range() {
+    let s = scanner_for_languages(&["en"]);
+    let text = "2026-03-05 9:00 - 17:00";
+    let m = s.scan(text, now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].kind, ExpressionKind::Combined);
+    assert_eq!(
+        m[0].resolved,
+        ResolvedTime::Range {
+            start: Utc.with_ymd_and_hms(2026, 3, 5, 9, 0, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2026, 3, 5, 17, 0, 0).unwrap(),
+        }
+    );
+}
+
+#[test]
+fn fr_date_with_time() {
+    let s = scanner_for_languages(&["fr"]);
+    let m = s.scan("le 5 mars à 15h30", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(
+        m[0].resolved,
+        ResolvedTime::Point(Utc.with_ymd_and_hms(2026, 3, 5, 15, 30, 0).unwrap())
+    );
+}
+
+#[test]
+fn es_date_with_time() {
+    let s = scanner_for_languages(&["es"]);
+    let m = s.scan("el 5 de marzo a las 15", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(
+        m[0].resolved,
+        ResolvedTime::Point(Utc.with_ymd_and_hms(2026, 3, 5, 15, 0, 0).unwrap())
+    );
+}
@@ -0,0 +1,178 @@
+use chrono::{TimeZone, Utc};
+use clockwords::{
+    DateBias, ExpressionKind, ParserConfig, ResolvedTime, Span, TimeExpressionScanner,
+    scanner_for_languages,
+};
+
+/// Saturday Feb 7, 2026 14:30:00 UTC
+fn now() -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, 7, 14, 30, 0).unwrap()
+}
+
+/// Open range starting at the given time in 2026.
+fn since(m: u32, d: u32, h: u32, min: u32) -> ResolvedTime {
+    ResolvedTime::Since(Utc.with_ymd_and_hms(2026, m, d, h, min, 0).unwrap())
+}
+
+/// Open range ending at the given time in 2026.
+fn until(m: u32, d: u32, h: u32, min: u32) -> ResolvedTime {
+    ResolvedTime::Until(Utc.with_ymd_and_hms(2026, m, d, h, min, 0).unwrap())
+}
+
+/// Test expecting exactly one open range covering the whole input.
+macro_rules! open_test {
+    ($name:ident, $lang:expr, $input:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            let s = scanner_for_languages(&[$lang]);
+            let m = s.scan($input, now());
+            assert_eq!(
+                m.len(),
+                1,
+                "expected 1 match for {:?}, got {}",
+                $input,
+                m.len()
+            );
+            assert_eq!(m[0].kind, ExpressionKind::OpenRange);
+            assert_eq!(m[0].span, Span::new(0, $input.len()));
+            assert_eq!(m[0].resolved, $expected);
+        }
+    };
+}
+
+// ================================================================
+//  English
+// ================================================================
+
+open_test!(en_since_monday, "en", "since Monday", since(2, 2, 0, 0));
+open_test!(en_until_5pm, "en", "until 5pm", until(2, 7, 17, 0));
+open_test!(en_before_lunch, "en", "before lunch", until(2, 7, 12, 0));
+open_test!(en_after_3pm, "en", "after 3pm", since(2, 7, 15, 0));
+open_test!(en_after_15_30, "en", "after 15:30", since(2, 7, 15, 30));
+open_test!(en_until_friday, "en", "until Friday", until(2, 14, 0, 0));
+open_test!(en_before_friday, "en", "before Friday", until(2, 13, 0, 0));
+open_test!(en_after_monday, "en", "after Monday", since(2, 10, 0, 0));
+open_test!(
+    en_since_yesterday_at_3pm,
+    "en",
+    "since yesterday at 3pm",
+    since(2, 6, 15, 0)
+);
+open_test!(
+    en_until_end_of_month,
+    "en",
+    "until end of month",
+    until(3, 1, 0, 0)
+);
+open_test!(
+    en_starting_next_week,
+    "en",
+    "starting next week",
+    since(2, 9, 0, 0)
+);
+
+#[test]
+fn en_duration_until_time_stays_a_range() {
+    let s = scanner_for_languages(&["en"]);
+    let m = s.scan("2 hours until 5pm", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].kind, ExpressionKind::TimeRange);
+}
+
+#[test]
+fn en_preposition_without_anchor() {
+    let s = scanner_for_languages(&["en"]);
+    assert!(s.scan("after all", now()).is_empty());
+}
+
+// ================================================================
+//  German
+// ================================================================
+
+open_test!(de_seit_gestern, "de", "seit gestern", since(2, 6, 0, 0));
+open_test!(de_bis_freitag, "de", "bis Freitag", until(2, 14, 0, 0));
+open_test!(de_ab_14_uhr, "de", "ab 14 Uhr", since(2, 7, 14, 0));
+open_test!(de_vor_15_uhr, "de", "vor 15 Uhr", until(2, 7, 15, 0));
+open_test!(de_nach_15_uhr, "de", "nach 15 Uhr", since(2, 7, 15, 0));
+open_test!(
+    de_bis_zum_monatsende,
+    "de",
+    "bis zum Monatsende",
+    until(3, 1, 0, 0)
+);
+open_test!(
+    de_bis_feierabend,
+    "de",
+    "bis Feierabend",
+    until(2, 7, 17, 0)
+);
+
+#[test]
+fn de_vor_tagen_stays_an_offset() {
+    let s = scanner_for_languages(&["de"]);
+    let m = s.scan("vor 3 Tagen", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].kind, ExpressionKind::RelativeDayOffset);
+}
+
+// ================================================================
+//  French
+// ================================================================
+
+open_test!(fr_depuis_lundi, "fr", "depuis lundi", since(2, 2, 0, 0));
+open_test!(fr_jusqu_a_demain, "fr", "jusqu'à demain", until(2, 9, 0, 0));
+open_test!(fr_jusqu_a_15h, "fr", "jusqu'à 15h", until(2, 7, 15, 0));
+open_test!(
+    fr_a_partir_de_14h,
+    "fr",
+    "à partir de 14h",
+    since(2, 7, 14, 0)
+);
+open_test!(fr_avant_15h, "fr", "avant 15h", until(2, 7, 15, 0));
+open_test!(
+    fr_jusqu_a_la_fin_du_mois,
+    "fr",
+    "jusqu'à la fin du mois",
+    until(3, 1, 0, 0)
+);
+
+// ================================================================
+//  Spanish
+// ================================================================
+
+open_test!(es_desde_ayer, "es", "desde ayer", since(2, 6, 0, 0));
+open_test!(es_hasta_las_5, "es", "hasta las 5", until(2, 7, 5, 0));
+open_test!(
+    es_a_partir_de_manana,
+    "es",
+    "a partir de mañana",
+    since(2, 8, 0, 0)
+);
+open_test!(
+    es_despues_de_las_15,
+    "es",
+    "después de las 15",
+    since(2, 7, 15, 0)
+);
+open_test!(
+    es_hasta_el_viernes,
+    "es",
+    "hasta el viernes",
+    until(2, 14, 0, 0)
+);
+
+// ================================================================
+//  Bias
+// ================================================================
+
+#[test]
+fn explicit_bias_is_kept() {
+    let config = ParserConfig {
+        weekday_bias: DateBias::Past,
+        ..ParserConfig::default()
+    };
+    let s =
+        TimeExpressionScanner::new(vec![Box::new(clockwords::lang::en::English::new())], config);
+    let m = s.scan("until Friday", now());
+    assert_eq!(m[0].resolved, until(2, 7, 0, 0));
+}
@@ -0,0 +1,258 @@
+use chrono::{TimeZone, Utc};
+use clockwords::{
+    DayParts, ExpressionKind, ParserConfig, ResolvedTime, Span, TimeExpressionScanner,
+    scanner_for_languages,
+};
+
+/// Saturday Feb 7, 2026 14:30:00 UTC
+fn now() -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, 7, 14, 30, 0).unwrap()
+}
+
+/// Range between two times given as (day of February 2026, hour).
+fn range(from: (u32, u32), to: (u32, u32)) -> ResolvedTime {
+    ResolvedTime::Range {
+        start: Utc.with_ymd_and_hms(2026, 2, from.0, from.1, 0, 0).unwrap(),
+        end: Utc.with_ymd_and_hms(2026, 2, to.0, to.1, 0, 0).unwrap(),
+    }
+}
+
+/// Helper: create a scanner for one language with a specific config.
+fn scanner_with_config(
+    lang: Box<dyn clockwords::lang::LanguageParser>,
+    config: ParserConfig,
+) -> TimeExpressionScanner {
+    TimeExpressionScanner::new(vec![lang], config)
+}
+
+/// Test expecting exactly one match of the given kind covering the whole input.
+macro_rules! part_test {
+    ($name:ident, $lang:expr, $input:expr, $kind:expr, $from:expr, $to:expr) => {
+        #[test]
+        fn $name() {
+            let s = scanner_for_languages(&[$lang]);
+            let m = s.scan($input, now());
+            assert_eq!(
+                m.len(),
+                1,
+                "expected 1 match for {:?}, got {}",
+                $input,
+                m.len()
+            );
+            assert_eq!(m[0].kind, $kind);
+            assert_eq!(m[0].span, Span::new(0, $input.len()));
+            assert_eq!(m[0].resolved, range($from, $to));
+        }
+    };
+}
+
+// ================================================================
+//  English
+// ================================================================
+
+part_test!(
+    en_this_morning,
+    "en",
+    "this morning",
+    ExpressionKind::PartOfDay,
+    (7, 6),
+    (7, 12)
+);
+part_test!(
+    en_tonight,
+    "en",
+    "tonight",
+    ExpressionKind::PartOfDay,
+    (7, 22),
+    (8, 6)
+);
+part_test!(
+    en_last_night,
+    "en",
+    "last night",
+    ExpressionKind::PartOfDay,
+    (6, 22),
+    (7, 6)
+);
+part_test!(
+    en_tomorrow_afternoon,
+    "en",
+    "tomorrow afternoon",
+    ExpressionKind::Combined,
+    (8, 12),
+    (8, 18)
+);
+part_test!(
+    en_last_friday_evening,
+    "en",
+    "last Friday evening",
+    ExpressionKind::Combined,
+    (6, 18),
+    (6, 22)
+);
+
+// ================================================================
+//  German
+// ================================================================
+
+part_test!(
+    de_heute_abend,
+    "de",
+    "heute Abend",
+    ExpressionKind::Combined,
+    (7, 18),
+    (7, 22)
+);
+part_test!(
+    de_morgen_frueh,
+    "de",
+    "morgen früh",
+    ExpressionKind::Combined,
+    (8, 6),
+    (8, 12)
+);
+part_test!(
+    de_gestern_nachmittag,
+    "de",
+    "gestern Nachmittag",
+    ExpressionKind::Combined,
+    (6, 12),
+    (6, 18)
+);
+part_test!(
+    de_letzten_freitagabend,
+    "de",
+    "letzten Freitagabend",
+    ExpressionKind::Combined,
+    (6, 18),
+    (6, 22)
+);
+part_test!(
+    de_letzte_nacht,
+    "de",
+    "letzte Nacht",
+    ExpressionKind::PartOfDay,
+    (6, 22),
+    (7, 6)
+);
+
+// ================================================================
+//  French
+// ================================================================
+
+part_test!(
+    fr_ce_soir,
+    "fr",
+    "ce soir",
+    ExpressionKind::PartOfDay,
+    (7, 18),
+    (7, 22)
+);
+part_test!(
+    fr_cet_apres_midi,
+    "fr",
+    "cet après-midi",
+    ExpressionKind::PartOfDay,
+    (7, 12),
+    (7, 18)
+);
+part_test!(
+    fr_demain_matin,
+    "fr",
+    "demain matin",
+    ExpressionKind::Combined,
+    (8, 6),
+    (8, 12)
+);
+part_test!(
+    fr_vendredi_dernier_au_soir,
+    "fr",
+    "vendredi dernier au soir",
+    ExpressionKind::Combined,
+    (6, 18),
+    (6, 22)
+);
+part_test!(
+    fr_la_nuit_derniere,
+    "fr",
+    "la nuit dernière",
+    ExpressionKind::PartOfDay,
+    (6, 22),
+    (7, 6)
+);
+
+// ================================================================
+//  Spanish
+// ================================================================
+
+part_test!(
+    es_esta_tarde,
+    "es",
+    "esta tarde",
+    ExpressionKind::PartOfDay,
+    (7, 12),
+    (7, 18)
+);
+part_test!(
+    es_esta_manana,
+    "es",
+    "esta mañana",
+    ExpressionKind::PartOfDay,
+    (7, 6),
+    (7, 12)
+);
+part_test!(
+    es_anoche,
+    "es",
+    "anoche",
+    ExpressionKind::PartOfDay,
+    (6, 22),
+    (7, 6)
+);
+part_test!(
+    es_manana_por_la_manana,
+    "es",
+    "mañana por la mañana",
+    ExpressionKind::Combined,
+    (8, 6),
+    (8, 12)
+);
+part_test!(
+    es_el_viernes_pasado_por_la_noche,
+    "es",
+    "el viernes pasado por la noche",
+    ExpressionKind::Combined,
+    (6, 22),
+    (7, 6)
+);
+
+// ================================================================
+//  Configuration
+// ================================================================
+
+#[test]
+fn custom_boundaries() {
+    let config = ParserConfig {
+        day_parts: DayParts {
+            morning: (5, 11),
+            ..DayParts::default()
+        },
+        ..ParserConfig::default()
+    };
+    let s = scanner_with_config(Box::new(clockwords::lang::en::English::new()), config);
+    let m = s.scan("tomorrow morning", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].resolved, range((8, 5), (8, 11)));
+}
+
+#[test]
+fn part_of_day_in_timezone() {
+    let config = ParserConfig {
+        timezone: chrono_tz::Europe::Berlin,
+        ..ParserConfig::default()
+    };
+    let s = scanner_with_config(Box::new(clockwords::lang::de::German::new()), config);
+    let m = s.scan("heute Abend", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].resolved, range((7, 17), (7, 21)));
+}
@@ -0,0 +1,329 @@
+use chrono::{NaiveTime, TimeZone, Utc, Weekday};
+use clockwords::{ExpressionKind, Recurrence, ResolvedTime, Span, TimeUnit, scanner_for_languages};
+
+/// Saturday Feb 7, 2026 14:30:00 UTC
+fn now() -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, 7, 14, 30, 0).unwrap()
+}
+
+/// Recurrence with an optional whole-hour time of day.
+fn recurrence(
+    frequency: TimeUnit,
+    interval: u32,
+    weekdays: &[Weekday],
+    hour: Option<u32>,
+) -> Recurrence {
+    Recurrence {
+        frequency,
+        interval,
+        weekdays: weekdays.to_vec(),
+        time: hour.map(|h| NaiveTime::from_hms_opt(h, 0, 0).unwrap()),
+    }
+}
+
+/// Test expecting exactly one recurring match covering the whole input.
+macro_rules! recur_test {
+    ($name:ident, $lang:expr, $input:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            let s = scanner_for_languages(&[$lang]);
+            let m = s.scan($input, now());
+            assert_eq!(
+                m.len(),
+                1,
+                "expected 1 match for {:?}, got {}",
+                $input,
+                m.len()
+            );
+            assert_eq!(m[0].kind, ExpressionKind::Recurring);
+            assert_eq!(m[0].span, Span::new(0, $input.len()));
+            assert_eq!(m[0].resolved, ResolvedTime::Recurrence($expected));
+        }
+    };
+}
+
+// ================================================================
+//  English
+// ================================================================
+
+recur_test!(
+    en_every_monday,
+    "en",
+    "every Monday",
+    recurrence(TimeUnit::Week, 1, &[Weekday::Mon], None)
+);
+recur_test!(
+    en_every_other_week,
+    "en",
+    "every other week",
+    recurrence(TimeUnit::Week, 2, &[], None)
+);
+recur_test!(
+    en_daily_at_9,
+    "en",
+    "daily at 9",
+    recurrence(TimeUnit::Day, 1, &[], Some(9))
+);
+recur_test!(
+    en_every_monday_and_thursday_at_3pm,
+    "en",
+    "every Monday and Thursday at 3pm",
+    recurrence(TimeUnit::Week, 1, &[Weekday::Mon, Weekday::Thu], Some(15))
+);
+recur_test!(
+    en_every_2_weeks_on_tuesday,
+    "en",
+    "every 2 weeks on Tuesday",
+    recurrence(TimeUnit::Week, 2, &[Weekday::Tue], None)
+);
+recur_test!(
+    en_every_weekday_at_noon,
+    "en",
+    "every weekday at noon",
+    recurrence(
+        TimeUnit::Day,
+        1,
+        &[
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri
+        ],
+        Some(12)
+    )
+);
+recur_test!(
+    en_every_three_hours,
+    "en",
+    "every three hours",
+    recurrence(TimeUnit::Hour, 3, &[], None)
+);
+
+// ================================================================
+//  German
+// ================================================================
+
+recur_test!(
+    de_jeden_dienstag_um_10,
+    "de",
+    "jeden Dienstag um 10",
+    recurrence(TimeUnit::Week, 1, &[Weekday::Tue], Some(10))
+);
+recur_test!(
+    de_jede_zweite_woche,
+    "de",
+    "jede zweite Woche",
+    recurrence(TimeUnit::Week, 2, &[], None)
+);
+recur_test!(
+    de_taeglich_um_9_uhr,
+    "de",
+    "täglich um 9 Uhr",
+    recurrence(TimeUnit::Day, 1, &[], Some(9))
+);
+recur_test!(
+    de_alle_drei_monate,
+    "de",
+    "alle drei Monate",
+    recurrence(TimeUnit::Month, 3, &[], None)
+);
+recur_test!(
+    de_montags_und_donnerstags,
+    "de",
+    "montags und donnerstags",
+    recurrence(TimeUnit::Week, 1, &[Weekday::Mon, Weekday::Thu], None)
+);
+
+// ================================================================
+//  French
+// ================================================================
+
+recur_test!(
+    fr_tous_les_lundis,
+    "fr",
+    "tous les lundis",
+    recurrence(TimeUnit::Week, 1, &[Weekday::Mon], None)
+);
+recur_test!(
+    fr_chaque_jour_a_midi,
+    "fr",
+    "chaque jour à midi",
+    recurrence(TimeUnit::Day, 1, &[], Some(12))
+);
+recur_test!(
+    fr_toutes_les_deux_semaines,
+    "fr",
+    "toutes les deux semaines",
+    recurrence(TimeUnit::Week, 2, &[], None)
+);
+recur_test!(
+    fr_un_lundi_sur_deux_a_10h,
+    "fr",
+    "un lundi sur deux à 10h",
+    recurrence(TimeUnit::Week, 2, &[Weekday::Mon], Some(10))
+);
+
+// ================================================================
+//  Spanish
+// ================================================================
+
+recur_test!(
+    es_cada_viernes,
+    "es",
+    "cada viernes",
+    recurrence(TimeUnit::Week, 1, &[Weekday::Fri], None)
+);
+recur_test!(
+    es_todos_los_lunes_y_jueves_a_las_9,
+    "es",
+    "todos los lunes y jueves a las 9",
+    recurrence(TimeUnit::Week, 1, &[Weekday::Mon, Weekday::Thu], Some(9))
+);
+recur_test!(
+    es_cada_dos_semanas,
+    "es",
+    "cada dos semanas",
+    recurrence(TimeUnit::Week, 2, &[], None)
+);
+recur_test!(
+    es_diariamente_al_mediodia,
+    "es",
+    "diariamente al mediodía",
+    recurrence(TimeUnit::Day, 1, &[], Some(12))
+);
+
+// ================================================================
+//  Occurrences
+// ================================================================
+
+#[test]
+fn occurrences_weekly_on_weekdays() {
+    let r = recurrence(TimeUnit::Week, 1, &[Weekday::Mon, Weekday::Thu], Some(9));
+    let got: Vec<_> = r
+        .occurrences(now(), chrono_tz::UTC, Weekday::Mon)
+        .take(3)
+        .collect();
+    assert_eq!(
+        got,
+        vec![
+            Utc.with_ymd_and_hms(2026, 2, 9, 9, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 2, 12, 9, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 2, 16, 9, 0, 0).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn occurrences_every_other_week() {
+    let r = recurrence(TimeUnit::Week, 2, &[Weekday::Tue], None);
+    let got: Vec<_> = r
+        .occurrences(now(), chrono_tz::UTC, Weekday::Mon)
+        .take(3)
+        .collect();
+    assert_eq!(
+        got,
+        vec![
+            Utc.with_ymd_and_hms(2026, 2, 10, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 2, 24, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 3, 10, 0, 0, 0).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn occurrences_every_other_week_from_sunday() {
+    // With weeks starting on Sunday, Feb 8 and Feb 9 are in the same week.
+    let r = recurrence(TimeUnit::Week, 2, &[Weekday::Sun, Weekday::Mon], None);
+    let got: Vec<_> = r
+        .occurrences(now(), chrono_tz::UTC, Weekday::Sun)
+        .take(4)
+        .collect();
+    assert_eq!(
+        got,
+        vec![
+            Utc.with_ymd_and_hms(2026, 2, 8, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 2, 9, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 2, 22, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 2, 23, 0, 0, 0).unwrap(),
+        ]
+    );
+
+    let got: Vec<_> = r
+        .occurrences(now(), chrono_tz::UTC, Weekday::Mon)
+        .take(2)
+        .collect();
+    assert_eq!(
+        got,
+        vec![
+            Utc.with_ymd_and_hms(2026, 2, 8, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 2, 16, 0, 0, 0).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn occurrences_daily_include_today_if_still_ahead() {
+    let r = recurrence(TimeUnit::Day, 1, &[], Some(18));
+    let first = r.occurrences(now(), chrono_tz::UTC, Weekday::Mon).next();
+    assert_eq!(
+        first,
+        Some(Utc.with_ymd_and_hms(2026, 2, 7, 18, 0, 0).unwrap())
+    );
+}
+
+#[test]
+fn occurrences_monthly_skip_short_months() {
+    let from = Utc.with_ymd_and_hms(2026, 1, 31, 0, 0, 0).unwrap();
+    let r = recurrence(TimeUnit::Month, 1, &[], None);
+    let got: Vec<_> = r
+        .occurrences(from, chrono_tz::UTC, Weekday::Mon)
+        .take(3)
+        .collect();
+    assert_eq!(
+        got,
+        vec![
+            Utc.with_ymd_and_hms(2026, 1, 31, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 3, 31, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 5, 31, 0, 0, 0).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn occurrences_hourly() {
+    let r = recurrence(TimeUnit::Hour, 3, &[], None);
+    let got: Vec<_> = r
+        .occurrences(now(), chrono_tz::UTC, Weekday::Mon)
+        .take(2)
+        .collect();
+    assert_eq!(
+        got,
+        vec![now(), Utc.with_ymd_and_hms(2026, 2, 7, 17, 30, 0).unwrap()]
+    );
+}
+
+#[test]
+fn occurrences_keep_local_time_across_dst() {
+    // Berlin switches to summer time on Mar 29, 2026.
+    let from = Utc.with_ymd_and_hms(2026, 3, 27, 12, 0, 0).unwrap();
+    let r = recurrence(TimeUnit::Day, 1, &[], Some(9));
+    let got: Vec<_> = r
+        .occurrences(from, chrono_tz::Europe::Berlin, Weekday::Mon)
+        .take(3)
+        .collect();
+    assert_eq!(
+        got,
+        vec![
+            Utc.with_ymd_and_hms(2026, 3, 28, 8, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 3, 29, 7, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 3, 30, 7, 0, 0).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn zero_interval_does_not_match() {
+    let s = scanner_for_languages(&["en"]);
+    assert!(s.scan("every 0 days", now()).is_empty());
+}
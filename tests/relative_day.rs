@@ -0,0 +1,251 @@
+use chrono::{TimeZone, Utc};
+use clockwords::{ExpressionKind, ResolvedTime, Span, scanner_for_languages};
+
+/// Saturday Feb 7, 2026 14:30:00 UTC
+fn now() -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, 7, 14, 30, 0).unwrap()
+}
+
+/// Full-day range for the given day of February 2026.
+fn day(d: u32) -> ResolvedTime {
+    let start = Utc.with_ymd_and_hms(2026, 2, d, 0, 0, 0).unwrap();
+    ResolvedTime::Range {
+        start,
+        end: start + chrono::Duration::days(1),
+    }
+}
+
+/// Point in time for the given day of February 2026 and hour.
+fn at(d: u32, h: u32) -> ResolvedTime {
+    ResolvedTime::Point(Utc.with_ymd_and_hms(2026, 2, d, h, 0, 0).unwrap())
+}
+
+/// Range between two hours on the given day of February 2026.
+fn hours(d: u32, from: u32, to: u32) -> ResolvedTime {
+    ResolvedTime::Range {
+        start: Utc.with_ymd_and_hms(2026, 2, d, from, 0, 0).unwrap(),
+        end: Utc.with_ymd_and_hms(2026, 2, d, to, 0, 0).unwrap(),
+    }
+}
+
+/// Test expecting exactly one match of the given kind covering the whole input.
+macro_rules! rday_test {
+    ($name:ident, $lang:expr, $input:expr, $kind:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            let s = scanner_for_languages(&[$lang]);
+            let m = s.scan($input, now());
+            assert_eq!(
+                m.len(),
+                1,
+                "expected 1 match for {:?}, got {}",
+                $input,
+                m.len()
+            );
+            assert_eq!(m[0].kind, $kind);
+            assert_eq!(m[0].span, Span::new(0, $input.len()));
+            assert_eq!(m[0].resolved, $expected);
+        }
+    };
+}
+
+// ================================================================
+//  English
+// ================================================================
+
+rday_test!(
+    en_the_day_after_tomorrow,
+    "en",
+    "the day after tomorrow",
+    ExpressionKind::RelativeDay,
+    day(9)
+);
+rday_test!(
+    en_day_before_yesterday,
+    "en",
+    "day before yesterday",
+    ExpressionKind::RelativeDay,
+    day(5)
+);
+rday_test!(
+    en_the_day_after_tomorrow_at_3pm,
+    "en",
+    "the day after tomorrow at 3pm",
+    ExpressionKind::Combined,
+    at(9, 15)
+);
+rday_test!(
+    en_the_day_before_yesterday_from_9_to_11,
+    "en",
+    "the day before yesterday from 9 to 11",
+    ExpressionKind::Combined,
+    hours(5, 9, 11)
+);
+
+// ================================================================
+//  German
+// ================================================================
+
+rday_test!(
+    de_uebermorgen,
+    "de",
+    "übermorgen",
+    ExpressionKind::RelativeDay,
+    day(9)
+);
+rday_test!(
+    de_vorgestern,
+    "de",
+    "Vorgestern",
+    ExpressionKind::RelativeDay,
+    day(5)
+);
+rday_test!(
+    de_uebermorgen_um_15_uhr,
+    "de",
+    "übermorgen um 15 Uhr",
+    ExpressionKind::Combined,
+    at(9, 15)
+);
+rday_test!(
+    de_vorgestern_um_8,
+    "de",
+    "vorgestern um 8",
+    ExpressionKind::Combined,
+    at(5, 8)
+);
+rday_test!(
+    de_gestern_um_15,
+    "de",
+    "gestern um 15",
+    ExpressionKind::Combined,
+    at(6, 15)
+);
+rday_test!(
+    de_morgen_um_8,
+    "de",
+    "morgen um 8",
+    ExpressionKind::Combined,
+    at(8, 8)
+);
+rday_test!(
+    de_vorgestern_von_9_bis_11_uhr,
+    "de",
+    "vorgestern von 9 bis 11 Uhr",
+    ExpressionKind::Combined,
+    hours(5, 9, 11)
+);
+
+// ================================================================
+//  French
+// ================================================================
+
+rday_test!(
+    fr_apres_demain,
+    "fr",
+    "après-demain",
+    ExpressionKind::RelativeDay,
+    day(9)
+);
+rday_test!(
+    fr_avant_hier,
+    "fr",
+    "avant-hier",
+    ExpressionKind::RelativeDay,
+    day(5)
+);
+rday_test!(
+    fr_apres_demain_a_15h,
+    "fr",
+    "après-demain à 15h",
+    ExpressionKind::Combined,
+    at(9, 15)
+);
+rday_test!(
+    fr_avant_hier_entre_9_et_11_heures,
+    "fr",
+    "avant-hier entre 9 et 11 heures",
+    ExpressionKind::Combined,
+    hours(5, 9, 11)
+);
+rday_test!(
+    fr_apres_demain_de_9h_a_11h,
+    "fr",
+    "après-demain de 9h à 11h",
+    ExpressionKind::Combined,
+    hours(9, 9, 11)
+);
+rday_test!(
+    fr_demain_de_9h_a_11h,
+    "fr",
+    "demain de 9h à 11h",
+    ExpressionKind::Combined,
+    hours(8, 9, 11)
+);
+
+// ================================================================
+//  Spanish
+// ================================================================
+
+rday_test!(
+    es_pasado_manana,
+    "es",
+    "pasado mañana",
+    ExpressionKind::RelativeDay,
+    day(9)
+);
+rday_test!(
+    es_anteayer,
+    "es",
+    "anteayer",
+    ExpressionKind::RelativeDay,
+    day(5)
+);
+rday_test!(
+    es_pasado_manana_a_las_15,
+    "es",
+    "pasado mañana a las 15:00",
+    ExpressionKind::Combined,
+    at(9, 15)
+);
+rday_test!(
+    es_anteayer_entre_las_9_y_las_11,
+    "es",
+    "anteayer entre las 9 y las 11",
+    ExpressionKind::Combined,
+    hours(5, 9, 11)
+);
+rday_test!(
+    es_pasado_manana_de_9_a_11,
+    "es",
+    "pasado mañana de 9 a 11",
+    ExpressionKind::Combined,
+    hours(9, 9, 11)
+);
+rday_test!(
+    es_anteayer_de_la_una_a_las_tres,
+    "es",
+    "anteayer de la una a las tres",
+    ExpressionKind::Combined,
+    hours(5, 1, 3)
+);
+
+#[test]
+fn day_keyword_before_a_quantity_range_stays_a_day() {
+    let m = scanner_for_languages(&["fr"]).scan("demain de 2 à 3 enfants", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].resolved, day(8));
+
+    let m = scanner_for_languages(&["es"]).scan("mañana de 1 a 3 días", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].resolved, day(8));
+}
+
+#[test]
+fn es_viernes_pasado_is_still_a_weekday() {
+    let s = scanner_for_languages(&["es"]);
+    let m = s.scan("el viernes pasado", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].kind, ExpressionKind::RelativeDay);
+    assert_eq!(m[0].resolved, day(6));
+}
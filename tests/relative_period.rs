@@ -0,0 +1,271 @@
+use chrono::{TimeZone, Utc, Weekday};
+use clockwords::{
+    ExpressionKind, ParserConfig, ResolvedTime, Span, TimeExpressionScanner, Tz,
+    scanner_for_languages,
+};
+
+/// Saturday Feb 7, 2026 14:30:00 UTC
+fn now() -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, 7, 14, 30, 0).unwrap()
+}
+
+/// Helper: create an English scanner with a custom config.
+fn scanner_with_config(config: ParserConfig) -> TimeExpressionScanner {
+    let languages: Vec<Box<dyn clockwords::lang::LanguageParser>> =
+        vec![Box::new(clockwords::lang::en::English::new())];
+    TimeExpressionScanner::new(languages, config)
+}
+
+/// Midnight-to-midnight range between two UTC dates.
+fn range(from: (i32, u32, u32), to: (i32, u32, u32)) -> ResolvedTime {
+    ResolvedTime::Range {
+        start: Utc
+            .with_ymd_and_hms(from.0, from.1, from.2, 0, 0, 0)
+            .unwrap(),
+        end: Utc.with_ymd_and_hms(to.0, to.1, to.2, 0, 0, 0).unwrap(),
+    }
+}
+
+/// Test expecting exactly one relative period match with the given range.
+macro_rules! period_test {
+    ($name:ident, $lang:expr, $input:expr, $from:expr, $to:expr) => {
+        #[test]
+        fn $name() {
+            let s = scanner_for_languages(&[$lang]);
+            let m = s.scan($input, now());
+            assert_eq!(
+                m.len(),
+                1,
+                "expected 1 match for {:?}, got {}",
+                $input,
+                m.len()
+            );
+            assert_eq!(m[0].kind, ExpressionKind::RelativePeriod);
+            assert_eq!(m[0].span, Span::new(0, $input.len()));
+            assert_eq!(m[0].resolved, range($from, $to));
+        }
+    };
+}
+
+// ================================================================
+//  English
+// ================================================================
+
+period_test!(en_this_week, "en", "this week", (2026, 2, 2), (2026, 2, 9));
+period_test!(en_last_week, "en", "last week", (2026, 1, 26), (2026, 2, 2));
+period_test!(en_next_week, "en", "next week", (2026, 2, 9), (2026, 2, 16));
+period_test!(
+    en_this_month,
+    "en",
+    "this month",
+    (2026, 2, 1),
+    (2026, 3, 1)
+);
+period_test!(
+    en_last_month,
+    "en",
+    "last month",
+    (2026, 1, 1),
+    (2026, 2, 1)
+);
+period_test!(en_next_year, "en", "next year", (2027, 1, 1), (2028, 1, 1));
+period_test!(
+    en_this_quarter,
+    "en",
+    "this quarter",
+    (2026, 1, 1),
+    (2026, 4, 1)
+);
+period_test!(
+    en_previous_quarter,
+    "en",
+    "previous quarter",
+    (2025, 10, 1),
+    (2026, 1, 1)
+);
+
+#[test]
+fn en_embedded_in_sentence() {
+    let s = scanner_for_languages(&["en"]);
+    let text = "Worked on billing last month";
+    let m = s.scan(text, now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(&text[m[0].span.as_range()], "last month");
+}
+
+// ================================================================
+//  German
+// ================================================================
+
+period_test!(
+    de_diese_woche,
+    "de",
+    "diese Woche",
+    (2026, 2, 2),
+    (2026, 2, 9)
+);
+period_test!(
+    de_letzte_woche,
+    "de",
+    "letzte Woche",
+    (2026, 1, 26),
+    (2026, 2, 2)
+);
+period_test!(
+    de_naechsten_monat,
+    "de",
+    "nächsten Monat",
+    (2026, 3, 1),
+    (2026, 4, 1)
+);
+period_test!(
+    de_letztes_jahr,
+    "de",
+    "letztes Jahr",
+    (2025, 1, 1),
+    (2026, 1, 1)
+);
+period_test!(
+    de_im_vorigen_quartal,
+    "de",
+    "im vorigen Quartal",
+    (2025, 10, 1),
+    (2026, 1, 1)
+);
+
+// ================================================================
+//  French
+// ================================================================
+
+period_test!(
+    fr_cette_semaine,
+    "fr",
+    "cette semaine",
+    (2026, 2, 2),
+    (2026, 2, 9)
+);
+period_test!(
+    fr_le_mois_dernier,
+    "fr",
+    "le mois dernier",
+    (2026, 1, 1),
+    (2026, 2, 1)
+);
+period_test!(
+    fr_la_semaine_prochaine,
+    "fr",
+    "la semaine prochaine",
+    (2026, 2, 9),
+    (2026, 2, 16)
+);
+period_test!(
+    fr_l_an_dernier,
+    "fr",
+    "l'an dernier",
+    (2025, 1, 1),
+    (2026, 1, 1)
+);
+period_test!(
+    fr_ce_mois_ci,
+    "fr",
+    "ce mois-ci",
+    (2026, 2, 1),
+    (2026, 3, 1)
+);
+
+// ================================================================
+//  Spanish
+// ================================================================
+
+period_test!(
+    es_esta_semana,
+    "es",
+    "esta semana",
+    (2026, 2, 2),
+    (2026, 2, 9)
+);
+period_test!(
+    es_el_mes_pasado,
+    "es",
+    "el mes pasado",
+    (2026, 1, 1),
+    (2026, 2, 1)
+);
+period_test!(
+    es_el_ano_que_viene,
+    "es",
+    "el año que viene",
+    (2027, 1, 1),
+    (2028, 1, 1)
+);
+period_test!(
+    es_el_proximo_trimestre,
+    "es",
+    "el próximo trimestre",
+    (2026, 4, 1),
+    (2026, 7, 1)
+);
+
+// ================================================================
+//  Configuration
+// ================================================================
+
+#[test]
+fn week_start_sunday() {
+    let config = ParserConfig {
+        week_start: Weekday::Sun,
+        ..Default::default()
+    };
+    let m = scanner_with_config(config).scan("this week", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].resolved, range((2026, 2, 1), (2026, 2, 8)));
+}
+
+#[test]
+fn week_start_on_today() {
+    // Today is Saturday, so a Saturday week starts today.
+    let config = ParserConfig {
+        week_start: Weekday::Sat,
+        ..Default::default()
+    };
+    let m = scanner_with_config(config).scan("last week", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].resolved, range((2026, 1, 31), (2026, 2, 7)));
+}
+
+#[test]
+fn month_in_timezone_across_dst() {
+    // March in Berlin starts in CET (UTC+1) and ends in CEST (UTC+2).
+    let config = ParserConfig {
+        timezone: Tz::Europe__Berlin,
+        ..Default::default()
+    };
+    let m = scanner_with_config(config).scan("next month", now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(
+        m[0].resolved,
+        ResolvedTime::Range {
+            start: Utc.with_ymd_and_hms(2026, 2, 28, 23, 0, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2026, 3, 31, 22, 0, 0).unwrap(),
+        }
+    );
+}
+
+#[test]
+fn week_late_evening_before_dst() {
+    // Sunday Mar 29, 2026 23:30 CEST: the week still ends at the coming midnight.
+    let config = ParserConfig {
+        timezone: Tz::Europe__Berlin,
+        ..Default::default()
+    };
+    let now = Utc.with_ymd_and_hms(2026, 3, 29, 21, 30, 0).unwrap();
+    let m = scanner_with_config(config).scan("this week", now);
+    assert_eq!(m.len(), 1);
+    assert_eq!(
+        m[0].resolved,
+        ResolvedTime::Range {
+            start: Utc.with_ymd_and_hms(2026, 3, 22, 23, 0, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2026, 3, 29, 22, 0, 0).unwrap(),
+        }
+    );
+}
@@ -0,0 +1,174 @@
+use chrono::{Duration, TimeZone, Utc};
+use clockwords::{ExpressionKind, ResolvedTime, Span, scanner_for_languages};
+
+/// Saturday Feb 7, 2026 14:30:00 UTC
+fn now() -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, 7, 14, 30, 0).unwrap()
+}
+
+/// Instant on the given day of February 2026, given as (day, hour, minute,
+/// second, millisecond).
+fn t(d: u32, h: u32, min: u32, sec: u32, ms: i64) -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, d, h, min, sec).unwrap() + Duration::milliseconds(ms)
+}
+
+/// Test expecting exactly one match of the given kind covering the whole
+/// input.
+macro_rules! sec_test {
+    ($name:ident, $lang:expr, $input:expr, $kind:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            let s = scanner_for_languages(&[$lang]);
+            let m = s.scan($input, now());
+            assert_eq!(
+                m.len(),
+                1,
+                "expected 1 match for {:?}, got {}",
+                $input,
+                m.len()
+            );
+            assert_eq!(m[0].kind, $kind);
+            assert_eq!(m[0].span, Span::new(0, $input.len()));
+            assert_eq!(m[0].resolved, $expected);
+        }
+    };
+}
+
+// ================================================================
+//  Points
+// ================================================================
+
+sec_test!(
+    en_at_seconds,
+    "en",
+    "at 15:30:45",
+    ExpressionKind::TimeSpecification,
+    ResolvedTime::Point(t(7, 15, 30, 45, 0))
+);
+sec_test!(
+    en_fraction_with_pm,
+    "en",
+    "at 3:30:15.25 pm",
+    ExpressionKind::TimeSpecification,
+    ResolvedTime::Point(t(7, 15, 30, 15, 250))
+);
+sec_test!(
+    en_tomorrow_at_seconds,
+    "en",
+    "tomorrow at 09:00:30",
+    ExpressionKind::Combined,
+    ResolvedTime::Point(t(8, 9, 0, 30, 0))
+);
+sec_test!(
+    de_um_seconds_uhr,
+    "de",
+    "um 09:00:30 Uhr",
+    ExpressionKind::TimeSpecification,
+    ResolvedTime::Point(t(7, 9, 0, 30, 0))
+);
+sec_test!(
+    de_weekday_um_seconds,
+    "de",
+    "Montag um 9:00:30",
+    ExpressionKind::Combined,
+    ResolvedTime::Point(t(9, 9, 0, 30, 0))
+);
+sec_test!(
+    fr_a_seconds,
+    "fr",
+    "à 13:30:15",
+    ExpressionKind::TimeSpecification,
+    ResolvedTime::Point(t(7, 13, 30, 15, 0))
+);
+sec_test!(
+    es_decimal_comma,
+    "es",
+    "a las 13:30:15,5",
+    ExpressionKind::TimeSpecification,
+    ResolvedTime::Point(t(7, 13, 30, 15, 500))
+);
+
+// ================================================================
+//  Ranges
+// ================================================================
+
+sec_test!(
+    en_from_to_seconds,
+    "en",
+    "from 09:15:00 to 09:45:30",
+    ExpressionKind::TimeRange,
+    ResolvedTime::Range {
+        start: t(7, 9, 15, 0, 0),
+        end: t(7, 9, 45, 30, 0),
+    }
+);
+sec_test!(
+    en_weekday_dash_seconds,
+    "en",
+    "next Monday 9:00:10-10:00",
+    ExpressionKind::Combined,
+    ResolvedTime::Range {
+        start: t(16, 9, 0, 10, 0),
+        end: t(16, 10, 0, 0, 0),
+    }
+);
+sec_test!(
+    en_duration_until_seconds,
+    "en",
+    "2 hours until 15:30:45",
+    ExpressionKind::TimeRange,
+    ResolvedTime::Range {
+        start: t(7, 13, 30, 45, 0),
+        end: t(7, 15, 30, 45, 0),
+    }
+);
+sec_test!(
+    de_von_bis_seconds,
+    "de",
+    "von 9:00:15 bis 10:00 Uhr",
+    ExpressionKind::TimeRange,
+    ResolvedTime::Range {
+        start: t(7, 9, 0, 15, 0),
+        end: t(7, 10, 0, 0, 0),
+    }
+);
+sec_test!(
+    fr_de_a_seconds,
+    "fr",
+    "de 10:00:05 à 11:00:10.125",
+    ExpressionKind::TimeRange,
+    ResolvedTime::Range {
+        start: t(7, 10, 0, 5, 0),
+        end: t(7, 11, 0, 10, 125),
+    }
+);
+sec_test!(
+    es_de_las_a_las_seconds,
+    "es",
+    "de las 9:00:01 a las 10:00:02",
+    ExpressionKind::TimeRange,
+    ResolvedTime::Range {
+        start: t(7, 9, 0, 1, 0),
+        end: t(7, 10, 0, 2, 0),
+    }
+);
+
+// ================================================================
+//  Recurrence and invalid input
+// ================================================================
+
+#[test]
+fn recurrence_keeps_seconds() {
+    let s = scanner_for_languages(&["en"]);
+    let m = s.scan("daily at 9:00:30", now());
+    let ResolvedTime::Recurrence(r) = &m[0].resolved else {
+        panic!("expected a recurrence, got {:?}", m[0].resolved);
+    };
+    assert_eq!(r.time, chrono::NaiveTime::from_hms_opt(9, 0, 30));
+}
+
+#[test]
+fn invalid_seconds() {
+    let s = scanner_for_languages(&["en"]);
+    assert!(s.scan("at 15:30:60", now()).is_empty());
+}
@@ -0,0 +1,315 @@
+use chrono::{TimeZone, Utc};
+use clockwords::{ExpressionKind, ResolvedTime, Span, scanner_for_languages};
+
+/// Saturday Feb 7, 2026 14:30:00 UTC
+fn now() -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, 7, 14, 30, 0).unwrap()
+}
+
+/// Point in time given as (day of February 2026, hour, minute).
+fn point(day: u32, hour: u32, min: u32) -> ResolvedTime {
+    ResolvedTime::Point(Utc.with_ymd_and_hms(2026, 2, day, hour, min, 0).unwrap())
+}
+
+/// Test expecting exactly one match of the given kind covering the whole input.
+macro_rules! spoken_test {
+    ($name:ident, $lang:expr, $input:expr, $kind:expr, $at:expr) => {
+        #[test]
+        fn $name() {
+            let s = scanner_for_languages(&[$lang]);
+            let m = s.scan($input, now());
+            assert_eq!(
+                m.len(),
+                1,
+                "expected 1 match for {:?}, got {}",
+                $input,
+                m.len()
+            );
+            assert_eq!(m[0].kind, $kind);
+            assert_eq!(m[0].span, Span::new(0, $input.len()));
+            assert_eq!(m[0].resolved, point($at.0, $at.1, $at.2));
+        }
+    };
+}
+
+// ================================================================
+//  English
+// ================================================================
+
+spoken_test!(
+    en_half_past_three,
+    "en",
+    "half past three",
+    ExpressionKind::TimeSpecification,
+    (7, 3, 30)
+);
+spoken_test!(
+    en_quarter_to_five_pm,
+    "en",
+    "quarter to five pm",
+    ExpressionKind::TimeSpecification,
+    (7, 16, 45)
+);
+spoken_test!(
+    en_at_ten_to_nine,
+    "en",
+    "at ten to nine",
+    ExpressionKind::TimeSpecification,
+    (7, 8, 50)
+);
+spoken_test!(
+    en_twenty_five_past_ten,
+    "en",
+    "twenty-five past 10",
+    ExpressionKind::TimeSpecification,
+    (7, 10, 25)
+);
+spoken_test!(
+    en_quarter_to_one,
+    "en",
+    "a quarter to one",
+    ExpressionKind::TimeSpecification,
+    (7, 12, 45)
+);
+spoken_test!(
+    en_yesterday_at_half_past_three,
+    "en",
+    "yesterday at half past 3pm",
+    ExpressionKind::Combined,
+    (6, 15, 30)
+);
+spoken_test!(
+    en_last_friday_at_quarter_past_nine,
+    "en",
+    "last Friday at quarter past nine",
+    ExpressionKind::Combined,
+    (6, 9, 15)
+);
+
+#[test]
+fn en_bare_number_range_is_not_a_reading() {
+    let s = scanner_for_languages(&["en"]);
+    assert!(
+        s.scan("nine to five", now())
+            .iter()
+            .all(|m| m.kind != ExpressionKind::TimeSpecification)
+    );
+}
+
+// ================================================================
+//  German
+// ================================================================
+
+spoken_test!(
+    de_halb_vier,
+    "de",
+    "halb vier",
+    ExpressionKind::TimeSpecification,
+    (7, 3, 30)
+);
+spoken_test!(
+    de_viertel_nach_drei,
+    "de",
+    "viertel nach drei",
+    ExpressionKind::TimeSpecification,
+    (7, 3, 15)
+);
+spoken_test!(
+    de_dreiviertel_vier,
+    "de",
+    "dreiviertel vier",
+    ExpressionKind::TimeSpecification,
+    (7, 3, 45)
+);
+spoken_test!(
+    de_viertel_vier,
+    "de",
+    "um viertel vier",
+    ExpressionKind::TimeSpecification,
+    (7, 3, 15)
+);
+spoken_test!(
+    de_fuenf_vor_halb_vier,
+    "de",
+    "fünf vor halb vier",
+    ExpressionKind::TimeSpecification,
+    (7, 3, 25)
+);
+spoken_test!(
+    de_15_uhr_30,
+    "de",
+    "15 Uhr 30",
+    ExpressionKind::TimeSpecification,
+    (7, 15, 30)
+);
+spoken_test!(
+    de_halb_eins,
+    "de",
+    "halb eins",
+    ExpressionKind::TimeSpecification,
+    (7, 12, 30)
+);
+spoken_test!(
+    de_gestern_um_halb_neun,
+    "de",
+    "gestern um halb neun",
+    ExpressionKind::Combined,
+    (6, 8, 30)
+);
+spoken_test!(
+    de_letzten_freitag_um_15_uhr_30,
+    "de",
+    "letzten Freitag um 15 Uhr 30",
+    ExpressionKind::Combined,
+    (6, 15, 30)
+);
+
+// ================================================================
+//  French
+// ================================================================
+
+spoken_test!(
+    fr_trois_heures_et_quart,
+    "fr",
+    "à trois heures et quart",
+    ExpressionKind::TimeSpecification,
+    (7, 3, 15)
+);
+spoken_test!(
+    fr_cinq_heures_moins_le_quart,
+    "fr",
+    "à cinq heures moins le quart",
+    ExpressionKind::TimeSpecification,
+    (7, 4, 45)
+);
+spoken_test!(
+    fr_15_heures_30,
+    "fr",
+    "à 15 heures 30",
+    ExpressionKind::TimeSpecification,
+    (7, 15, 30)
+);
+spoken_test!(
+    fr_bare_trois_heures_et_quart,
+    "fr",
+    "trois heures et quart",
+    ExpressionKind::TimeSpecification,
+    (7, 3, 15)
+);
+spoken_test!(
+    fr_bare_cinq_heures_moins_le_quart,
+    "fr",
+    "cinq heures moins le quart",
+    ExpressionKind::TimeSpecification,
+    (7, 4, 45)
+);
+spoken_test!(
+    fr_bare_15_heures_30,
+    "fr",
+    "15 heures 30",
+    ExpressionKind::TimeSpecification,
+    (7, 15, 30)
+);
+spoken_test!(
+    fr_dix_heures_moins_vingt_cinq,
+    "fr",
+    "à dix heures moins vingt-cinq",
+    ExpressionKind::TimeSpecification,
+    (7, 9, 35)
+);
+spoken_test!(
+    fr_demain_a_neuf_heures_et_demie,
+    "fr",
+    "demain à 9 heures et demie",
+    ExpressionKind::Combined,
+    (8, 9, 30)
+);
+spoken_test!(
+    fr_vendredi_dernier,
+    "fr",
+    "vendredi dernier à 15 heures 45",
+    ExpressionKind::Combined,
+    (6, 15, 45)
+);
+
+// ================================================================
+//  Spanish
+// ================================================================
+
+spoken_test!(
+    es_las_tres_y_media,
+    "es",
+    "las tres y media",
+    ExpressionKind::TimeSpecification,
+    (7, 3, 30)
+);
+spoken_test!(
+    es_las_cinco_menos_cuarto,
+    "es",
+    "a las cinco menos cuarto",
+    ExpressionKind::TimeSpecification,
+    (7, 4, 45)
+);
+spoken_test!(
+    es_la_una_y_diez,
+    "es",
+    "la una y diez",
+    ExpressionKind::TimeSpecification,
+    (7, 1, 10)
+);
+spoken_test!(
+    es_ayer_a_las_nueve_y_cuarto,
+    "es",
+    "ayer a las nueve y cuarto",
+    ExpressionKind::Combined,
+    (6, 9, 15)
+);
+spoken_test!(
+    es_el_viernes_pasado,
+    "es",
+    "el viernes pasado a las 3 y media",
+    ExpressionKind::Combined,
+    (6, 3, 30)
+);
+
+// ================================================================
+//  Interaction with other expressions
+// ================================================================
+
+#[test]
+fn spelled_clock_readings_win_over_durations() {
+    let s = scanner_for_languages(&["fr"]);
+    for (text, at) in [
+        ("deux heures et quart", (7, 2, 15)),
+        ("2 heures 30", (7, 2, 30)),
+    ] {
+        let m = s.scan(text, now());
+        assert_eq!(m.len(), 1, "{text}");
+        assert_eq!(m[0].kind, ExpressionKind::TimeSpecification, "{text}");
+        assert_eq!(m[0].resolved, point(at.0, at.1, at.2), "{text}");
+    }
+}
+
+#[test]
+fn spelled_durations_stay_durations() {
+    let s = scanner_for_languages(&["fr", "es"]);
+    for text in [
+        "pendant deux heures et quart",
+        "une heure et demie",
+        "hora y media",
+        "durant 2 heures 30",
+    ] {
+        let m = s.scan(text, now());
+        assert_eq!(m.len(), 1, "{text}");
+        assert_eq!(m[0].kind, ExpressionKind::Duration, "{text}");
+    }
+}
+
+#[test]
+fn spoken_time_in_sentence() {
+    let s = scanner_for_languages(&["de"]);
+    let text = "Meeting um viertel nach zehn im Büro";
+    let m = s.scan(text, now());
+    assert_eq!(m.len(), 1);
+    assert_eq!(&text[m[0].span.as_range()], "um viertel nach zehn");
+}
@@ -0,0 +1,153 @@
+use chrono::{Duration, TimeZone, Utc};
+use clockwords::{ExpressionKind, ResolvedTime, Span, scanner_for_languages};
+
+/// Saturday Feb 7, 2026 14:30:00 UTC
+fn now() -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, 7, 14, 30, 0).unwrap()
+}
+
+fn day(y: i32, m: u32, d: u32) -> ResolvedTime {
+    let start = Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap();
+    ResolvedTime::Range {
+        start,
+        end: start + Duration::days(1),
+    }
+}
+
+/// Test expecting exactly one sub-day offset resolving to `now + $delta`.
+macro_rules! time_offset_test {
+    ($name:ident, $lang:expr, $input:expr, $delta:expr) => {
+        #[test]
+        fn $name() {
+            let s = scanner_for_languages(&[$lang]);
+            let m = s.scan($input, now());
+            assert_eq!(
+                m.len(),
+                1,
+                "expected 1 match for {:?}, got {}",
+                $input,
+                m.len()
+            );
+            assert_eq!(m[0].kind, ExpressionKind::RelativeTimeOffset);
+            assert_eq!(m[0].span, Span::new(0, $input.len()));
+            assert_eq!(m[0].resolved, ResolvedTime::Point(now() + $delta));
+        }
+    };
+}
+
+/// Test expecting exactly one day-or-longer offset covering the given day.
+macro_rules! day_offset_test {
+    ($name:ident, $lang:expr, $input:expr, $y:expr, $m:expr, $d:expr) => {
+        #[test]
+        fn $name() {
+            let s = scanner_for_languages(&[$lang]);
+            let m = s.scan($input, now());
+            assert_eq!(
+                m.len(),
+                1,
+                "expected 1 match for {:?}, got {}",
+                $input,
+                m.len()
+            );
+            assert_eq!(m[0].kind, ExpressionKind::RelativeDayOffset);
+            assert_eq!(m[0].span, Span::new(0, $input.len()));
+            assert_eq!(m[0].resolved, day($y, $m, $d));
+        }
+    };
+}
+
+// ================================================================
+//  English
+// ================================================================
+
+time_offset_test!(
+    en_in_30_minutes,
+    "en",
+    "in 30 minutes",
+    Duration::minutes(30)
+);
+time_offset_test!(en_in_an_hour, "en", "in an hour", Duration::hours(1));
+time_offset_test!(en_two_hours_ago, "en", "two hours ago", Duration::hours(-2));
+time_offset_test!(en_45_secs_ago, "en", "45 secs ago", Duration::seconds(-45));
+day_offset_test!(en_in_3_weeks, "en", "in 3 weeks", 2026, 2, 28);
+day_offset_test!(en_a_month_ago, "en", "a month ago", 2026, 1, 7);
+day_offset_test!(en_in_two_years, "en", "in two years", 2028, 2, 7);
+
+// ================================================================
+//  German
+// ================================================================
+
+time_offset_test!(de_vor_2_stunden, "de", "vor 2 Stunden", Duration::hours(-2));
+time_offset_test!(
+    de_in_einer_minute,
+    "de",
+    "in einer Minute",
+    Duration::minutes(1)
+);
+day_offset_test!(de_vor_einem_tag, "de", "vor einem Tag", 2026, 2, 6);
+day_offset_test!(de_in_zwei_wochen, "de", "in zwei Wochen", 2026, 2, 21);
+day_offset_test!(de_vor_einem_monat, "de", "vor einem Monat", 2026, 1, 7);
+day_offset_test!(de_in_3_jahren, "de", "in 3 Jahren", 2029, 2, 7);
+
+// ================================================================
+//  French
+// ================================================================
+
+time_offset_test!(
+    fr_dans_10_secondes,
+    "fr",
+    "dans 10 secondes",
+    Duration::seconds(10)
+);
+time_offset_test!(
+    fr_il_y_a_une_heure,
+    "fr",
+    "il y a une heure",
+    Duration::hours(-1)
+);
+day_offset_test!(fr_dans_3_semaines, "fr", "dans 3 semaines", 2026, 2, 28);
+day_offset_test!(fr_il_y_a_un_mois, "fr", "il y a un mois", 2026, 1, 7);
+day_offset_test!(fr_dans_deux_ans, "fr", "dans deux ans", 2028, 2, 7);
+
+// ================================================================
+//  Spanish
+// ================================================================
+
+time_offset_test!(es_hace_2_horas, "es", "hace 2 horas", Duration::hours(-2));
+time_offset_test!(
+    es_dentro_de_una_hora,
+    "es",
+    "dentro de una hora",
+    Duration::hours(1)
+);
+time_offset_test!(
+    es_en_30_minutos,
+    "es",
+    "en 30 minutos",
+    Duration::minutes(30)
+);
+day_offset_test!(es_hace_un_mes, "es", "hace un mes", 2026, 1, 7);
+day_offset_test!(es_en_dos_semanas, "es", "en dos semanas", 2026, 2, 21);
+day_offset_test!(es_hace_un_ano, "es", "hace un año", 2025, 2, 7);
+
+// ================================================================
+//  Month clamping
+// ================================================================
+
+#[test]
+fn month_offset_clamps_to_end_of_month() {
+    let s = scanner_for_languages(&["en"]);
+    let jan_31 = Utc.with_ymd_and_hms(2026, 1, 31, 12, 0, 0).unwrap();
+    let m = s.scan("in a month", jan_31);
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].resolved, day(2026, 2, 28));
+}
+
+#[test]
+fn year_offset_from_leap_day_clamps() {
+    let s = scanner_for_languages(&["en"]);
+    let leap_day = Utc.with_ymd_and_hms(2028, 2, 29, 12, 0, 0).unwrap();
+    let m = s.scan("1 year ago", leap_day);
+    assert_eq!(m.len(), 1);
+    assert_eq!(m[0].resolved, day(2027, 2, 28));
+}
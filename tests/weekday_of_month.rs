@@ -0,0 +1,238 @@
+use chrono::{NaiveDate, TimeZone, Utc, Weekday};
+use clockwords::resolve::nth_weekday_of_month;
+use clockwords::{ExpressionKind, ResolvedTime, Span, scanner_for_languages};
+
+/// Saturday Feb 7, 2026 14:30:00 UTC
+fn now() -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, 7, 14, 30, 0).unwrap()
+}
+
+/// Full-day range for the given date.
+fn day(y: i32, m: u32, d: u32) -> ResolvedTime {
+    let start = Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap();
+    ResolvedTime::Range {
+        start,
+        end: start + chrono::Duration::days(1),
+    }
+}
+
+/// Point in time for the given date and hour.
+fn at(y: i32, m: u32, d: u32, h: u32) -> ResolvedTime {
+    ResolvedTime::Point(Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap())
+}
+
+/// Test expecting exactly one match of the given kind covering the whole input.
+macro_rules! wom_test {
+    ($name:ident, $lang:expr, $input:expr, $kind:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            let s = scanner_for_languages(&[$lang]);
+            let m = s.scan($input, now());
+            assert_eq!(
+                m.len(),
+                1,
+                "expected 1 match for {:?}, got {}",
+                $input,
+                m.len()
+            );
+            assert_eq!(m[0].kind, $kind);
+            assert_eq!(m[0].span, Span::new(0, $input.len()));
+            assert_eq!(m[0].resolved, $expected);
+        }
+    };
+}
+
+// ================================================================
+//  Resolver
+// ================================================================
+
+#[test]
+fn nth_weekday() {
+    let d = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+    assert_eq!(
+        nth_weekday_of_month(2026, 3, Weekday::Mon, 1),
+        d(2026, 3, 2)
+    );
+    assert_eq!(
+        nth_weekday_of_month(2026, 3, Weekday::Sun, 1),
+        d(2026, 3, 1)
+    );
+    assert_eq!(
+        nth_weekday_of_month(2026, 5, Weekday::Tue, 2),
+        d(2026, 5, 12)
+    );
+    assert_eq!(
+        nth_weekday_of_month(2026, 3, Weekday::Fri, -1),
+        d(2026, 3, 27)
+    );
+    assert_eq!(
+        nth_weekday_of_month(2026, 2, Weekday::Sat, -1),
+        d(2026, 2, 28)
+    );
+    assert_eq!(
+        nth_weekday_of_month(2026, 12, Weekday::Thu, -1),
+        d(2026, 12, 31)
+    );
+    assert_eq!(nth_weekday_of_month(2026, 2, Weekday::Mon, 5), None);
+    assert_eq!(nth_weekday_of_month(2026, 2, Weekday::Mon, 0), None);
+}
+
+// ================================================================
+//  English
+// ================================================================
+
+wom_test!(
+    en_first_monday_of_march,
+    "en",
+    "the first Monday of March",
+    ExpressionKind::WeekdayOfMonth,
+    day(2026, 3, 2)
+);
+wom_test!(
+    en_last_friday_of_the_month,
+    "en",
+    "last Friday of the month",
+    ExpressionKind::WeekdayOfMonth,
+    day(2026, 2, 27)
+);
+wom_test!(
+    en_second_tuesday_of_next_month,
+    "en",
+    "the second Tuesday of next month",
+    ExpressionKind::WeekdayOfMonth,
+    day(2026, 3, 10)
+);
+wom_test!(
+    en_first_monday_of_last_month,
+    "en",
+    "the 1st Monday of last month",
+    ExpressionKind::WeekdayOfMonth,
+    day(2026, 1, 5)
+);
+wom_test!(
+    en_third_thursday_in_may_with_year,
+    "en",
+    "the 3rd Thursday in May 2027",
+    ExpressionKind::WeekdayOfMonth,
+    day(2027, 5, 20)
+);
+wom_test!(
+    en_first_monday_of_march_at_10am,
+    "en",
+    "the first Monday of March at 10am",
+    ExpressionKind::Combined,
+    at(2026, 3, 2, 10)
+);
+
+#[test]
+fn en_fifth_monday_of_february_does_not_exist() {
+    let s = scanner_for_languages(&["en"]);
+    let m = s.scan("the fifth Monday of February 2026", now());
+    assert!(m.iter().all(|m| m.kind != ExpressionKind::WeekdayOfMonth));
+}
+
+// ================================================================
+//  German
+// ================================================================
+
+wom_test!(
+    de_zweiten_dienstag_im_mai,
+    "de",
+    "am zweiten Dienstag im Mai",
+    ExpressionKind::WeekdayOfMonth,
+    day(2026, 5, 12)
+);
+wom_test!(
+    de_letzter_freitag_des_monats,
+    "de",
+    "letzter Freitag des Monats",
+    ExpressionKind::WeekdayOfMonth,
+    day(2026, 2, 27)
+);
+wom_test!(
+    de_1_montag_im_naechsten_monat,
+    "de",
+    "am 1. Montag im nächsten Monat",
+    ExpressionKind::WeekdayOfMonth,
+    day(2026, 3, 2)
+);
+wom_test!(
+    de_ersten_montag_im_maerz_um_10,
+    "de",
+    "am ersten Montag im März um 10 Uhr",
+    ExpressionKind::Combined,
+    at(2026, 3, 2, 10)
+);
+
+// ================================================================
+//  French
+// ================================================================
+
+wom_test!(
+    fr_premier_lundi_du_mois,
+    "fr",
+    "le premier lundi du mois",
+    ExpressionKind::WeekdayOfMonth,
+    day(2026, 2, 2)
+);
+wom_test!(
+    fr_dernier_vendredi_de_mars,
+    "fr",
+    "le dernier vendredi de mars",
+    ExpressionKind::WeekdayOfMonth,
+    day(2026, 3, 27)
+);
+wom_test!(
+    fr_2e_mardi_du_mois_prochain,
+    "fr",
+    "le 2e mardi du mois prochain",
+    ExpressionKind::WeekdayOfMonth,
+    day(2026, 3, 10)
+);
+wom_test!(
+    fr_premier_lundi_d_avril,
+    "fr",
+    "le premier lundi d'avril",
+    ExpressionKind::WeekdayOfMonth,
+    day(2026, 4, 6)
+);
+wom_test!(
+    fr_premier_lundi_du_mois_a_10h,
+    "fr",
+    "le premier lundi du mois à 10h",
+    ExpressionKind::Combined,
+    at(2026, 2, 2, 10)
+);
+
+// ================================================================
+//  Spanish
+// ================================================================
+
+wom_test!(
+    es_ultimo_viernes_de_marzo,
+    "es",
+    "el último viernes de marzo",
+    ExpressionKind::WeekdayOfMonth,
+    day(2026, 3, 27)
+);
+wom_test!(
+    es_primer_lunes_del_mes,
+    "es",
+    "el primer lunes del mes",
+    ExpressionKind::WeekdayOfMonth,
+    day(2026, 2, 2)
+);
+wom_test!(
+    es_segundo_martes_del_mes_que_viene,
+    "es",
+    "el segundo martes del mes que viene",
+    ExpressionKind::WeekdayOfMonth,
+    day(2026, 3, 10)
+);
+wom_test!(
+    es_ultimo_viernes_de_marzo_a_las_16,
+    "es",
+    "el último viernes de marzo a las 16:00",
+    ExpressionKind::Combined,
+    at(2026, 3, 27, 16)
+);
@@ -0,0 +1,303 @@
+use chrono::{TimeZone, Utc};
+use clockwords::{
+    ExpressionKind, ParserConfig, ResolvedTime, Span, TimeExpressionScanner, Tz,
+    scanner_for_languages,
+};
+
+/// Saturday Feb 7, 2026 14:30:00 UTC
+fn now() -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 2, 7, 14, 30, 0).unwrap()
+}
+
+/// Point in time on the given day of February 2026, in UTC.
+fn at(d: u32, h: u32, min: u32) -> ResolvedTime {
+    ResolvedTime::Point(Utc.with_ymd_and_hms(2026, 2, d, h, min, 0).unwrap())
+}
+
+/// Test expecting exactly one match covering the whole input, resolved in
+/// the mentioned zone.
+macro_rules! zone_test {
+    ($name:ident, $lang:expr, $input:expr, $kind:expr, $zone:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            let s = scanner_for_languages(&[$lang]);
+            let m = s.scan($input, now());
+            assert_eq!(
+                m.len(),
+                1,
+                "expected 1 match for {:?}, got {}",
+                $input,
+                m.len()
+            );
+            assert_eq!(m[0].kind, $kind);
+            assert_eq!(m[0].span, Span::new(0, $input.len()));
+            assert_eq!(m[0].timezone, Some($zone));
+            assert_eq!(m[0].resolved, $expected);
+        }
+    };
+}
+
+// ================================================================
+//  Abbreviations
+// ================================================================
+
+zone_test!(
+    en_pst,
+    "en",
+    "at 9am PST",
+    ExpressionKind::TimeSpecification,
+    Tz::Etc__GMTPlus8,
+    at(7, 17, 0)
+);
+zone_test!(
+    en_tomorrow_cet,
+    "en",
+    "tomorrow at 3pm CET",
+    ExpressionKind::Combined,
+    Tz::Etc__GMTMinus1,
+    at(8, 14, 0)
+);
+zone_test!(
+    en_range_est,
+    "en",
+    "from 9am to 5pm EST",
+    ExpressionKind::TimeRange,
+    Tz::Etc__GMTPlus5,
+    ResolvedTime::Range {
+        start: Utc.with_ymd_and_hms(2026, 2, 7, 14, 0, 0).unwrap(),
+        end: Utc.with_ymd_and_hms(2026, 2, 7, 22, 0, 0).unwrap(),
+    }
+);
+zone_test!(
+    de_mesz,
+    "de",
+    "um 15 Uhr MESZ",
+    ExpressionKind::TimeSpecification,
+    Tz::Etc__GMTMinus2,
+    at(7, 13, 0)
+);
+zone_test!(
+    es_cet,
+    "es",
+    "a las 3 CET",
+    ExpressionKind::TimeSpecification,
+    Tz::Etc__GMTMinus1,
+    at(7, 2, 0)
+);
+zone_test!(
+    en_region_time,
+    "en",
+    "at 3pm ET",
+    ExpressionKind::TimeSpecification,
+    Tz::America__New_York,
+    at(7, 20, 0)
+);
+
+#[test]
+fn abbreviation_names_a_fixed_offset() {
+    let s = scanner_for_languages(&["en"]);
+    let october = Utc.with_ymd_and_hms(2026, 10, 14, 12, 0, 0).unwrap();
+    let m = s.scan("at 3pm CET", october);
+    assert_eq!(
+        m[0].resolved,
+        ResolvedTime::Point(Utc.with_ymd_and_hms(2026, 10, 14, 14, 0, 0).unwrap())
+    );
+    let m = s.scan("at 9am PST", october);
+    assert_eq!(
+        m[0].resolved,
+        ResolvedTime::Point(Utc.with_ymd_and_hms(2026, 10, 14, 17, 0, 0).unwrap())
+    );
+    let m = s.scan("at 9am PDT", now());
+    assert_eq!(m[0].resolved, at(7, 16, 0));
+}
+
+// ================================================================
+//  IANA names and UTC offsets
+// ================================================================
+
+zone_test!(
+    en_iana_name,
+    "en",
+    "at 3pm Europe/London",
+    ExpressionKind::TimeSpecification,
+    Tz::Europe__London,
+    at(7, 15, 0)
+);
+zone_test!(
+    en_utc_plus_2,
+    "en",
+    "at 15:00 UTC+2",
+    ExpressionKind::TimeSpecification,
+    Tz::Etc__GMTMinus2,
+    at(7, 13, 0)
+);
+zone_test!(
+    en_utc_plus_5_30,
+    "en",
+    "at 9:30 UTC+5:30",
+    ExpressionKind::TimeSpecification,
+    Tz::Asia__Kolkata,
+    at(7, 4, 0)
+);
+zone_test!(
+    en_utc_plus_05_45,
+    "en",
+    "at 9:00 UTC+05:45",
+    ExpressionKind::TimeSpecification,
+    Tz::Asia__Kathmandu,
+    at(7, 3, 15)
+);
+zone_test!(
+    fr_utc_minus_3,
+    "fr",
+    "demain à 9h UTC−3",
+    ExpressionKind::Combined,
+    Tz::Etc__GMTPlus3,
+    at(8, 12, 0)
+);
+zone_test!(
+    fr_bare_utc_plus_2,
+    "fr",
+    "14h UTC+2",
+    ExpressionKind::TimeSpecification,
+    Tz::Etc__GMTMinus2,
+    at(7, 12, 0)
+);
+
+// ================================================================
+//  City phrases
+// ================================================================
+
+zone_test!(
+    en_new_york_time,
+    "en",
+    "at 9am New York time",
+    ExpressionKind::TimeSpecification,
+    Tz::America__New_York,
+    at(7, 14, 0)
+);
+zone_test!(
+    en_pacific_time,
+    "en",
+    "at 10am Pacific time",
+    ExpressionKind::TimeSpecification,
+    Tz::America__Los_Angeles,
+    at(7, 18, 0)
+);
+zone_test!(
+    de_new_yorker_zeit,
+    "de",
+    "morgen um 15 Uhr New Yorker Zeit",
+    ExpressionKind::Combined,
+    Tz::America__New_York,
+    at(8, 20, 0)
+);
+zone_test!(
+    fr_heure_de_paris,
+    "fr",
+    "à 15h heure de Paris",
+    ExpressionKind::TimeSpecification,
+    Tz::Europe__Paris,
+    at(7, 14, 0)
+);
+zone_test!(
+    fr_bare_heure_de_paris,
+    "fr",
+    "15h heure de Paris",
+    ExpressionKind::TimeSpecification,
+    Tz::Europe__Paris,
+    at(7, 14, 0)
+);
+zone_test!(
+    es_hora_de_nueva_york,
+    "es",
+    "a las 9 hora de Nueva York",
+    ExpressionKind::TimeSpecification,
+    Tz::America__New_York,
+    at(7, 14, 0)
+);
+
+// ================================================================
+//  Relative days, open ranges and configuration
+// ================================================================
+
+#[test]
+fn relative_day_follows_mentioned_zone() {
+    // Saturday 20:00 UTC is already Sunday in Tokyo
+    let now = Utc.with_ymd_and_hms(2026, 2, 7, 20, 0, 0).unwrap();
+    let s = scanner_for_languages(&["en"]);
+    let m = s.scan("tomorrow at 9am JST", now);
+    assert_eq!(m[0].timezone, Some(Tz::Etc__GMTMinus9));
+    assert_eq!(
+        m[0].resolved,
+        ResolvedTime::Point(Utc.with_ymd_and_hms(2026, 2, 9, 0, 0, 0).unwrap())
+    );
+}
+
+#[test]
+fn open_range_with_zone() {
+    let s = scanner_for_languages(&["en"]);
+    let m = s.scan("until 5pm PST", now());
+    assert_eq!(m[0].kind, ExpressionKind::OpenRange);
+    assert_eq!(m[0].span, Span::new(0, 13));
+    assert_eq!(
+        m[0].resolved,
+        ResolvedTime::Until(Utc.with_ymd_and_hms(2026, 2, 8, 1, 0, 0).unwrap())
+    );
+}
+
+#[test]
+fn zone_name_is_kept_as_written() {
+    for (lang, text, written) in [
+        ("en", "at 9am PST", "PST"),
+        ("de", "um 15 Uhr MESZ", "MESZ"),
+        ("fr", "14h UTC+2", "UTC+2"),
+        ("en", "at 3pm Europe/London", "Europe/London"),
+        ("en", "at 9am New York time", "New York time"),
+    ] {
+        let s = scanner_for_languages(&[lang]);
+        let m = s.scan(text, now());
+        assert_eq!(m.len(), 1, "{text:?}");
+        assert_eq!(m[0].zone_name.as_deref(), Some(written), "{text:?}");
+    }
+    let m = scanner_for_languages(&["en"]).scan("at 9am PST", now());
+    assert_eq!(m[0].timezone, Some(Tz::Etc__GMTPlus8));
+}
+
+#[test]
+fn configured_abbreviation_overrides_table() {
+    let config = ParserConfig {
+        zone_abbreviations: vec![("CST".to_string(), Tz::Asia__Shanghai)],
+        ..ParserConfig::default()
+    };
+    let s =
+        TimeExpressionScanner::new(vec![Box::new(clockwords::lang::en::English::new())], config);
+    let m = s.scan("at 9am CST", now());
+    assert_eq!(m[0].timezone, Some(Tz::Asia__Shanghai));
+    assert_eq!(m[0].resolved, at(7, 1, 0));
+}
+
+#[test]
+fn unknown_zone_is_ignored() {
+    let s = scanner_for_languages(&["en"]);
+    let m = s.scan("at 3pm ABC", now());
+    assert_eq!(m[0].span, Span::new(0, 6));
+    assert_eq!(m[0].timezone, None);
+    assert_eq!(m[0].zone_name, None);
+    assert_eq!(m[0].resolved, at(7, 15, 0));
+}
+
+#[test]
+fn offset_without_zone_is_rejected() {
+    let s = scanner_for_languages(&["en"]);
+    assert!(s.scan("at 9:00 UTC+03:30", now()).is_empty());
+    assert!(s.scan("at 9:00 UTC+15", now()).is_empty());
+}
+
+#[test]
+fn lowercase_word_is_not_an_abbreviation() {
+    let s = scanner_for_languages(&["en"]);
+    let m = s.scan("at 3pm est", now());
+    assert_eq!(m[0].span, Span::new(0, 6));
+    assert_eq!(m[0].timezone, None);
+}